@@ -1,3 +1,7 @@
+use crate::config::LocMode;
+use crate::response::GateFinding;
+use crate::swamped::SwampedSummary;
+use crate::validate_insights::{BaselineDiff, CheckError, PartialRunSummary};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -160,6 +164,12 @@ pub struct DecisionReason {
     pub code: String,
     pub class: ErrorClass,
     pub tier: ViolationTier,
+    /// What produced the reason: `validate`, `gate`, or `tool:<id>`. Gate verdicts only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// File the underlying finding points at, when it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -209,6 +219,9 @@ pub struct ValidateRequest {
     pub baseline_maintenance: Option<BaselineMaintenance>,
     #[serde(default)]
     pub response_mode: Option<ResponseMode>,
+    /// Keep only the top N violations/findings (severity, then code); the verdict still counts all.
+    #[serde(default)]
+    pub max_violations: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -216,6 +229,9 @@ pub struct LocSummary {
     pub files_scanned: usize,
     pub max_loc: usize,
     pub worst_path: Option<String>,
+    /// Counting mode of the check that produced `max_loc`.
+    #[serde(default)]
+    pub loc_mode: LocMode,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -240,6 +256,20 @@ pub enum EffectiveConfigSource {
     Env,
     Default,
     Unset,
+    /// Set by the tool's own `env` table (only in `tools describe --resolved-env`).
+    Tool,
+}
+
+/// One variable of a tool's resolved environment; sensitive values are masked.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolvedEnvVar {
+    pub name: String,
+    pub description: Option<String>,
+    /// Declared in an `[[checks.env_registry]]` registry file.
+    pub registered: bool,
+    pub sensitive: bool,
+    pub source: EffectiveConfigSource,
+    pub value: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -294,6 +324,56 @@ pub struct ValidateOutput {
     pub evidence: EvidenceEnvelope,
     #[serde(default)]
     pub payload_meta: Option<PayloadMeta>,
+    /// Wall time per check block (ms); present only when timings were requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings_ms: Option<BTreeMap<String, u64>>,
+    /// What a baseline write would change; present only for `--baseline-dry-run`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_diff: Option<BaselineDiff>,
+    /// Packs listed in the contract's `disabled_packs` and the checks skipped for each.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_packs: Vec<DisabledPackSummary>,
+    /// Set when only a subset of checks ran (`--only`/`--skip`/`--skip-contract`); the verdict
+    /// then covers just that subset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_run: Option<PartialRunSummary>,
+    /// Checks that could not run (bad config, unreadable baseline, ...), apart from the policy
+    /// violations of checks that did. Each one also stays a blocking `<check>.check_failed`.
+    #[serde(default)]
+    pub check_errors: Vec<CheckError>,
+    /// Set by the CLI/MCP output layer when it collapsed a payload carrying `swamped_summary`:
+    /// `violations`, `findings_v2` and the verdict reasons then hold only the top examples.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub swamped: bool,
+    /// Present when violations exceeded `[reporting] swamped_threshold`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swamped_summary: Option<SwampedSummary>,
+}
+
+/// Check kinds to run in validate; the default (all empty/false) runs everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CheckSelection {
+    /// Run only these check kinds (e.g. `loc`, `boundary`); empty means all.
+    #[serde(default)]
+    pub only: Vec<String>,
+    /// Check kinds to leave out, applied after `only`.
+    #[serde(default)]
+    pub skip: Vec<String>,
+    /// Also skip contract-level checks (mandatory checks, exception budgets, quality_delta).
+    #[serde(default)]
+    pub skip_contract: bool,
+}
+
+impl CheckSelection {
+    pub fn is_full(&self) -> bool {
+        self.only.is_empty() && self.skip.is_empty() && !self.skip_contract
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DisabledPackSummary {
+    pub id: String,
+    pub skipped_check_ids: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -322,6 +402,9 @@ pub struct ToolsListOutput {
 pub struct ToolsDescribeRequest {
     pub repo_root: Option<String>,
     pub tool_id: String,
+    /// Attach `resolved_env`, the effective environment the tool runs with.
+    #[serde(default)]
+    pub resolved_env: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -335,6 +418,8 @@ pub struct ProjectToolSpec {
     pub timeout_ms: u64,
     pub max_stdout_bytes: usize,
     pub max_stderr_bytes: usize,
+    #[serde(default)]
+    pub max_tail_bytes: Option<usize>,
     pub mutability: String,
     #[serde(default)]
     pub compatible_gate_kinds: Vec<String>,
@@ -365,6 +450,8 @@ pub struct ToolsDescribeOutput {
     pub error: Option<ApiError>,
     pub repo_root: String,
     pub tool: Option<ProjectToolSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_env: Option<Vec<ResolvedEnvVar>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -410,7 +497,9 @@ pub struct ToolsRunOutput {
     pub payload_meta: Option<PayloadMeta>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum GateKind {
     CiFast,
@@ -460,6 +549,9 @@ pub struct GateRequest {
     pub wait_ms: Option<u64>,
     #[serde(default)]
     pub response_mode: Option<ResponseMode>,
+    /// Keep each verdict reason's `source` (validate|gate|tool:<id>) and `origin` file.
+    #[serde(default)]
+    pub explain_verdict: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -467,6 +559,9 @@ pub struct WitnessMeta {
     pub path: String,
     pub size_bytes: usize,
     pub sha256: String,
+    /// sha256 of the witness with timings, durations and job timestamps/ids nulled: equal across
+    /// runs that reached the same gate state.
+    pub content_sha256: String,
     pub rotated_files: usize,
 }
 
@@ -495,176 +590,26 @@ pub struct GateOutput {
     pub job_state: Option<GateJobState>,
     #[serde(default)]
     pub job_error: Option<ApiError>,
+    /// validate findings and tool report findings in one list, deduped by code/path/line.
+    #[serde(default)]
+    pub all_findings: Vec<GateFinding>,
+    /// Checks config hash (as locked by `governance.config_hash`) the gate ran against; recorded
+    /// when a witness is written.
+    #[serde(default)]
+    pub config_hash: Option<String>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn gate_kind_rejects_ci_fast_hyphen_alias() {
-        let err = serde_json::from_value::<GateRequest>(serde_json::json!({
-            "repo_root": ".",
-            "kind": "ci-fast",
-            "dry_run": true,
-            "write_witness": false
-        }))
-        .unwrap_err();
-        assert!(
-            err.to_string().contains("unknown variant"),
-            "unexpected parse error: {err}"
-        );
-    }
-
-    #[test]
-    fn validate_request_rejects_unknown_fields() {
-        let err = serde_json::from_value::<ValidateRequest>(serde_json::json!({
-            "repo_root": ".",
-            "mode": "ratchet",
-            "unknown": 1
-        }))
-        .unwrap_err();
-        assert!(err.to_string().contains("unknown field"), "{err}");
-    }
-
-    #[test]
-    fn violation_tier_default_is_blocking() {
-        let v: Violation = serde_json::from_value(serde_json::json!({
-            "code": "test.x",
-            "message": "msg"
-        }))
-        .expect("deserialize Violation without tier");
-        assert_eq!(v.tier, ViolationTier::Blocking);
-    }
-
-    #[test]
-    fn violation_tier_roundtrip() {
-        let v = Violation::observation("test.x", "msg", None, None);
-        let json = serde_json::to_value(&v).unwrap();
-        assert_eq!(json["tier"], "observation");
-        let back: Violation = serde_json::from_value(json).unwrap();
-        assert_eq!(back.tier, ViolationTier::Observation);
-    }
-
-    #[test]
-    fn verdict_roundtrip() {
-        let v = Verdict {
-            decision: Decision {
-                status: DecisionStatus::Blocked,
-                reasons: vec![DecisionReason {
-                    code: "boundary.rule_violation".to_string(),
-                    class: ErrorClass::ContractBreak,
-                    tier: ViolationTier::Blocking,
-                }],
-                blocking_count: 1,
-                observation_count: 0,
-            },
-            quality_posture: Some(QualityPosture {
-                trust_score: 72,
-                trust_grade: "C".to_string(),
-                coverage_covered: 8,
-                coverage_total: 12,
-                weighted_risk: 34,
-                findings_total: 5,
-                risk_by_severity: [("high".to_string(), 2), ("medium".to_string(), 3)]
-                    .into_iter()
-                    .collect(),
-            }),
-            suppressed_count: 2,
-            suppressed_codes: vec![
-                "exception.expired".to_string(),
-                "boundary.rule_violation".to_string(),
-            ],
-        };
-        let json = serde_json::to_value(&v).unwrap();
-        assert_eq!(json["decision"]["status"], "blocked");
-        assert_eq!(json["suppressed_count"], 2);
-        let back: Verdict = serde_json::from_value(json).unwrap();
-        assert_eq!(back.decision.status, DecisionStatus::Blocked);
-        assert_eq!(back.suppressed_count, 2);
-        assert_eq!(back.suppressed_codes.len(), 2);
-    }
-
-    #[test]
-    fn verdict_back_compat_defaults_for_new_fields() {
-        let back: Verdict = serde_json::from_value(serde_json::json!({
-            "decision": {
-                "status": "pass",
-                "reasons": [],
-                "blocking_count": 0,
-                "observation_count": 0
-            }
-        }))
-        .expect("deserialize legacy Verdict payload");
-        assert!(back.quality_posture.is_none());
-        assert_eq!(back.suppressed_count, 0);
-        assert!(back.suppressed_codes.is_empty());
-    }
-
-    #[test]
-    fn quality_posture_roundtrip() {
-        let qp = QualityPosture {
-            trust_score: 85,
-            trust_grade: "B".to_string(),
-            coverage_covered: 8,
-            coverage_total: 10,
-            weighted_risk: 12,
-            findings_total: 3,
-            risk_by_severity: [("high".to_string(), 1), ("medium".to_string(), 2)]
-                .into_iter()
-                .collect(),
-        };
-        let json = serde_json::to_value(&qp).unwrap();
-        let back: QualityPosture = serde_json::from_value(json).unwrap();
-        assert_eq!(back.trust_score, 85);
-    }
-
-    #[test]
-    fn validate_request_with_baseline_maintenance() {
-        let req: ValidateRequest = serde_json::from_value(serde_json::json!({
-            "mode": "ratchet",
-            "write_baseline": true,
-            "baseline_maintenance": {
-                "reason": "Quarterly baseline refresh after major refactor",
-                "owner": "team-lead"
-            }
-        }))
-        .expect("deserialize with baseline_maintenance");
-        assert!(req.baseline_maintenance.is_some());
-        let bm = req.baseline_maintenance.unwrap();
-        assert_eq!(bm.owner, "team-lead");
-    }
-
-    #[test]
-    fn validate_request_without_baseline_maintenance_still_works() {
-        let req: ValidateRequest = serde_json::from_value(serde_json::json!({
-            "mode": "warn"
-        }))
-        .expect("deserialize without baseline_maintenance");
-        assert!(req.baseline_maintenance.is_none());
-    }
-
-    #[test]
-    fn validate_request_response_mode_roundtrip() {
-        let req: ValidateRequest = serde_json::from_value(serde_json::json!({
-            "mode": "ratchet",
-            "response_mode": "full"
-        }))
-        .expect("deserialize ValidateRequest");
-        assert_eq!(req.response_mode, Some(ResponseMode::Full));
-    }
-
-    #[test]
-    fn gate_request_status_mode_parses() {
-        let req: GateRequest = serde_json::from_value(serde_json::json!({
-            "kind": "ci_fast",
-            "op": "status",
-            "job_id": "gate-1",
-            "wait_ms": 2500
-        }))
-        .expect("deserialize GateRequest status");
-        assert_eq!(req.op, Some(GateOp::Status));
-        assert_eq!(req.job_id.as_deref(), Some("gate-1"));
-        assert_eq!(req.wait_ms, Some(2500));
-    }
+/// Result of an explicit prior-baseline migration (`validate --baseline-migrate-only`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BaselineMigrateOutput {
+    pub ok: bool,
+    pub error: Option<ApiError>,
+    pub repo_root: String,
+    /// Repo-relative snapshot path from the quality contract.
+    pub snapshot_path: String,
+    /// The snapshot as written; absent on error.
+    pub snapshot: Option<serde_json::Value>,
 }
+
+#[cfg(test)]
+mod tests;
@@ -1,5 +1,6 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Canonical tool ids: a small, fixed vocabulary to prevent agents from inventing a zoo of tool names.
 ///
@@ -19,7 +20,8 @@ pub enum CanonicalToolId {
 /// Canonical tooling wiring for gates and init.
 ///
 /// - Each field is an ordered list of concrete `tool_id`s to execute for that canonical action.
-/// - `disabled` is required to distinguish “intentionally off” from “forgot to wire”.
+/// - `disabled` is required to distinguish “intentionally off” from “forgot to wire” (enforced by pack validation).
+/// - `aliases` maps an old `tool_id` to its current `tool_id`, so gate sequences keep working across renames.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct CanonicalToolsConfig {
     #[serde(default)]
@@ -32,5 +34,8 @@ pub struct CanonicalToolsConfig {
     pub fmt: Vec<String>,
     #[serde(default)]
     pub docs: Vec<String>,
+    #[serde(default)]
     pub disabled: Vec<CanonicalToolId>,
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
 }
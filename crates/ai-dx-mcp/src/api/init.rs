@@ -1,4 +1,5 @@
 use super::{ApiError, PayloadMeta};
+use crate::init::InitDeletePreview;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -12,8 +13,15 @@ pub struct InitRequest {
     pub registry_source: Option<String>,
     /// Optional pack selection override (e.g., ["builtin:rust", "builtin:node"]).
     pub packs: Option<Vec<String>>,
+    /// Reproduce the builtin packs (ids and versions) recorded in this packs.lock; absolute or
+    /// relative to `repo_root`. Cannot be combined with `packs`.
+    #[serde(default)]
+    pub packs_from: Option<String>,
     /// Optional external packs (pinned by sha256). Download is allowed only during init.
     pub external_packs: Option<Vec<ExternalPackRef>>,
+    /// Append a Markdown rendering of the planned writes/deletes and selected packs to `summary_md`.
+    #[serde(default)]
+    pub summary: Option<bool>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -33,6 +41,10 @@ pub struct InitPlan {
     pub writes: Vec<InitWriteFile>,
     #[serde(default)]
     pub deletes: Vec<String>,
+    /// Files left behind by packs that are no longer selected, each with a reason. Preview only:
+    /// init does not delete them (they may carry local edits); remove them by hand if unused.
+    #[serde(default)]
+    pub deletes_preview: Vec<InitDeletePreview>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
@@ -1,3 +1,4 @@
+use crate::validate_insights::FileHotspot;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -19,6 +20,12 @@ pub struct FindingDetailsV2 {
     pub evidence_refs: Vec<String>,
     pub fix_recipe: Option<String>,
     pub legacy_details: Option<serde_json::Value>,
+    /// Set on allowlist-suppressed findings listed by `validate --include-suppressed`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub suppressed: bool,
+    /// The allowlist exception's `reason` when `suppressed` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppression_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -26,7 +33,17 @@ pub struct FindingV2 {
     pub code: String,
     pub message: String,
     pub path: Option<String>,
+    /// Stable identity across runs: sha256 of code, normalized path and rule context (never
+    /// line numbers), so findings keep their identity when unrelated edits shift lines.
+    #[serde(default)]
+    pub fingerprint: String,
     pub details: FindingDetailsV2,
+    /// When the findings ledger first saw this fingerprint (`[governance] findings_ledger`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<String>,
+    /// HEAD commit at the first sighting, when the repo has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_seen_commit: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -34,6 +51,9 @@ pub struct RiskSummary {
     pub findings_total: usize,
     pub by_category: BTreeMap<String, usize>,
     pub by_severity: BTreeMap<String, usize>,
+    /// Violation code family (text before the first dot), e.g. `boundary` or `supply_chain`.
+    #[serde(default)]
+    pub by_code_prefix: BTreeMap<String, usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -47,6 +67,9 @@ pub struct CoverageSummary {
     pub effective_covered_modes: Vec<String>,
     #[serde(default)]
     pub declared_but_ineffective_modes: Vec<String>,
+    /// Catalog modes declared by enabled packs (`failure_modes` in pack.toml) -> pack ids.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pack_modes: BTreeMap<String, Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -76,6 +99,9 @@ pub struct AgentDigest {
     pub suppressed_count: usize,
     #[serde(default)]
     pub suppressed_top_codes: Vec<String>,
+    /// Files with the most findings (top 3), most findings first, ties by path.
+    #[serde(default)]
+    pub hotspot_files: Vec<FileHotspot>,
 }
 
 #[cfg(test)]
@@ -93,5 +119,6 @@ mod tests {
         .expect("deserialize legacy AgentDigest payload");
         assert_eq!(digest.suppressed_count, 0);
         assert!(digest.suppressed_top_codes.is_empty());
+        assert!(digest.hotspot_files.is_empty());
     }
 }
@@ -0,0 +1,170 @@
+use super::*;
+
+#[test]
+fn gate_kind_rejects_ci_fast_hyphen_alias() {
+    let err = serde_json::from_value::<GateRequest>(serde_json::json!({
+        "repo_root": ".",
+        "kind": "ci-fast",
+        "dry_run": true,
+        "write_witness": false
+    }))
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("unknown variant"),
+        "unexpected parse error: {err}"
+    );
+}
+
+#[test]
+fn validate_request_rejects_unknown_fields() {
+    let err = serde_json::from_value::<ValidateRequest>(serde_json::json!({
+        "repo_root": ".",
+        "mode": "ratchet",
+        "unknown": 1
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("unknown field"), "{err}");
+}
+
+#[test]
+fn violation_tier_default_is_blocking() {
+    let v: Violation = serde_json::from_value(serde_json::json!({
+        "code": "test.x",
+        "message": "msg"
+    }))
+    .expect("deserialize Violation without tier");
+    assert_eq!(v.tier, ViolationTier::Blocking);
+}
+
+#[test]
+fn violation_tier_roundtrip() {
+    let v = Violation::observation("test.x", "msg", None, None);
+    let json = serde_json::to_value(&v).unwrap();
+    assert_eq!(json["tier"], "observation");
+    let back: Violation = serde_json::from_value(json).unwrap();
+    assert_eq!(back.tier, ViolationTier::Observation);
+}
+
+#[test]
+fn verdict_roundtrip() {
+    let v = Verdict {
+        decision: Decision {
+            status: DecisionStatus::Blocked,
+            reasons: vec![DecisionReason {
+                code: "boundary.rule_violation".to_string(),
+                class: ErrorClass::ContractBreak,
+                tier: ViolationTier::Blocking,
+                source: None,
+                origin: None,
+            }],
+            blocking_count: 1,
+            observation_count: 0,
+        },
+        quality_posture: Some(QualityPosture {
+            trust_score: 72,
+            trust_grade: "C".to_string(),
+            coverage_covered: 8,
+            coverage_total: 12,
+            weighted_risk: 34,
+            findings_total: 5,
+            risk_by_severity: [("high".to_string(), 2), ("medium".to_string(), 3)]
+                .into_iter()
+                .collect(),
+        }),
+        suppressed_count: 2,
+        suppressed_codes: vec![
+            "exception.expired".to_string(),
+            "boundary.rule_violation".to_string(),
+        ],
+    };
+    let json = serde_json::to_value(&v).unwrap();
+    assert_eq!(json["decision"]["status"], "blocked");
+    assert_eq!(json["suppressed_count"], 2);
+    let back: Verdict = serde_json::from_value(json).unwrap();
+    assert_eq!(back.decision.status, DecisionStatus::Blocked);
+    assert_eq!(back.suppressed_count, 2);
+    assert_eq!(back.suppressed_codes.len(), 2);
+}
+
+#[test]
+fn verdict_back_compat_defaults_for_new_fields() {
+    let back: Verdict = serde_json::from_value(serde_json::json!({
+        "decision": {
+            "status": "pass",
+            "reasons": [],
+            "blocking_count": 0,
+            "observation_count": 0
+        }
+    }))
+    .expect("deserialize minimal Verdict payload");
+    assert!(back.quality_posture.is_none());
+    assert_eq!(back.suppressed_count, 0);
+    assert!(back.suppressed_codes.is_empty());
+}
+
+#[test]
+fn quality_posture_roundtrip() {
+    let qp = QualityPosture {
+        trust_score: 85,
+        trust_grade: "B".to_string(),
+        coverage_covered: 8,
+        coverage_total: 10,
+        weighted_risk: 12,
+        findings_total: 3,
+        risk_by_severity: [("high".to_string(), 1), ("medium".to_string(), 2)]
+            .into_iter()
+            .collect(),
+    };
+    let json = serde_json::to_value(&qp).unwrap();
+    let back: QualityPosture = serde_json::from_value(json).unwrap();
+    assert_eq!(back.trust_score, 85);
+}
+
+#[test]
+fn validate_request_with_baseline_maintenance() {
+    let req: ValidateRequest = serde_json::from_value(serde_json::json!({
+        "mode": "ratchet",
+        "write_baseline": true,
+        "baseline_maintenance": {
+            "reason": "Quarterly baseline refresh after major refactor",
+            "owner": "team-lead"
+        }
+    }))
+    .expect("deserialize with baseline_maintenance");
+    assert!(req.baseline_maintenance.is_some());
+    let bm = req.baseline_maintenance.unwrap();
+    assert_eq!(bm.owner, "team-lead");
+}
+
+#[test]
+fn validate_request_without_baseline_maintenance_still_works() {
+    let req: ValidateRequest = serde_json::from_value(serde_json::json!({
+        "mode": "warn"
+    }))
+    .expect("deserialize without baseline_maintenance");
+    assert!(req.baseline_maintenance.is_none());
+}
+
+#[test]
+fn validate_request_response_mode_roundtrip() {
+    let req: ValidateRequest = serde_json::from_value(serde_json::json!({
+        "mode": "ratchet",
+        "response_mode": "full"
+    }))
+    .expect("deserialize ValidateRequest");
+    assert_eq!(req.response_mode, Some(ResponseMode::Full));
+}
+
+#[test]
+fn gate_request_status_mode_parses() {
+    let req: GateRequest = serde_json::from_value(serde_json::json!({
+        "kind": "ci_fast",
+        "op": "status",
+        "job_id": "gate-1",
+        "wait_ms": 2500
+    }))
+    .expect("deserialize GateRequest status");
+    assert_eq!(req.op, Some(GateOp::Status));
+    assert_eq!(req.job_id.as_deref(), Some("gate-1"));
+    assert_eq!(req.wait_ms, Some(2500));
+}
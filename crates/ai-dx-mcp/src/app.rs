@@ -1,42 +1,43 @@
 use crate::{
     api::{
-        ApiError, BaselineMaintenance, BoundarySummary, DecisionStatus, EffectiveConfigSummary,
-        GateKind, GateOutput, InitOutput, InitRequest, LocSummary, PublicSurfaceSummary,
-        ToolsRunOutput, ToolsRunRequest, ValidateMode, ValidateOutput, Violation, ViolationTier,
+        ApiError, BaselineMaintenance, CheckSelection, DecisionStatus, GateKind, InitOutput,
+        InitRequest, ToolsRunOutput, ToolsRunRequest, ValidateMode, ValidateOutput, Violation,
+        ViolationTier,
     },
-    checks::{
-        arch_layers::run_arch_layers_check,
-        boundary::run_boundary_check,
-        complexity_budget::run_complexity_budget_check,
-        contract_break::run_contract_break_check,
-        dead_api::{run_dead_code_check, run_orphan_api_check},
-        duplicates::run_duplicates_check,
-        env_registry::run_env_registry_check,
-        loc::run_loc_check,
-        quality_delta::FileUniverse,
-        reuse_first::run_reuse_first_check,
-        supply_chain::run_supply_chain_check,
-        surface::run_surface_check,
-        tool_budget::run_tool_budget_check,
+    config::TrustGrade,
+    failure_modes::{
+        default_failure_mode_catalog, load_failure_mode_catalog, merge_pack_failure_modes,
     },
-    failure_modes::{default_failure_mode_catalog, load_failure_mode_catalog},
-    packs::validate_packs,
+    gate_runner::GatePlanOutput,
+    packs::{apply_disabled_packs, enabled_pack_failure_modes, validate_packs},
     repo::{RepoConfigError, load_repo_config},
     validate_insights::{
         build_agent_digest_with_suppressed, build_coverage, build_quality_posture,
         build_risk_summary, build_trust_score, to_findings_v2,
     },
+    witness::GateDiffOutput,
 };
-use std::{
-    collections::{BTreeMap, BTreeSet},
-    path::Path,
-};
+use std::{collections::BTreeMap, path::Path, time::Instant};
 
+mod baseline_migrate;
+mod check_run;
+mod gate;
+mod inspect;
+mod selection;
 mod support;
 
+pub use baseline_migrate::validate_baseline_migrate_only;
+pub use gate::{GateRunOptions, gate, gate_with_budget, gate_with_options};
+pub use inspect::{boundary_test, exceptions_report, tools_describe};
+pub(crate) use support::{check_errors_from, empty_output_with_error};
+
+use check_run::{CheckRun, missing_mandatory_checks, run_selected_checks};
+use selection::select_checks;
+
 use support::{
-    collect_suppressed_codes, compute_checks_hash, detect_tool_duplicates, empty_output_with_error,
-    has_prior_baselines,
+    collect_suppressed_codes, compute_checks_hash, detect_defaulted_tool_timeouts,
+    detect_incomplete_tool_metadata, detect_tool_duplicates, field_order_checks_hash,
+    has_prior_baselines, record_timing, timings_requested_by_env,
 };
 
 pub(crate) fn map_config_error(repo_root: &str, err: RepoConfigError) -> ApiError {
@@ -75,14 +76,174 @@ pub fn validate(
     write_baseline: bool,
     baseline_maintenance: Option<&BaselineMaintenance>,
 ) -> ValidateOutput {
+    validate_with_timings(
+        repo_root,
+        mode,
+        write_baseline,
+        baseline_maintenance,
+        timings_requested_by_env(),
+    )
+}
+
+/// Like [`validate`], but `timings=true` attaches per-check wall time as `timings_ms`.
+pub fn validate_with_timings(
+    repo_root: &str,
+    mode: ValidateMode,
+    write_baseline: bool,
+    baseline_maintenance: Option<&BaselineMaintenance>,
+    timings: bool,
+) -> ValidateOutput {
+    validate_impl(
+        repo_root,
+        mode,
+        &CheckSelection::default(),
+        ValidateRun {
+            write_baseline,
+            baseline_maintenance,
+            timings,
+            ..ValidateRun::default()
+        },
+    )
+}
+
+/// Runs only the check kinds picked by `selection` (never writes a baseline); the output's
+/// `partial_run` records what was left out.
+pub fn validate_selected(
+    repo_root: &str,
+    mode: ValidateMode,
+    timings: bool,
+    selection: &CheckSelection,
+) -> ValidateOutput {
+    validate_impl(
+        repo_root,
+        mode,
+        selection,
+        ValidateRun {
+            timings,
+            ..ValidateRun::default()
+        },
+    )
+}
+
+/// Pass/fail-only validate for preflights: the decision matches a full run, but `violations`
+/// keeps only blocking ones and the insight fields (`findings_v2`, `risk_summary`, `coverage`,
+/// `trust_score`, `quality_posture`, `agent_digest`) are left out. Never writes a baseline.
+pub fn validate_errors_only(
+    repo_root: &str,
+    mode: ValidateMode,
+    timings: bool,
+    selection: &CheckSelection,
+) -> ValidateOutput {
+    validate_impl(
+        repo_root,
+        mode,
+        selection,
+        ValidateRun {
+            timings,
+            errors_only: true,
+            ..ValidateRun::default()
+        },
+    )
+}
+
+/// The gate's validate(ratchet) preflight under `[gate] preflight_lean`: every violation
+/// and `findings_v2` are kept, so the gate verdict and `all_findings` match a full run, while
+/// `risk_summary`, `coverage`, `trust_score`, `quality_posture` and `agent_digest` are skipped.
+pub(crate) fn validate_gate_preflight(repo_root: &str) -> ValidateOutput {
+    validate_impl(
+        repo_root,
+        ValidateMode::Ratchet,
+        &CheckSelection::default(),
+        ValidateRun {
+            lean: true,
+            ..ValidateRun::default()
+        },
+    )
+}
+
+/// Runs validate and attaches `baseline_diff` (what `--write-baseline` would change) without
+/// writing the snapshot. Requires a quality contract.
+pub fn validate_baseline_dry_run(
+    repo_root: &str,
+    mode: ValidateMode,
+    timings: bool,
+) -> ValidateOutput {
+    validate_impl(
+        repo_root,
+        mode,
+        &CheckSelection::default(),
+        ValidateRun {
+            timings,
+            baseline_dry_run: true,
+            ..ValidateRun::default()
+        },
+    )
+}
+
+/// The checks hash validate computes (and `governance.config_hash` locks): disabled packs are
+/// removed from the config first.
+pub(crate) fn effective_checks_hash(repo_root: &Path) -> Result<String, RepoConfigError> {
+    let mut cfg = load_repo_config(repo_root)?;
+    let disabled_pack_ids = cfg
+        .quality_contract
+        .as_ref()
+        .map(|c| c.disabled_packs.clone())
+        .unwrap_or_default();
+    apply_disabled_packs(repo_root, &disabled_pack_ids, &mut cfg.checks);
+    Ok(compute_checks_hash(&cfg))
+}
+
+/// How one validate run behaves beyond mode and check selection.
+#[derive(Default)]
+struct ValidateRun<'a> {
+    write_baseline: bool,
+    baseline_maintenance: Option<&'a BaselineMaintenance>,
+    timings: bool,
+    baseline_dry_run: bool,
+    /// Compute the decision and blocking violations only (see [`validate_errors_only`]).
+    errors_only: bool,
+    /// Keep every violation and `findings_v2` but skip the other display insights
+    /// (see [`validate_gate_preflight`]).
+    lean: bool,
+}
+
+fn validate_impl(
+    repo_root: &str,
+    mode: ValidateMode,
+    selection: &CheckSelection,
+    run: ValidateRun<'_>,
+) -> ValidateOutput {
+    let ValidateRun {
+        write_baseline,
+        baseline_maintenance,
+        timings,
+        baseline_dry_run,
+        errors_only,
+        lean,
+    } = run;
+    let lean = lean || errors_only;
+    let validate_started_at = Instant::now();
+    let mut timings_ms: BTreeMap<String, u64> = BTreeMap::new();
     let repo_root_path = Path::new(repo_root);
-    let cfg = match load_repo_config(repo_root_path) {
+    let mut cfg = match load_repo_config(repo_root_path) {
         Ok(c) => c,
         Err(e) => {
             return empty_output_with_error(repo_root, mode, map_config_error(repo_root, e), None);
         }
     };
 
+    if baseline_dry_run && cfg.quality_contract.is_none() {
+        return empty_output_with_error(
+            repo_root,
+            mode,
+            ApiError {
+                code: "config.baseline_dry_run_requires_quality_contract".to_string(),
+                message: "baseline dry-run needs .agents/mcp/compas/quality_contract.toml (no snapshot to diff against)".to_string(),
+            },
+            None,
+        );
+    }
+
     if write_baseline && matches!(mode, ValidateMode::Ratchet) {
         match baseline_maintenance {
             None => {
@@ -115,14 +276,6 @@ pub fn validate(
     }
 
     let mut violations_raw: Vec<Violation> = vec![];
-    let mut loc_summary: Option<LocSummary> = None;
-    let mut boundary_summary: Option<BoundarySummary> = None;
-    let mut public_surface_summary: Option<PublicSurfaceSummary> = None;
-    let mut effective_config: Option<EffectiveConfigSummary> = None;
-    let mut file_universe = FileUniverse::default();
-    let mut loc_per_file: BTreeMap<String, usize> = BTreeMap::new();
-    let mut surface_items: BTreeSet<String> = BTreeSet::new();
-    let mut duplicate_groups: Vec<Vec<String>> = vec![];
 
     // P0 anti-gaming: allow_any policy warning is always blocking.
     for plugin_id in &cfg.allow_any_plugins {
@@ -136,271 +289,56 @@ pub fn validate(
         ));
     }
 
-    // Mandatory checks contract.
-    if let Some(contract) = &cfg.quality_contract {
-        let mut active_check_types: BTreeSet<&str> = BTreeSet::new();
-        if !cfg.checks.boundary.is_empty() {
-            active_check_types.insert("boundary");
-        }
-        if !cfg.checks.supply_chain.is_empty() {
-            active_check_types.insert("supply_chain");
-        }
-        if !cfg.checks.loc.is_empty() {
-            active_check_types.insert("loc");
-        }
-        if !cfg.checks.surface.is_empty() {
-            active_check_types.insert("surface");
-        }
-        if !cfg.checks.duplicates.is_empty() {
-            active_check_types.insert("duplicates");
-        }
-        if !cfg.checks.env_registry.is_empty() {
-            active_check_types.insert("env_registry");
-        }
-        if !cfg.checks.tool_budget.is_empty() {
-            active_check_types.insert("tool_budget");
-        }
-        if !cfg.checks.reuse_first.is_empty() {
-            active_check_types.insert("reuse_first");
-        }
-        if !cfg.checks.arch_layers.is_empty() {
-            active_check_types.insert("arch_layers");
-        }
-        if !cfg.checks.dead_code.is_empty() {
-            active_check_types.insert("dead_code");
-        }
-        if !cfg.checks.orphan_api.is_empty() {
-            active_check_types.insert("orphan_api");
-        }
-        if !cfg.checks.complexity_budget.is_empty() {
-            active_check_types.insert("complexity_budget");
-        }
-        if !cfg.checks.contract_break.is_empty() {
-            active_check_types.insert("contract_break");
-        }
-        for mandatory in &contract.governance.mandatory_checks {
-            if !active_check_types.contains(mandatory.as_str()) {
-                violations_raw.push(Violation::blocking(
-                    "config.mandatory_check_removed",
-                    format!("mandatory check '{mandatory}' is not configured"),
-                    None,
-                    None,
-                ));
-            }
+    let disabled_pack_ids = cfg
+        .quality_contract
+        .as_ref()
+        .map(|c| c.disabled_packs.clone())
+        .unwrap_or_default();
+    let (disabled_packs, disabled_pack_violations) =
+        apply_disabled_packs(repo_root_path, &disabled_pack_ids, &mut cfg.checks);
+    violations_raw.extend(disabled_pack_violations);
+
+    let (run_checks, partial_run) = match select_checks(&cfg.checks, selection) {
+        Ok(v) => v,
+        Err(message) => {
+            return empty_output_with_error(
+                repo_root,
+                mode,
+                ApiError {
+                    code: "config.check_selection_invalid".to_string(),
+                    message,
+                },
+                None,
+            );
         }
+    };
+    // Contract-level checks below run unless the selection opts out of them.
+    let enforced_contract = cfg
+        .quality_contract
+        .as_ref()
+        .filter(|_| !selection.skip_contract);
+
+    if let Some(contract) = enforced_contract {
+        violations_raw.extend(missing_mandatory_checks(&cfg, contract));
     }
 
     violations_raw.extend(validate_packs(repo_root_path));
     violations_raw.extend(detect_tool_duplicates(&cfg));
+    violations_raw.extend(detect_incomplete_tool_metadata(&cfg));
+    violations_raw.extend(detect_defaulted_tool_timeouts(&cfg));
 
-    if !cfg.checks.boundary.is_empty() {
-        let mut files_scanned = 0usize;
-        let mut rules_checked = 0usize;
-        let mut vio_count = 0usize;
-        for boundary_cfg in &cfg.checks.boundary {
-            match run_boundary_check(repo_root_path, boundary_cfg) {
-                Ok(r) => {
-                    files_scanned += r.files_scanned;
-                    rules_checked += r.rules_checked;
-                    vio_count += r.violations.len();
-                    violations_raw.extend(r.violations);
-                }
-                Err(msg) => {
-                    violations_raw.push(Violation::blocking(
-                        "boundary.check_failed",
-                        format!("boundary check failed (id={}): {msg}", boundary_cfg.id),
-                        None,
-                        None,
-                    ));
-                }
-            }
-        }
-        file_universe.boundary_universe = files_scanned;
-        file_universe.boundary_scanned = files_scanned;
-        boundary_summary = Some(BoundarySummary {
-            files_scanned,
-            rules_checked,
-            violations: vio_count,
-        });
-    }
-
-    if !cfg.checks.loc.is_empty() {
-        let mut files_scanned = 0usize;
-        let mut max_loc = 0usize;
-        let mut files_universe = 0usize;
-        let mut worst_path: Option<String> = None;
-        for loc_cfg in &cfg.checks.loc {
-            match run_loc_check(repo_root_path, loc_cfg) {
-                Ok(r) => {
-                    files_scanned += r.files_scanned;
-                    files_universe += r.files_universe;
-                    max_loc = max_loc.max(r.max_loc);
-                    if worst_path.is_none() {
-                        worst_path = r.worst_path;
-                    }
-                    for (k, v) in r.loc_per_file {
-                        // deterministic max merge for duplicated paths across check instances
-                        let entry = loc_per_file.entry(k).or_insert(0);
-                        *entry = (*entry).max(v);
-                    }
-                    violations_raw.extend(r.violations);
-                }
-                Err(msg) => {
-                    violations_raw.push(Violation::blocking(
-                        "loc.check_failed",
-                        format!("loc check failed (id={}): {msg}", loc_cfg.id),
-                        None,
-                        None,
-                    ));
-                }
-            }
-        }
-        file_universe.loc_universe = files_universe;
-        file_universe.loc_scanned = files_scanned;
-        loc_summary = Some(LocSummary {
-            files_scanned,
-            max_loc,
-            worst_path,
-        });
-    }
-
-    if !cfg.checks.surface.is_empty() {
-        let mut best: Option<(usize, PublicSurfaceSummary)> = None;
-        let mut files_scanned = 0usize;
-        let mut files_universe = 0usize;
-        for surface_cfg in &cfg.checks.surface {
-            match run_surface_check(repo_root_path, surface_cfg) {
-                Ok(r) => {
-                    files_scanned += r.files_scanned;
-                    files_universe += r.files_universe;
-                    violations_raw.extend(r.violations);
-                    surface_items.extend(r.current_items.into_iter());
-                    let summary = PublicSurfaceSummary {
-                        baseline_path: surface_cfg.baseline_path.clone(),
-                        max_pub_items: r.max_items,
-                        items_total: r.items_total,
-                        added_vs_baseline: 0,
-                        removed_vs_baseline: 0,
-                    };
-                    let score = r.items_total;
-                    if best
-                        .as_ref()
-                        .map(|(best_score, _)| score > *best_score)
-                        .unwrap_or(true)
-                    {
-                        best = Some((score, summary));
-                    }
-                }
-                Err(msg) => {
-                    violations_raw.push(Violation::blocking(
-                        "surface.check_failed",
-                        format!("surface check failed (id={}): {msg}", surface_cfg.id),
-                        None,
-                        None,
-                    ));
-                }
-            }
-        }
-        file_universe.surface_universe = files_universe;
-        file_universe.surface_scanned = files_scanned;
-        public_surface_summary = best.map(|(_, s)| s);
-    }
-
-    if !cfg.checks.duplicates.is_empty() {
-        let mut files_scanned = 0usize;
-        let mut files_universe = 0usize;
-        let mut merged_groups: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
-        for dup_cfg in &cfg.checks.duplicates {
-            match run_duplicates_check(repo_root_path, dup_cfg) {
-                Ok(r) => {
-                    files_scanned += r.files_scanned;
-                    files_universe += r.files_universe;
-                    for (sha, paths) in r.groups {
-                        let group = merged_groups.entry(sha).or_default();
-                        for p in paths {
-                            group.insert(p);
-                        }
-                    }
-                    violations_raw.extend(r.violations);
-                }
-                Err(msg) => violations_raw.push(Violation::blocking(
-                    "duplicates.check_failed",
-                    format!("duplicates check failed (id={}): {msg}", dup_cfg.id),
-                    None,
-                    None,
-                )),
-            }
-        }
-        file_universe.duplicates_universe = files_universe;
-        file_universe.duplicates_scanned = files_scanned;
-        duplicate_groups = merged_groups
-            .into_values()
-            .map(|set| set.into_iter().collect::<Vec<_>>())
-            .collect();
-        duplicate_groups.sort();
-    }
-
-    if !cfg.checks.supply_chain.is_empty() {
-        for sc_cfg in &cfg.checks.supply_chain {
-            let out = run_supply_chain_check(repo_root_path, sc_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.tool_budget.is_empty() {
-        for budget_cfg in &cfg.checks.tool_budget {
-            let out = run_tool_budget_check(&cfg, budget_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.reuse_first.is_empty() {
-        for reuse_cfg in &cfg.checks.reuse_first {
-            let out = run_reuse_first_check(repo_root_path, reuse_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.arch_layers.is_empty() {
-        for layers_cfg in &cfg.checks.arch_layers {
-            let out = run_arch_layers_check(repo_root_path, layers_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.dead_code.is_empty() {
-        for dead_cfg in &cfg.checks.dead_code {
-            let out = run_dead_code_check(repo_root_path, dead_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.orphan_api.is_empty() {
-        for orphan_cfg in &cfg.checks.orphan_api {
-            let out = run_orphan_api_check(repo_root_path, orphan_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.complexity_budget.is_empty() {
-        for cx_cfg in &cfg.checks.complexity_budget {
-            let out = run_complexity_budget_check(repo_root_path, cx_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if !cfg.checks.contract_break.is_empty() {
-        for contract_cfg in &cfg.checks.contract_break {
-            let out = run_contract_break_check(repo_root_path, contract_cfg);
-            violations_raw.extend(out.violations);
-        }
-    }
-
-    if let Some(env_cfg) = cfg.checks.env_registry.first() {
-        let env_result = run_env_registry_check(repo_root_path, env_cfg, &cfg.tools);
-        violations_raw.extend(env_result.violations);
-        effective_config = Some(env_result.summary);
-    }
+    let CheckRun {
+        violations: check_violations,
+        loc: loc_summary,
+        boundary: boundary_summary,
+        public_surface: public_surface_summary,
+        effective_config,
+        file_universe,
+        loc_per_file,
+        surface_items,
+        duplicate_groups,
+    } = run_selected_checks(repo_root_path, &cfg, &run_checks, &mut timings_ms);
+    violations_raw.extend(check_violations);
 
     // quality_contract mode-aware presence signal
     if cfg.quality_contract.is_none() {
@@ -417,7 +355,7 @@ pub fn validate(
         });
     }
 
-    let failure_mode_catalog = match load_failure_mode_catalog(repo_root_path) {
+    let mut failure_mode_catalog = match load_failure_mode_catalog(repo_root_path) {
         Ok(catalog) => catalog,
         Err(e) => {
             violations_raw.push(Violation::blocking(
@@ -429,16 +367,18 @@ pub fn validate(
             default_failure_mode_catalog()
         }
     };
+    let pack_modes = merge_pack_failure_modes(
+        &mut failure_mode_catalog,
+        &enabled_pack_failure_modes(repo_root_path, &disabled_pack_ids),
+    );
 
-    let max_exception_window_days = cfg
-        .quality_contract
-        .as_ref()
-        .map(|c| c.exceptions.max_exception_window_days);
-    let suppression = if let Some(max_days) = max_exception_window_days {
+    let exception_limits = cfg.quality_contract.as_ref().map(|c| &c.exceptions);
+    let suppression = if let Some(limits) = exception_limits {
         crate::exceptions::apply_allowlist_with_limits(
             repo_root_path,
             violations_raw.clone(),
-            Some(max_days),
+            Some(limits.max_exception_window_days),
+            limits.max_long_lived_exceptions,
         )
     } else {
         crate::exceptions::apply_allowlist(repo_root_path, violations_raw.clone())
@@ -452,8 +392,9 @@ pub fn validate(
 
     // Additional non-suppressible phase2/policy violations
     let mut phase2_violations: Vec<Violation> = vec![];
+    let mut baseline_diff = None;
 
-    if let Some(contract) = &cfg.quality_contract {
+    if let Some(contract) = enforced_contract {
         // Mandatory failure-modes catalog guards
         for mandatory in &contract.governance.mandatory_failure_modes {
             if !failure_mode_catalog.contains(mandatory) {
@@ -512,8 +453,10 @@ pub fn validate(
         }
 
         let config_hash = compute_checks_hash(&cfg);
+        let field_order_config_hash = field_order_checks_hash(&cfg);
         if let Some(locked_hash) = &contract.governance.config_hash
             && locked_hash != &config_hash
+            && locked_hash != &field_order_config_hash
         {
             phase2_violations.push(Violation::blocking(
                 "config.threshold_weakened",
@@ -527,6 +470,7 @@ pub fn validate(
 
         if matches!(mode, ValidateMode::Ratchet)
             && !write_baseline
+            && !baseline_dry_run
             && !snapshot_path.is_file()
             && has_prior_baselines(repo_root_path)
         {
@@ -541,23 +485,40 @@ pub fn validate(
                 Ok(s) => {
                     if let Err(e) = crate::checks::quality_delta::write_snapshot(&snapshot_path, &s)
                     {
+                        let message = format!("prior baseline migration write failed: {e}");
                         phase2_violations.push(Violation::blocking(
                             "quality_delta.check_failed",
-                            format!("prior baseline migration write failed: {e}"),
+                            message,
                             Some(snapshot_path.display().to_string()),
                             None,
                         ));
                     }
                 }
-                Err(e) => phase2_violations.push(Violation::blocking(
-                    "quality_delta.check_failed",
-                    format!("prior baseline migration failed: {e}"),
-                    Some(snapshot_path.display().to_string()),
-                    None,
-                )),
+                Err(e) => {
+                    let message = format!("prior baseline migration failed: {e}");
+                    phase2_violations.push(Violation::blocking(
+                        "quality_delta.check_failed",
+                        message,
+                        Some(snapshot_path.display().to_string()),
+                        None,
+                    ));
+                }
             }
         }
 
+        // A snapshot written before the canonical hash still matches its layout-sensitive
+        // hash; compare against that until the next --write-baseline stores the canonical one.
+        let config_hash = if !write_baseline
+            && crate::checks::quality_delta::load_snapshot(&snapshot_path)
+                .ok()
+                .flatten()
+                .is_some_and(|base| base.config_hash == field_order_config_hash)
+        {
+            field_order_config_hash
+        } else {
+            config_hash
+        };
+
         let mut surface_items_sorted = surface_items.into_iter().collect::<Vec<_>>();
         surface_items_sorted.sort();
         let current_snapshot = crate::checks::quality_delta::QualitySnapshot {
@@ -597,31 +558,126 @@ pub fn validate(
                 ));
             }
         }
+
+        if baseline_dry_run {
+            match crate::checks::quality_delta::dry_run_quality_delta(
+                &snapshot_path,
+                contract,
+                &current_snapshot,
+            ) {
+                Ok(diff) => baseline_diff = Some(diff),
+                Err(e) => {
+                    phase2_violations.push(Violation::blocking(
+                        "quality_delta.check_failed",
+                        e,
+                        Some(snapshot_path.display().to_string()),
+                        None,
+                    ));
+                }
+            }
+        }
     }
 
     let mut final_violations = suppression.violations;
+    let mut ledger_entries = BTreeMap::new();
+    if let Some(contract) = enforced_contract.filter(|c| c.governance.ledger_enabled()) {
+        let pass = crate::findings_ledger::apply_findings_ledger(
+            repo_root_path,
+            final_violations,
+            contract.governance.block_only_new,
+            write_baseline,
+            !baseline_dry_run,
+        );
+        final_violations = pass.violations;
+        ledger_entries = pass.entries;
+        phase2_violations.extend(pass.errors);
+    }
+    let check_errors = check_errors_from(violations_raw.iter().chain(&phase2_violations));
     final_violations.extend(phase2_violations);
-    let findings_display = to_findings_v2(&final_violations);
-    let risk_display = build_risk_summary(&findings_display);
-    let coverage_display = build_coverage(&failure_mode_catalog, repo_root_path, &cfg);
-    let trust_display = build_trust_score(
-        &findings_display,
-        final_violations.is_empty() || matches!(mode, ValidateMode::Warn),
-        coverage_display.percent,
-    );
+    if let Some(contract) = enforced_contract {
+        let escalations =
+            crate::escalation::escalate_by_count(&contract.escalation, &final_violations);
+        final_violations.extend(escalations);
+    }
+    let trust_floor = enforced_contract
+        .and_then(|c| c.governance.min_trust_grade)
+        .filter(|_| !matches!(mode, ValidateMode::Warn));
+    // Lean runs skip the display insights unless the trust floor needs them to decide.
+    let mut insights = None;
+    if !lean || trust_floor.is_some() {
+        let mut findings_display = to_findings_v2(&final_violations);
+        let mut risk_display = build_risk_summary(&findings_display);
+        let mut coverage_display = build_coverage(&failure_mode_catalog, repo_root_path, &cfg);
+        coverage_display.pack_modes = pack_modes;
+        let trust_display = build_trust_score(
+            &findings_display,
+            final_violations.is_empty() || matches!(mode, ValidateMode::Warn),
+            coverage_display.percent,
+        );
+        if let Some(floor) = trust_floor
+            && TrustGrade::parse(&trust_display.grade).is_none_or(|grade| grade > floor)
+        {
+            final_violations.push(Violation::blocking(
+                "governance.trust_grade_below_minimum",
+                format!(
+                    "trust grade {} (score {}) is below governance.min_trust_grade {floor:?}",
+                    trust_display.grade, trust_display.score
+                ),
+                Some(".agents/mcp/compas/quality_contract.toml".to_string()),
+                Some(serde_json::json!({
+                    "trust_grade": trust_display.grade,
+                    "trust_score": trust_display.score,
+                    "min_trust_grade": floor,
+                })),
+            ));
+            findings_display = to_findings_v2(&final_violations);
+            risk_display = build_risk_summary(&findings_display);
+        }
+        crate::findings_ledger::annotate_first_seen(&mut findings_display, &ledger_entries);
+        insights = Some((
+            findings_display,
+            risk_display,
+            coverage_display,
+            trust_display,
+        ));
+    }
+    let lean_findings = (lean && !errors_only).then(|| match &insights {
+        Some((findings, ..)) => findings.clone(),
+        None => {
+            let mut findings = to_findings_v2(&final_violations);
+            crate::findings_ledger::annotate_first_seen(&mut findings, &ledger_entries);
+            findings
+        }
+    });
+    let insights = insights.filter(|_| !lean);
     let suppressed = suppression.suppressed;
     let mut verdict = crate::judge::judge_validate(&final_violations, mode);
-    verdict.quality_posture = Some(quality_posture.clone());
+    verdict.quality_posture = insights.as_ref().map(|_| quality_posture.clone());
     verdict.suppressed_count = suppressed.len();
     verdict.suppressed_codes = collect_suppressed_codes(&suppressed);
-    let agent_digest = build_agent_digest_with_suppressed(
-        &verdict.decision,
-        &final_violations,
-        &findings_display,
-        &suppressed,
-    );
+    let agent_digest = insights.as_ref().map(|(findings_display, ..)| {
+        build_agent_digest_with_suppressed(
+            &verdict.decision,
+            &final_violations,
+            findings_display,
+            &suppressed,
+        )
+    });
     let ok = matches!(mode, ValidateMode::Warn)
         || matches!(verdict.decision.status, DecisionStatus::Pass);
+    if errors_only {
+        final_violations.retain(|v| v.tier == ViolationTier::Blocking);
+        verdict
+            .decision
+            .reasons
+            .retain(|r| r.tier == ViolationTier::Blocking);
+    }
+    let (findings_display, risk_display, coverage_display, trust_display) = match insights {
+        Some((findings, risk, coverage, trust)) => {
+            (findings, Some(risk), Some(coverage), Some(trust))
+        }
+        None => (lean_findings.unwrap_or_default(), None, None, None),
+    };
 
     let mut out = ValidateOutput {
         ok,
@@ -636,57 +692,44 @@ pub fn validate(
         boundary: boundary_summary,
         public_surface: public_surface_summary,
         effective_config,
-        risk_summary: Some(risk_display),
-        coverage: Some(coverage_display),
-        trust_score: Some(trust_display),
+        risk_summary: risk_display,
+        coverage: coverage_display,
+        trust_score: trust_display,
+        quality_posture: verdict.quality_posture.clone(),
         verdict: Some(verdict),
-        quality_posture: Some(quality_posture),
-        agent_digest: Some(agent_digest),
+        agent_digest,
         summary_md: None,
         evidence: crate::api::EvidenceEnvelope::default(),
         payload_meta: None,
+        timings_ms: None,
+        baseline_diff,
+        disabled_packs,
+        partial_run,
+        check_errors,
+        swamped: false,
+        swamped_summary: None,
     };
+    if let Some(threshold) = cfg
+        .quality_contract
+        .as_ref()
+        .and_then(|c| c.reporting.swamped_threshold)
+    {
+        out.swamped_summary = crate::swamped::swamped_summary(&out.violations, threshold);
+    }
+    if timings {
+        record_timing(&mut timings_ms, "total", validate_started_at);
+        out.timings_ms = Some(timings_ms);
+    }
     out.evidence = crate::evidence::build_validate_envelope(&out);
     out
 }
 
-pub async fn gate(
-    repo_root: &str,
-    kind: GateKind,
-    dry_run: bool,
-    write_witness: bool,
-) -> GateOutput {
-    gate_with_budget(repo_root, kind, dry_run, write_witness, None).await
+/// Compares two gate witness files (receipts and violations); paths are used as given.
+pub fn gate_diff(a_path: &str, b_path: &str) -> GateDiffOutput {
+    crate::witness::diff_witness_files(a_path, b_path)
 }
 
-pub async fn gate_with_budget(
-    repo_root: &str,
-    kind: GateKind,
-    dry_run: bool,
-    write_witness: bool,
-    gate_budget_ms: Option<u64>,
-) -> GateOutput {
-    let mut out =
-        crate::gate_runner::gate(repo_root, kind, dry_run, write_witness, gate_budget_ms).await;
-    let suppressed_codes = collect_suppressed_codes(&out.validate.suppressed);
-    let suppressed_count = out.validate.suppressed.len();
-
-    if let Some(verdict) = out.verdict.as_mut() {
-        verdict.quality_posture = out.validate.quality_posture.clone();
-        verdict.suppressed_count = suppressed_count;
-        verdict.suppressed_codes = suppressed_codes;
-    }
-
-    if let Some(verdict) = out.verdict.as_ref() {
-        out.agent_digest = Some(build_agent_digest_with_suppressed(
-            &verdict.decision,
-            &out.validate.violations,
-            &out.validate.findings_v2,
-            &out.validate.suppressed,
-        ));
-    }
-
-    out.validate.evidence = crate::evidence::build_validate_envelope(&out.validate);
-    out.evidence = crate::evidence::build_gate_envelope(&out);
-    out
+/// The tool sequence `gate <kind>` would run, with reasons; validate and tools are not run.
+pub fn gate_plan(repo_root: &str, kind: GateKind) -> GatePlanOutput {
+    crate::gate_runner::gate_plan(repo_root, kind)
 }
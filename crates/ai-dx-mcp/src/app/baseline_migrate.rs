@@ -0,0 +1,126 @@
+use super::{
+    ValidateRun, effective_checks_hash, map_config_error, support::has_prior_baselines,
+    validate_impl,
+};
+use crate::{
+    api::{ApiError, BaselineMigrateOutput, CheckSelection, ValidateMode},
+    repo::load_repo_config,
+};
+use std::path::Path;
+
+/// Migrates prior baselines (`baselines/{loc,public_surface,duplicates}.json`) into the
+/// quality snapshot and nothing else. Checks run read-only (warn mode, no ledger or snapshot
+/// writes) only to seed the snapshot's trust/coverage/risk posture; nothing blocks. Refuses
+/// to overwrite an existing snapshot.
+pub fn validate_baseline_migrate_only(repo_root: &str) -> BaselineMigrateOutput {
+    let repo_root_path = Path::new(repo_root);
+    let mut out = BaselineMigrateOutput {
+        ok: false,
+        error: None,
+        repo_root: repo_root.to_string(),
+        snapshot_path: String::new(),
+        snapshot: None,
+    };
+    let fail = |mut out: BaselineMigrateOutput, code: &str, message: String| {
+        out.error = Some(ApiError {
+            code: code.to_string(),
+            message,
+        });
+        out
+    };
+    let cfg = match load_repo_config(repo_root_path) {
+        Ok(c) => c,
+        Err(e) => {
+            out.error = Some(map_config_error(repo_root, e));
+            return out;
+        }
+    };
+    let Some(contract) = cfg.quality_contract else {
+        return fail(
+            out,
+            "config.baseline_migrate_requires_quality_contract",
+            "baseline migration needs .agents/mcp/compas/quality_contract.toml (it names the snapshot path)".to_string(),
+        );
+    };
+    out.snapshot_path = contract.baseline.snapshot_path.clone();
+    let snapshot_path = repo_root_path.join(&contract.baseline.snapshot_path);
+    if snapshot_path.is_file() {
+        let message = format!(
+            "{} already exists; refresh it with --write-baseline instead",
+            out.snapshot_path
+        );
+        return fail(out, "baseline.snapshot_exists", message);
+    }
+    if !has_prior_baselines(repo_root_path) {
+        return fail(
+            out,
+            "baseline.no_prior_baselines",
+            "no prior baselines under .agents/mcp/compas/baselines (loc.json, public_surface.json, duplicates.json)".to_string(),
+        );
+    }
+
+    let posture_run = validate_impl(
+        repo_root,
+        ValidateMode::Warn,
+        &CheckSelection::default(),
+        ValidateRun {
+            baseline_dry_run: true,
+            ..ValidateRun::default()
+        },
+    );
+    if let Some(error) = posture_run.error {
+        out.error = Some(error);
+        return out;
+    }
+    let Some(posture) = posture_run.quality_posture else {
+        return fail(
+            out,
+            "baseline.migrate_failed",
+            "validate produced no quality posture to seed the snapshot".to_string(),
+        );
+    };
+    let config_hash = match effective_checks_hash(repo_root_path) {
+        Ok(hash) => hash,
+        Err(e) => {
+            out.error = Some(map_config_error(repo_root, e));
+            return out;
+        }
+    };
+
+    let snapshot = match crate::checks::quality_delta::migrate_from_prior_baselines(
+        repo_root_path,
+        posture.trust_score,
+        posture.coverage_covered,
+        posture.coverage_total,
+        posture.weighted_risk,
+        &config_hash,
+    ) {
+        Ok(s) => s,
+        Err(e) => {
+            return fail(
+                out,
+                "baseline.migrate_failed",
+                format!("prior baseline migration failed: {e}"),
+            );
+        }
+    };
+    if let Err(e) = crate::checks::quality_delta::write_snapshot(&snapshot_path, &snapshot) {
+        return fail(
+            out,
+            "baseline.migrate_failed",
+            format!("prior baseline migration write failed: {e}"),
+        );
+    }
+    match serde_json::to_value(&snapshot) {
+        Ok(v) => out.snapshot = Some(v),
+        Err(e) => {
+            return fail(
+                out,
+                "baseline.migrate_failed",
+                format!("failed to serialize migrated snapshot: {e}"),
+            );
+        }
+    }
+    out.ok = true;
+    out
+}
@@ -0,0 +1,461 @@
+use super::support::record_timing;
+use crate::{
+    api::{BoundarySummary, EffectiveConfigSummary, LocSummary, PublicSurfaceSummary, Violation},
+    checks::{
+        arch_layers::run_arch_layers_check,
+        binary_artifacts::run_binary_artifacts_check,
+        boundary::run_boundary_check,
+        complexity_budget::run_complexity_budget_check,
+        contract_break::run_contract_break_check,
+        dead_api::{run_dead_code_check, run_orphan_api_check},
+        duplicates::run_duplicates_check,
+        env_registry::run_env_registry_check,
+        file_encoding::run_file_encoding_check,
+        import_cycles::run_import_cycles_check,
+        loc::run_loc_check,
+        magic_numbers::run_magic_numbers_check,
+        max_params::run_max_params_check,
+        naming_convention::run_naming_convention_check,
+        quality_delta::FileUniverse,
+        reuse_first::run_reuse_first_check,
+        supply_chain::run_supply_chain_check,
+        surface::run_surface_check,
+        tool_budget::run_tool_budget_check,
+    },
+    config::{ChecksConfigV2, LocMode, QualityContractConfig},
+    repo::RepoConfig,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    time::Instant,
+};
+
+/// What the selected checks reported, merged across the instances of each kind.
+#[derive(Default)]
+pub(super) struct CheckRun {
+    pub(super) violations: Vec<Violation>,
+    pub(super) loc: Option<LocSummary>,
+    pub(super) boundary: Option<BoundarySummary>,
+    pub(super) public_surface: Option<PublicSurfaceSummary>,
+    pub(super) effective_config: Option<EffectiveConfigSummary>,
+    /// Snapshot inputs for quality_delta.
+    pub(super) file_universe: FileUniverse,
+    pub(super) loc_per_file: BTreeMap<String, usize>,
+    pub(super) surface_items: BTreeSet<String>,
+    pub(super) duplicate_groups: Vec<Vec<String>>,
+}
+
+/// `config.mandatory_check_removed` for every `governance.mandatory_checks` kind without a
+/// configured instance.
+pub(super) fn missing_mandatory_checks(
+    cfg: &RepoConfig,
+    contract: &QualityContractConfig,
+) -> Vec<Violation> {
+    let mut violations_raw: Vec<Violation> = vec![];
+    let mut active_check_types: BTreeSet<&str> = BTreeSet::new();
+    if !cfg.checks.boundary.is_empty() {
+        active_check_types.insert("boundary");
+    }
+    if !cfg.checks.supply_chain.is_empty() {
+        active_check_types.insert("supply_chain");
+    }
+    if !cfg.checks.loc.is_empty() {
+        active_check_types.insert("loc");
+    }
+    if !cfg.checks.surface.is_empty() {
+        active_check_types.insert("surface");
+    }
+    if !cfg.checks.duplicates.is_empty() {
+        active_check_types.insert("duplicates");
+    }
+    if !cfg.checks.env_registry.is_empty() {
+        active_check_types.insert("env_registry");
+    }
+    if !cfg.checks.tool_budget.is_empty() {
+        active_check_types.insert("tool_budget");
+    }
+    if !cfg.checks.reuse_first.is_empty() {
+        active_check_types.insert("reuse_first");
+    }
+    if !cfg.checks.arch_layers.is_empty() {
+        active_check_types.insert("arch_layers");
+    }
+    if !cfg.checks.dead_code.is_empty() {
+        active_check_types.insert("dead_code");
+    }
+    if !cfg.checks.orphan_api.is_empty() {
+        active_check_types.insert("orphan_api");
+    }
+    if !cfg.checks.complexity_budget.is_empty() {
+        active_check_types.insert("complexity_budget");
+    }
+    if !cfg.checks.contract_break.is_empty() {
+        active_check_types.insert("contract_break");
+    }
+    if !cfg.checks.test_presence.is_empty() {
+        active_check_types.insert("test_presence");
+    }
+    if !cfg.checks.file_encoding.is_empty() {
+        active_check_types.insert("file_encoding");
+    }
+    if !cfg.checks.max_params.is_empty() {
+        active_check_types.insert("max_params");
+    }
+    if !cfg.checks.binary_artifacts.is_empty() {
+        active_check_types.insert("binary_artifacts");
+    }
+    if !cfg.checks.import_cycles.is_empty() {
+        active_check_types.insert("import_cycles");
+    }
+    if !cfg.checks.naming_convention.is_empty() {
+        active_check_types.insert("naming_convention");
+    }
+    if !cfg.checks.magic_numbers.is_empty() {
+        active_check_types.insert("magic_numbers");
+    }
+    for mandatory in &contract.governance.mandatory_checks {
+        if !active_check_types.contains(mandatory.as_str()) {
+            violations_raw.push(Violation::blocking(
+                "config.mandatory_check_removed",
+                format!("mandatory check '{mandatory}' is not configured"),
+                None,
+                None,
+            ));
+        }
+    }
+    violations_raw
+}
+
+/// Runs every check instance in `run_checks` (each kind timed under its name).
+pub(super) fn run_selected_checks(
+    repo_root_path: &Path,
+    cfg: &RepoConfig,
+    run_checks: &ChecksConfigV2,
+    timings_ms: &mut BTreeMap<String, u64>,
+) -> CheckRun {
+    let mut violations_raw: Vec<Violation> = vec![];
+    let mut loc_summary: Option<LocSummary> = None;
+    let mut boundary_summary: Option<BoundarySummary> = None;
+    let mut public_surface_summary: Option<PublicSurfaceSummary> = None;
+    let mut effective_config: Option<EffectiveConfigSummary> = None;
+    let mut file_universe = FileUniverse::default();
+    let mut loc_per_file: BTreeMap<String, usize> = BTreeMap::new();
+    let mut surface_items: BTreeSet<String> = BTreeSet::new();
+    let mut duplicate_groups: Vec<Vec<String>> = vec![];
+
+    let started_at = Instant::now();
+    if !run_checks.boundary.is_empty() {
+        let mut files_scanned = 0usize;
+        let mut rules_checked = 0usize;
+        let mut vio_count = 0usize;
+        for boundary_cfg in &run_checks.boundary {
+            match run_boundary_check(repo_root_path, boundary_cfg) {
+                Ok(r) => {
+                    files_scanned += r.files_scanned;
+                    rules_checked += r.rules_checked;
+                    vio_count += r.violations.len();
+                    violations_raw.extend(r.violations);
+                }
+                Err(msg) => {
+                    violations_raw.push(Violation::blocking(
+                        "boundary.check_failed",
+                        format!("boundary check failed (id={}): {msg}", boundary_cfg.id),
+                        None,
+                        None,
+                    ));
+                }
+            }
+        }
+        file_universe.boundary_universe = files_scanned;
+        file_universe.boundary_scanned = files_scanned;
+        boundary_summary = Some(BoundarySummary {
+            files_scanned,
+            rules_checked,
+            violations: vio_count,
+        });
+        record_timing(timings_ms, "boundary", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.loc.is_empty() {
+        let mut files_scanned = 0usize;
+        let mut max_loc = 0usize;
+        let mut files_universe = 0usize;
+        let mut worst_path: Option<String> = None;
+        let mut loc_mode = LocMode::default();
+        for loc_cfg in &run_checks.loc {
+            match run_loc_check(repo_root_path, loc_cfg) {
+                Ok(r) => {
+                    files_scanned += r.files_scanned;
+                    files_universe += r.files_universe;
+                    if worst_path.is_none() || r.max_loc > max_loc {
+                        max_loc = r.max_loc;
+                        worst_path = r.worst_path;
+                        loc_mode = loc_cfg.loc_mode;
+                    }
+                    for (k, v) in r.loc_per_file {
+                        // deterministic max merge for duplicated paths across check instances
+                        let entry = loc_per_file.entry(k).or_insert(0);
+                        *entry = (*entry).max(v);
+                    }
+                    violations_raw.extend(r.violations);
+                }
+                Err(msg) => {
+                    violations_raw.push(Violation::blocking(
+                        "loc.check_failed",
+                        format!("loc check failed (id={}): {msg}", loc_cfg.id),
+                        None,
+                        None,
+                    ));
+                }
+            }
+        }
+        file_universe.loc_universe = files_universe;
+        file_universe.loc_scanned = files_scanned;
+        loc_summary = Some(LocSummary {
+            files_scanned,
+            max_loc,
+            worst_path,
+            loc_mode,
+        });
+        record_timing(timings_ms, "loc", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.surface.is_empty() {
+        let mut best: Option<PublicSurfaceSummary> = None;
+        let mut files_scanned = 0usize;
+        let mut files_universe = 0usize;
+        for surface_cfg in &run_checks.surface {
+            match run_surface_check(repo_root_path, surface_cfg) {
+                Ok(r) => {
+                    files_scanned += r.files_scanned;
+                    files_universe += r.files_universe;
+                    violations_raw.extend(r.violations);
+                    surface_items.extend(r.current_items);
+                    let summary = PublicSurfaceSummary {
+                        baseline_path: surface_cfg.baseline_path.clone(),
+                        max_pub_items: r.max_items,
+                        items_total: r.items_total,
+                        added_vs_baseline: 0,
+                        removed_vs_baseline: 0,
+                    };
+                    // Largest surface wins; ties go to the lexicographically smallest
+                    // baseline_path so the pick does not depend on config order.
+                    let replace = best.as_ref().is_none_or(|b| {
+                        (
+                            summary.items_total,
+                            std::cmp::Reverse(&summary.baseline_path),
+                        ) > (b.items_total, std::cmp::Reverse(&b.baseline_path))
+                    });
+                    if replace {
+                        best = Some(summary);
+                    }
+                }
+                Err(msg) => {
+                    violations_raw.push(Violation::blocking(
+                        "surface.check_failed",
+                        format!("surface check failed (id={}): {msg}", surface_cfg.id),
+                        None,
+                        None,
+                    ));
+                }
+            }
+        }
+        file_universe.surface_universe = files_universe;
+        file_universe.surface_scanned = files_scanned;
+        public_surface_summary = best;
+        record_timing(timings_ms, "surface", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.duplicates.is_empty() {
+        let mut files_scanned = 0usize;
+        let mut files_universe = 0usize;
+        let mut merged_groups: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for dup_cfg in &run_checks.duplicates {
+            match run_duplicates_check(repo_root_path, dup_cfg) {
+                Ok(r) => {
+                    files_scanned += r.files_scanned;
+                    files_universe += r.files_universe;
+                    // Snapshot groups use the firing threshold too, so quality_delta never
+                    // ratchets on groups `min_group_size` configured away.
+                    let min_group_size = crate::checks::duplicates::firing_min_group_size(dup_cfg);
+                    for (sha, paths) in r.groups {
+                        if paths.len() < min_group_size {
+                            continue;
+                        }
+                        let group = merged_groups.entry(sha).or_default();
+                        for p in paths {
+                            group.insert(p);
+                        }
+                    }
+                    violations_raw.extend(r.violations);
+                }
+                Err(msg) => {
+                    violations_raw.push(Violation::blocking(
+                        "duplicates.check_failed",
+                        format!("duplicates check failed (id={}): {msg}", dup_cfg.id),
+                        None,
+                        None,
+                    ));
+                }
+            }
+        }
+        file_universe.duplicates_universe = files_universe;
+        file_universe.duplicates_scanned = files_scanned;
+        duplicate_groups = merged_groups
+            .into_values()
+            .map(|set| set.into_iter().collect::<Vec<_>>())
+            .collect();
+        duplicate_groups.sort();
+        record_timing(timings_ms, "duplicates", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.supply_chain.is_empty() {
+        for sc_cfg in &run_checks.supply_chain {
+            let out = run_supply_chain_check(repo_root_path, sc_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "supply_chain", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.tool_budget.is_empty() {
+        for budget_cfg in &run_checks.tool_budget {
+            let out = run_tool_budget_check(cfg, budget_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "tool_budget", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.reuse_first.is_empty() {
+        for reuse_cfg in &run_checks.reuse_first {
+            let out = run_reuse_first_check(repo_root_path, reuse_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "reuse_first", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.arch_layers.is_empty() {
+        for layers_cfg in &run_checks.arch_layers {
+            let out = run_arch_layers_check(repo_root_path, layers_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "arch_layers", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.dead_code.is_empty() {
+        for dead_cfg in &run_checks.dead_code {
+            let out = run_dead_code_check(repo_root_path, dead_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "dead_code", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.orphan_api.is_empty() {
+        for orphan_cfg in &run_checks.orphan_api {
+            let out = run_orphan_api_check(repo_root_path, orphan_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "orphan_api", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.complexity_budget.is_empty() {
+        for cx_cfg in &run_checks.complexity_budget {
+            let out = run_complexity_budget_check(repo_root_path, cx_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "complexity_budget", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.contract_break.is_empty() {
+        for contract_cfg in &run_checks.contract_break {
+            let out = run_contract_break_check(repo_root_path, contract_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "contract_break", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.file_encoding.is_empty() {
+        for encoding_cfg in &run_checks.file_encoding {
+            let out = run_file_encoding_check(repo_root_path, encoding_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "file_encoding", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.max_params.is_empty() {
+        for params_cfg in &run_checks.max_params {
+            let out = run_max_params_check(repo_root_path, params_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "max_params", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.binary_artifacts.is_empty() {
+        for artifacts_cfg in &run_checks.binary_artifacts {
+            let out = run_binary_artifacts_check(repo_root_path, artifacts_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "binary_artifacts", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.import_cycles.is_empty() {
+        for cycles_cfg in &run_checks.import_cycles {
+            let out = run_import_cycles_check(repo_root_path, cycles_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "import_cycles", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.naming_convention.is_empty() {
+        for naming_cfg in &run_checks.naming_convention {
+            let out = run_naming_convention_check(repo_root_path, naming_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "naming_convention", started_at);
+    }
+
+    let started_at = Instant::now();
+    if !run_checks.magic_numbers.is_empty() {
+        for magic_cfg in &run_checks.magic_numbers {
+            let out = run_magic_numbers_check(repo_root_path, magic_cfg);
+            violations_raw.extend(out.violations);
+        }
+        record_timing(timings_ms, "magic_numbers", started_at);
+    }
+
+    if let Some(env_cfg) = run_checks.env_registry.first() {
+        let started_at = Instant::now();
+        let env_result = run_env_registry_check(repo_root_path, env_cfg, &cfg.tools);
+        violations_raw.extend(env_result.violations);
+        effective_config = Some(env_result.summary);
+        record_timing(timings_ms, "env_registry", started_at);
+    }
+
+    CheckRun {
+        violations: violations_raw,
+        loc: loc_summary,
+        boundary: boundary_summary,
+        public_surface: public_surface_summary,
+        effective_config,
+        file_universe,
+        loc_per_file,
+        surface_items,
+        duplicate_groups,
+    }
+}
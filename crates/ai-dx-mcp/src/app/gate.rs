@@ -0,0 +1,86 @@
+use super::support::collect_suppressed_codes;
+use crate::{
+    api::{GateKind, GateOutput},
+    validate_insights::build_agent_digest_with_suppressed,
+};
+
+pub async fn gate(
+    repo_root: &str,
+    kind: GateKind,
+    dry_run: bool,
+    write_witness: bool,
+) -> GateOutput {
+    gate_with_budget(repo_root, kind, dry_run, write_witness, None, None, None).await
+}
+
+pub async fn gate_with_budget(
+    repo_root: &str,
+    kind: GateKind,
+    dry_run: bool,
+    write_witness: bool,
+    gate_budget_ms: Option<u64>,
+    witness_dir: Option<&str>,
+    fail_fast: Option<bool>,
+) -> GateOutput {
+    gate_with_options(
+        repo_root,
+        kind,
+        dry_run,
+        write_witness,
+        GateRunOptions {
+            budget_ms: gate_budget_ms,
+            witness_dir,
+            fail_fast,
+            ..GateRunOptions::default()
+        },
+    )
+    .await
+}
+
+/// Per-invocation gate overrides; the defaults follow the repo config.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GateRunOptions<'a> {
+    /// Total wall-time cap for the tool sequence.
+    pub budget_ms: Option<u64>,
+    /// Repo-relative witness dir (overrides `[proof] witness_dir`).
+    pub witness_dir: Option<&'a str>,
+    /// Overrides `[gate] fail_fast`.
+    pub fail_fast: Option<bool>,
+    /// Raise every tool's stdout/stderr tail cap to the hard ceiling for this run.
+    pub capture_full: bool,
+    /// Keep the verdict reasons' `source`/`origin` in the witness file.
+    pub explain_verdict: bool,
+}
+
+pub async fn gate_with_options(
+    repo_root: &str,
+    kind: GateKind,
+    dry_run: bool,
+    write_witness: bool,
+    options: GateRunOptions<'_>,
+) -> GateOutput {
+    let mut out = crate::gate_runner::gate(repo_root, kind, dry_run, write_witness, options).await;
+    let suppressed_codes = collect_suppressed_codes(&out.validate.suppressed);
+    let suppressed_count = out.validate.suppressed.len();
+
+    if let Some(verdict) = out.verdict.as_mut() {
+        verdict.quality_posture = out.validate.quality_posture.clone();
+        verdict.suppressed_count = suppressed_count;
+        verdict.suppressed_codes = suppressed_codes;
+    }
+
+    if let Some(verdict) = out.verdict.as_ref() {
+        out.agent_digest = Some(build_agent_digest_with_suppressed(
+            &verdict.decision,
+            &out.validate.violations,
+            &out.validate.findings_v2,
+            &out.validate.suppressed,
+        ));
+    }
+    out.all_findings =
+        crate::response::collect_all_findings(&out.validate.findings_v2, &out.receipts);
+
+    out.validate.evidence = crate::evidence::build_validate_envelope(&out.validate);
+    out.evidence = crate::evidence::build_gate_envelope(&out);
+    out
+}
@@ -0,0 +1,161 @@
+use super::map_config_error;
+use crate::{
+    api::{ApiError, ToolsDescribeOutput},
+    checks::boundary::BoundaryTestOutput,
+    exceptions::ExceptionsReportOutput,
+    repo::load_repo_config,
+};
+use std::path::Path;
+
+/// Lists allowlist entries by days until expiry, using the same loader (and fail-closed
+/// validation) as suppression.
+pub fn exceptions_report(repo_root: &str) -> ExceptionsReportOutput {
+    let today = chrono::Utc::now().date_naive();
+    let (exceptions, error) = match crate::exceptions::expiry_calendar(Path::new(repo_root), today)
+    {
+        Ok(v) => (v, None),
+        Err(v) => (
+            vec![],
+            Some(ApiError {
+                code: v.code,
+                message: v.message,
+            }),
+        ),
+    };
+    ExceptionsReportOutput {
+        ok: error.is_none(),
+        error,
+        repo_root: repo_root.to_string(),
+        today: today.format("%Y-%m-%d").to_string(),
+        exceptions,
+    }
+}
+
+/// Describes one tool; `resolved_env=true` adds the effective environment it runs with
+/// (registered vars from every `[[checks.env_registry]]` plus the tool's own `env`).
+pub fn tools_describe(repo_root: &str, tool_id: &str, resolved_env: bool) -> ToolsDescribeOutput {
+    let mut out = ToolsDescribeOutput {
+        ok: false,
+        error: None,
+        repo_root: repo_root.to_string(),
+        tool: None,
+        resolved_env: None,
+    };
+    let cfg = match load_repo_config(Path::new(repo_root)) {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            out.error = Some(map_config_error(repo_root, e));
+            return out;
+        }
+    };
+    let (Some(tool), Some(plugin_id)) = (cfg.tools.get(tool_id), cfg.tool_owners.get(tool_id))
+    else {
+        out.error = Some(ApiError {
+            code: "tools.unknown_tool_id".to_string(),
+            message: format!("unknown tool_id={tool_id}"),
+        });
+        return out;
+    };
+    out.tool = Some(crate::repo_view::to_public_tool_spec_with_owner(
+        tool, plugin_id,
+    ));
+    if resolved_env {
+        match crate::checks::env_registry::resolve_tool_env(
+            Path::new(repo_root),
+            &cfg.checks.env_registry,
+            tool,
+        ) {
+            Ok(env) => out.resolved_env = Some(env),
+            Err(v) => {
+                out.error = Some(ApiError {
+                    code: v.code,
+                    message: v.message,
+                });
+                return out;
+            }
+        }
+    }
+    out.ok = true;
+    out
+}
+
+/// Runs one configured boundary rule against `input` (used as given) and lists every match.
+pub fn boundary_test(repo_root: &str, rule_id: &str, input: &str) -> BoundaryTestOutput {
+    let mut out = BoundaryTestOutput {
+        ok: false,
+        error: None,
+        repo_root: repo_root.to_string(),
+        rule_id: rule_id.to_string(),
+        check_id: None,
+        input: input.to_string(),
+        matches: vec![],
+    };
+    let fail = |mut out: BoundaryTestOutput, code: &str, message: String| {
+        out.error = Some(ApiError {
+            code: code.to_string(),
+            message,
+        });
+        out
+    };
+
+    let cfg = match load_repo_config(Path::new(repo_root)) {
+        Ok(c) => c,
+        Err(e) => {
+            out.error = Some(map_config_error(repo_root, e));
+            return out;
+        }
+    };
+    let owners: Vec<_> = cfg
+        .checks
+        .boundary
+        .iter()
+        .flat_map(|check| {
+            check
+                .rules
+                .iter()
+                .filter(|r| r.id.trim() == rule_id)
+                .map(move |r| (check, r))
+        })
+        .collect();
+    let (check, rule) = match owners.as_slice() {
+        [one] => *one,
+        [] => {
+            return fail(
+                out,
+                "boundary_test.rule_not_found",
+                format!("no [[checks.boundary]] rule with id {rule_id:?}"),
+            );
+        }
+        many => {
+            let checks: Vec<&str> = many.iter().map(|(c, _)| c.id.as_str()).collect();
+            return fail(
+                out,
+                "boundary_test.rule_ambiguous",
+                format!(
+                    "rule id {rule_id:?} is defined by several boundary checks: {}",
+                    checks.join(", ")
+                ),
+            );
+        }
+    };
+    out.check_id = Some(check.id.clone());
+
+    let source = match std::fs::read_to_string(input) {
+        Ok(v) => v,
+        Err(e) => {
+            return fail(
+                out,
+                "boundary_test.input_read_failed",
+                format!("failed to read {input}: {e}"),
+            );
+        }
+    };
+    match crate::checks::boundary::match_boundary_rule(check, rule, input, source) {
+        Ok(matches) => {
+            out.ok = true;
+            out.matches = matches;
+            out
+        }
+        Err(msg) => fail(out, "boundary_test.rule_invalid", msg),
+    }
+}
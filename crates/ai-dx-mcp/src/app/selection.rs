@@ -0,0 +1,96 @@
+use crate::{api::CheckSelection, config::ChecksConfigV2, validate_insights::PartialRunSummary};
+
+/// Check kinds addressable by `validate --only/--skip` (the `[checks.<kind>]` table names).
+const CHECK_KINDS: &[&str] = &[
+    "loc",
+    "env_registry",
+    "boundary",
+    "surface",
+    "duplicates",
+    "supply_chain",
+    "tool_budget",
+    "reuse_first",
+    "arch_layers",
+    "dead_code",
+    "orphan_api",
+    "complexity_budget",
+    "contract_break",
+    "test_presence",
+    "file_encoding",
+    "max_params",
+    "binary_artifacts",
+    "import_cycles",
+    "naming_convention",
+    "magic_numbers",
+];
+
+fn take_kind<T>(checks: &mut Vec<T>) -> bool {
+    let configured = !checks.is_empty();
+    checks.clear();
+    configured
+}
+
+/// Empties `kind` in `checks`; true when it had at least one configured check.
+fn clear_check_kind(checks: &mut ChecksConfigV2, kind: &str) -> bool {
+    match kind {
+        "loc" => take_kind(&mut checks.loc),
+        "env_registry" => take_kind(&mut checks.env_registry),
+        "boundary" => take_kind(&mut checks.boundary),
+        "surface" => take_kind(&mut checks.surface),
+        "duplicates" => take_kind(&mut checks.duplicates),
+        "supply_chain" => take_kind(&mut checks.supply_chain),
+        "tool_budget" => take_kind(&mut checks.tool_budget),
+        "reuse_first" => take_kind(&mut checks.reuse_first),
+        "arch_layers" => take_kind(&mut checks.arch_layers),
+        "dead_code" => take_kind(&mut checks.dead_code),
+        "orphan_api" => take_kind(&mut checks.orphan_api),
+        "complexity_budget" => take_kind(&mut checks.complexity_budget),
+        "contract_break" => take_kind(&mut checks.contract_break),
+        "test_presence" => take_kind(&mut checks.test_presence),
+        "file_encoding" => take_kind(&mut checks.file_encoding),
+        "max_params" => take_kind(&mut checks.max_params),
+        "binary_artifacts" => take_kind(&mut checks.binary_artifacts),
+        "import_cycles" => take_kind(&mut checks.import_cycles),
+        "naming_convention" => take_kind(&mut checks.naming_convention),
+        "magic_numbers" => take_kind(&mut checks.magic_numbers),
+        _ => false,
+    }
+}
+
+/// The checks to execute under `selection`, plus the partial-run marker when anything is left
+/// out. The full config stays untouched so config hashing and coverage see every check.
+pub(super) fn select_checks(
+    checks: &ChecksConfigV2,
+    selection: &CheckSelection,
+) -> Result<(ChecksConfigV2, Option<PartialRunSummary>), String> {
+    if let Some(unknown) = selection
+        .only
+        .iter()
+        .chain(&selection.skip)
+        .find(|k| !CHECK_KINDS.contains(&k.as_str()))
+    {
+        return Err(format!(
+            "unknown check kind {unknown:?}; expected one of: {}",
+            CHECK_KINDS.join(", ")
+        ));
+    }
+    let mut run = checks.clone();
+    if selection.is_full() {
+        return Ok((run, None));
+    }
+    let mut skipped_checks = vec![];
+    for kind in CHECK_KINDS {
+        let selected = (selection.only.is_empty() || selection.only.iter().any(|k| k == kind))
+            && !selection.skip.iter().any(|k| k == kind);
+        if !selected && clear_check_kind(&mut run, kind) {
+            skipped_checks.push(kind.to_string());
+        }
+    }
+    Ok((
+        run,
+        Some(PartialRunSummary {
+            selection: selection.clone(),
+            skipped_checks,
+        }),
+    ))
+}
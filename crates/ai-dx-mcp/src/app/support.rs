@@ -1,13 +1,15 @@
 use crate::{
     api::{ApiError, ValidateMode, ValidateOutput, Violation},
     repo::RepoConfig,
+    validate_insights::CheckError,
 };
 use std::{
     collections::{BTreeMap, BTreeSet},
     path::Path,
+    time::Instant,
 };
 
-pub(super) fn empty_output_with_error(
+pub(crate) fn empty_output_with_error(
     repo_root: &str,
     mode: ValidateMode,
     error: ApiError,
@@ -35,14 +37,117 @@ pub(super) fn empty_output_with_error(
         summary_md: None,
         evidence: crate::api::EvidenceEnvelope::default(),
         payload_meta: None,
+        timings_ms: None,
+        baseline_diff: None,
+        disabled_packs: vec![],
+        partial_run: None,
+        check_errors: vec![],
+        swamped: false,
+        swamped_summary: None,
     }
 }
 
+pub(super) fn timings_requested_by_env() -> bool {
+    std::env::var("COMPAS_TIMINGS")
+        .ok()
+        .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+}
+
+pub(super) fn record_timing(timings: &mut BTreeMap<String, u64>, name: &str, started_at: Instant) {
+    let elapsed = started_at.elapsed().as_millis() as u64;
+    *timings.entry(name.to_string()).or_insert(0) += elapsed;
+}
+
+/// Hashes the checks config by meaning, not layout: canonical JSON (sorted keys), integral
+/// floats written as integers, empty lists and unset/`false` fields dropped, and each
+/// `[[checks.<kind>]]` list ordered by content, so reordering TOML keys or check blocks, or a
+/// release adding a check kind or field the config does not use, leaves the hash unchanged.
 pub(super) fn compute_checks_hash(cfg: &RepoConfig) -> String {
-    let canonical = serde_json::to_string(&cfg.checks).unwrap_or_default();
+    let mut value = serde_json::to_value(&cfg.checks).unwrap_or_default();
+    normalize_numbers(&mut value);
+    prune_unset(&mut value);
+    if let Some(kinds) = value.as_object_mut() {
+        for checks in kinds.values_mut() {
+            if let Some(list) = checks.as_array_mut() {
+                list.sort_by_cached_key(crate::canonical_json::to_canonical_string);
+            }
+        }
+    }
+    let canonical = crate::canonical_json::to_canonical_string(&value);
     format!("sha256:{}", crate::hash::sha256_hex(canonical.as_bytes()))
 }
 
+/// The layout-sensitive hash earlier releases stored (plain `serde_json` in field order).
+/// Snapshots and `governance.config_hash` locks carrying it are still accepted until the next
+/// `--write-baseline` stores `compute_checks_hash`.
+pub(super) fn field_order_checks_hash(cfg: &RepoConfig) -> String {
+    let plain = serde_json::to_string(&cfg.checks).unwrap_or_default();
+    format!("sha256:{}", crate::hash::sha256_hex(plain.as_bytes()))
+}
+
+fn normalize_numbers(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Number(n) => {
+            if let Some(f) = n.as_f64().filter(|_| n.is_f64())
+                && f.fract() == 0.0
+                && f.abs() < 9.0e15
+            {
+                *value = serde_json::Value::from(f as i64);
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(normalize_numbers),
+        serde_json::Value::Object(map) => map.values_mut().for_each(normalize_numbers),
+        _ => {}
+    }
+}
+
+/// Drops object fields that hold `null`, `false`, `[]` or `{}` (after pruning their own
+/// fields): each is what an omitted `#[serde(default)]` field deserializes to.
+fn prune_unset(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(items) => items.iter_mut().for_each(prune_unset),
+        serde_json::Value::Object(map) => {
+            map.values_mut().for_each(prune_unset);
+            map.retain(|_, v| match v {
+                serde_json::Value::Null | serde_json::Value::Bool(false) => false,
+                serde_json::Value::Array(items) => !items.is_empty(),
+                serde_json::Value::Object(fields) => !fields.is_empty(),
+                _ => true,
+            });
+        }
+        _ => {}
+    }
+}
+
+/// One `CheckError` per `<check>.check_failed` violation, whichever check raised it.
+///
+/// Checks word the message as `<check> check failed (id=<id>): <reason>`; other failures
+/// (quality_delta, gate-level checks) carry no id there and fall back to the violation path.
+pub(crate) fn check_errors_from<'a>(
+    violations: impl IntoIterator<Item = &'a Violation>,
+) -> Vec<CheckError> {
+    violations
+        .into_iter()
+        .filter_map(|v| {
+            let check = v.code.strip_suffix(".check_failed")?;
+            let prefix = format!("{check} check failed (id=");
+            let parsed = v
+                .message
+                .strip_prefix(&prefix)
+                .and_then(|rest| rest.split_once("): "));
+            let (id, message) = match parsed {
+                Some((id, reason)) => (id.to_string(), reason.to_string()),
+                None => (v.path.clone().unwrap_or_default(), v.message.clone()),
+            };
+            Some(CheckError {
+                check: check.to_string(),
+                id,
+                message,
+            })
+        })
+        .collect()
+}
+
 pub(super) fn has_prior_baselines(repo_root: &Path) -> bool {
     let base = repo_root.join(".agents/mcp/compas/baselines");
     base.join("loc.json").is_file()
@@ -59,6 +164,77 @@ pub(super) fn collect_suppressed_codes(violations: &[Violation]) -> Vec<String>
         .collect()
 }
 
+/// Observes tools that run with the contract's `[tools.defaults] timeout_ms` instead of
+/// declaring their own.
+pub(super) fn detect_defaulted_tool_timeouts(cfg: &RepoConfig) -> Vec<Violation> {
+    cfg.timeout_defaulted_tools
+        .iter()
+        .filter_map(|tool_id| {
+            let timeout_ms = cfg.tools.get(tool_id)?.timeout_ms;
+            let plugin_id = cfg.tool_owners.get(tool_id);
+            Some(Violation::observation(
+                "tools.timeout_defaulted",
+                format!(
+                    "tool {tool_id} declares no timeout_ms and runs with the [tools.defaults] value ({}ms)",
+                    timeout_ms.unwrap_or_default()
+                ),
+                plugin_id.map(|p| format!(".agents/mcp/compas/plugins/{p}/plugin.toml")),
+                Some(serde_json::json!({
+                    "tool_id": tool_id,
+                    "plugin_id": plugin_id,
+                    "timeout_ms": timeout_ms,
+                })),
+            ))
+        })
+        .collect()
+}
+
+/// Flags tools whose description is shorter than `[tools] min_description_len` (trimmed chars)
+/// or that have no owning `plugin_id`.
+pub(super) fn detect_incomplete_tool_metadata(cfg: &RepoConfig) -> Vec<Violation> {
+    let min_description_len = cfg
+        .quality_contract
+        .as_ref()
+        .map(|c| c.tools.clone())
+        .unwrap_or_default()
+        .min_description_len;
+    let mut violations: Vec<Violation> = vec![];
+    for (tool_id, tool) in &cfg.tools {
+        let plugin_id = cfg
+            .tool_owners
+            .get(tool_id)
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty());
+        let description_len = tool.description.trim().chars().count();
+        let mut missing: Vec<&str> = vec![];
+        if description_len < min_description_len {
+            missing.push("description");
+        }
+        if plugin_id.is_none() {
+            missing.push("plugin_id");
+        }
+        if missing.is_empty() {
+            continue;
+        }
+        violations.push(Violation::observation(
+            "tools.metadata_incomplete",
+            format!(
+                "tool {tool_id} has incomplete metadata: {} (description {description_len} chars, min {min_description_len})",
+                missing.join(", ")
+            ),
+            plugin_id.map(|p| format!(".agents/mcp/compas/plugins/{p}/plugin.toml")),
+            Some(serde_json::json!({
+                "tool_id": tool_id,
+                "plugin_id": plugin_id,
+                "missing": missing,
+                "description_len": description_len,
+                "min_description_len": min_description_len,
+            })),
+        ));
+    }
+    violations
+}
+
 pub(super) fn detect_tool_duplicates(cfg: &RepoConfig) -> Vec<Violation> {
     #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
     struct Signature {
@@ -68,6 +244,7 @@ pub(super) fn detect_tool_duplicates(cfg: &RepoConfig) -> Vec<Violation> {
         timeout_ms: Option<u64>,
         max_stdout_bytes: Option<usize>,
         max_stderr_bytes: Option<usize>,
+        max_tail_bytes: Option<usize>,
         env_pairs: Vec<(String, String)>,
     }
 
@@ -86,6 +263,7 @@ pub(super) fn detect_tool_duplicates(cfg: &RepoConfig) -> Vec<Violation> {
             timeout_ms: tool.timeout_ms,
             max_stdout_bytes: tool.max_stdout_bytes,
             max_stderr_bytes: tool.max_stderr_bytes,
+            max_tail_bytes: tool.max_tail_bytes,
             env_pairs,
         };
         by_signature.entry(sig).or_default().push(tool_id.clone());
@@ -114,6 +292,7 @@ pub(super) fn detect_tool_duplicates(cfg: &RepoConfig) -> Vec<Violation> {
                     "timeout_ms": sig.timeout_ms,
                     "max_stdout_bytes": sig.max_stdout_bytes,
                     "max_stderr_bytes": sig.max_stderr_bytes,
+                    "max_tail_bytes": sig.max_tail_bytes,
                 })),
             ));
         }
@@ -155,3 +334,73 @@ pub(super) fn detect_tool_duplicates(cfg: &RepoConfig) -> Vec<Violation> {
 
     violations
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_errors_cover_every_check_failed_violation() {
+        let violations = [
+            Violation::blocking(
+                "magic_numbers.check_failed",
+                "magic_numbers check failed (id=mn): bad glob",
+                None,
+                None,
+            ),
+            Violation::blocking("loc.max_exceeded", "too long", None, None),
+            Violation::blocking(
+                "quality_delta.check_failed",
+                "snapshot unreadable",
+                Some("/repo/.agents/mcp/compas/baselines/quality_snapshot.json".to_string()),
+                None,
+            ),
+            Violation::blocking(
+                "change_impact.check_failed",
+                "diff base missing",
+                None,
+                None,
+            ),
+        ];
+        let got: Vec<(String, String, String)> = check_errors_from(&violations)
+            .into_iter()
+            .map(|e| (e.check, e.id, e.message))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                ("magic_numbers".into(), "mn".into(), "bad glob".into()),
+                (
+                    "quality_delta".into(),
+                    "/repo/.agents/mcp/compas/baselines/quality_snapshot.json".into(),
+                    "snapshot unreadable".into()
+                ),
+                (
+                    "change_impact".into(),
+                    String::new(),
+                    "diff base missing".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_hash_normalizes_integral_floats_and_prunes_unset_fields() {
+        let mut value = serde_json::json!({
+            "ratio": 2.0,
+            "mixed": [1.5, 3.0, -4.0],
+            "unset": null,
+            "off": false,
+            "on": true,
+            "empty": [],
+            "nested": {"inner": [], "gone": null},
+            "kept": {"limit": 10.0},
+        });
+        normalize_numbers(&mut value);
+        prune_unset(&mut value);
+        assert_eq!(
+            crate::canonical_json::to_canonical_string(&value),
+            r#"{"kept":{"limit":10},"mixed":[1.5,3,-4],"on":true,"ratio":2}"#
+        );
+    }
+}
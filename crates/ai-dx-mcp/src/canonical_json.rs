@@ -0,0 +1,75 @@
+//! Byte-stable JSON for hashing and signing: equal values always serialize to equal bytes,
+//! however the source was ordered or indented.
+
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// RFC 8785 key order: UTF-16 code units, not UTF-8 bytes.
+fn cmp_keys(a: &str, b: &str) -> Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| cmp_keys(a, b));
+            out.push('{');
+            for (i, (key, v)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(v, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, v) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(v, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Sorted keys, no insignificant whitespace; strings and numbers use serde_json's encoding.
+pub fn to_canonical_string(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+pub fn canonicalize_str(raw: &str) -> Result<String, serde_json::Error> {
+    Ok(to_canonical_string(&serde_json::from_str(raw)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn differently_ordered_equivalent_documents_canonicalize_identically() {
+        let a = r#"{"b": 1, "a": {"y": [1, 2, {"d": null, "c": "x"}], "x": true}}"#;
+        let b = "{\n  \"a\": {\n    \"x\": true,\n    \"y\": [1, 2, {\"c\": \"x\", \"d\": null}]\n  },\n  \"b\": 1\n}\n";
+        let ca = canonicalize_str(a).expect("canonicalize a");
+        assert_eq!(ca, canonicalize_str(b).expect("canonicalize b"));
+        assert_eq!(ca, r#"{"a":{"x":true,"y":[1,2,{"c":"x","d":null}]},"b":1}"#);
+    }
+
+    #[test]
+    fn keys_sort_by_utf16_code_units_and_strings_stay_escaped() {
+        // U+E000 sorts after U+1F600 in UTF-16 (0xE000 > 0xD83D) but before it in UTF-8.
+        let value = serde_json::json!({ "\u{1F600}": 1, "\u{E000}": 2, "q": "a\"b\n" });
+        assert_eq!(
+            to_canonical_string(&value),
+            "{\"q\":\"a\\\"b\\n\",\"\u{1F600}\":1,\"\u{E000}\":2}"
+        );
+    }
+}
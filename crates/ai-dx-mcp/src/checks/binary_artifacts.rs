@@ -0,0 +1,181 @@
+use crate::api::Violation;
+use crate::checks::common::{collect_candidate_files, walk_repo_files};
+use crate::config::BinaryArtifactsCheckConfigV2;
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub struct BinaryArtifactsCheckResult {
+    pub files_scanned: usize,
+    pub violations: Vec<Violation>,
+}
+
+fn has_binary_extension(rel: &str, extensions: &[String]) -> bool {
+    Path::new(rel)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| extensions.iter().any(|x| x.eq_ignore_ascii_case(ext)))
+}
+
+/// Flags matched files above `max_file_bytes` or with a configured binary extension.
+pub fn run_binary_artifacts_check(
+    repo_root: &Path,
+    cfg: &BinaryArtifactsCheckConfigV2,
+) -> BinaryArtifactsCheckResult {
+    let mut files = match collect_candidate_files(repo_root, &cfg.include_globs, &cfg.exclude_globs)
+    {
+        Ok(v) => v,
+        Err(msg) => {
+            return BinaryArtifactsCheckResult {
+                files_scanned: 0,
+                violations: vec![Violation::blocking(
+                    "binary_artifacts.check_failed",
+                    format!("binary_artifacts check failed (id={}): {msg}", cfg.id),
+                    None,
+                    None,
+                )],
+            };
+        }
+    };
+    if cfg.respect_gitignore {
+        let visible: BTreeSet<PathBuf> = walk_repo_files(repo_root, true, None)
+            .files
+            .into_iter()
+            .collect();
+        files.retain(|(_, path)| visible.contains(path));
+    }
+
+    let mut files_scanned = 0usize;
+    let mut violations = vec![];
+    for (rel, path) in files {
+        let size_bytes = match std::fs::symlink_metadata(&path) {
+            Ok(m) => m.len(),
+            Err(e) => {
+                violations.push(Violation::blocking(
+                    "binary_artifacts.read_failed",
+                    format!("failed to stat {rel}: {e}"),
+                    Some(rel.clone()),
+                    None,
+                ));
+                continue;
+            }
+        };
+        files_scanned += 1;
+
+        let (reason, message) = if size_bytes > cfg.max_file_bytes {
+            (
+                "size",
+                format!(
+                    "{rel} is {size_bytes} bytes (max_file_bytes={})",
+                    cfg.max_file_bytes
+                ),
+            )
+        } else if has_binary_extension(&rel, &cfg.binary_extensions) {
+            (
+                "extension",
+                format!("{rel} looks like a binary artifact ({size_bytes} bytes)"),
+            )
+        } else {
+            continue;
+        };
+        let details = json!({
+            "check_id": cfg.id,
+            "size_bytes": size_bytes,
+            "max_file_bytes": cfg.max_file_bytes,
+            "reason": reason,
+        });
+        let violation = if cfg.blocking {
+            Violation::blocking
+        } else {
+            Violation::observation
+        };
+        violations.push(violation(
+            "binary_artifacts.committed",
+            message,
+            Some(rel),
+            Some(details),
+        ));
+    }
+
+    BinaryArtifactsCheckResult {
+        files_scanned,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ViolationTier;
+    use tempfile::tempdir;
+
+    fn cfg() -> BinaryArtifactsCheckConfigV2 {
+        BinaryArtifactsCheckConfigV2 {
+            id: "artifacts".to_string(),
+            include_globs: vec![],
+            exclude_globs: vec![],
+            max_file_bytes: 1024,
+            binary_extensions: vec!["so".to_string(), "jar".to_string()],
+            respect_gitignore: false,
+            blocking: false,
+        }
+    }
+
+    #[test]
+    fn large_binary_fires_and_small_text_does_not() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::create_dir_all(dir.path().join("assets")).expect("mkdir");
+        std::fs::write(dir.path().join("assets/blob.dat"), vec![0u8; 4096]).expect("write");
+        std::fs::write(dir.path().join("README.md"), "hello\n").expect("write");
+
+        let out = run_binary_artifacts_check(dir.path(), &cfg());
+        assert_eq!(out.files_scanned, 2);
+        assert_eq!(out.violations.len(), 1, "{:?}", out.violations);
+        let v = &out.violations[0];
+        assert_eq!(v.code, "binary_artifacts.committed");
+        assert_eq!(v.path.as_deref(), Some("assets/blob.dat"));
+        assert_eq!(v.tier, ViolationTier::Observation);
+        let details = v.details.as_ref().expect("details");
+        assert_eq!(details["size_bytes"], 4096);
+        assert_eq!(details["reason"], "size");
+    }
+
+    #[test]
+    fn binary_extension_fires_regardless_of_size_and_can_block() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("libfoo.SO"), b"\x7fELF").expect("write");
+        let out = run_binary_artifacts_check(
+            dir.path(),
+            &BinaryArtifactsCheckConfigV2 {
+                blocking: true,
+                ..cfg()
+            },
+        );
+        assert_eq!(out.violations.len(), 1, "{:?}", out.violations);
+        assert_eq!(out.violations[0].tier, ViolationTier::Blocking);
+        assert_eq!(
+            out.violations[0].details.as_ref().expect("details")["reason"],
+            "extension"
+        );
+    }
+
+    #[test]
+    fn gitignored_artifacts_are_skipped_only_when_enabled() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::create_dir_all(dir.path().join("dist")).expect("mkdir");
+        std::fs::write(dir.path().join(".gitignore"), "dist/\n").expect("write");
+        std::fs::write(dir.path().join("dist/app.jar"), b"PK").expect("write");
+
+        let out = run_binary_artifacts_check(dir.path(), &cfg());
+        assert_eq!(out.violations.len(), 1, "{:?}", out.violations);
+        let out = run_binary_artifacts_check(
+            dir.path(),
+            &BinaryArtifactsCheckConfigV2 {
+                respect_gitignore: true,
+                ..cfg()
+            },
+        );
+        assert!(out.violations.is_empty(), "{:?}", out.violations);
+    }
+}
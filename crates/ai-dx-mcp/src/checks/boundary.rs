@@ -1,16 +1,42 @@
-use crate::api::Violation;
-use crate::config::BoundaryCheckConfigV2;
+use crate::api::{ApiError, Violation};
+use crate::checks::common::walk_repo_files;
+use crate::config::{BoundaryCheckConfigV2, BoundaryRuleConfigV2};
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::path::Path;
-use walkdir::WalkDir;
+
+/// One match of a boundary rule's `deny_regex`; `line`/`column` are 1-based, `start`/`end` are
+/// byte offsets into the scanned text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BoundaryRuleMatch {
+    pub line: usize,
+    pub column: usize,
+    pub start: usize,
+    pub end: usize,
+    pub matched: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BoundaryTestOutput {
+    pub ok: bool,
+    pub error: Option<ApiError>,
+    pub repo_root: String,
+    pub rule_id: String,
+    /// The `[[checks.boundary]]` check that owns the rule.
+    pub check_id: Option<String>,
+    pub input: String,
+    pub matches: Vec<BoundaryRuleMatch>,
+}
 
 #[derive(Debug)]
 pub struct BoundaryCheckResult {
     pub violations: Vec<Violation>,
     pub files_scanned: usize,
     pub rules_checked: usize,
+    pub skipped_by_depth: usize,
 }
 
 fn build_globset(globs: &[String]) -> Result<GlobSet, String> {
@@ -49,7 +75,7 @@ fn looks_like_rust_mod_decl_with_body(line: &str) -> bool {
     starts_like_mod && t.contains('{')
 }
 
-fn strip_rust_cfg_test_modules(source: &str) -> String {
+pub(crate) fn strip_rust_cfg_test_modules(source: &str) -> String {
     let lines: Vec<&str> = source.split('\n').collect();
     let mut out = String::with_capacity(source.len());
     let mut i = 0usize;
@@ -100,6 +126,72 @@ fn strip_rust_cfg_test_modules(source: &str) -> String {
     out
 }
 
+struct CompiledBoundaryRule {
+    id: String,
+    message: String,
+    regex: Regex,
+}
+
+fn compile_boundary_rule(rule: &BoundaryRuleConfigV2) -> Result<CompiledBoundaryRule, String> {
+    let id = rule.id.trim();
+    if id.is_empty() {
+        return Err("boundary rule has empty id".to_string());
+    }
+    let regex = Regex::new(rule.deny_regex.trim()).map_err(|e| {
+        format!(
+            "failed to compile boundary rule regex id={id} regex={:?}: {e}",
+            rule.deny_regex
+        )
+    })?;
+    let message = rule
+        .message
+        .clone()
+        .unwrap_or_else(|| "boundary rule violation".to_string());
+    Ok(CompiledBoundaryRule {
+        id: id.to_string(),
+        message,
+        regex,
+    })
+}
+
+/// The text rules are matched against: `#[cfg(test)]` modules blanked (lines kept) for `.rs`
+/// paths when the check strips them.
+fn scan_source(cfg: &BoundaryCheckConfigV2, path: &str, source: String) -> String {
+    if cfg.strip_rust_cfg_test_blocks && path.ends_with(".rs") {
+        strip_rust_cfg_test_modules(&source)
+    } else {
+        source
+    }
+}
+
+fn rule_match(source: &str, m: regex::Match<'_>) -> BoundaryRuleMatch {
+    let line_start = source[..m.start()].rfind('\n').map_or(0, |i| i + 1);
+    BoundaryRuleMatch {
+        line: line_for_offset(source, m.start()),
+        column: source[line_start..m.start()].chars().count() + 1,
+        start: m.start(),
+        end: m.end(),
+        matched: m.as_str().to_string(),
+    }
+}
+
+/// Every match of `rule` in `source`, scanned exactly as `run_boundary_check` would scan a
+/// file at `path` (validate itself reports only the first match per file).
+pub fn match_boundary_rule(
+    cfg: &BoundaryCheckConfigV2,
+    rule: &BoundaryRuleConfigV2,
+    path: &str,
+    source: String,
+) -> Result<Vec<BoundaryRuleMatch>, String> {
+    let rule = compile_boundary_rule(rule)?;
+    let source = scan_source(cfg, path, source);
+    Ok(rule
+        .regex
+        .find_iter(&source)
+        .map(|m| rule_match(&source, m))
+        .collect())
+}
+
 pub fn run_boundary_check(
     repo_root: &Path,
     cfg: &BoundaryCheckConfigV2,
@@ -118,37 +210,18 @@ pub fn run_boundary_check(
     let includes = build_globset(&include_globs)?;
     let excludes = build_globset(&exclude_globs)?;
 
-    let mut compiled_rules: Vec<(String, String, Regex)> = vec![];
-    for rule in &cfg.rules {
-        let id = rule.id.trim();
-        if id.is_empty() {
-            return Err("boundary rule has empty id".to_string());
-        }
-        let regex = Regex::new(rule.deny_regex.trim()).map_err(|e| {
-            format!(
-                "failed to compile boundary rule regex id={id} regex={:?}: {e}",
-                rule.deny_regex
-            )
-        })?;
-        let message = rule
-            .message
-            .clone()
-            .unwrap_or_else(|| "boundary rule violation".to_string());
-        compiled_rules.push((id.to_string(), message, regex));
-    }
+    let compiled_rules = cfg
+        .rules
+        .iter()
+        .map(compile_boundary_rule)
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut violations: Vec<Violation> = vec![];
     let mut files_scanned = 0usize;
 
-    for entry in WalkDir::new(repo_root)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(Result::ok)
-    {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-        let path = entry.path();
+    let walk = walk_repo_files(repo_root, cfg.respect_gitignore, cfg.max_depth);
+    for entry in walk.files {
+        let path = entry.as_path();
         let rel = match normalize_rel_path(repo_root, path) {
             Some(v) => v,
             None => continue,
@@ -172,23 +245,19 @@ pub fn run_boundary_check(
             }
         };
 
-        let source_for_scan = if cfg.strip_rust_cfg_test_blocks && rel.ends_with(".rs") {
-            strip_rust_cfg_test_modules(&source)
-        } else {
-            source
-        };
+        let source_for_scan = scan_source(cfg, &rel, source);
 
-        for (rule_id, rule_message, regex) in &compiled_rules {
-            if let Some(m) = regex.find(&source_for_scan) {
-                let line = line_for_offset(&source_for_scan, m.start());
+        for rule in &compiled_rules {
+            if let Some(m) = rule.regex.find(&source_for_scan) {
+                let found = rule_match(&source_for_scan, m);
                 violations.push(Violation::blocking(
                     "boundary.rule_violation",
-                    format!("{rule_message} (rule_id={rule_id})"),
+                    format!("{} (rule_id={})", rule.message, rule.id),
                     Some(rel.clone()),
                     Some(json!({
-                        "rule_id": rule_id,
-                        "line": line,
-                        "matched": m.as_str(),
+                        "rule_id": rule.id,
+                        "line": found.line,
+                        "matched": found.matched,
                     })),
                 ));
             }
@@ -199,5 +268,6 @@ pub fn run_boundary_check(
         violations,
         files_scanned,
         rules_checked: compiled_rules.len(),
+        skipped_by_depth: walk.skipped_by_depth,
     })
 }
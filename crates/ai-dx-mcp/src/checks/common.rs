@@ -28,6 +28,62 @@ pub(crate) fn normalize_rel(repo_root: &Path, path: &Path) -> Option<String> {
     Some(rel.to_string_lossy().replace('\\', "/"))
 }
 
+/// Regular files found by [`walk_repo_files`].
+pub(crate) struct RepoWalk {
+    pub(crate) files: Vec<PathBuf>,
+    /// Directories at the `max_depth` limit whose contents were not walked.
+    pub(crate) skipped_by_depth: usize,
+}
+
+/// Lists regular files under `repo_root`. With `respect_gitignore`, `.gitignore`d paths and
+/// `.git/` are skipped (no git checkout required); otherwise every file is visited.
+/// `max_depth` counts path components below the root (`1` = files directly in the root).
+pub(crate) fn walk_repo_files(
+    repo_root: &Path,
+    respect_gitignore: bool,
+    max_depth: Option<usize>,
+) -> RepoWalk {
+    let at_limit = |depth: usize| max_depth.is_some_and(|max| depth >= max);
+    let mut walk = RepoWalk {
+        files: vec![],
+        skipped_by_depth: 0,
+    };
+    if !respect_gitignore {
+        for e in WalkDir::new(repo_root)
+            .follow_links(false)
+            .max_depth(max_depth.unwrap_or(usize::MAX))
+            .into_iter()
+            .filter_map(Result::ok)
+        {
+            if e.file_type().is_file() {
+                walk.files.push(e.into_path());
+            } else if e.file_type().is_dir() && at_limit(e.depth()) {
+                walk.skipped_by_depth += 1;
+            }
+        }
+        return walk;
+    }
+    for e in ignore::WalkBuilder::new(repo_root)
+        .follow_links(false)
+        .hidden(false)
+        .ignore(false)
+        .parents(false)
+        .git_global(false)
+        .require_git(false)
+        .max_depth(max_depth)
+        .filter_entry(|e| e.file_name() != ".git")
+        .build()
+        .filter_map(Result::ok)
+    {
+        match e.file_type() {
+            Some(t) if t.is_file() => walk.files.push(e.into_path()),
+            Some(t) if t.is_dir() && at_limit(e.depth()) => walk.skipped_by_depth += 1,
+            _ => {}
+        }
+    }
+    walk
+}
+
 pub(crate) fn collect_candidate_files(
     repo_root: &Path,
     include_globs: &[String],
@@ -1,6 +1,7 @@
 use crate::api::Violation;
 use crate::checks::common::{collect_candidate_files, is_probably_code_file};
 use crate::config::{DeadCodeCheckConfigV2, OrphanApiCheckConfigV2};
+use globset::{Glob, GlobMatcher};
 use regex::Regex;
 use serde_json::json;
 use std::collections::BTreeMap;
@@ -23,14 +24,51 @@ pub struct DeadCodeCheckResult {
 #[derive(Debug)]
 pub struct OrphanApiCheckResult {
     pub symbols_scanned: usize,
+    pub allowlisted_exports: usize,
     pub violations: Vec<Violation>,
 }
 
+struct ExportAllowRule {
+    path: Option<GlobMatcher>,
+    name: GlobMatcher,
+}
+
+impl ExportAllowRule {
+    fn matches(&self, s: &Symbol) -> bool {
+        self.name.is_match(&s.name) && self.path.as_ref().is_none_or(|p| p.is_match(&s.rel_path))
+    }
+}
+
+fn compile_allow_exports(entries: &[String]) -> Result<Vec<ExportAllowRule>, String> {
+    let compile = |g: &str| {
+        Glob::new(g)
+            .map(|g| g.compile_matcher())
+            .map_err(|e| format!("invalid allow_exports entry {g:?}: {e}"))
+    };
+    entries
+        .iter()
+        .map(|entry| match entry.rsplit_once("::") {
+            Some((path, name)) => Ok(ExportAllowRule {
+                path: Some(compile(path)?),
+                name: compile(name)?,
+            }),
+            None => Ok(ExportAllowRule {
+                path: None,
+                name: compile(entry)?,
+            }),
+        })
+        .collect()
+}
+
 fn parse_symbols(rel: &str, raw: &str) -> Vec<Symbol> {
     let mut out = vec![];
     let re_rust = Regex::new(r"^\s*(pub\s+)?(?:async\s+)?fn\s+([A-Za-z_][A-Za-z0-9_]*)").unwrap();
     let re_rust_type =
         Regex::new(r"^\s*pub\s+(?:struct|enum|trait|mod|type)\s+([A-Za-z_][A-Za-z0-9_]*)").unwrap();
+    let re_rust_reexport = Regex::new(
+        r"^\s*pub\s+use\s+(?:[A-Za-z_][A-Za-z0-9_]*::)*([A-Za-z_][A-Za-z0-9_]*)(?:\s+as\s+([A-Za-z_][A-Za-z0-9_]*))?\s*;",
+    )
+    .unwrap();
     let re_py = Regex::new(r"^\s*def\s+([A-Za-z_][A-Za-z0-9_]*)\s*\(").unwrap();
     let re_js_fn = Regex::new(r"^\s*(export\s+)?function\s+([A-Za-z_][A-Za-z0-9_]*)\s*\(").unwrap();
     let re_js_const = Regex::new(
@@ -57,6 +95,16 @@ fn parse_symbols(rel: &str, raw: &str) -> Vec<Symbol> {
             });
             continue;
         }
+        if let Some(c) = re_rust_reexport.captures(line) {
+            let name = c.get(2).or(c.get(1)).unwrap().as_str().to_string();
+            out.push(Symbol {
+                name,
+                rel_path: rel.to_string(),
+                line: idx + 1,
+                public: true,
+            });
+            continue;
+        }
         if let Some(c) = re_py.captures(line) {
             let name = c.get(1).unwrap().as_str().to_string();
             out.push(Symbol {
@@ -182,12 +230,16 @@ pub fn run_orphan_api_check(
     repo_root: &Path,
     cfg: &OrphanApiCheckConfigV2,
 ) -> OrphanApiCheckResult {
-    let (symbols, texts) = match collect_symbols(repo_root, &cfg.include_globs, &cfg.exclude_globs)
-    {
+    let scanned = compile_allow_exports(&cfg.allow_exports).and_then(|allow| {
+        collect_symbols(repo_root, &cfg.include_globs, &cfg.exclude_globs)
+            .map(|(symbols, texts)| (allow, symbols, texts))
+    });
+    let (allow, symbols, texts) = match scanned {
         Ok(v) => v,
         Err(msg) => {
             return OrphanApiCheckResult {
                 symbols_scanned: 0,
+                allowlisted_exports: 0,
                 violations: vec![Violation::blocking(
                     "orphan_api.check_failed",
                     format!("orphan_api check failed (id={}): {msg}", cfg.id),
@@ -198,10 +250,10 @@ pub fn run_orphan_api_check(
         }
     };
 
-    let public_symbols: Vec<&Symbol> = symbols
+    let (allowlisted, public_symbols): (Vec<&Symbol>, Vec<&Symbol>) = symbols
         .iter()
         .filter(|s| s.public && s.name.len() >= cfg.min_symbol_len)
-        .collect();
+        .partition(|s| allow.iter().any(|rule| rule.matches(s)));
     let names: Vec<String> = public_symbols.iter().map(|s| s.name.clone()).collect();
     let counts = symbol_usage_counts(&texts, &names);
 
@@ -230,6 +282,7 @@ pub fn run_orphan_api_check(
 
     OrphanApiCheckResult {
         symbols_scanned: symbols.len(),
+        allowlisted_exports: allowlisted.len(),
         violations,
     }
 }
@@ -288,6 +341,7 @@ pub fn api_orphan() -> i32 { 1 }
                 include_globs: vec!["src/**/*.rs".to_string()],
                 exclude_globs: vec![],
                 min_symbol_len: 3,
+                allow_exports: vec![],
                 blocking: false,
             },
         );
@@ -297,4 +351,61 @@ pub fn api_orphan() -> i32 { 1 }
                 .any(|v| v.code == "orphan_api.unused_public_symbol")
         );
     }
+
+    #[test]
+    fn orphan_api_skips_allowlisted_reexports() {
+        let dir = tempdir().unwrap();
+        let repo = dir.path();
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+        std::fs::write(
+            repo.join("src/lib.rs"),
+            r#"
+pub use external_crate::FacadeClient;
+pub use external_crate::codec::encode as facade_encode;
+pub use external_crate::StrayHandle;
+pub fn api_orphan() -> i32 { 1 }
+"#,
+        )
+        .unwrap();
+        let out = run_orphan_api_check(
+            repo,
+            &OrphanApiCheckConfigV2 {
+                id: "orphan".to_string(),
+                include_globs: vec!["src/**/*.rs".to_string()],
+                exclude_globs: vec![],
+                min_symbol_len: 3,
+                allow_exports: vec![
+                    "FacadeClient".to_string(),
+                    "src/lib.rs::facade_*".to_string(),
+                ],
+                blocking: false,
+            },
+        );
+        assert_eq!(out.allowlisted_exports, 2);
+        let flagged: Vec<&str> = out
+            .violations
+            .iter()
+            .filter(|v| v.code == "orphan_api.unused_public_symbol")
+            .filter_map(|v| v.details.as_ref()?.get("symbol")?.as_str())
+            .collect();
+        assert_eq!(flagged, vec!["StrayHandle", "api_orphan"]);
+    }
+
+    #[test]
+    fn orphan_api_rejects_invalid_allow_exports_glob() {
+        let dir = tempdir().unwrap();
+        let out = run_orphan_api_check(
+            dir.path(),
+            &OrphanApiCheckConfigV2 {
+                id: "orphan".to_string(),
+                include_globs: vec!["src/**/*.rs".to_string()],
+                exclude_globs: vec![],
+                min_symbol_len: 3,
+                allow_exports: vec!["src/[lib.rs::api".to_string()],
+                blocking: false,
+            },
+        );
+        assert_eq!(out.violations.len(), 1);
+        assert_eq!(out.violations[0].code, "orphan_api.check_failed");
+    }
 }
@@ -1,13 +1,14 @@
 use crate::api::Violation;
+use crate::checks::common::walk_repo_files;
 use crate::config::DuplicatesCheckConfigV2;
 use crate::hash::sha256_hex;
+use crate::textutil::{CommentLang, strip_comments};
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
-use walkdir::WalkDir;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DuplicatesBaseline {
@@ -28,12 +29,16 @@ pub struct DuplicatesCheckResult {
     pub groups_total: usize,
     pub duplicate_files_total: usize,
     pub groups: BTreeMap<String, Vec<String>>,
+    pub skipped_by_depth: usize,
 }
 
 struct DuplicatesScan {
     groups: BTreeMap<String, Vec<String>>,
+    /// Size in bytes of one copy, per group hash.
+    group_bytes: BTreeMap<String, u64>,
     files_scanned: usize,
     files_universe: usize,
+    skipped_by_depth: usize,
     violations: Vec<Violation>,
 }
 
@@ -79,15 +84,9 @@ fn scan_duplicate_groups(
 
     let mut rel_paths: Vec<String> = vec![];
     let mut files_universe = 0usize;
-    for entry in WalkDir::new(repo_root)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(Result::ok)
-    {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-        let path = entry.path();
+    let walk = walk_repo_files(repo_root, cfg.respect_gitignore, cfg.max_depth);
+    for entry in walk.files {
+        let path = entry.as_path();
         let rel = match normalize_rel_path(repo_root, path) {
             Some(p) => p,
             None => continue,
@@ -102,6 +101,7 @@ fn scan_duplicate_groups(
 
     let mut violations: Vec<Violation> = vec![];
     let mut by_hash: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut group_bytes: BTreeMap<String, u64> = BTreeMap::new();
     let mut files_scanned = 0usize;
 
     for rel in rel_paths {
@@ -136,7 +136,14 @@ fn scan_duplicate_groups(
             }
         };
         files_scanned += 1;
-        let hash = sha256_hex(&bytes);
+        let hash = match (cfg.ignore_comments, CommentLang::for_path(&rel)) {
+            (true, Some(lang)) => match std::str::from_utf8(&bytes) {
+                Ok(text) => sha256_hex(strip_comments(lang, text).as_bytes()),
+                Err(_) => sha256_hex(&bytes),
+            },
+            _ => sha256_hex(&bytes),
+        };
+        group_bytes.entry(hash.clone()).or_insert(meta.len());
         by_hash.entry(hash).or_default().push(rel);
     }
 
@@ -158,39 +165,60 @@ fn scan_duplicate_groups(
         groups.insert(hash, paths);
     }
 
+    group_bytes.retain(|hash, _| groups.contains_key(hash));
     Ok(DuplicatesScan {
         groups,
+        group_bytes,
         files_scanned,
         files_universe,
+        skipped_by_depth: walk.skipped_by_depth,
         violations,
     })
 }
 
+/// Smallest group that fires `duplicates.found` and counts in the quality snapshot.
+pub(crate) fn firing_min_group_size(cfg: &DuplicatesCheckConfigV2) -> usize {
+    cfg.min_group_size.max(2)
+}
+
 pub fn run_duplicates_check(
     repo_root: &Path,
     cfg: &DuplicatesCheckConfigV2,
 ) -> Result<DuplicatesCheckResult, String> {
     let scan = scan_duplicate_groups(repo_root, cfg)?;
+    let group_bytes = scan.group_bytes;
     let current = scan.groups;
     let mut violations = scan.violations;
 
-    if !current.is_empty() {
-        let duplicate_files_total: usize = current.values().map(|v| v.len()).sum();
+    let min_group_size = firing_min_group_size(cfg);
+    let firing: Vec<(&String, &Vec<String>)> = current
+        .iter()
+        .filter(|(_, paths)| paths.len() >= min_group_size)
+        .collect();
+    if !firing.is_empty() {
+        let firing_files: usize = firing.iter().map(|(_, paths)| paths.len()).sum();
+        let heavy = firing.iter().any(|(sha, paths)| {
+            paths.len() >= cfg.severity.high_min_copies
+                && group_bytes.get(*sha).copied().unwrap_or(0) >= cfg.severity.high_min_bytes
+        });
         violations.push(Violation::observation(
             "duplicates.found",
             format!(
                 "duplicate files found (groups={}, files={})",
-                current.len(),
-                duplicate_files_total
+                firing.len(),
+                firing_files
             ),
             Some(cfg.baseline_path.clone()),
             Some(json!({
-                "groups": current.len(),
-                "files": duplicate_files_total,
-                "examples": current.iter().take(5).map(|(sha, paths)| {
+                "groups": firing.len(),
+                "files": firing_files,
+                "min_group_size": min_group_size,
+                "severity": if heavy { "high" } else { "medium" },
+                "examples": firing.iter().take(5).map(|(sha, paths)| {
                     json!({
                         "sha256_prefix": sha.chars().take(12).collect::<String>(),
                         "paths": paths,
+                        "bytes": group_bytes.get(*sha).copied().unwrap_or(0),
                     })
                 }).collect::<Vec<_>>(),
             })),
@@ -205,5 +233,6 @@ pub fn run_duplicates_check(
         groups_total: current.len(),
         duplicate_files_total,
         groups: current,
+        skipped_by_depth: scan.skipped_by_depth,
     })
 }
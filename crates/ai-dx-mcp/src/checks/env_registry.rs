@@ -1,4 +1,6 @@
-use crate::api::{EffectiveConfigEntry, EffectiveConfigSource, EffectiveConfigSummary, Violation};
+use crate::api::{
+    EffectiveConfigEntry, EffectiveConfigSource, EffectiveConfigSummary, ResolvedEnvVar, Violation,
+};
 use crate::config::{EnvRegistryCheckConfigV2, ProjectTool};
 use serde::Deserialize;
 use serde_json::json;
@@ -41,9 +43,15 @@ fn is_valid_env_name(name: &str) -> bool {
     chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
 }
 
+/// Masks a sensitive value, keeping only a length and short hash hint so drift is still visible.
+fn mask_secret(raw: &[u8]) -> String {
+    let digest = crate::hash::sha256_hex(raw);
+    format!("<redacted len={} sha256={}>", raw.len(), &digest[..8])
+}
+
 fn redact_value(raw: String, sensitive: bool) -> String {
     if sensitive {
-        "<redacted>".to_string()
+        mask_secret(raw.as_bytes())
     } else {
         raw
     }
@@ -82,54 +90,43 @@ fn empty_summary(cfg: &EnvRegistryCheckConfigV2, used_vars: Vec<String>) -> Effe
     }
 }
 
-pub fn run_env_registry_check(
+/// Reads and validates the registry file: names are trimmed, well-formed and unique; sorted by name.
+fn load_registry(
     repo_root: &Path,
     cfg: &EnvRegistryCheckConfigV2,
-    tools: &BTreeMap<String, ProjectTool>,
-) -> EnvRegistryResult {
-    let usage = collect_tool_env_usage(tools);
-    let used_vars: Vec<String> = usage.keys().cloned().collect();
+) -> Result<Vec<EnvVarSpec>, Violation> {
     let registry_abs = repo_root.join(&cfg.registry_path);
 
     if !registry_abs.is_file() {
-        return EnvRegistryResult {
-            violations: vec![mk_violation(
-                "env_registry.registry_missing",
-                format!("env registry file is missing: {:?}", registry_abs),
-                Some(cfg.registry_path.clone()),
-                None,
-            )],
-            summary: empty_summary(cfg, used_vars),
-        };
+        return Err(mk_violation(
+            "env_registry.registry_missing",
+            format!("env registry file is missing: {:?}", registry_abs),
+            Some(cfg.registry_path.clone()),
+            None,
+        ));
     }
 
     let raw = match std::fs::read_to_string(&registry_abs) {
         Ok(v) => v,
         Err(e) => {
-            return EnvRegistryResult {
-                violations: vec![mk_violation(
-                    "env_registry.registry_invalid",
-                    format!("failed to read env registry {:?}: {e}", registry_abs),
-                    Some(cfg.registry_path.clone()),
-                    None,
-                )],
-                summary: empty_summary(cfg, used_vars),
-            };
+            return Err(mk_violation(
+                "env_registry.registry_invalid",
+                format!("failed to read env registry {:?}: {e}", registry_abs),
+                Some(cfg.registry_path.clone()),
+                None,
+            ));
         }
     };
 
     let parsed: EnvRegistryFile = match toml::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return EnvRegistryResult {
-                violations: vec![mk_violation(
-                    "env_registry.registry_invalid",
-                    format!("failed to parse env registry {:?}: {e}", registry_abs),
-                    Some(cfg.registry_path.clone()),
-                    None,
-                )],
-                summary: empty_summary(cfg, used_vars),
-            };
+            return Err(mk_violation(
+                "env_registry.registry_invalid",
+                format!("failed to parse env registry {:?}: {e}", registry_abs),
+                Some(cfg.registry_path.clone()),
+                None,
+            ));
         }
     };
 
@@ -141,45 +138,128 @@ pub fn run_env_registry_check(
         spec.description = spec.description.map(|d| d.trim().to_string());
 
         if spec.name.is_empty() {
-            return EnvRegistryResult {
-                violations: vec![mk_violation(
-                    "env_registry.registry_invalid",
-                    "env registry entry has empty name".to_string(),
-                    Some(cfg.registry_path.clone()),
-                    None,
-                )],
-                summary: empty_summary(cfg, used_vars),
-            };
+            return Err(mk_violation(
+                "env_registry.registry_invalid",
+                "env registry entry has empty name".to_string(),
+                Some(cfg.registry_path.clone()),
+                None,
+            ));
         }
 
         if !is_valid_env_name(&spec.name) {
-            return EnvRegistryResult {
-                violations: vec![mk_violation(
-                    "env_registry.registry_invalid",
-                    format!("invalid env var name in registry: {}", spec.name),
-                    Some(cfg.registry_path.clone()),
-                    None,
-                )],
-                summary: empty_summary(cfg, used_vars),
-            };
+            return Err(mk_violation(
+                "env_registry.registry_invalid",
+                format!("invalid env var name in registry: {}", spec.name),
+                Some(cfg.registry_path.clone()),
+                None,
+            ));
         }
 
         if !seen.insert(spec.name.clone()) {
-            return EnvRegistryResult {
-                violations: vec![mk_violation(
-                    "env_registry.registry_invalid",
-                    format!("duplicate env var in registry: {}", spec.name),
-                    Some(cfg.registry_path.clone()),
-                    None,
-                )],
-                summary: empty_summary(cfg, used_vars),
-            };
+            return Err(mk_violation(
+                "env_registry.registry_invalid",
+                format!("duplicate env var in registry: {}", spec.name),
+                Some(cfg.registry_path.clone()),
+                None,
+            ));
         }
 
         specs.push(spec);
     }
 
     specs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(specs)
+}
+
+/// Value of a registered var as seen from this process: env first, then the registry default.
+fn process_value(spec: &EnvVarSpec) -> (EffectiveConfigSource, Option<String>) {
+    if let Ok(v) = std::env::var(&spec.name) {
+        (
+            EffectiveConfigSource::Env,
+            Some(redact_value(v, spec.sensitive)),
+        )
+    } else if let Some(raw) = std::env::var_os(&spec.name) {
+        let value = if spec.sensitive {
+            mask_secret(raw.as_encoded_bytes())
+        } else {
+            "<non-utf8>".to_string()
+        };
+        (EffectiveConfigSource::Env, Some(value))
+    } else if let Some(default) = spec.default.clone() {
+        (
+            EffectiveConfigSource::Default,
+            Some(redact_value(default, spec.sensitive)),
+        )
+    } else {
+        (EffectiveConfigSource::Unset, None)
+    }
+}
+
+/// The environment `tool` would run with as far as compas knows it: every var registered in
+/// `registries` (first registry wins on duplicates) plus the tool's own `env`, which overrides
+/// the process value. Sensitive values are masked.
+pub(crate) fn resolve_tool_env(
+    repo_root: &Path,
+    registries: &[EnvRegistryCheckConfigV2],
+    tool: &ProjectTool,
+) -> Result<Vec<ResolvedEnvVar>, Violation> {
+    let mut resolved: BTreeMap<String, ResolvedEnvVar> = BTreeMap::new();
+    for cfg in registries {
+        for spec in load_registry(repo_root, cfg)? {
+            if resolved.contains_key(&spec.name) {
+                continue;
+            }
+            let (source, value) = match tool.env.get(&spec.name) {
+                Some(v) => (
+                    EffectiveConfigSource::Tool,
+                    Some(redact_value(v.clone(), spec.sensitive)),
+                ),
+                None => process_value(&spec),
+            };
+            resolved.insert(
+                spec.name.clone(),
+                ResolvedEnvVar {
+                    name: spec.name,
+                    description: spec.description,
+                    registered: true,
+                    sensitive: spec.sensitive,
+                    source,
+                    value,
+                },
+            );
+        }
+    }
+    for (name, value) in &tool.env {
+        resolved
+            .entry(name.clone())
+            .or_insert_with(|| ResolvedEnvVar {
+                name: name.clone(),
+                description: None,
+                registered: false,
+                sensitive: false,
+                source: EffectiveConfigSource::Tool,
+                value: Some(value.clone()),
+            });
+    }
+    Ok(resolved.into_values().collect())
+}
+
+pub fn run_env_registry_check(
+    repo_root: &Path,
+    cfg: &EnvRegistryCheckConfigV2,
+    tools: &BTreeMap<String, ProjectTool>,
+) -> EnvRegistryResult {
+    let usage = collect_tool_env_usage(tools);
+    let used_vars: Vec<String> = usage.keys().cloned().collect();
+    let specs = match load_registry(repo_root, cfg) {
+        Ok(specs) => specs,
+        Err(v) => {
+            return EnvRegistryResult {
+                violations: vec![v],
+                summary: empty_summary(cfg, used_vars),
+            };
+        }
+    };
 
     let registered: HashSet<&str> = specs.iter().map(|v| v.name.as_str()).collect();
     let mut violations: Vec<Violation> = vec![];
@@ -210,24 +290,7 @@ pub fn run_env_registry_check(
             .map(|s| s.iter().cloned().collect::<Vec<_>>())
             .unwrap_or_default();
 
-        let (source, value) = if let Ok(v) = std::env::var(&spec.name) {
-            (
-                EffectiveConfigSource::Env,
-                Some(redact_value(v, spec.sensitive)),
-            )
-        } else if std::env::var_os(&spec.name).is_some() {
-            (
-                EffectiveConfigSource::Env,
-                Some(redact_value("<non-utf8>".to_string(), spec.sensitive)),
-            )
-        } else if let Some(default) = spec.default.clone() {
-            (
-                EffectiveConfigSource::Default,
-                Some(redact_value(default, spec.sensitive)),
-            )
-        } else {
-            (EffectiveConfigSource::Unset, None)
-        };
+        let (source, value) = process_value(&spec);
 
         if spec.required && matches!(source, EffectiveConfigSource::Unset) {
             violations.push(mk_violation(
@@ -0,0 +1,173 @@
+use crate::api::Violation;
+use crate::checks::common::collect_candidate_files;
+use crate::config::FileEncodingCheckConfigV2;
+use serde_json::json;
+use std::io::Read;
+use std::path::Path;
+
+/// Only the head of each file is inspected; encoding problems nearly always show up early.
+const SCAN_LIMIT_BYTES: u64 = 256 * 1024;
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+#[derive(Debug)]
+pub struct FileEncodingCheckResult {
+    pub files_scanned: usize,
+    pub violations: Vec<Violation>,
+}
+
+fn read_head(path: &Path) -> std::io::Result<(Vec<u8>, bool)> {
+    let file = std::fs::File::open(path)?;
+    let mut buf = vec![];
+    file.take(SCAN_LIMIT_BYTES + 1).read_to_end(&mut buf)?;
+    let truncated = buf.len() as u64 > SCAN_LIMIT_BYTES;
+    buf.truncate(SCAN_LIMIT_BYTES as usize);
+    Ok((buf, truncated))
+}
+
+/// Byte offset of the first invalid UTF-8 sequence; an incomplete sequence cut off by the scan
+/// limit is not an error.
+fn invalid_utf8_offset(bytes: &[u8], truncated: bool) -> Option<usize> {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => None,
+        Err(e) if e.error_len().is_none() && truncated => None,
+        Err(e) => Some(e.valid_up_to()),
+    }
+}
+
+fn first_crlf_line(bytes: &[u8]) -> Option<usize> {
+    let idx = bytes.windows(2).position(|w| w == b"\r\n")?;
+    Some(bytes[..idx].iter().filter(|b| **b == b'\n').count() + 1)
+}
+
+/// Flags matched files that are not UTF-8, start with a UTF-8 BOM, or use CRLF line endings.
+pub fn run_file_encoding_check(
+    repo_root: &Path,
+    cfg: &FileEncodingCheckConfigV2,
+) -> FileEncodingCheckResult {
+    let files = match collect_candidate_files(repo_root, &cfg.include_globs, &cfg.exclude_globs) {
+        Ok(v) => v,
+        Err(msg) => {
+            return FileEncodingCheckResult {
+                files_scanned: 0,
+                violations: vec![Violation::blocking(
+                    "file_encoding.check_failed",
+                    format!("file_encoding check failed (id={}): {msg}", cfg.id),
+                    None,
+                    None,
+                )],
+            };
+        }
+    };
+
+    let mut files_scanned = 0usize;
+    let mut violations = vec![];
+    for (rel, path) in files {
+        let (bytes, truncated) = match read_head(&path) {
+            Ok(v) => v,
+            Err(e) => {
+                violations.push(Violation::blocking(
+                    "file_encoding.read_failed",
+                    format!("failed to read {rel}: {e}"),
+                    Some(rel.clone()),
+                    None,
+                ));
+                continue;
+            }
+        };
+        files_scanned += 1;
+
+        if bytes.starts_with(UTF8_BOM) {
+            violations.push(Violation::blocking(
+                "file_encoding.bom",
+                format!("{rel} starts with a UTF-8 byte order mark"),
+                Some(rel.clone()),
+                Some(json!({ "check_id": cfg.id })),
+            ));
+        }
+        if let Some(offset) = invalid_utf8_offset(&bytes, truncated) {
+            violations.push(Violation::blocking(
+                "file_encoding.non_utf8",
+                format!("{rel} is not valid UTF-8 (first invalid byte at offset {offset})"),
+                Some(rel.clone()),
+                Some(json!({ "check_id": cfg.id, "offset": offset })),
+            ));
+            continue;
+        }
+        if let Some(line) = first_crlf_line(&bytes) {
+            violations.push(Violation::blocking(
+                "file_encoding.crlf",
+                format!("{rel} uses CRLF line endings (first at line {line})"),
+                Some(rel.clone()),
+                Some(json!({ "check_id": cfg.id, "line": line })),
+            ));
+        }
+    }
+
+    FileEncodingCheckResult {
+        files_scanned,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn cfg() -> FileEncodingCheckConfigV2 {
+        FileEncodingCheckConfigV2 {
+            id: "encoding".to_string(),
+            include_globs: vec!["src/**/*.rs".to_string()],
+            exclude_globs: vec![],
+        }
+    }
+
+    fn codes_for<'a>(r: &'a FileEncodingCheckResult, path: &str) -> Vec<&'a str> {
+        r.violations
+            .iter()
+            .filter(|v| v.path.as_deref() == Some(path))
+            .map(|v| v.code.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn flags_crlf_bom_and_non_utf8_but_not_clean_files() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("clean.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+        std::fs::write(src.join("crlf.rs"), "fn a() {}\nfn b() {}\r\n").unwrap();
+        std::fs::write(src.join("bom.rs"), b"\xEF\xBB\xBFfn a() {}\n").unwrap();
+        std::fs::write(src.join("latin1.rs"), b"// caf\xE9\r\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored\r\n").unwrap();
+
+        let r = run_file_encoding_check(dir.path(), &cfg());
+        assert_eq!(r.files_scanned, 4);
+        assert!(
+            codes_for(&r, "src/clean.rs").is_empty(),
+            "{:?}",
+            r.violations
+        );
+        assert_eq!(codes_for(&r, "src/crlf.rs"), vec!["file_encoding.crlf"]);
+        assert_eq!(codes_for(&r, "src/bom.rs"), vec!["file_encoding.bom"]);
+        assert_eq!(
+            codes_for(&r, "src/latin1.rs"),
+            vec!["file_encoding.non_utf8"]
+        );
+
+        let crlf = r
+            .violations
+            .iter()
+            .find(|v| v.code == "file_encoding.crlf")
+            .unwrap();
+        assert_eq!(crlf.details.as_ref().unwrap()["line"], 2);
+    }
+
+    #[test]
+    fn multibyte_char_cut_by_scan_limit_is_not_an_error() {
+        let mut bytes = "é".repeat(SCAN_LIMIT_BYTES as usize / 2).into_bytes();
+        bytes.truncate(SCAN_LIMIT_BYTES as usize - 1);
+        assert_eq!(invalid_utf8_offset(&bytes, true), None);
+        assert!(invalid_utf8_offset(&bytes, false).is_some());
+    }
+}
@@ -0,0 +1,592 @@
+use crate::api::Violation;
+use crate::checks::boundary::strip_rust_cfg_test_modules;
+use crate::checks::common::collect_candidate_files;
+use crate::config::ImportCyclesCheckConfigV2;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct ImportCyclesCheckResult {
+    pub modules_scanned: usize,
+    pub edges_total: usize,
+    pub violations: Vec<Violation>,
+}
+
+/// Module graph keyed by repo-relative file path; edges point at the imported module's file.
+type Graph = BTreeMap<String, BTreeSet<String>>;
+
+/// Rust module path of `rel` inside its crate: the directory of the nearest `src` ancestor
+/// plus the segments the `mod` file layout gives (`a/mod.rs` and `a.rs` are both `a`).
+fn rust_module(rel: &str) -> Option<(String, Vec<String>)> {
+    let parts: Vec<&str> = rel.split('/').collect();
+    let src_idx = parts.iter().rposition(|p| *p == "src")?;
+    let crate_src = parts[..=src_idx].join("/");
+    let mut segments: Vec<String> = parts[src_idx + 1..].iter().map(|s| s.to_string()).collect();
+    let file = segments.pop()?;
+    let stem = file.strip_suffix(".rs")?;
+    let at_root = segments.is_empty() && matches!(stem, "lib" | "main");
+    if stem != "mod" && !at_root {
+        segments.push(stem.to_string());
+    }
+    Some((crate_src, segments))
+}
+
+fn rust_module_file(
+    modules: &BTreeSet<String>,
+    crate_src: &str,
+    path: &[String],
+) -> Option<String> {
+    let candidates = if path.is_empty() {
+        vec![
+            format!("{crate_src}/lib.rs"),
+            format!("{crate_src}/main.rs"),
+        ]
+    } else {
+        let joined = path.join("/");
+        vec![
+            format!("{crate_src}/{joined}.rs"),
+            format!("{crate_src}/{joined}/mod.rs"),
+        ]
+    };
+    candidates.into_iter().find(|c| modules.contains(c))
+}
+
+/// Body of every `use` declaration (the text between `use` and `;`), joined across lines.
+fn rust_use_trees(source: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut pending: Option<String> = None;
+    for line in source.lines() {
+        let mut text = match pending.take() {
+            Some(mut acc) => {
+                acc.push(' ');
+                acc.push_str(line.trim());
+                acc
+            }
+            None => {
+                let t = line.trim_start();
+                let t = t
+                    .strip_prefix("pub(crate) ")
+                    .or_else(|| t.strip_prefix("pub(super) "))
+                    .or_else(|| t.strip_prefix("pub "))
+                    .unwrap_or(t);
+                let Some(rest) = t.strip_prefix("use ") else {
+                    continue;
+                };
+                rest.trim().to_string()
+            }
+        };
+        match text.find(';') {
+            Some(end) => {
+                text.truncate(end);
+                out.push(text);
+            }
+            None => pending = Some(text),
+        }
+    }
+    out
+}
+
+fn rust_path_segments(path: &str) -> Vec<String> {
+    path.split("::")
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Expands a use tree into one path per imported item: `crate::{a::X, b::{Y, self}}` gives
+/// `crate::a::X`, `crate::b::Y` and `crate::b`. Globs and `self` stop at their parent path;
+/// aliases are dropped.
+fn expand_rust_use_tree(tree: &str, prefix: &[String], out: &mut Vec<Vec<String>>) {
+    let tree = tree.trim();
+    if let Some(open) = tree.find('{') {
+        let mut base = prefix.to_vec();
+        base.extend(rust_path_segments(&tree[..open]));
+        let close = tree.rfind('}').filter(|c| *c > open).unwrap_or(tree.len());
+        let inner = &tree[open + 1..close];
+        let mut depth = 0usize;
+        let mut item_start = 0;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    expand_rust_use_tree(&inner[item_start..i], &base, out);
+                    item_start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        expand_rust_use_tree(&inner[item_start..], &base, out);
+        return;
+    }
+    let path = tree.split(" as ").next().unwrap_or_default();
+    let mut segments = prefix.to_vec();
+    segments.extend(
+        rust_path_segments(path)
+            .into_iter()
+            .filter(|s| s != "*" && (s != "self" || prefix.is_empty())),
+    );
+    if !segments.is_empty() {
+        out.push(segments);
+    }
+}
+
+/// Resolves `crate::`/`super::`/`self::` imports to the longest module prefix that exists.
+fn rust_imports(modules: &BTreeSet<String>, rel: &str, source: &str) -> Vec<String> {
+    let Some((crate_src, current)) = rust_module(rel) else {
+        return vec![];
+    };
+    let mut paths = vec![];
+    for tree in rust_use_trees(&strip_rust_cfg_test_modules(source)) {
+        expand_rust_use_tree(&tree, &[], &mut paths);
+    }
+    let mut out = vec![];
+    for segments in paths {
+        let mut base = match segments[0].as_str() {
+            "crate" => vec![],
+            "self" => current.clone(),
+            "super" => current.clone(),
+            _ => continue,
+        };
+        let mut rest = &segments[1..];
+        if segments[0] == "super" {
+            base.pop();
+        }
+        while rest.first().is_some_and(|s| s == "super") {
+            base.pop();
+            rest = &rest[1..];
+        }
+        let mut path = base;
+        path.extend(rest.iter().cloned());
+        while !path.is_empty() {
+            if let Some(file) = rust_module_file(modules, &crate_src, &path) {
+                out.push(file);
+                break;
+            }
+            path.pop();
+        }
+    }
+    out
+}
+
+/// Dotted Python module name of `rel` (`pkg/__init__.py` is `pkg`).
+fn python_module(rel: &str) -> Option<String> {
+    let stem = rel.strip_suffix(".py")?;
+    let stem = stem.strip_suffix("/__init__").unwrap_or(stem);
+    Some(stem.replace('/', "."))
+}
+
+/// Python modules by the dotted name an absolute import uses: the file path relative to each
+/// configured root (`.` is the repo root). Files outside every root get no name.
+fn python_names_by_root(modules: &BTreeSet<String>, roots: &[String]) -> BTreeMap<String, String> {
+    let mut by_name = BTreeMap::new();
+    for root in roots {
+        let root = root.trim_matches('/');
+        for rel in modules {
+            let under_root = if root.is_empty() || root == "." {
+                Some(rel.as_str())
+            } else {
+                rel.strip_prefix(root).and_then(|r| r.strip_prefix('/'))
+            };
+            if let Some(name) = under_root.and_then(python_module) {
+                by_name.entry(name).or_insert_with(|| rel.clone());
+            }
+        }
+    }
+    by_name
+}
+
+/// Longest dotted prefix of `dotted` that names a module in `by_name`.
+fn python_resolve(by_name: &BTreeMap<String, String>, dotted: &str) -> Option<String> {
+    let mut parts: Vec<&str> = dotted.split('.').filter(|p| !p.is_empty()).collect();
+    while !parts.is_empty() {
+        if let Some(file) = by_name.get(&parts.join(".")) {
+            return Some(file.clone());
+        }
+        parts.pop();
+    }
+    None
+}
+
+/// Lines of `source` that run when the module is imported: bodies of `def` blocks and
+/// `if TYPE_CHECKING:` blocks are left out.
+fn python_module_level_lines(source: &str) -> Vec<&str> {
+    let mut out = vec![];
+    let mut skip_above: Option<usize> = None;
+    for line in source.lines() {
+        let t = line.trim_start();
+        if t.is_empty() || t.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - t.len();
+        if let Some(block) = skip_above {
+            if indent > block {
+                continue;
+            }
+            skip_above = None;
+        }
+        let opens_skipped_block = t.starts_with("def ")
+            || t.starts_with("async def ")
+            || t.starts_with("if TYPE_CHECKING")
+            || t.starts_with("if typing.TYPE_CHECKING");
+        if opens_skipped_block {
+            skip_above = Some(indent);
+            continue;
+        }
+        out.push(t);
+    }
+    out
+}
+
+fn python_imports(
+    by_name: &BTreeMap<String, String>,
+    by_path: &BTreeMap<String, String>,
+    rel: &str,
+    source: &str,
+) -> Vec<String> {
+    let Some(module) = python_module(rel) else {
+        return vec![];
+    };
+    let package: Vec<&str> = if rel.ends_with("/__init__.py") {
+        module.split('.').collect()
+    } else {
+        let mut p: Vec<&str> = module.split('.').collect();
+        p.pop();
+        p
+    };
+    let mut out = vec![];
+    for t in python_module_level_lines(source) {
+        if let Some(rest) = t.strip_prefix("import ") {
+            for part in rest.split(',') {
+                let name = part.split_whitespace().next().unwrap_or_default();
+                out.extend(python_resolve(by_name, name));
+            }
+        } else if let Some(rest) = t.strip_prefix("from ")
+            && let Some((target, names)) = rest.split_once(" import ")
+        {
+            let target = target.trim();
+            let dots = target.chars().take_while(|c| *c == '.').count();
+            // Relative imports resolve against the file's own directory, absolute ones
+            // against the configured roots.
+            let (base, lookup) = if dots == 0 {
+                (target.to_string(), by_name)
+            } else {
+                let keep = package.len().saturating_sub(dots - 1);
+                let mut parts: Vec<&str> = package[..keep].to_vec();
+                parts.extend(target[dots..].split('.').filter(|p| !p.is_empty()));
+                (parts.join("."), by_path)
+            };
+            // `from pkg import mod` imports a module when `pkg.mod` is one.
+            for name in names.trim_matches(['(', ')']).split(',') {
+                let name = name.split_whitespace().next().unwrap_or_default();
+                if name.is_empty() || name == "*" {
+                    continue;
+                }
+                let full = if base.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{base}.{name}")
+                };
+                out.extend(python_resolve(lookup, &full));
+            }
+            if names.trim() == "*" && !base.is_empty() {
+                out.extend(python_resolve(lookup, &base));
+            }
+        }
+    }
+    out
+}
+
+/// Tarjan's strongly connected components; only components with more than one member are
+/// returned, each sorted, in a deterministic order.
+fn cycles(graph: &Graph) -> Vec<Vec<String>> {
+    struct Tarjan<'a> {
+        graph: &'a Graph,
+        next_index: usize,
+        index: BTreeMap<&'a str, usize>,
+        lowlink: BTreeMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<String>>,
+    }
+
+    impl<'a> Tarjan<'a> {
+        fn visit(&mut self, node: &'a str) {
+            self.index.insert(node, self.next_index);
+            self.lowlink.insert(node, self.next_index);
+            self.next_index += 1;
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            let graph = self.graph;
+            for next in graph.get(node).into_iter().flatten() {
+                let next = next.as_str();
+                if !self.index.contains_key(next) {
+                    self.visit(next);
+                    let low = self.lowlink[node].min(self.lowlink[next]);
+                    self.lowlink.insert(node, low);
+                } else if self.on_stack.contains(next) {
+                    let low = self.lowlink[node].min(self.index[next]);
+                    self.lowlink.insert(node, low);
+                }
+            }
+
+            if self.lowlink[node] == self.index[node] {
+                let mut component = vec![];
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member.to_string());
+                    if member == node {
+                        break;
+                    }
+                }
+                if component.len() > 1 {
+                    component.sort();
+                    self.components.push(component);
+                }
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        graph,
+        next_index: 0,
+        index: BTreeMap::new(),
+        lowlink: BTreeMap::new(),
+        stack: vec![],
+        on_stack: BTreeSet::new(),
+        components: vec![],
+    };
+    for node in graph.keys() {
+        if !tarjan.index.contains_key(node.as_str()) {
+            tarjan.visit(node);
+        }
+    }
+    let mut components = tarjan.components;
+    components.sort();
+    components
+}
+
+/// Builds the module import graph of matched `.rs`/`.py` files and reports every import
+/// cycle (strongly connected component with more than one module) as `import_cycles.detected`.
+pub fn run_import_cycles_check(
+    repo_root: &Path,
+    cfg: &ImportCyclesCheckConfigV2,
+) -> ImportCyclesCheckResult {
+    let files = match collect_candidate_files(repo_root, &cfg.include_globs, &cfg.exclude_globs) {
+        Ok(v) => v,
+        Err(msg) => {
+            return ImportCyclesCheckResult {
+                modules_scanned: 0,
+                edges_total: 0,
+                violations: vec![Violation::blocking(
+                    "import_cycles.check_failed",
+                    format!("import_cycles check failed (id={}): {msg}", cfg.id),
+                    None,
+                    None,
+                )],
+            };
+        }
+    };
+    let files: Vec<(String, std::path::PathBuf)> = files
+        .into_iter()
+        .filter(|(rel, _)| rel.ends_with(".rs") || rel.ends_with(".py"))
+        .collect();
+    let modules: BTreeSet<String> = files.iter().map(|(rel, _)| rel.clone()).collect();
+    let python_by_path = python_names_by_root(&modules, &[".".to_string()]);
+    let python_by_name = python_names_by_root(&modules, &cfg.python_roots);
+
+    let mut violations = vec![];
+    let mut graph: Graph = BTreeMap::new();
+    for (rel, path) in &files {
+        let source = match std::fs::read_to_string(path) {
+            Ok(v) => v,
+            Err(e) => {
+                violations.push(Violation::blocking(
+                    "import_cycles.read_failed",
+                    format!("failed to read {rel}: {e}"),
+                    Some(rel.clone()),
+                    None,
+                ));
+                continue;
+            }
+        };
+        let imports = if rel.ends_with(".rs") {
+            rust_imports(&modules, rel, &source)
+        } else {
+            python_imports(&python_by_name, &python_by_path, rel, &source)
+        };
+        let edges = graph.entry(rel.clone()).or_default();
+        edges.extend(imports.into_iter().filter(|target| target != rel));
+    }
+
+    let finding = if cfg.blocking {
+        Violation::blocking
+    } else {
+        Violation::observation
+    };
+    for members in cycles(&graph) {
+        violations.push(finding(
+            "import_cycles.detected",
+            format!(
+                "import cycle between {} modules: {}",
+                members.len(),
+                members.join(", ")
+            ),
+            members.first().cloned(),
+            Some(json!({
+                "check_id": cfg.id,
+                "members": members,
+            })),
+        ));
+    }
+
+    ImportCyclesCheckResult {
+        modules_scanned: graph.len(),
+        edges_total: graph.values().map(BTreeSet::len).sum(),
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn cfg() -> ImportCyclesCheckConfigV2 {
+        ImportCyclesCheckConfigV2 {
+            id: "cycles".to_string(),
+            include_globs: vec![],
+            exclude_globs: vec![],
+            python_roots: vec![".".to_string(), "src".to_string()],
+            blocking: false,
+        }
+    }
+
+    fn write(repo: &Path, rel: &str, content: &str) {
+        let path = repo.join(rel);
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+        std::fs::write(path, content).expect("write");
+    }
+
+    #[test]
+    fn three_module_rust_cycle_is_reported_once() {
+        let dir = tempdir().expect("tempdir");
+        let repo = dir.path();
+        write(repo, "src/lib.rs", "mod a;\nmod b;\nmod c;\n");
+        write(repo, "src/a.rs", "use crate::b::B;\npub struct A;\n");
+        write(
+            repo,
+            "src/b.rs",
+            "use crate::c::{C, helper};\npub struct B;\n",
+        );
+        write(
+            repo,
+            "src/c/mod.rs",
+            "use super::a::A;\npub struct C;\npub fn helper() {}\n",
+        );
+
+        let out = run_import_cycles_check(repo, &cfg());
+        assert_eq!(out.violations.len(), 1, "{:?}", out.violations);
+        let v = &out.violations[0];
+        assert_eq!(v.code, "import_cycles.detected");
+        assert_eq!(v.path.as_deref(), Some("src/a.rs"));
+        assert_eq!(
+            v.details.as_ref().expect("details")["members"],
+            json!(["src/a.rs", "src/b.rs", "src/c/mod.rs"])
+        );
+    }
+
+    #[test]
+    fn acyclic_graph_and_test_only_back_edges_pass() {
+        let dir = tempdir().expect("tempdir");
+        let repo = dir.path();
+        write(repo, "src/lib.rs", "mod a;\nmod b;\n");
+        write(repo, "src/a.rs", "use crate::b::B;\npub struct A;\n");
+        write(
+            repo,
+            "src/b.rs",
+            "pub struct B;\n\n#[cfg(test)]\nmod tests {\n    use crate::a::A;\n}\n",
+        );
+        write(repo, "app/models.py", "import os\n");
+        write(
+            repo,
+            "app/views.py",
+            "from app import models\nfrom .models import *\n",
+        );
+
+        let out = run_import_cycles_check(repo, &cfg());
+        assert!(out.violations.is_empty(), "{:?}", out.violations);
+        assert_eq!(out.modules_scanned, 5);
+        assert_eq!(out.edges_total, 2);
+    }
+
+    #[test]
+    fn python_relative_imports_close_a_cycle() {
+        let dir = tempdir().expect("tempdir");
+        let repo = dir.path();
+        write(repo, "src/pkg/__init__.py", "");
+        write(repo, "src/pkg/orders.py", "from .billing import charge\n");
+        write(repo, "src/pkg/billing.py", "import pkg.orders\n");
+
+        let out = run_import_cycles_check(repo, &cfg());
+        let members: Vec<&serde_json::Value> = out
+            .violations
+            .iter()
+            .filter_map(|v| v.details.as_ref().map(|d| &d["members"]))
+            .collect();
+        assert_eq!(
+            members,
+            vec![&json!(["src/pkg/billing.py", "src/pkg/orders.py"])]
+        );
+    }
+
+    #[test]
+    fn grouped_rust_uses_close_a_cycle_and_findings_default_to_observation() {
+        let dir = tempdir().expect("tempdir");
+        let repo = dir.path();
+        write(repo, "src/lib.rs", "mod a;\nmod b;\nmod c;\n");
+        write(
+            repo,
+            "src/a.rs",
+            "use crate::{\n    b::B,\n    c::{self, helper as h},\n};\npub struct A;\n",
+        );
+        write(repo, "src/b.rs", "use crate::{a::A};\npub struct B;\n");
+        write(repo, "src/c.rs", "pub fn helper() {}\n");
+
+        let out = run_import_cycles_check(repo, &cfg());
+        assert_eq!(out.edges_total, 3, "{:?}", out.violations);
+        assert_eq!(out.violations.len(), 1, "{:?}", out.violations);
+        let v = &out.violations[0];
+        assert_eq!(v.tier, crate::api::ViolationTier::Observation);
+        assert_eq!(
+            v.details.as_ref().expect("details")["members"],
+            json!(["src/a.rs", "src/b.rs"])
+        );
+
+        let mut blocking = cfg();
+        blocking.blocking = true;
+        let out = run_import_cycles_check(repo, &blocking);
+        assert_eq!(out.violations[0].tier, crate::api::ViolationTier::Blocking);
+    }
+
+    #[test]
+    fn python_deferred_imports_and_stdlib_names_add_no_edges() {
+        let dir = tempdir().expect("tempdir");
+        let repo = dir.path();
+        write(repo, "app/__init__.py", "");
+        write(
+            repo,
+            "app/orders.py",
+            "from typing import TYPE_CHECKING\nimport json\n\nif TYPE_CHECKING:\n    from app.billing import Invoice\n\ndef total():\n    from app import billing\n    return billing.charge()\n",
+        );
+        write(repo, "app/billing.py", "from app.orders import total\n");
+        write(repo, "tools/json.py", "import app.orders\n");
+
+        let out = run_import_cycles_check(repo, &cfg());
+        assert!(out.violations.is_empty(), "{:?}", out.violations);
+        assert_eq!(out.edges_total, 2);
+    }
+}
@@ -1,11 +1,12 @@
 use crate::api::Violation;
-use crate::config::LocCheckConfigV2;
+use crate::checks::common::walk_repo_files;
+use crate::config::{LocCheckConfigV2, LocMode};
+use crate::textutil::{CommentLang, strip_comments};
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
-use walkdir::WalkDir;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocBaseline {
@@ -46,6 +47,18 @@ fn count_non_empty_lines(bytes: &[u8]) -> usize {
     count
 }
 
+/// `sloc` counts what is left after `strip_comments`; files whose extension has no known
+/// comment syntax (or that are not UTF-8) fall back to the raw count.
+fn count_lines(rel: &str, bytes: &[u8], mode: LocMode) -> usize {
+    if mode == LocMode::Sloc
+        && let Some(lang) = CommentLang::for_path(rel)
+        && let Ok(src) = std::str::from_utf8(bytes)
+    {
+        return count_non_empty_lines(strip_comments(lang, src).as_bytes());
+    }
+    count_non_empty_lines(bytes)
+}
+
 pub struct LocCheckResult {
     pub violations: Vec<Violation>,
     pub files_scanned: usize,
@@ -53,6 +66,7 @@ pub struct LocCheckResult {
     pub max_loc: usize,
     pub worst_path: Option<String>,
     pub loc_per_file: BTreeMap<String, usize>,
+    pub skipped_by_depth: usize,
 }
 
 pub fn run_loc_check(repo_root: &Path, cfg: &LocCheckConfigV2) -> Result<LocCheckResult, String> {
@@ -69,15 +83,9 @@ pub fn run_loc_check(repo_root: &Path, cfg: &LocCheckConfigV2) -> Result<LocChec
     let mut files: BTreeMap<String, usize> = BTreeMap::new();
     let mut violations: Vec<Violation> = vec![];
     let mut files_universe = 0usize;
-    for entry in WalkDir::new(repo_root)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(Result::ok)
-    {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-        let path = entry.path();
+    let walk = walk_repo_files(repo_root, cfg.respect_gitignore, cfg.max_depth);
+    for entry in walk.files {
+        let path = entry.as_path();
         let rel = match normalize_rel_path(repo_root, path) {
             Some(p) => p,
             None => continue,
@@ -99,12 +107,16 @@ pub fn run_loc_check(repo_root: &Path, cfg: &LocCheckConfigV2) -> Result<LocChec
                 continue;
             }
         };
-        let loc = count_non_empty_lines(&bytes);
+        let loc = count_lines(&rel, &bytes, cfg.loc_mode);
         files.insert(rel, loc);
     }
 
     let mut max_loc = 0usize;
     let mut worst_path: Option<String> = None;
+    let unit = match cfg.loc_mode {
+        LocMode::Raw => "loc",
+        LocMode::Sloc => "sloc",
+    };
 
     for (path, loc) in &files {
         if *loc > max_loc {
@@ -115,7 +127,7 @@ pub fn run_loc_check(repo_root: &Path, cfg: &LocCheckConfigV2) -> Result<LocChec
         if *loc > cfg.max_loc {
             violations.push(Violation::observation(
                 "loc.max_exceeded",
-                format!("file exceeds max_loc={} (loc={})", cfg.max_loc, loc),
+                format!("file exceeds max_loc={} ({unit}={loc})", cfg.max_loc),
                 Some(path.clone()),
                 None,
             ));
@@ -129,6 +141,7 @@ pub fn run_loc_check(repo_root: &Path, cfg: &LocCheckConfigV2) -> Result<LocChec
         max_loc,
         worst_path,
         loc_per_file: files,
+        skipped_by_depth: walk.skipped_by_depth,
     })
 }
 
@@ -161,6 +174,9 @@ mod tests {
             max_loc: 1,
             include_globs: vec!["crates/**/*.rs".to_string()],
             exclude_globs: vec![],
+            respect_gitignore: false,
+            max_depth: None,
+            loc_mode: LocMode::Raw,
             baseline_path: ".agents/mcp/compas/baselines/loc.json".to_string(),
         };
 
@@ -173,6 +189,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sloc_mode_ignores_comment_lines_that_raw_counts() {
+        let dir = tempdir().unwrap();
+        let repo_root = dir.path();
+        fs::create_dir_all(repo_root.join("src")).unwrap();
+        fs::write(
+            repo_root.join("src/lib.rs"),
+            "//! Crate docs.\n\n/// Adds.\n/* block\n   comment */\nfn add() -> &'static str { \"// kept\" }\n",
+        )
+        .unwrap();
+        fs::write(repo_root.join("src/notes.txt"), "// not code\ntext\n").unwrap();
+
+        let mut cfg = LocCheckConfigV2 {
+            id: "loc".to_string(),
+            max_loc: 2,
+            include_globs: vec!["src/**".to_string()],
+            exclude_globs: vec![],
+            respect_gitignore: false,
+            max_depth: None,
+            loc_mode: LocMode::Raw,
+            baseline_path: ".agents/mcp/compas/baselines/loc.json".to_string(),
+        };
+        let raw = run_loc_check(repo_root, &cfg).unwrap();
+        assert_eq!(raw.loc_per_file["src/lib.rs"], 5);
+        assert_eq!(raw.violations.len(), 1);
+        assert!(raw.violations[0].message.contains("(loc=5)"));
+
+        cfg.loc_mode = LocMode::Sloc;
+        let sloc = run_loc_check(repo_root, &cfg).unwrap();
+        assert_eq!(sloc.loc_per_file["src/lib.rs"], 1);
+        assert_eq!(
+            sloc.loc_per_file["src/notes.txt"], 2,
+            "unknown extensions keep the raw count"
+        );
+        assert!(sloc.violations.is_empty(), "{:?}", sloc.violations);
+    }
+
     #[test]
     fn loc_reports_per_file_map_for_quality_delta() {
         let dir = tempdir().unwrap();
@@ -188,6 +241,9 @@ mod tests {
             max_loc: 100,
             include_globs: vec!["crates/**/*.rs".to_string()],
             exclude_globs: vec![],
+            respect_gitignore: false,
+            max_depth: None,
+            loc_mode: LocMode::Raw,
             baseline_path: ".agents/mcp/compas/baselines/loc.json".to_string(),
         };
 
@@ -196,4 +252,76 @@ mod tests {
         assert_eq!(r.files_universe, 1);
         assert!(r.loc_per_file.contains_key("crates/x/lib.rs"));
     }
+
+    #[test]
+    fn loc_skips_gitignored_paths_only_when_enabled() {
+        let dir = tempdir().unwrap();
+        let repo_root = dir.path();
+        fs::create_dir_all(repo_root.join("crates/x")).unwrap();
+        fs::create_dir_all(repo_root.join("target/debug/build")).unwrap();
+        fs::write(repo_root.join(".gitignore"), "target/\n").unwrap();
+        fs::write(repo_root.join("crates/x/lib.rs"), "fn a() {}\n").unwrap();
+        fs::write(
+            repo_root.join("target/debug/build/out.rs"),
+            "fn generated() {}\n",
+        )
+        .unwrap();
+
+        let mut cfg = LocCheckConfigV2 {
+            id: "loc".to_string(),
+            max_loc: 100,
+            include_globs: vec!["**/*.rs".to_string()],
+            exclude_globs: vec![],
+            respect_gitignore: false,
+            max_depth: None,
+            loc_mode: LocMode::Raw,
+            baseline_path: ".agents/mcp/compas/baselines/loc.json".to_string(),
+        };
+        let r = run_loc_check(repo_root, &cfg).unwrap();
+        assert!(r.loc_per_file.contains_key("target/debug/build/out.rs"));
+
+        cfg.respect_gitignore = true;
+        let r = run_loc_check(repo_root, &cfg).unwrap();
+        assert_eq!(r.files_scanned, 1);
+        assert!(r.loc_per_file.contains_key("crates/x/lib.rs"));
+    }
+
+    #[test]
+    fn loc_max_depth_excludes_deepest_files_in_both_walkers() {
+        let dir = tempdir().unwrap();
+        let repo_root = dir.path();
+        fs::create_dir_all(repo_root.join("src/vendor/deep/deeper")).unwrap();
+        fs::write(repo_root.join("build.rs"), "fn main() {}\n").unwrap();
+        fs::write(repo_root.join("src/lib.rs"), "fn a() {}\n").unwrap();
+        fs::write(repo_root.join("src/vendor/v.rs"), "fn v() {}\n").unwrap();
+        fs::write(repo_root.join("src/vendor/deep/d.rs"), "fn d() {}\n").unwrap();
+        fs::write(repo_root.join("src/vendor/deep/deeper/e.rs"), "fn e() {}\n").unwrap();
+
+        let mut cfg = LocCheckConfigV2 {
+            id: "loc".to_string(),
+            max_loc: 100,
+            include_globs: vec!["**/*.rs".to_string()],
+            exclude_globs: vec![],
+            respect_gitignore: false,
+            max_depth: None,
+            loc_mode: LocMode::Raw,
+            baseline_path: ".agents/mcp/compas/baselines/loc.json".to_string(),
+        };
+        let r = run_loc_check(repo_root, &cfg).unwrap();
+        assert_eq!(r.files_scanned, 5);
+        assert_eq!(r.skipped_by_depth, 0);
+
+        cfg.max_depth = Some(3);
+        for respect_gitignore in [false, true] {
+            cfg.respect_gitignore = respect_gitignore;
+            let r = run_loc_check(repo_root, &cfg).unwrap();
+            let scanned: Vec<&str> = r.loc_per_file.keys().map(String::as_str).collect();
+            assert_eq!(
+                scanned,
+                vec!["build.rs", "src/lib.rs", "src/vendor/v.rs"],
+                "respect_gitignore={respect_gitignore}"
+            );
+            assert_eq!(r.skipped_by_depth, 1, "src/vendor/deep is left unwalked");
+        }
+    }
 }
@@ -0,0 +1,388 @@
+use crate::api::Violation;
+use crate::checks::boundary::strip_rust_cfg_test_modules;
+use crate::checks::common::collect_candidate_files;
+use crate::config::MagicNumbersCheckConfigV2;
+use crate::textutil::{CommentLang, strip_comments};
+use regex::Regex;
+use serde_json::json;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct MagicNumbersCheckResult {
+    pub files_scanned: usize,
+    pub violations: Vec<Violation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lang {
+    Rust,
+    Python,
+}
+
+fn lang_for(rel: &str) -> Option<Lang> {
+    match Path::new(rel).extension().and_then(|s| s.to_str())? {
+        "rs" => Some(Lang::Rust),
+        "py" => Some(Lang::Python),
+        _ => None,
+    }
+}
+
+/// Lines that name a value: Rust `const`/`static` items and Python UPPER_CASE assignments.
+fn constant_definition_regex(lang: Lang) -> Regex {
+    let pattern = match lang {
+        Lang::Rust => {
+            r"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:const|static)\s+(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*:"
+        }
+        Lang::Python => r"^\s*_*[A-Z][A-Z0-9_]*\s*(?::[^=]*)?=(?:[^=]|$)",
+    };
+    Regex::new(pattern).unwrap()
+}
+
+fn literal_regex() -> Regex {
+    Regex::new(
+        r"^(0[xX][0-9a-fA-F_]+|0[oO][0-7_]+|0[bB][01_]+|\d[\d_]*(?:\.\d[\d_]*)?(?:[eE][+-]?\d[\d_]*)?)(?:[iu](?:8|16|32|64|128|size)|f32|f64)?",
+    )
+    .unwrap()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Replaces string (and Rust char) literal contents with spaces, keeping newlines, so digits
+/// inside them are not read as literals.
+fn blank_strings(lang: Lang, src: &str) -> String {
+    let chars: Vec<char> = src.chars().collect();
+    let mut out = String::with_capacity(src.len());
+    let blank = |c: char| if c == '\n' { '\n' } else { ' ' };
+    let mut i = 0usize;
+    while i < chars.len() {
+        let c = chars[i];
+        let prev_ident = i > 0 && is_ident_char(chars[i - 1]);
+        if lang == Lang::Rust && c == 'r' && !prev_ident {
+            let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+            if chars.get(i + 1 + hashes) == Some(&'"') {
+                let close: String = std::iter::once('"')
+                    .chain(std::iter::repeat_n('#', hashes))
+                    .collect();
+                let body_start = i + 2 + hashes;
+                let rest: String = chars[body_start..].iter().collect();
+                let body_len = rest
+                    .find(&close)
+                    .map_or(chars.len() - body_start, |b| rest[..b].chars().count());
+                out.extend(std::iter::repeat_n(' ', 2 + hashes));
+                out.extend(
+                    chars[body_start..body_start + body_len]
+                        .iter()
+                        .map(|c| blank(*c)),
+                );
+                i = body_start + body_len;
+                if i < chars.len() {
+                    out.extend(std::iter::repeat_n(' ', close.chars().count()));
+                    i += close.chars().count();
+                }
+                continue;
+            }
+        }
+        if lang == Lang::Rust && c == '\'' {
+            // Char literal (`'7'`, `'\n'`); anything else is a lifetime.
+            let end = match (chars.get(i + 1), chars.get(i + 2)) {
+                (Some('\\'), _) => chars[i + 2..]
+                    .iter()
+                    .position(|c| *c == '\'')
+                    .map(|p| i + 2 + p),
+                (Some(_), Some('\'')) => Some(i + 2),
+                _ => None,
+            };
+            if let Some(end) = end {
+                out.extend(std::iter::repeat_n(' ', end - i + 1));
+                i = end + 1;
+            } else {
+                out.push(c);
+                i += 1;
+            }
+            continue;
+        }
+        if c == '"' || (lang == Lang::Python && c == '\'') {
+            out.push(' ');
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    out.push(' ');
+                    i += 1;
+                }
+                out.push(blank(chars[i]));
+                i += 1;
+            }
+            if i < chars.len() {
+                out.push(' ');
+                i += 1;
+            }
+            continue;
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// Numeric value of a literal without its type suffix (`_` separators dropped).
+fn literal_value(number: &str) -> Option<f64> {
+    let digits: String = number.chars().filter(|c| *c != '_').collect();
+    let lower = digits.to_ascii_lowercase();
+    for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(rest) = lower.strip_prefix(prefix) {
+            return u64::from_str_radix(rest, radix).ok().map(|v| v as f64);
+        }
+    }
+    lower.parse::<f64>().ok()
+}
+
+fn is_power_of_two(value: f64) -> bool {
+    value.fract() == 0.0 && value.abs() >= 1.0 && (value.abs() as u64).is_power_of_two()
+}
+
+/// `-` directly before a literal negates it unless it follows an operand (`a - 2`).
+fn is_negated(line: &str, start: usize) -> bool {
+    let mut before = line[..start].trim_end().chars().rev();
+    if before.next() != Some('-') {
+        return false;
+    }
+    match before.find(|c| !c.is_whitespace()) {
+        None => true,
+        Some(c) => !(is_ident_char(c) || c == ')' || c == ']'),
+    }
+}
+
+/// Flags numeric literals with `|value| >= min_magnitude` in matched Rust/Python files, except
+/// allowed values, optional powers of two, constant definitions and Rust `#[cfg(test)]` modules.
+pub fn run_magic_numbers_check(
+    repo_root: &Path,
+    cfg: &MagicNumbersCheckConfigV2,
+) -> MagicNumbersCheckResult {
+    let files = match collect_candidate_files(repo_root, &cfg.include_globs, &cfg.exclude_globs) {
+        Ok(v) => v,
+        Err(msg) => {
+            return MagicNumbersCheckResult {
+                files_scanned: 0,
+                violations: vec![Violation::blocking(
+                    "magic_numbers.check_failed",
+                    format!("magic_numbers check failed (id={}): {msg}", cfg.id),
+                    None,
+                    None,
+                )],
+            };
+        }
+    };
+    let literal = literal_regex();
+    let rust_constant_definition = constant_definition_regex(Lang::Rust);
+    let python_constant_definition = constant_definition_regex(Lang::Python);
+    let min_magnitude = cfg.min_magnitude as f64;
+
+    let mut files_scanned = 0usize;
+    let mut violations = vec![];
+    for (rel, path) in files {
+        let Some(lang) = lang_for(&rel) else {
+            continue;
+        };
+        let source = match std::fs::read_to_string(&path) {
+            Ok(v) => v,
+            Err(e) => {
+                violations.push(Violation::blocking(
+                    "magic_numbers.read_failed",
+                    format!("failed to read {rel}: {e}"),
+                    Some(rel.clone()),
+                    None,
+                ));
+                continue;
+            }
+        };
+        files_scanned += 1;
+
+        let (comment_lang, source, constant_definition) = match lang {
+            Lang::Rust => (
+                CommentLang::Rust,
+                strip_rust_cfg_test_modules(&source),
+                &rust_constant_definition,
+            ),
+            Lang::Python => (CommentLang::Python, source, &python_constant_definition),
+        };
+        let code = blank_strings(lang, &strip_comments(comment_lang, &source));
+
+        // Continuation lines of a multi-line constant definition are exempt as well.
+        let mut in_constant = false;
+        let mut depth = 0i64;
+        for (idx, line) in code.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("#[") || trimmed.starts_with("#![") {
+                continue;
+            }
+            if !in_constant && constant_definition.is_match(line) {
+                in_constant = true;
+                depth = 0;
+            }
+            if in_constant {
+                depth += line.matches(['(', '[', '{']).count() as i64;
+                depth -= line.matches([')', ']', '}']).count() as i64;
+                let open = depth > 0 || (lang == Lang::Rust && !line.contains(';'));
+                in_constant = open;
+                continue;
+            }
+
+            for (start, c) in line.char_indices() {
+                if !c.is_ascii_digit() {
+                    continue;
+                }
+                let mut prev = line[..start].chars().rev();
+                match (prev.next(), prev.next()) {
+                    (Some(p), _) if is_ident_char(p) => continue,
+                    // Field access (`pair.2`) but not the end of a range (`0..86400`).
+                    (Some('.'), Some(pp)) if pp != '.' => continue,
+                    _ => {}
+                }
+                let Some(caps) = literal.captures(&line[start..]) else {
+                    continue;
+                };
+                let (Some(m), Some(number)) = (caps.get(0), caps.get(1)) else {
+                    continue;
+                };
+                if line[start + m.end()..]
+                    .chars()
+                    .next()
+                    .is_some_and(is_ident_char)
+                {
+                    continue;
+                }
+                let Some(magnitude) = literal_value(number.as_str()) else {
+                    continue;
+                };
+                let value = if is_negated(line, start) {
+                    -magnitude
+                } else {
+                    magnitude
+                };
+                if magnitude < min_magnitude
+                    || cfg.allowed_values.contains(&value)
+                    || (cfg.allow_powers_of_two && is_power_of_two(value))
+                {
+                    continue;
+                }
+                let line_no = idx + 1;
+                let text = m.as_str();
+                violations.push(Violation::observation(
+                    "magic_numbers.detected",
+                    format!("{rel}:{line_no}: magic number `{text}`; name it with a constant"),
+                    Some(rel.clone()),
+                    Some(json!({
+                        "check_id": cfg.id,
+                        "line": line_no,
+                        "literal": text,
+                        "value": value,
+                    })),
+                ));
+            }
+        }
+    }
+
+    MagicNumbersCheckResult {
+        files_scanned,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn cfg() -> MagicNumbersCheckConfigV2 {
+        MagicNumbersCheckConfigV2 {
+            id: "magic".to_string(),
+            include_globs: vec!["src/**".to_string()],
+            exclude_globs: vec![],
+            min_magnitude: 2,
+            allowed_values: vec![0.0, 1.0, -1.0],
+            allow_powers_of_two: false,
+        }
+    }
+
+    fn literals(r: &MagicNumbersCheckResult) -> Vec<(u64, String)> {
+        r.violations
+            .iter()
+            .map(|v| {
+                let d = v.details.as_ref().unwrap();
+                (
+                    d["line"].as_u64().unwrap(),
+                    d["literal"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skips_strings_comments_fields_and_constants() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(
+            src.join("lib.rs"),
+            concat!(
+                "const LIMITS: [u32; 2] = [\n    300,\n    900,\n];\n",
+                "pub const fn twice(x: u32) -> u32 { x * 2 }\n",
+                "fn f(pair: (u8, u8)) -> u8 {\n",
+                "    let _s = \"port 8080\"; // retry 30 times\n",
+                "    let _c = '7';\n",
+                "    for _ in 0..86400 {}\n",
+                "    pair.1 + 0x10u8 - 1\n",
+                "}\n",
+                "#[cfg(test)]\nmod tests {\n    const _X: u8 = 3;\n    fn t() { let _ = 42; }\n}\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            src.join("app.py"),
+            "TIMEOUT_S = 30\nURL = 'http://x:8080'\n# sleep 60\nwait(TIMEOUT_S * 60, -1)\n",
+        )
+        .unwrap();
+
+        let r = run_magic_numbers_check(dir.path(), &cfg());
+        assert_eq!(r.files_scanned, 2);
+        let mut found = literals(&r);
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                (4, "60".to_string()),
+                (5, "2".to_string()),
+                (9, "86400".to_string()),
+                (10, "0x10u8".to_string()),
+            ]
+        );
+        assert!(
+            r.violations
+                .iter()
+                .all(|v| v.code == "magic_numbers.detected")
+        );
+    }
+
+    #[test]
+    fn threshold_allowed_values_and_powers_of_two() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(
+            src.join("lib.rs"),
+            "fn f() -> i64 { 1024 + 7 - 100 + 1_000 * 3 + (-250) }\n",
+        )
+        .unwrap();
+
+        let mut c = cfg();
+        c.min_magnitude = 10;
+        c.allowed_values.push(-250.0);
+        c.allow_powers_of_two = true;
+        let r = run_magic_numbers_check(dir.path(), &c);
+        let found: Vec<String> = literals(&r).into_iter().map(|(_, l)| l).collect();
+        assert_eq!(found, vec!["100", "1_000"]);
+        assert_eq!(r.violations[1].details.as_ref().unwrap()["value"], 1000.0);
+    }
+}
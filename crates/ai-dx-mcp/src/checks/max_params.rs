@@ -0,0 +1,387 @@
+use crate::api::Violation;
+use crate::checks::common::collect_candidate_files;
+use crate::config::MaxParamsCheckConfigV2;
+use crate::textutil::{CommentLang, strip_comments};
+use regex::Regex;
+use serde_json::json;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct MaxParamsCheckResult {
+    pub functions_scanned: usize,
+    pub violations: Vec<Violation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lang {
+    Rust,
+    Python,
+    Js,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Signature {
+    symbol: String,
+    line: usize,
+    params: usize,
+}
+
+fn lang_for(rel: &str) -> Option<Lang> {
+    match Path::new(rel).extension().and_then(|s| s.to_str())? {
+        "rs" => Some(Lang::Rust),
+        "py" => Some(Lang::Python),
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => Some(Lang::Js),
+        _ => None,
+    }
+}
+
+/// Declaration heads whose parameter list follows the captured name (after optional generics).
+fn declaration_patterns(lang: Lang) -> &'static [&'static str] {
+    match lang {
+        Lang::Rust => &[r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)"],
+        Lang::Python => &[r"\bdef\s+([A-Za-z_][A-Za-z0-9_]*)"],
+        Lang::Js => &[
+            r"\bfunction\s*\*?\s*([A-Za-z_$][A-Za-z0-9_$]*)",
+            r"\b(?:const|let|var)\s+([A-Za-z_$][A-Za-z0-9_$]*)\s*=\s*(?:async\s*)?",
+        ],
+    }
+}
+
+fn skip_ws(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+/// Skips a balanced `<...>` generic list starting at `i`; `->`/`=>` arrows do not close it.
+fn skip_generics(bytes: &[u8], mut i: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'<' => depth += 1,
+            b'>' if i > 0 && matches!(bytes[i - 1], b'-' | b'=') => {}
+            b'>' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b'{' | b';' => return None,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Splits the parenthesised list starting at `open` into top-level parameters.
+///
+/// Nested brackets, generic angles and string literals are skipped so their commas do not
+/// count; comments are already stripped by the caller. Returns the parameters and the index just past the closing `)`.
+fn split_params(bytes: &[u8], open: usize, lang: Lang) -> Option<(Vec<String>, usize)> {
+    let mut params: Vec<String> = vec![];
+    let mut current: Vec<u8> = vec![];
+    let mut depth = 0i32;
+    let mut angle = 0i32;
+    let mut i = open + 1;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            b'"' | b'\'' | b'`' if !(lang == Lang::Rust && b == b'\'') => {
+                current.push(b);
+                i += 1;
+                while i < bytes.len() && bytes[i] != b {
+                    if bytes[i] == b'\\' {
+                        current.push(bytes[i]);
+                        i += 1;
+                    }
+                    if i < bytes.len() {
+                        current.push(bytes[i]);
+                    }
+                    i += 1;
+                }
+                current.push(b);
+                i += 1;
+                continue;
+            }
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' if depth == 0 => {
+                params.push(String::from_utf8_lossy(&current).trim().to_string());
+                params.retain(|p| !p.is_empty());
+                return Some((params, i + 1));
+            }
+            b')' | b']' | b'}' => depth -= 1,
+            b'<' if lang != Lang::Python => angle += 1,
+            b'>' if lang != Lang::Python && !matches!(bytes[i - 1], b'-' | b'=') => {
+                angle = (angle - 1).max(0)
+            }
+            b',' if depth == 0 && angle == 0 => {
+                params.push(String::from_utf8_lossy(&current).trim().to_string());
+                current.clear();
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        current.push(b);
+        i += 1;
+    }
+    None
+}
+
+/// Receivers and Python's bare `*` / `/` markers are not parameters callers have to supply.
+fn counts_as_param(lang: Lang, param: &str) -> bool {
+    match lang {
+        Lang::Rust => {
+            let p = param.trim_start_matches('&').trim_start();
+            let p = p
+                .strip_prefix('\'')
+                .and_then(|rest| rest.split_once(char::is_whitespace).map(|(_, r)| r.trim()))
+                .unwrap_or(p);
+            let p = p.strip_prefix("mut ").unwrap_or(p).trim_start();
+            !(p == "self" || p.starts_with("self:") || p.starts_with("self :"))
+        }
+        Lang::Python => {
+            let name = param.split([':', '=']).next().unwrap_or("").trim();
+            !matches!(name, "self" | "cls" | "*" | "/")
+        }
+        Lang::Js => {
+            let name = param.split(':').next().unwrap_or("").trim();
+            name != "this"
+        }
+    }
+}
+
+fn extract_signatures(lang: Lang, raw: &str) -> Vec<Signature> {
+    let comment_lang = match lang {
+        Lang::Rust => CommentLang::Rust,
+        Lang::Python => CommentLang::Python,
+        Lang::Js => CommentLang::CLike,
+    };
+    // Line breaks survive stripping, so reported lines still match the original file.
+    let code = strip_comments(comment_lang, raw);
+    let raw = code.as_str();
+    let bytes = raw.as_bytes();
+    let mut out: Vec<Signature> = vec![];
+    for pattern in declaration_patterns(lang) {
+        let Ok(re) = Regex::new(pattern) else {
+            continue;
+        };
+        for caps in re.captures_iter(raw) {
+            let (Some(whole), Some(name)) = (caps.get(0), caps.get(1)) else {
+                continue;
+            };
+            let mut i = skip_ws(bytes, whole.end());
+            if lang != Lang::Python && bytes.get(i) == Some(&b'<') {
+                let Some(next) = skip_generics(bytes, i) else {
+                    continue;
+                };
+                i = skip_ws(bytes, next);
+            }
+            if bytes.get(i) != Some(&b'(') {
+                continue;
+            }
+            let Some((params, end)) = split_params(bytes, i, lang) else {
+                continue;
+            };
+            // `const f = (a, b) => ...` is a function; `const x = (a + b)` is not.
+            if pattern.contains("const|let|var") {
+                let rest = &raw[skip_ws(bytes, end)..];
+                let head = rest.split('\n').next().unwrap_or("");
+                let arrow =
+                    rest.starts_with("=>") || (head.starts_with(':') && head.contains("=>"));
+                if !arrow {
+                    continue;
+                }
+            }
+            out.push(Signature {
+                symbol: name.as_str().to_string(),
+                line: raw[..name.start()].matches('\n').count() + 1,
+                params: params.iter().filter(|p| counts_as_param(lang, p)).count(),
+            });
+        }
+    }
+    out.sort_by_key(|s| s.line);
+    out
+}
+
+/// Flags Rust/Python/JS-family functions whose parameter list exceeds `max_params`.
+pub fn run_max_params_check(
+    repo_root: &Path,
+    cfg: &MaxParamsCheckConfigV2,
+) -> MaxParamsCheckResult {
+    let files = match collect_candidate_files(repo_root, &cfg.include_globs, &cfg.exclude_globs) {
+        Ok(v) => v,
+        Err(msg) => {
+            return MaxParamsCheckResult {
+                functions_scanned: 0,
+                violations: vec![Violation::blocking(
+                    "max_params.check_failed",
+                    format!("max_params check failed (id={}): {msg}", cfg.id),
+                    None,
+                    None,
+                )],
+            };
+        }
+    };
+
+    let mut functions_scanned = 0usize;
+    let mut violations = vec![];
+    for (rel, path) in files {
+        let Some(lang) = lang_for(&rel) else {
+            continue;
+        };
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(v) => v,
+            Err(e) => {
+                violations.push(Violation::blocking(
+                    "max_params.read_failed",
+                    format!("failed to read {rel}: {e}"),
+                    Some(rel.clone()),
+                    None,
+                ));
+                continue;
+            }
+        };
+        for sig in extract_signatures(lang, &raw) {
+            functions_scanned += 1;
+            if sig.params <= cfg.max_params {
+                continue;
+            }
+            violations.push(Violation::blocking(
+                "max_params.exceeded",
+                format!(
+                    "function {} has {} parameters (max {})",
+                    sig.symbol, sig.params, cfg.max_params
+                ),
+                Some(rel.clone()),
+                Some(json!({
+                    "check_id": cfg.id,
+                    "symbol": sig.symbol,
+                    "line": sig.line,
+                    "param_count": sig.params,
+                    "max_params": cfg.max_params,
+                })),
+            ));
+        }
+    }
+
+    MaxParamsCheckResult {
+        functions_scanned,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn params_of(lang: Lang, src: &str) -> Vec<(String, usize)> {
+        extract_signatures(lang, src)
+            .into_iter()
+            .map(|s| (s.symbol, s.params))
+            .collect()
+    }
+
+    #[test]
+    fn rust_signatures_ignore_receivers_and_generic_commas() {
+        let src = r#"
+impl X {
+    pub fn method(&mut self, a: HashMap<String, u32>, f: impl Fn(u8, u8) -> u8) {}
+}
+pub(crate) async fn generic<T: Into<String>, U>(
+    a: T, // first, documented
+    b: U,
+    c: (u8, u8),
+) -> Vec<T> { todo!() }
+fn lifetimes<'a>(&'a self, s: &'a str) {}
+"#;
+        assert_eq!(
+            params_of(Lang::Rust, src),
+            vec![
+                ("method".to_string(), 2),
+                ("generic".to_string(), 3),
+                ("lifetimes".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn python_signatures_skip_self_cls_and_markers() {
+        let src = "class A:\n    def m(self, a, b=\"x,y\", *args, c, **kw):\n        pass\n\n    @classmethod\n    def k(cls, a, /, b, *, c):\n        pass\n";
+        assert_eq!(
+            params_of(Lang::Python, src),
+            vec![("m".to_string(), 5), ("k".to_string(), 3)]
+        );
+    }
+
+    #[test]
+    fn js_signatures_cover_functions_and_arrows() {
+        let src = "export function f<T>(a: Map<string, T>, b = [1, 2], { c, d }) {}\nconst g = async (x, y) => x + y;\nconst notFn = (1, 2);\nfunction h(this: Window, a: (p: number) => void) {}\n";
+        assert_eq!(
+            params_of(Lang::Js, src),
+            vec![
+                ("f".to_string(), 3),
+                ("g".to_string(), 2),
+                ("h".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn commented_out_declarations_are_ignored() {
+        let src = "// fn old(a: u8, b: u8) {}\n/* fn older(a: u8) {} */\nfn kept(a: u8) {}\n";
+        let sigs = extract_signatures(Lang::Rust, src);
+        assert_eq!(sigs.len(), 1);
+        assert_eq!((sigs[0].symbol.as_str(), sigs[0].line), ("kept", 3));
+        let py = "\"\"\"\ndef doc(a, b):\n\"\"\"\n# def note(a):\ndef real(a):\n    pass\n";
+        assert_eq!(params_of(Lang::Python, py), vec![("real".to_string(), 1)]);
+    }
+
+    #[test]
+    fn seven_params_trip_limit_of_five_in_each_language() {
+        let dir = tempdir().unwrap();
+        let repo = dir.path();
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+        std::fs::write(
+            repo.join("src/lib.rs"),
+            "pub fn wide(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8) {}\npub fn narrow(a: u8) {}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            repo.join("src/app.py"),
+            "def wide(a, b, c, d, e, f, g):\n    pass\n",
+        )
+        .unwrap();
+        std::fs::write(
+            repo.join("src/app.js"),
+            "function wide(a, b, c, d, e, f, g) {}\n",
+        )
+        .unwrap();
+
+        let out = run_max_params_check(
+            repo,
+            &MaxParamsCheckConfigV2 {
+                id: "params".to_string(),
+                include_globs: vec!["src/**".to_string()],
+                exclude_globs: vec![],
+                max_params: 5,
+            },
+        );
+        assert_eq!(out.functions_scanned, 4);
+        let mut hits: Vec<&str> = out
+            .violations
+            .iter()
+            .filter(|v| v.code == "max_params.exceeded")
+            .filter_map(|v| v.path.as_deref())
+            .collect();
+        hits.sort();
+        assert_eq!(hits, vec!["src/app.js", "src/app.py", "src/lib.rs"]);
+        let details = out.violations[0].details.as_ref().expect("details");
+        assert_eq!(details["symbol"], "wide");
+        assert_eq!(details["param_count"], 7);
+        assert_eq!(details["max_params"], 5);
+    }
+}
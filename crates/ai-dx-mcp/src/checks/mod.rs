@@ -1,4 +1,5 @@
 pub mod arch_layers;
+pub mod binary_artifacts;
 pub mod boundary;
 pub mod common;
 pub mod complexity_budget;
@@ -6,9 +7,15 @@ pub mod contract_break;
 pub mod dead_api;
 pub mod duplicates;
 pub mod env_registry;
+pub mod file_encoding;
+pub mod import_cycles;
 pub mod loc;
+pub mod magic_numbers;
+pub mod max_params;
+pub mod naming_convention;
 pub mod quality_delta;
 pub mod reuse_first;
 pub mod supply_chain;
 pub mod surface;
+pub mod test_presence;
 pub mod tool_budget;
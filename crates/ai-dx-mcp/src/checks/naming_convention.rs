@@ -0,0 +1,335 @@
+use crate::api::{Violation, ViolationTier};
+use crate::checks::common::collect_candidate_files;
+use crate::config::{NamingConventionCheckConfigV2, NamingConventionRule, NamingLanguage};
+use regex::Regex;
+use serde_json::json;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct NamingConventionCheckResult {
+    pub files_scanned: usize,
+    pub violations: Vec<Violation>,
+}
+
+struct CompiledRule {
+    language: NamingLanguage,
+    file_name: Regex,
+    type_name: Regex,
+    declaration: Regex,
+    tier: ViolationTier,
+}
+
+fn language_extension(language: NamingLanguage) -> &'static str {
+    match language {
+        NamingLanguage::Rust => "rs",
+        NamingLanguage::Python => "py",
+    }
+}
+
+fn language_label(language: NamingLanguage) -> &'static str {
+    match language {
+        NamingLanguage::Rust => "rust",
+        NamingLanguage::Python => "python",
+    }
+}
+
+/// Defaults: snake_case file stems and PascalCase types; Python also allows the leading
+/// underscores of `__init__` / `_private` modules and `_Private` classes.
+fn default_patterns(language: NamingLanguage) -> (&'static str, &'static str) {
+    match language {
+        NamingLanguage::Rust => (r"^[a-z][a-z0-9_]*$", r"^[A-Z][A-Za-z0-9]*$"),
+        NamingLanguage::Python => (r"^_{0,2}[a-z][a-z0-9_]*$", r"^_?[A-Z][A-Za-z0-9]*$"),
+    }
+}
+
+fn declaration_regex(language: NamingLanguage) -> Regex {
+    let pattern = match language {
+        NamingLanguage::Rust => {
+            r"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:unsafe\s+)?(?:struct|enum|union|trait|type)\s+([A-Za-z_][A-Za-z0-9_]*)"
+        }
+        NamingLanguage::Python => r"^\s*class\s+([A-Za-z_][A-Za-z0-9_]*)",
+    };
+    Regex::new(pattern).unwrap()
+}
+
+fn compile_rule(rule: &NamingConventionRule) -> Result<CompiledRule, String> {
+    let (file_default, type_default) = default_patterns(rule.language);
+    let compile = |raw: Option<&String>, default: &str, field: &str| {
+        let pattern = raw.map(String::as_str).unwrap_or(default);
+        Regex::new(pattern).map_err(|e| format!("invalid {field} {pattern:?}: {e}"))
+    };
+    Ok(CompiledRule {
+        language: rule.language,
+        file_name: compile(
+            rule.file_name_regex.as_ref(),
+            file_default,
+            "file_name_regex",
+        )?,
+        type_name: compile(
+            rule.type_name_regex.as_ref(),
+            type_default,
+            "type_name_regex",
+        )?,
+        declaration: declaration_regex(rule.language),
+        tier: rule.tier,
+    })
+}
+
+fn is_comment_line(language: NamingLanguage, line: &str) -> bool {
+    let t = line.trim_start();
+    match language {
+        NamingLanguage::Rust => t.starts_with("//") || t.starts_with("/*") || t.starts_with('*'),
+        NamingLanguage::Python => t.starts_with('#'),
+    }
+}
+
+fn violation(
+    tier: ViolationTier,
+    message: String,
+    rel: &str,
+    details: serde_json::Value,
+) -> Violation {
+    let ctor = match tier {
+        ViolationTier::Blocking => Violation::blocking,
+        ViolationTier::Observation => Violation::observation,
+    };
+    ctor(
+        "naming_convention.violation",
+        message,
+        Some(rel.to_string()),
+        Some(details),
+    )
+}
+
+/// Flags file names (up to their first dot) and declared type names that miss the rule's
+/// convention for the file's language.
+pub fn run_naming_convention_check(
+    repo_root: &Path,
+    cfg: &NamingConventionCheckConfigV2,
+) -> NamingConventionCheckResult {
+    let failed = |msg: String| NamingConventionCheckResult {
+        files_scanned: 0,
+        violations: vec![Violation::blocking(
+            "naming_convention.check_failed",
+            format!("naming_convention check failed (id={}): {msg}", cfg.id),
+            None,
+            None,
+        )],
+    };
+    let rules = match cfg
+        .rules
+        .iter()
+        .map(compile_rule)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(v) => v,
+        Err(msg) => return failed(msg),
+    };
+    let files = match collect_candidate_files(repo_root, &cfg.include_globs, &cfg.exclude_globs) {
+        Ok(v) => v,
+        Err(msg) => return failed(msg),
+    };
+
+    let mut files_scanned = 0usize;
+    let mut violations = vec![];
+    for (rel, path) in files {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let applicable: Vec<&CompiledRule> = rules
+            .iter()
+            .filter(|r| language_extension(r.language) == ext)
+            .collect();
+        if applicable.is_empty() {
+            continue;
+        }
+        let source = match std::fs::read_to_string(&path) {
+            Ok(v) => v,
+            Err(e) => {
+                violations.push(Violation::blocking(
+                    "naming_convention.read_failed",
+                    format!("failed to read {rel}: {e}"),
+                    Some(rel.clone()),
+                    None,
+                ));
+                continue;
+            }
+        };
+        files_scanned += 1;
+
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        let stem = file_name.split('.').next().unwrap_or_default();
+        for rule in &applicable {
+            let language = language_label(rule.language);
+            if !rule.file_name.is_match(stem) {
+                violations.push(violation(
+                    rule.tier,
+                    format!(
+                        "{rel}: file name `{stem}` does not match {} ({language})",
+                        rule.file_name.as_str()
+                    ),
+                    &rel,
+                    json!({
+                        "check_id": cfg.id,
+                        "kind": "file_name",
+                        "language": language,
+                        "name": stem,
+                        "expected": rule.file_name.as_str(),
+                    }),
+                ));
+            }
+
+            for (idx, line) in source.lines().enumerate() {
+                if is_comment_line(rule.language, line) {
+                    continue;
+                }
+                let Some(name) = rule.declaration.captures(line).and_then(|c| c.get(1)) else {
+                    continue;
+                };
+                let name = name.as_str();
+                if rule.type_name.is_match(name) {
+                    continue;
+                }
+                let line_no = idx + 1;
+                violations.push(violation(
+                    rule.tier,
+                    format!(
+                        "{rel}:{line_no}: type `{name}` does not match {} ({language})",
+                        rule.type_name.as_str()
+                    ),
+                    &rel,
+                    json!({
+                        "check_id": cfg.id,
+                        "kind": "type_name",
+                        "language": language,
+                        "name": name,
+                        "line": line_no,
+                        "expected": rule.type_name.as_str(),
+                    }),
+                ));
+            }
+        }
+    }
+
+    NamingConventionCheckResult {
+        files_scanned,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn cfg(rules: Vec<NamingConventionRule>) -> NamingConventionCheckConfigV2 {
+        NamingConventionCheckConfigV2 {
+            id: "naming".to_string(),
+            include_globs: vec!["src/**".to_string()],
+            exclude_globs: vec![],
+            rules,
+        }
+    }
+
+    fn rule(language: NamingLanguage) -> NamingConventionRule {
+        NamingConventionRule {
+            language,
+            file_name_regex: None,
+            type_name_regex: None,
+            tier: ViolationTier::Blocking,
+        }
+    }
+
+    #[test]
+    fn default_rules_flag_miscased_files_and_types() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(
+            src.join("good_mod.rs"),
+            "// struct not_a_type\npub struct Good;\npub(crate) enum Also { A }\n",
+        )
+        .unwrap();
+        std::fs::write(src.join("BadFile.rs"), "pub struct lower_case;\n").unwrap();
+        std::fs::write(src.join("ops.inc.rs"), "type Alias = u8;\n").unwrap();
+        std::fs::write(
+            src.join("__init__.py"),
+            "# class nope\nclass _Private:\n    pass\nclass snake_class:\n    pass\n",
+        )
+        .unwrap();
+
+        let r = run_naming_convention_check(
+            dir.path(),
+            &cfg(vec![
+                rule(NamingLanguage::Rust),
+                rule(NamingLanguage::Python),
+            ]),
+        );
+        assert_eq!(r.files_scanned, 4);
+        let mut found: Vec<(String, String, String)> = r
+            .violations
+            .iter()
+            .map(|v| {
+                let d = v.details.as_ref().unwrap();
+                (
+                    v.path.clone().unwrap(),
+                    d["kind"].as_str().unwrap().to_string(),
+                    d["name"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect();
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "src/BadFile.rs".to_string(),
+                    "file_name".to_string(),
+                    "BadFile".to_string()
+                ),
+                (
+                    "src/BadFile.rs".to_string(),
+                    "type_name".to_string(),
+                    "lower_case".to_string()
+                ),
+                (
+                    "src/__init__.py".to_string(),
+                    "type_name".to_string(),
+                    "snake_class".to_string()
+                ),
+            ]
+        );
+        let py = r
+            .violations
+            .iter()
+            .find(|v| v.path.as_deref() == Some("src/__init__.py"))
+            .unwrap();
+        assert_eq!(py.details.as_ref().unwrap()["line"], 4);
+    }
+
+    #[test]
+    fn rule_tier_and_custom_regex_apply() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("api.rs"), "pub struct HttpAPI;\n").unwrap();
+
+        let mut strict = rule(NamingLanguage::Rust);
+        strict.type_name_regex = Some(r"^[A-Z][a-z0-9]*(?:[A-Z][a-z0-9]+)*$".to_string());
+        strict.tier = ViolationTier::Observation;
+        let r = run_naming_convention_check(dir.path(), &cfg(vec![strict]));
+        assert_eq!(r.violations.len(), 1, "{:?}", r.violations);
+        assert_eq!(r.violations[0].code, "naming_convention.violation");
+        assert_eq!(r.violations[0].tier, ViolationTier::Observation);
+
+        let mut broken = rule(NamingLanguage::Rust);
+        broken.file_name_regex = Some("(".to_string());
+        let r = run_naming_convention_check(dir.path(), &cfg(vec![broken]));
+        assert_eq!(r.violations.len(), 1);
+        assert_eq!(r.violations[0].code, "naming_convention.check_failed");
+    }
+}
@@ -1,5 +1,6 @@
 use crate::api::{BaselineMaintenance, Violation, ViolationTier};
 use crate::config::QualityContractConfig;
+use crate::validate_insights::{BaselineDiff, LocDelta, MetricDelta};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -359,6 +360,91 @@ pub fn run_quality_delta(
     })
 }
 
+fn metric(baseline: Option<i64>, current: i64) -> MetricDelta {
+    let baseline = baseline.unwrap_or(0);
+    MetricDelta {
+        baseline,
+        current,
+        delta: current - baseline,
+    }
+}
+
+/// Reports what `write_baseline` would change at `snapshot_path` without touching it.
+/// `regressions` is the same ratchet comparison `run_quality_delta` performs.
+pub fn dry_run_quality_delta(
+    snapshot_path: &Path,
+    contract: &QualityContractConfig,
+    current: &QualitySnapshot,
+) -> Result<BaselineDiff, String> {
+    let baseline = load_snapshot(snapshot_path)?;
+    let base = baseline.as_ref();
+
+    let empty_loc = BTreeMap::new();
+    let base_loc = base.map_or(&empty_loc, |b| &b.loc_per_file);
+    let paths: BTreeSet<&String> = base_loc.keys().chain(current.loc_per_file.keys()).collect();
+    let loc_changed = paths
+        .into_iter()
+        .filter_map(|path| {
+            let before = base_loc.get(path).copied();
+            let after = current.loc_per_file.get(path).copied();
+            (before != after).then(|| LocDelta {
+                path: path.clone(),
+                baseline: before,
+                current: after,
+            })
+        })
+        .collect();
+
+    let base_surface: BTreeSet<&String> = base
+        .map(|b| b.surface_items.iter().collect())
+        .unwrap_or_default();
+    let curr_surface: BTreeSet<&String> = current.surface_items.iter().collect();
+    let base_dups: BTreeSet<&Vec<String>> = base
+        .map(|b| b.duplicate_groups.iter().collect())
+        .unwrap_or_default();
+    let curr_dups: BTreeSet<&Vec<String>> = current.duplicate_groups.iter().collect();
+
+    Ok(BaselineDiff {
+        snapshot_path: snapshot_path.display().to_string(),
+        baseline_present: base.is_some(),
+        trust_score: metric(
+            base.map(|b| b.trust_score as i64),
+            current.trust_score as i64,
+        ),
+        coverage_covered: metric(
+            base.map(|b| b.coverage_covered as i64),
+            current.coverage_covered as i64,
+        ),
+        coverage_total: metric(
+            base.map(|b| b.coverage_total as i64),
+            current.coverage_total as i64,
+        ),
+        weighted_risk: metric(
+            base.map(|b| b.weighted_risk as i64),
+            current.weighted_risk as i64,
+        ),
+        findings_total: metric(
+            base.map(|b| b.findings_total as i64),
+            current.findings_total as i64,
+        ),
+        loc_changed,
+        surface_added: curr_surface
+            .difference(&base_surface)
+            .map(|s| (*s).clone())
+            .collect(),
+        surface_removed: base_surface
+            .difference(&curr_surface)
+            .map(|s| (*s).clone())
+            .collect(),
+        duplicate_groups_added: curr_dups.difference(&base_dups).count(),
+        duplicate_groups_removed: base_dups.difference(&curr_dups).count(),
+        config_hash_changed: base.is_some_and(|b| b.config_hash != current.config_hash),
+        regressions: base
+            .map(|b| compare(b, current, contract))
+            .unwrap_or_default(),
+    })
+}
+
 pub fn migrate_from_prior_baselines(
     repo_root: &Path,
     trust_score: i32,
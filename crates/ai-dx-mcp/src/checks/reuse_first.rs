@@ -2,6 +2,7 @@ use crate::api::Violation;
 use crate::checks::common::{collect_candidate_files, is_probably_code_file};
 use crate::config::ReuseFirstCheckConfigV2;
 use crate::hash::sha256_hex;
+use crate::textutil::{CommentLang, strip_comments};
 use regex::Regex;
 use serde_json::json;
 use std::collections::{BTreeMap, BTreeSet};
@@ -25,16 +26,6 @@ fn ext(rel: &str) -> Option<&str> {
     Path::new(rel).extension().and_then(|s| s.to_str())
 }
 
-fn strip_inline_comments(line: &str) -> &str {
-    if let Some(idx) = line.find("//") {
-        return &line[..idx];
-    }
-    if let Some(idx) = line.find('#') {
-        return &line[..idx];
-    }
-    line
-}
-
 fn normalize_block(lines: &[String]) -> String {
     let mut joined = lines.join("\n").to_ascii_lowercase();
     for (pat, repl) in [
@@ -47,14 +38,7 @@ fn normalize_block(lines: &[String]) -> String {
             joined = re.replace_all(&joined, repl).to_string();
         }
     }
-    joined
-        .lines()
-        .collect::<Vec<_>>()
-        .iter()
-        .map(|l| strip_inline_comments(l))
-        .flat_map(|l| l.chars())
-        .filter(|c| !c.is_whitespace())
-        .collect::<String>()
+    joined.chars().filter(|c| !c.is_whitespace()).collect()
 }
 
 fn parse_symbol_from_line(line: &str) -> Option<String> {
@@ -138,7 +122,13 @@ fn extract_brace_block(lines: &[String], start: usize) -> Vec<String> {
 }
 
 fn extract_blocks_for_file(rel_path: &str, raw: &str, min_block_lines: usize) -> Vec<CodeBlock> {
-    let lines: Vec<String> = raw.lines().map(ToString::to_string).collect();
+    // Comments are stripped up front (line breaks kept) so they neither start blocks nor
+    // break otherwise identical bodies.
+    let code = match CommentLang::for_path(rel_path) {
+        Some(lang) => strip_comments(lang, raw),
+        None => raw.to_string(),
+    };
+    let lines: Vec<String> = code.lines().map(ToString::to_string).collect();
     if lines.is_empty() {
         return vec![];
     }
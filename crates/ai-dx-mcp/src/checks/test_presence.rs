@@ -0,0 +1,214 @@
+use crate::api::Violation;
+use crate::checks::common::collect_candidate_files;
+use crate::config::TestPresenceCheckConfigV2;
+use globset::{Glob, GlobMatcher, GlobSet, GlobSetBuilder};
+use serde_json::json;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct TestPresenceCheckResult {
+    pub sources_checked: usize,
+    pub violations: Vec<Violation>,
+}
+
+struct CompiledRule {
+    source: GlobMatcher,
+    test_globs: Vec<String>,
+}
+
+fn compile_rules(cfg: &TestPresenceCheckConfigV2) -> Result<Vec<CompiledRule>, String> {
+    let mut out = vec![];
+    for rule in &cfg.rules {
+        if rule.test_globs.is_empty() {
+            return Err(format!(
+                "rule for source_glob {:?} has no test_globs",
+                rule.source_glob
+            ));
+        }
+        let source = Glob::new(&rule.source_glob)
+            .map_err(|e| format!("invalid source_glob {:?}: {e}", rule.source_glob))?
+            .compile_matcher();
+        out.push(CompiledRule {
+            source,
+            test_globs: rule.test_globs.clone(),
+        });
+    }
+    Ok(out)
+}
+
+fn source_stem(path: &str) -> &str {
+    Path::new(path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default()
+}
+
+fn expand_test_globs(test_globs: &[String], stem: &str) -> Result<(Vec<String>, GlobSet), String> {
+    let mut b = GlobSetBuilder::new();
+    let mut expanded = vec![];
+    for raw in test_globs {
+        let pattern = raw.replace("{stem}", stem);
+        let g = Glob::new(&pattern).map_err(|e| format!("invalid test_glob {:?}: {e}", raw))?;
+        b.add(g);
+        expanded.push(pattern);
+    }
+    let set = b
+        .build()
+        .map_err(|e| format!("failed to build test_globs globset: {e}"))?;
+    Ok((expanded, set))
+}
+
+fn check_failed(cfg: &TestPresenceCheckConfigV2, msg: String) -> TestPresenceCheckResult {
+    TestPresenceCheckResult {
+        sources_checked: 0,
+        violations: vec![Violation::blocking(
+            "test_presence.check_failed",
+            format!("test_presence check failed (id={}): {msg}", cfg.id),
+            None,
+            None,
+        )],
+    }
+}
+
+/// Flags changed source files whose mapped tests neither exist in the tree nor appear in the diff.
+/// Deleted sources (absent on disk) are skipped.
+pub fn run_test_presence_check(
+    repo_root: &Path,
+    cfg: &TestPresenceCheckConfigV2,
+    changed_files: &[String],
+) -> TestPresenceCheckResult {
+    let rules = match compile_rules(cfg) {
+        Ok(v) => v,
+        Err(msg) => return check_failed(cfg, msg),
+    };
+
+    let mut repo_files: Option<Vec<String>> = None;
+    let mut sources_checked = 0usize;
+    let mut violations = vec![];
+    for path in changed_files {
+        let Some(rule) = rules.iter().find(|r| r.source.is_match(path)) else {
+            continue;
+        };
+        if !repo_root.join(path).is_file() {
+            continue;
+        }
+        sources_checked += 1;
+
+        let (expanded, tests) = match expand_test_globs(&rule.test_globs, source_stem(path)) {
+            Ok(v) => v,
+            Err(msg) => return check_failed(cfg, msg),
+        };
+
+        if changed_files.iter().any(|f| tests.is_match(f)) {
+            continue;
+        }
+        if repo_files.is_none() {
+            match collect_candidate_files(repo_root, &[], &[]) {
+                Ok(files) => repo_files = Some(files.into_iter().map(|(rel, _)| rel).collect()),
+                Err(msg) => return check_failed(cfg, msg),
+            }
+        }
+        if repo_files
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .any(|f| tests.is_match(f))
+        {
+            continue;
+        }
+
+        let mk = if cfg.blocking {
+            Violation::blocking
+        } else {
+            Violation::observation
+        };
+        violations.push(mk(
+            "test_presence.missing_test",
+            format!(
+                "changed source '{path}' has no matching test file (expected one of {expanded:?})"
+            ),
+            Some(path.clone()),
+            Some(json!({
+                "check_id": cfg.id,
+                "expected_test_globs": expanded,
+            })),
+        ));
+    }
+
+    TestPresenceCheckResult {
+        sources_checked,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ViolationTier;
+    use crate::config::TestPresenceRuleConfigV2;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn cfg(blocking: bool) -> TestPresenceCheckConfigV2 {
+        TestPresenceCheckConfigV2 {
+            id: "tests-required".to_string(),
+            rules: vec![TestPresenceRuleConfigV2 {
+                source_glob: "src/**/*.rs".to_string(),
+                test_globs: vec!["tests/{stem}*.rs".to_string()],
+            }],
+            blocking,
+        }
+    }
+
+    fn write(root: &Path, rel: &str) {
+        let p = root.join(rel);
+        fs::create_dir_all(p.parent().unwrap()).unwrap();
+        fs::write(p, "fn x() {}\n").unwrap();
+    }
+
+    #[test]
+    fn test_presence_flags_changed_source_without_tests() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "src/foo.rs");
+        write(dir.path(), "tests/bar.rs");
+
+        let r = run_test_presence_check(dir.path(), &cfg(true), &["src/foo.rs".to_string()]);
+        assert_eq!(r.sources_checked, 1);
+        assert_eq!(r.violations.len(), 1);
+        assert_eq!(r.violations[0].code, "test_presence.missing_test");
+        assert_eq!(r.violations[0].path.as_deref(), Some("src/foo.rs"));
+        assert_eq!(r.violations[0].tier, ViolationTier::Blocking);
+
+        let r = run_test_presence_check(dir.path(), &cfg(false), &["src/foo.rs".to_string()]);
+        assert_eq!(r.violations[0].tier, ViolationTier::Observation);
+    }
+
+    #[test]
+    fn test_presence_accepts_existing_changed_or_deleted() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "src/foo.rs");
+        write(dir.path(), "src/baz.rs");
+        write(dir.path(), "tests/foo_cli.rs");
+
+        // Existing test on disk, a test changed alongside, and a deleted source.
+        let changed = vec![
+            "src/foo.rs".to_string(),
+            "src/baz.rs".to_string(),
+            "tests/baz.rs".to_string(),
+            "src/gone.rs".to_string(),
+            "README.md".to_string(),
+        ];
+        let r = run_test_presence_check(dir.path(), &cfg(true), &changed);
+        assert_eq!(r.sources_checked, 2);
+        assert!(r.violations.is_empty(), "{:?}", r.violations);
+    }
+
+    #[test]
+    fn test_presence_rejects_invalid_glob() {
+        let dir = tempdir().unwrap();
+        let mut c = cfg(false);
+        c.rules[0].source_glob = "src/[".to_string();
+        let r = run_test_presence_check(dir.path(), &c, &["src/foo.rs".to_string()]);
+        assert_eq!(r.violations[0].code, "test_presence.check_failed");
+    }
+}
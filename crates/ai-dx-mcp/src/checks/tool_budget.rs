@@ -1,4 +1,4 @@
-use crate::api::Violation;
+use crate::api::{GateKind, Violation};
 use crate::config::ToolBudgetCheckConfigV2;
 use crate::repo::RepoConfig;
 use serde_json::json;
@@ -22,6 +22,13 @@ fn checks_total(cfg: &RepoConfig) -> usize {
         + cfg.checks.orphan_api.len()
         + cfg.checks.complexity_budget.len()
         + cfg.checks.contract_break.len()
+        + cfg.checks.test_presence.len()
+        + cfg.checks.file_encoding.len()
+        + cfg.checks.binary_artifacts.len()
+        + cfg.checks.import_cycles.len()
+        + cfg.checks.max_params.len()
+        + cfg.checks.naming_convention.len()
+        + cfg.checks.magic_numbers.len()
 }
 
 pub fn run_tool_budget_check(
@@ -33,7 +40,7 @@ pub fn run_tool_budget_check(
     let tools_total = cfg.tools.len();
     if tools_total > check.max_tools_total {
         violations.push(Violation::observation(
-            "tool_budget.max_tools_total_exceeded",
+            "tool_budget.total_exceeded",
             format!(
                 "tool count exceeds budget: total={} > max={}",
                 tools_total, check.max_tools_total
@@ -70,24 +77,52 @@ pub fn run_tool_budget_check(
         }
     }
 
-    for (kind, total) in [
-        ("ci_fast", cfg.gate.ci_fast.len()),
-        ("ci", cfg.gate.ci.len()),
-        ("flagship", cfg.gate.flagship.len()),
+    let contributing: Vec<&str> = cfg
+        .plugins
+        .values()
+        .filter(|p| !p.tool_ids.is_empty())
+        .map(|p| p.id.as_str())
+        .collect();
+    if let Some(max) = check.max_plugins_with_tools
+        && contributing.len() > max
+    {
+        violations.push(Violation::observation(
+            "tool_budget.plugin_fanout_exceeded",
+            format!(
+                "plugins contributing tools exceed budget: total={} > max={}",
+                contributing.len(),
+                max
+            ),
+            Some(".agents/mcp/compas/plugins".to_string()),
+            Some(json!({
+                "check_id": check.id,
+                "total": contributing.len(),
+                "max": max,
+                "plugin_ids": contributing,
+            })),
+        ));
+    }
+
+    for (kind, name, total) in [
+        (GateKind::CiFast, "ci_fast", cfg.gate.ci_fast.len()),
+        (GateKind::Ci, "ci", cfg.gate.ci.len()),
+        (GateKind::Flagship, "flagship", cfg.gate.flagship.len()),
     ] {
-        if total > check.max_gate_tools_per_kind {
+        let max = check
+            .max_gate_tools
+            .get(&kind)
+            .copied()
+            .unwrap_or(check.max_gate_tools_per_kind);
+        if total > max {
             violations.push(Violation::observation(
-                "tool_budget.max_gate_tools_exceeded",
-                format!(
-                    "gate {} exceeds budget: total={} > max={}",
-                    kind, total, check.max_gate_tools_per_kind
-                ),
+                "tool_budget.gate_exceeded",
+                format!("gate {name} exceeds budget: total={total} > max={max}"),
                 Some(".agents/mcp/compas/plugins".to_string()),
                 Some(json!({
                     "check_id": check.id,
-                    "gate_kind": kind,
+                    "gate_kind": name,
                     "total": total,
-                    "max": check.max_gate_tools_per_kind,
+                    "max": max,
                 })),
             ));
         }
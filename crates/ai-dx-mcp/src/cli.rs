@@ -1,34 +1,182 @@
-use ai_dx_mcp::api::{BaselineMaintenance, GateKind, ValidateMode};
+use ai_dx_mcp::api::{AgentDigest, ApiError, GateKind, ValidateMode};
 
+mod color;
 mod init_parse;
 #[path = "cli_plugins.rs"]
 mod plugins_impl;
-#[path = "registry_manifest.rs"]
-mod registry_manifest;
+mod validate;
 
 const DEFAULT_PLUGIN_REGISTRY_SOURCE: &str = "https://github.com/AmirTlinov/compas-plugin-registry/releases/latest/download/registry.manifest.v1.json";
 const PLUGIN_REGISTRY_ENV: &str = "COMPAS_PLUGIN_REGISTRY";
 
 pub(crate) fn print_help() {
     println!(
-        "Usage:\n  compas_mcp help\n  compas_mcp version\n  compas_mcp init [--apply] [--profile <ai_first>] [--registry <url-or-path>] [--packs <builtin:...,...>] [--repo-root <path>]\n  compas_mcp validate [ratchet|strict|warn] [--write-baseline] [--baseline-reason <text>] [--baseline-owner <id>] [--repo-root <path>]\n  compas_mcp gate [ci_fast|ci|flagship] [--dry-run] [--write-witness] [--repo-root <path>]\n  compas_mcp exec <tool_id> [--dry-run] [--repo-root <path>] [-- <tool-args...>]\n  compas_mcp plugins [install|update|uninstall|list|packs|info|doctor] [--registry <url-or-path>] [--repo-root <path>] [--admin-lane] [--allow-experimental] [--allow-sunset] [-- <registry-installer-args...>]\n\nNotes:\n  - No args => start MCP server over stdio.\n  - v1-style flags --init/--validate/--gate are removed in v2.\n  - `init --registry` is advisory only: it loads a signed manifest and returns bootstrap recommendations without mutating install policy.\n  - Defaults via env:\n      AI_DX_REPO_ROOT=<path>\n      AI_DX_WRITE_WITNESS=1|true\n      COMPAS_PLUGIN_REGISTRY=<url-or-path>\n\nExamples:\n  compas_mcp init --apply\n  compas_mcp init --apply --profile ai_first\n  compas_mcp init --registry https://github.com/AmirTlinov/compas-plugin-registry/releases/latest/download/registry.manifest.v1.json\n  compas_mcp validate ratchet\n  compas_mcp validate ratchet --write-baseline --baseline-reason \"Quarterly baseline refresh after policy change\" --baseline-owner team-lead\n  compas_mcp gate ci_fast --dry-run\n  compas_mcp exec merge-truth-check -- --profile ci\n  compas_mcp plugins list -- --json\n  compas_mcp plugins packs -- --json\n  compas_mcp plugins info spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins experimental-plugin --allow-experimental\n  compas_mcp plugins update --admin-lane --plugins sunset-plugin --allow-sunset\n"
+        "Usage:\n  compas_mcp help\n  compas_mcp version\n  compas_mcp init [--apply] [--summary] [--profile <ai_first>] [--registry <url-or-path>] [--packs <builtin:...,...>|--packs-from <packs.lock>] [--repo-root <path>]\n  compas_mcp validate [ratchet|strict|warn] [--write-baseline] [--baseline-reason <text>] [--baseline-owner <id>|--baseline-owner-from-git] [--max-violations <n>] [--timings] [--explain] [--baseline-dry-run] [--baseline-migrate-only] [--schema-version <2|4>] [--summary-only|--quiet] [--include-suppressed] [--only <kinds>] [--skip <kinds>] [--skip-contract] [--errors-only] [--digest-out <path>] [--repo-root <path>]\n  compas_mcp gate [ci_fast|ci|flagship] [--dry-run] [--write-witness] [--budget-ms <n>] [--reports] [--explain-verdict] [--witness-dir <path>] [--no-fail-fast|--fail-fast=<true|false>] [--capture-full] [--digest-out <path>] [--print-plan] [--repo-root <path>]\n  compas_mcp gate-diff --a <witness.json> --b <witness.json>\n  compas_mcp boundary-test --rule <id> --input <file> [--repo-root <path>]\n  compas_mcp exceptions [--repo-root <path>]\n  compas_mcp canonicalize --in <file.json>\n  compas_mcp exec <tool_id> [--dry-run] [--repo-root <path>] [-- <tool-args...>]\n  compas_mcp tools describe <tool_id> [--resolved-env] [--repo-root <path>]\n  compas_mcp plugins [install|update|uninstall|list|packs|info|doctor|freeze|export] [--registry <url-or-path>] [--repo-root <path>] [--admin-lane] [--allow-experimental] [--allow-sunset] [--unfreeze] [--expect-registry-version <v>] [-- <registry-installer-args...>]\n\nNotes:\n  - No args => start MCP server over stdio.\n  - Global `--error-format json` prints errors as {{\"ok\":false,\"error\":{{\"code\",\"message\"}}}} on stdout; argument errors and runtime errors (plugins.failed, canonicalize.*, cli.digest_out_failed) both exit 2, apart from the exit 1 of a command that ran and reported ok=false.\n  - Global `--compact-json` prints JSON outputs (init/validate/gate/gate-diff/exec/plugins) on a single line instead of pretty-printed; `--canonical-json` also sorts keys (byte-stable, for signing).\n  - `canonicalize --in <file.json>` prints the file as canonical JSON (sorted keys, no insignificant whitespace, no trailing newline) so reformatting cannot change what gets signed or hashed.\n  - v1-style flags --init/--validate/--gate are removed in v2.\n  - `validate --schema-version 2` drops findings_v2/agent_digest for older consumers; unsupported versions fail.\n  - `validate --summary-only` (alias `--quiet`) prints the decision and agent digest as text instead of JSON; exit code is unchanged. The status is colored only on a terminal; global `--no-color` or NO_COLOR=<non-empty> turn color off.\n  - Global `--exit-zero` (advisory rollout) exits 0 even when a command reports ok=false; the JSON still says ok=false. Usage errors keep exit 2. Unlike `validate warn`, the verdict itself is unchanged.\n  - `validate --write-baseline --baseline-reason <text> --baseline-owner-from-git` takes the owner from `git config user.email` in the repo; fails if no git identity is configured.\n  - `validate --baseline-migrate-only` turns prior baselines (.agents/mcp/compas/baselines/{{loc,public_surface,duplicates}}.json) into the quality snapshot and prints it; checks run read-only to seed trust/coverage/risk, nothing blocks, and an existing snapshot is never overwritten (fails with baseline.snapshot_exists).\n  - `validate --include-suppressed` also lists allowlist-suppressed findings in findings_v2 with details.suppressed=true and the exception reason; the decision ignores them.\n  - `validate --only boundary,loc` / `--skip <kinds>` run a subset of check kinds ([checks.<kind>] names); contract-level checks (mandatory checks, exception budgets, quality_delta) still run unless `--skip-contract`, and the output carries `partial_run` with the skipped kinds.\n  - `validate --errors-only` computes only the decision and blocking violations (findings_v2, risk_summary, coverage, trust_score, quality_posture and agent_digest are omitted); pass/fail and the exit code match a full run.\n  - `validate|gate --digest-out <path>` also writes just the agent_digest JSON (top_blockers, root_causes, minimal_fix_steps, ...) to <path>; stdout is unchanged.\n  - `gate --witness-dir <path>` writes witness + chain.json under a repo-relative dir (overrides `[proof] witness_dir`; default .agents/mcp/compas/witness).\n  - `gate --no-fail-fast` (or `--fail-fast=false`) keeps running tools after a failure so every receipt is collected; the verdict still blocks (overrides `[gate] fail_fast` in the quality contract).\n  - `gate --capture-full` raises every tool's stdout/stderr tail cap (max_stdout_bytes/max_stderr_bytes/max_tail_bytes) to the 1000000-byte ceiling for this run only; stdout_sha256/stderr_sha256 still cover the full streams.\n  - `gate --print-plan` prints the ordered tool sequence the gate would run (aliases resolved, each step with its reasons, change_impact-required tools and what the sequence lacks) without running validate or any tool; exit 1 when the plan already blocks.\n  - `gate --reports` lifts each tool's structured report findings into top-level `tool_reports` [{{tool_id, findings}}].\n  - `gate --explain-verdict` annotates each verdict reason with `source` (validate, gate, or tool:<id>) and, when the finding has one, the `origin` file; without it the witness omits both too.\n  - `init --packs-from <packs.lock>` reproduces the builtin packs (ids + versions) recorded in another repo's packs.lock; a version this build does not ship fails with init.packs_lock_version_mismatch.\n  - `init --summary` appends a Markdown plan (selected packs, every planned write/delete path) to summary_md; without it summary_md is only the status block.\n  - `boundary-test --rule <id> --input <file>` runs one [[checks.boundary]] rule against a file (cfg(test) stripping included) and prints every matched line/column/span without a full validate.\n  - `tools describe <tool_id> --resolved-env` adds the environment the tool runs with: every [[checks.env_registry]] var (process env or registry default) overridden by the tool's own env; sensitive values are masked.\n  - `exceptions` prints the allowlist expiry calendar as JSON: every entry with code, path, owner, expires_at and days_until_expiry (negative once expired), soonest first.\n  - `--repo-root` (and AI_DX_REPO_ROOT) is resolved to its canonical path, so a symlinked root reports and writes under the real tree; a root resolving to `/` or a non-directory fails with cli.invalid_args. Plugin lockfile paths whose directories resolve outside the root are rejected.\n  - `init --registry` is advisory only: it loads a signed manifest and returns bootstrap recommendations without mutating install policy.\n  - Defaults via env:\n      AI_DX_REPO_ROOT=<path>\n      AI_DX_WRITE_WITNESS=1|true\n      COMPAS_TIMINGS=1|true (validate: attach per-check timings_ms)\n      AI_DX_GATE_BUDGET_MS=<n> (total gate wall-time cap; --budget-ms wins)\n      COMPAS_DIFF_BASE=<ref> (tried first for merge-base change_impact)\n      COMPAS_PLUGIN_REGISTRY=<url-or-path>\n\nExamples:\n  compas_mcp init --apply\n  compas_mcp init --apply --profile ai_first\n  compas_mcp init --registry https://github.com/AmirTlinov/compas-plugin-registry/releases/latest/download/registry.manifest.v1.json\n  compas_mcp validate ratchet\n  compas_mcp validate ratchet --write-baseline --baseline-reason \"Quarterly baseline refresh after policy change\" --baseline-owner team-lead\n  compas_mcp validate ratchet --baseline-dry-run\n  compas_mcp gate ci_fast --dry-run\n  compas_mcp boundary-test --rule no-unwrap --input src/lib.rs\n  compas_mcp gate-diff --a witness_ok.json --b .agents/mcp/compas/witness/gate_ci_fast.json\n  compas_mcp exec merge-truth-check -- --profile ci\n  compas_mcp plugins list -- --json\n  compas_mcp plugins packs -- --json\n  compas_mcp plugins info spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins experimental-plugin --allow-experimental\n  compas_mcp plugins update --admin-lane --plugins sunset-plugin --allow-sunset\n"
     );
 }
 
 pub(crate) fn print_plugins_help() {
     println!(
-        "Usage:\n  compas_mcp plugins [install|update|uninstall|list|packs|info|doctor] [--registry <url-or-path>] [--repo-root <path>] [--admin-lane] [--allow-experimental] [--allow-sunset] [-- <registry-installer-args...>]\n\nDefaults:\n  --registry: $COMPAS_PLUGIN_REGISTRY or {}\n  --repo-root: $AI_DX_REPO_ROOT or .\n\nNotes:\n  - Registry source must be signed JSON manifest.\n  - install/update/uninstall are blocked unless --admin-lane is provided.\n  - install/update enforce policy:\n      - tier=experimental requires --allow-experimental\n      - tier=sunset (or sunset marker metadata) requires --allow-sunset\n\nExamples:\n  compas_mcp plugins list -- --json\n  compas_mcp plugins packs -- --json\n  compas_mcp plugins info spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins experimental-plugin --allow-experimental\n  compas_mcp plugins update --admin-lane --plugins sunset-plugin --allow-sunset\n",
+        "Usage:\n  compas_mcp plugins [install|update|uninstall|list|packs|info|doctor|freeze|export] [--registry <url-or-path>] [--repo-root <path>] [--admin-lane] [--allow-experimental] [--allow-sunset] [--unfreeze] [--expect-registry-version <v>] [-- <registry-installer-args...>]\n\nDefaults:\n  --registry: $COMPAS_PLUGIN_REGISTRY or {}\n  --repo-root: $AI_DX_REPO_ROOT or .\n\nNotes:\n  - Registry source must be signed JSON manifest.\n  - install/update/uninstall/freeze are blocked unless --admin-lane is provided.\n  - freeze re-verifies lockfile hashes and pins the locked set; install/update/uninstall that change it require --unfreeze.\n  - doctor --fix (requires --admin-lane) restores missing/modified locked files from the verified registry cache after re-checking their hashes; unknown files are left for manual review.\n  - info --all prints the info payload for every manifest plugin as a JSON array.\n  - list --installed joins the manifest with the lockfile: each row gains `installed` (bool) and `drift` (ok|missing|modified from locked file hashes; null when not installed).\n  - info --diff-installed adds `installed` (up_to_date|outdated|not_installed) comparing lockfile hashes with the manifest's current plugin files.\n  - install/update --offline use only the local cache (manifest + signature from the last online run, extracted archive) and fail with \"not cached\" instead of fetching.\n  - uninstall --purge-cache also deletes this manifest's registry cache entry (manifest-v1/<sha>); opt-in because the cache is shared across repos.\n  - export --format requirements|json prints the install command that recreates the locked set (reads the lockfile only).\n  - --strict-manifest fails on manifest keys outside the schema (typos the default parse ignores); meant for registry authors.\n  - --expect-registry-version <v> aborts before any change when the verified manifest's registry_version differs.\n  - install/update enforce policy:\n      - tier=experimental requires --allow-experimental\n      - tier=sunset (or sunset marker metadata) requires --allow-sunset\n      - compat.min_compas_version above the running compas version blocks with plugins.incompatible_compas_version unless --force\n\nExamples:\n  compas_mcp plugins list -- --json\n  compas_mcp plugins packs -- --json\n  compas_mcp plugins info spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins spec-adr-gate\n  compas_mcp plugins install --admin-lane --plugins experimental-plugin --allow-experimental\n  compas_mcp plugins update --admin-lane --plugins sunset-plugin --allow-sunset\n  compas_mcp plugins doctor --fix --admin-lane\n  compas_mcp plugins freeze --admin-lane\n  compas_mcp plugins export --format requirements\n",
         DEFAULT_PLUGIN_REGISTRY_SOURCE
     );
 }
 
+pub(crate) use color::{color_enabled, take_no_color};
 pub(crate) use init_parse::parse_init_cli;
+pub(crate) use validate::{format_validate_summary, parse_validate_cli};
 
-fn default_repo_root(repo_root: Option<String>) -> String {
-    repo_root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ErrorFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+/// Strips the global `--error-format <human|json>` flag (anywhere before a `--` passthrough).
+pub(crate) fn take_error_format(args: &mut Vec<String>) -> Result<ErrorFormat, String> {
+    let mut format = ErrorFormat::Human;
+    let mut i = 0usize;
+    while i < args.len() && args[i] != "--" {
+        if args[i] != "--error-format" {
+            i += 1;
+            continue;
+        }
+        let v = args
+            .get(i + 1)
+            .ok_or_else(|| "--error-format requires a value".to_string())?;
+        format = match v.as_str() {
+            "human" => ErrorFormat::Human,
+            "json" => ErrorFormat::Json,
+            other => return Err(format!("unknown --error-format: {other} (use human|json)")),
+        };
+        args.drain(i..i + 2);
+    }
+    Ok(format)
+}
+
+/// How CLI JSON payloads are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum JsonStyle {
+    #[default]
+    Pretty,
+    /// Single line (`--compact-json`).
+    Compact,
+    /// Single line with sorted keys (`--canonical-json`), byte-stable for signing.
+    Canonical,
+}
+
+/// Strips the global `--compact-json` / `--canonical-json` flags (anywhere before a `--`
+/// passthrough); canonical wins when both are given since it is compact as well.
+pub(crate) fn take_json_style(args: &mut Vec<String>) -> JsonStyle {
+    let mut style = JsonStyle::Pretty;
+    let mut i = 0usize;
+    while i < args.len() && args[i] != "--" {
+        match args[i].as_str() {
+            "--compact-json" if style != JsonStyle::Canonical => style = JsonStyle::Compact,
+            "--canonical-json" => style = JsonStyle::Canonical,
+            "--compact-json" => {}
+            _ => {
+                i += 1;
+                continue;
+            }
+        }
+        args.remove(i);
+    }
+    style
+}
+
+/// Removes every occurrence of a boolean global flag before a `--` passthrough.
+fn take_global_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let mut found = false;
+    let mut i = 0usize;
+    while i < args.len() && args[i] != "--" {
+        if args[i] == flag {
+            found = true;
+            args.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    found
+}
+
+/// Strips the global `--exit-zero` flag: report failures in the output (`ok=false`) but exit 0.
+pub(crate) fn take_exit_zero(args: &mut Vec<String>) -> bool {
+    take_global_flag(args, "--exit-zero")
+}
+
+pub(crate) fn render_json<T: serde::Serialize + ?Sized>(
+    value: &T,
+    style: JsonStyle,
+) -> serde_json::Result<String> {
+    match style {
+        JsonStyle::Pretty => serde_json::to_string_pretty(value),
+        JsonStyle::Compact => serde_json::to_string(value),
+        JsonStyle::Canonical => {
+            serde_json::to_value(value).map(|v| ai_dx_mcp::canonical_json::to_canonical_string(&v))
+        }
+    }
+}
+
+/// `--digest-out`: writes just the agent digest (same JSON style as stdout) to `path`, used
+/// as given. Outputs without a digest (early errors) leave the file untouched.
+pub(crate) fn write_digest_out(
+    path: &str,
+    digest: Option<&AgentDigest>,
+    style: JsonStyle,
+) -> Result<(), String> {
+    let Some(digest) = digest else {
+        return Ok(());
+    };
+    let json = render_json(digest, style).map_err(|e| format!("failed to render digest: {e}"))?;
+    std::fs::write(path, json).map_err(|e| format!("failed to write --digest-out {path}: {e}"))
+}
+
+pub(crate) fn exit_with_error(
+    format: ErrorFormat,
+    code: &str,
+    message: impl std::fmt::Display,
+    exit_code: i32,
+) -> ! {
+    match format {
+        ErrorFormat::Human => eprintln!("compas: {message}"),
+        ErrorFormat::Json => {
+            let payload = serde_json::json!({
+                "ok": false,
+                "error": ApiError {
+                    code: code.to_string(),
+                    message: message.to_string(),
+                },
+            });
+            println!("{payload}");
+        }
+    }
+    std::process::exit(exit_code);
+}
+
+/// `--repo-root`, then `AI_DX_REPO_ROOT`, then `.`; resolved to the canonical path.
+fn default_repo_root(repo_root: Option<String>) -> Result<String, String> {
+    let raw = repo_root
         .or_else(|| std::env::var("AI_DX_REPO_ROOT").ok())
         .filter(|s| !s.trim().is_empty())
-        .unwrap_or_else(|| ".".to_string())
+        .unwrap_or_else(|| ".".to_string());
+    ai_dx_mcp::repo::resolve_repo_root(&raw)
+}
+
+/// `git config user.email` as seen from `repo_root` (repo config first, then global).
+fn git_user_email(repo_root: &str) -> Result<String, String> {
+    let output = std::process::Command::new("git")
+        .args(["-C", repo_root, "config", "user.email"])
+        .output()
+        .map_err(|e| format!("--baseline-owner-from-git: failed to run git: {e}"))?;
+    let email = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() || email.is_empty() {
+        return Err(format!(
+            "--baseline-owner-from-git: git user.email is not configured for {repo_root}; set it or pass --baseline-owner"
+        ));
+    }
+    Ok(email)
 }
 
 fn parse_validate_mode(s: &str) -> Option<ValidateMode> {
@@ -62,6 +210,8 @@ pub(crate) enum PluginsAction {
     Packs,
     Info,
     Doctor,
+    Freeze,
+    Export,
 }
 
 impl PluginsAction {
@@ -74,6 +224,8 @@ impl PluginsAction {
             "packs" => Some(Self::Packs),
             "info" => Some(Self::Info),
             "doctor" => Some(Self::Doctor),
+            "freeze" => Some(Self::Freeze),
+            "export" => Some(Self::Export),
             _ => None,
         }
     }
@@ -85,11 +237,13 @@ pub(crate) struct PluginsCli {
     pub(crate) registry_source: String,
     pub(crate) repo_root: String,
     pub(crate) installer_args: Vec<String>,
+    pub(crate) json_style: JsonStyle,
 }
 
 pub(crate) fn parse_plugins_cli(args: &[String]) -> Result<PluginsCli, String> {
     let action_raw = args.first().ok_or_else(|| {
-        "plugins requires subcommand: install|update|uninstall|list|packs|info|doctor".to_string()
+        "plugins requires subcommand: install|update|uninstall|list|packs|info|doctor|freeze|export"
+            .to_string()
     })?;
     let action = PluginsAction::from_str(action_raw)
         .ok_or_else(|| format!("unknown plugins command: {action_raw}"))?;
@@ -147,8 +301,9 @@ pub(crate) fn parse_plugins_cli(args: &[String]) -> Result<PluginsCli, String> {
     Ok(PluginsCli {
         action,
         registry_source,
-        repo_root: default_repo_root(repo_root),
+        repo_root: default_repo_root(repo_root)?,
         installer_args,
+        json_style: JsonStyle::Pretty,
     })
 }
 
@@ -163,130 +318,304 @@ pub(crate) async fn run_plugins_cli(parsed: PluginsCli) -> Result<i32, String> {
     plugins_impl::run_plugins_cli(&parsed).await
 }
 
-pub(crate) fn parse_validate_cli(
-    args: &[String],
-) -> Result<(ValidateMode, bool, String, Option<BaselineMaintenance>), String> {
-    let mut mode = ValidateMode::Ratchet;
-    let mut mode_set = false;
-    let mut write_baseline = false;
+#[derive(Debug, Clone)]
+pub(crate) struct GateCli {
+    pub(crate) kind: GateKind,
+    pub(crate) dry_run: bool,
+    pub(crate) write_witness: bool,
+    pub(crate) repo_root: String,
+    pub(crate) budget_ms: Option<u64>,
+    pub(crate) reports: bool,
+    pub(crate) explain_verdict: bool,
+    pub(crate) witness_dir: Option<String>,
+    pub(crate) fail_fast: Option<bool>,
+    pub(crate) digest_out: Option<String>,
+    pub(crate) capture_full: bool,
+    pub(crate) print_plan: bool,
+}
+
+pub(crate) fn parse_gate_cli(args: &[String]) -> Result<GateCli, String> {
+    let mut kind = GateKind::CiFast;
+    let mut kind_set = false;
+    let mut dry_run = false;
+    let mut write_witness = false;
+    let mut reports = false;
+    let mut explain_verdict = false;
+    let mut budget_ms: Option<u64> = None;
     let mut repo_root: Option<String> = None;
-    let mut baseline_reason: Option<String> = None;
-    let mut baseline_owner: Option<String> = None;
+    let mut witness_dir: Option<String> = None;
+    let mut fail_fast: Option<bool> = None;
+    let mut digest_out: Option<String> = None;
+    let mut capture_full = false;
+    let mut print_plan = false;
 
     let mut i = 0usize;
     while i < args.len() {
         let a = &args[i];
         match a.as_str() {
-            "--write-baseline" => {
-                write_baseline = true;
+            "--dry-run" => {
+                dry_run = true;
                 i += 1;
             }
-            "--repo-root" => {
+            "--write-witness" => {
+                write_witness = true;
+                i += 1;
+            }
+            "--reports" => {
+                reports = true;
+                i += 1;
+            }
+            "--explain-verdict" => {
+                explain_verdict = true;
+                i += 1;
+            }
+            "--capture-full" => {
+                capture_full = true;
+                i += 1;
+            }
+            "--print-plan" => {
+                print_plan = true;
+                i += 1;
+            }
+            "--no-fail-fast" => {
+                fail_fast = Some(false);
+                i += 1;
+            }
+            flag if flag.starts_with("--fail-fast=") => {
+                fail_fast = Some(match &flag["--fail-fast=".len()..] {
+                    "true" => true,
+                    "false" => false,
+                    other => {
+                        return Err(format!("--fail-fast expects true|false, got {other:?}"));
+                    }
+                });
+                i += 1;
+            }
+            "--budget-ms" => {
                 let v = args
                     .get(i + 1)
-                    .ok_or_else(|| "--repo-root requires a value".to_string())?;
-                if v.starts_with("--") {
-                    return Err("--repo-root requires a value".to_string());
-                }
-                repo_root = Some(v.clone());
+                    .ok_or_else(|| "--budget-ms requires a value".to_string())?;
+                budget_ms = Some(parse_budget_ms("--budget-ms", v)?);
                 i += 2;
             }
-            "--baseline-reason" => {
+            "--digest-out" => {
                 let v = args
                     .get(i + 1)
-                    .ok_or_else(|| "--baseline-reason requires a value".to_string())?;
-                if v.starts_with("--") {
-                    return Err("--baseline-reason requires a value".to_string());
-                }
-                baseline_reason = Some(v.clone());
+                    .filter(|v| !v.starts_with("--"))
+                    .ok_or_else(|| "--digest-out requires a path".to_string())?;
+                digest_out = Some(v.clone());
+                i += 2;
+            }
+            "--witness-dir" => {
+                let v = args
+                    .get(i + 1)
+                    .filter(|v| !v.starts_with("--"))
+                    .ok_or_else(|| "--witness-dir requires a value".to_string())?;
+                witness_dir = Some(v.clone());
                 i += 2;
             }
-            "--baseline-owner" => {
+            "--repo-root" => {
                 let v = args
                     .get(i + 1)
-                    .ok_or_else(|| "--baseline-owner requires a value".to_string())?;
+                    .ok_or_else(|| "--repo-root requires a value".to_string())?;
                 if v.starts_with("--") {
-                    return Err("--baseline-owner requires a value".to_string());
+                    return Err("--repo-root requires a value".to_string());
                 }
-                baseline_owner = Some(v.clone());
+                repo_root = Some(v.clone());
                 i += 2;
             }
-            _ if !a.starts_with("--") && !mode_set => {
-                mode =
-                    parse_validate_mode(a).ok_or_else(|| format!("unknown validate mode: {a}"))?;
-                mode_set = true;
+            _ if !a.starts_with("--") && !kind_set => {
+                kind = parse_gate_kind(a).ok_or_else(|| format!("unknown gate kind: {a}"))?;
+                kind_set = true;
                 i += 1;
             }
             _ => return Err(format!("unknown argument: {a}")),
         }
     }
 
-    let baseline_maintenance = match (baseline_reason, baseline_owner) {
-        (None, None) => None,
-        (Some(reason), Some(owner)) => Some(BaselineMaintenance { reason, owner }),
-        (Some(_), None) => {
-            return Err(
-                "--baseline-owner is required when --baseline-reason is provided".to_string(),
-            );
-        }
-        (None, Some(_)) => {
-            return Err(
-                "--baseline-reason is required when --baseline-owner is provided".to_string(),
-            );
-        }
+    if print_plan
+        && (dry_run
+            || write_witness
+            || reports
+            || explain_verdict
+            || capture_full
+            || budget_ms.is_some()
+            || witness_dir.is_some()
+            || fail_fast.is_some()
+            || digest_out.is_some())
+    {
+        return Err(
+            "--print-plan only prints the resolved tool sequence; drop the run flags".to_string(),
+        );
+    }
+
+    let write_witness = write_witness
+        || std::env::var("AI_DX_WRITE_WITNESS")
+            .ok()
+            .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+    let budget_ms = match budget_ms {
+        Some(v) => Some(v),
+        None => match std::env::var("AI_DX_GATE_BUDGET_MS") {
+            Ok(v) if !v.trim().is_empty() => {
+                Some(parse_budget_ms("AI_DX_GATE_BUDGET_MS", v.trim())?)
+            }
+            _ => None,
+        },
     };
+    Ok(GateCli {
+        kind,
+        dry_run,
+        write_witness,
+        repo_root: default_repo_root(repo_root)?,
+        budget_ms,
+        reports,
+        explain_verdict,
+        witness_dir,
+        fail_fast,
+        digest_out,
+        capture_full,
+        print_plan,
+    })
+}
 
-    Ok((
-        mode,
-        write_baseline,
-        default_repo_root(repo_root),
-        baseline_maintenance,
-    ))
+fn parse_budget_ms(source: &str, raw: &str) -> Result<u64, String> {
+    match raw.parse::<u64>() {
+        Ok(v) if v > 0 => Ok(v),
+        _ => Err(format!(
+            "{source} expects a positive integer (milliseconds), got {raw:?}"
+        )),
+    }
 }
 
-pub(crate) fn parse_gate_cli(args: &[String]) -> Result<(GateKind, bool, bool, String), String> {
-    let mut kind = GateKind::CiFast;
-    let mut kind_set = false;
-    let mut dry_run = false;
-    let mut write_witness = false;
-    let mut repo_root: Option<String> = None;
+pub(crate) fn parse_gate_diff_cli(args: &[String]) -> Result<(String, String), String> {
+    let mut a: Option<String> = None;
+    let mut b: Option<String> = None;
 
     let mut i = 0usize;
     while i < args.len() {
-        let a = &args[i];
-        match a.as_str() {
-            "--dry-run" => {
-                dry_run = true;
-                i += 1;
+        let flag = args[i].as_str();
+        match flag {
+            "--a" | "--b" => {
+                let v = args
+                    .get(i + 1)
+                    .filter(|v| !v.starts_with("--"))
+                    .ok_or_else(|| format!("{flag} requires a witness path"))?;
+                if flag == "--a" {
+                    a = Some(v.clone());
+                } else {
+                    b = Some(v.clone());
+                }
+                i += 2;
             }
-            "--write-witness" => {
-                write_witness = true;
+            other => return Err(format!("unknown argument: {other}")),
+        }
+    }
+
+    match (a, b) {
+        (Some(a), Some(b)) => Ok((a, b)),
+        _ => Err("gate-diff requires --a <witness> and --b <witness>".to_string()),
+    }
+}
+
+pub(crate) fn parse_canonicalize_cli(args: &[String]) -> Result<String, String> {
+    match args {
+        [flag, path] if flag == "--in" && !path.starts_with("--") => Ok(path.clone()),
+        _ => Err("canonicalize requires --in <file.json>".to_string()),
+    }
+}
+
+pub(crate) fn parse_exceptions_cli(args: &[String]) -> Result<String, String> {
+    let mut repo_root: Option<String> = None;
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--repo-root" => {
+                let v = args
+                    .get(i + 1)
+                    .filter(|v| !v.starts_with("--"))
+                    .ok_or_else(|| "--repo-root requires a value".to_string())?;
+                repo_root = Some(v.clone());
+                i += 2;
+            }
+            other => return Err(format!("unknown argument: {other}")),
+        }
+    }
+    default_repo_root(repo_root)
+}
+
+/// `tools describe <tool_id> [--resolved-env] [--repo-root <path>]`.
+pub(crate) fn parse_tools_cli(args: &[String]) -> Result<(String, bool, String), String> {
+    match args.first().map(String::as_str) {
+        Some("describe") => {}
+        Some(other) => {
+            return Err(format!(
+                "unknown tools command: {other} (expected describe)"
+            ));
+        }
+        None => return Err("tools requires subcommand: describe".to_string()),
+    }
+    let mut tool_id: Option<String> = None;
+    let mut resolved_env = false;
+    let mut repo_root: Option<String> = None;
+    let mut i = 1usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--resolved-env" => {
+                resolved_env = true;
                 i += 1;
             }
             "--repo-root" => {
                 let v = args
                     .get(i + 1)
+                    .filter(|v| !v.starts_with("--"))
                     .ok_or_else(|| "--repo-root requires a value".to_string())?;
-                if v.starts_with("--") {
-                    return Err("--repo-root requires a value".to_string());
-                }
                 repo_root = Some(v.clone());
                 i += 2;
             }
-            _ if !a.starts_with("--") && !kind_set => {
-                kind = parse_gate_kind(a).ok_or_else(|| format!("unknown gate kind: {a}"))?;
-                kind_set = true;
+            other if other.starts_with("--") => {
+                return Err(format!("unknown argument: {other}"));
+            }
+            other if tool_id.is_none() => {
+                tool_id = Some(other.to_string());
                 i += 1;
             }
-            _ => return Err(format!("unknown argument: {a}")),
+            other => return Err(format!("unexpected argument: {other}")),
         }
     }
+    let tool_id = tool_id.ok_or_else(|| "tools describe requires <tool_id>".to_string())?;
+    Ok((tool_id, resolved_env, default_repo_root(repo_root)?))
+}
 
-    let write_witness = write_witness
-        || std::env::var("AI_DX_WRITE_WITNESS")
-            .ok()
-            .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
-    Ok((kind, dry_run, write_witness, default_repo_root(repo_root)))
+pub(crate) fn parse_boundary_test_cli(args: &[String]) -> Result<(String, String, String), String> {
+    let mut rule: Option<String> = None;
+    let mut input: Option<String> = None;
+    let mut repo_root: Option<String> = None;
+
+    let mut i = 0usize;
+    while i < args.len() {
+        let flag = args[i].as_str();
+        match flag {
+            "--rule" | "--input" | "--repo-root" => {
+                let v = args
+                    .get(i + 1)
+                    .filter(|v| !v.starts_with("--"))
+                    .cloned()
+                    .ok_or_else(|| format!("{flag} requires a value"))?;
+                match flag {
+                    "--rule" => rule = Some(v),
+                    "--input" => input = Some(v),
+                    _ => repo_root = Some(v),
+                }
+                i += 2;
+            }
+            other => return Err(format!("unknown argument: {other}")),
+        }
+    }
+
+    match (rule, input) {
+        (Some(rule), Some(input)) => Ok((rule, input, default_repo_root(repo_root)?)),
+        _ => Err("boundary-test requires --rule <id> and --input <file>".to_string()),
+    }
 }
 
 pub(crate) fn parse_exec_cli(
@@ -339,53 +668,8 @@ pub(crate) fn parse_exec_cli(
         }
     }
 
-    Ok((tool_id, extra_args, dry_run, default_repo_root(repo_root)))
+    Ok((tool_id, extra_args, dry_run, default_repo_root(repo_root)?))
 }
 
 #[cfg(test)]
-mod tests {
-    use super::{GateKind, parse_exec_cli, parse_gate_cli};
-
-    #[test]
-    fn parse_exec_cli_parses_tool_flags_and_passthrough_args() {
-        let args = vec![
-            "merge-truth-check".to_string(),
-            "--repo-root".to_string(),
-            "/tmp/repo".to_string(),
-            "--dry-run".to_string(),
-            "--".to_string(),
-            "--profile".to_string(),
-            "ci".to_string(),
-        ];
-        let (tool_id, extra_args, dry_run, repo_root) =
-            parse_exec_cli(&args).expect("exec args should parse");
-        assert_eq!(tool_id, "merge-truth-check");
-        assert_eq!(extra_args, vec!["--profile".to_string(), "ci".to_string()]);
-        assert!(dry_run);
-        assert_eq!(repo_root, "/tmp/repo");
-    }
-
-    #[test]
-    fn parse_exec_cli_requires_tool_id() {
-        let err = parse_exec_cli(&[]).expect_err("tool id is required");
-        assert!(err.contains("exec requires <tool_id>"));
-    }
-
-    #[test]
-    fn parse_exec_cli_requires_double_dash_before_tool_args() {
-        let args = vec!["merge-truth-check".to_string(), "ci".to_string()];
-        let err = parse_exec_cli(&args).expect_err("positional arg after tool id must fail");
-        assert!(err.contains("use `--` before tool args"));
-    }
-
-    #[test]
-    fn parse_gate_cli_honors_canonical_gate_ids() {
-        let args = vec!["ci_fast".to_string(), "--dry-run".to_string()];
-        let (kind, dry_run, write_witness, repo_root) =
-            parse_gate_cli(&args).expect("gate args should parse");
-        assert_eq!(kind, GateKind::CiFast);
-        assert!(dry_run);
-        assert!(!write_witness);
-        assert_eq!(repo_root, ".");
-    }
-}
+mod tests;
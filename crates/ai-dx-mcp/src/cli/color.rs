@@ -0,0 +1,64 @@
+use std::io::IsTerminal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Color {
+    Green,
+    Yellow,
+    Red,
+}
+
+/// Strips the global `--no-color` flag (anywhere before a `--` passthrough).
+pub(crate) fn take_no_color(args: &mut Vec<String>) -> bool {
+    super::take_global_flag(args, "--no-color")
+}
+
+/// The one place human output decides on ANSI color: never with `--no-color` or a non-empty
+/// `NO_COLOR` (https://no-color.org), otherwise only when stdout is a terminal.
+pub(crate) fn color_enabled(no_color_flag: bool) -> bool {
+    decide(
+        no_color_flag,
+        std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+fn decide(no_color_flag: bool, no_color_env: bool, stdout_is_tty: bool) -> bool {
+    !no_color_flag && !no_color_env && stdout_is_tty
+}
+
+pub(crate) fn paint(text: &str, color: Color, enabled: bool) -> String {
+    if !enabled {
+        return text.to_string();
+    }
+    let code = match color {
+        Color::Green => "32",
+        Color::Yellow => "33",
+        Color::Red => "31",
+    };
+    format!("\x1b[{code}m{text}\x1b[0m")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_needs_a_tty_and_no_opt_out() {
+        assert!(decide(false, false, true));
+        assert!(!decide(true, false, true));
+        assert!(!decide(false, true, true));
+        assert!(!decide(false, false, false));
+    }
+
+    #[test]
+    fn take_no_color_stops_at_passthrough() {
+        let mut args: Vec<String> = ["compas", "--no-color", "validate", "--", "--no-color"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(take_no_color(&mut args));
+        assert_eq!(args, vec!["compas", "validate", "--", "--no-color"]);
+        assert_eq!(paint("x", Color::Red, false), "x");
+        assert_eq!(paint("x", Color::Red, true), "\x1b[31mx\x1b[0m");
+    }
+}
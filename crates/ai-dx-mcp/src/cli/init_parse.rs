@@ -7,7 +7,9 @@ pub(crate) fn parse_init_cli(args: &[String]) -> Result<(InitRequest, String), S
     let mut profile: Option<String> = None;
     let mut registry_source: Option<String> = None;
     let mut packs: Vec<String> = vec![];
+    let mut packs_from: Option<String> = None;
     let mut repo_root: Option<String> = None;
+    let mut summary = false;
 
     let mut i = 0usize;
     while i < args.len() {
@@ -17,6 +19,10 @@ pub(crate) fn parse_init_cli(args: &[String]) -> Result<(InitRequest, String), S
                 apply = true;
                 i += 1;
             }
+            "--summary" => {
+                summary = true;
+                i += 1;
+            }
             "--profile" => {
                 let v = args
                     .get(i + 1)
@@ -51,6 +57,19 @@ pub(crate) fn parse_init_cli(args: &[String]) -> Result<(InitRequest, String), S
                 }
                 i += 2;
             }
+            "--packs-from" => {
+                let v = args
+                    .get(i + 1)
+                    .ok_or_else(|| "--packs-from requires a packs.lock path".to_string())?;
+                if v.starts_with("--") {
+                    return Err("--packs-from requires a packs.lock path".to_string());
+                }
+                // Relative to the invocation directory, like any CLI path argument.
+                let path = std::path::absolute(v)
+                    .map_err(|e| format!("failed to resolve --packs-from {v:?}: {e}"))?;
+                packs_from = Some(path.to_string_lossy().to_string());
+                i += 2;
+            }
             "--repo-root" => {
                 let v = args
                     .get(i + 1)
@@ -65,7 +84,11 @@ pub(crate) fn parse_init_cli(args: &[String]) -> Result<(InitRequest, String), S
         }
     }
 
-    let repo_root = default_repo_root(repo_root);
+    if packs_from.is_some() && !packs.is_empty() {
+        return Err("--packs-from cannot be combined with --packs".to_string());
+    }
+
+    let repo_root = default_repo_root(repo_root)?;
     Ok((
         InitRequest {
             repo_root: Some(repo_root.clone()),
@@ -74,6 +97,8 @@ pub(crate) fn parse_init_cli(args: &[String]) -> Result<(InitRequest, String), S
             registry_source,
             packs: if packs.is_empty() { None } else { Some(packs) },
             external_packs: None,
+            packs_from,
+            summary: Some(summary),
         },
         repo_root,
     ))
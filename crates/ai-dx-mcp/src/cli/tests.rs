@@ -0,0 +1,299 @@
+use super::{
+    ErrorFormat, GateKind, parse_exec_cli, parse_gate_cli, parse_validate_cli, take_error_format,
+};
+
+#[test]
+fn parse_exec_cli_parses_tool_flags_and_passthrough_args() {
+    let args = vec![
+        "merge-truth-check".to_string(),
+        "--repo-root".to_string(),
+        "/tmp/repo".to_string(),
+        "--dry-run".to_string(),
+        "--".to_string(),
+        "--profile".to_string(),
+        "ci".to_string(),
+    ];
+    let (tool_id, extra_args, dry_run, repo_root) =
+        parse_exec_cli(&args).expect("exec args should parse");
+    assert_eq!(tool_id, "merge-truth-check");
+    assert_eq!(extra_args, vec!["--profile".to_string(), "ci".to_string()]);
+    assert!(dry_run);
+    assert_eq!(repo_root, "/tmp/repo");
+}
+
+#[test]
+fn parse_exec_cli_requires_tool_id() {
+    let err = parse_exec_cli(&[]).expect_err("tool id is required");
+    assert!(err.contains("exec requires <tool_id>"));
+}
+
+#[test]
+fn parse_exec_cli_requires_double_dash_before_tool_args() {
+    let args = vec!["merge-truth-check".to_string(), "ci".to_string()];
+    let err = parse_exec_cli(&args).expect_err("positional arg after tool id must fail");
+    assert!(err.contains("use `--` before tool args"));
+}
+
+#[test]
+fn parse_gate_cli_honors_canonical_gate_ids() {
+    let args = vec!["ci_fast".to_string(), "--dry-run".to_string()];
+    let parsed = parse_gate_cli(&args).expect("gate args should parse");
+    assert_eq!(parsed.kind, GateKind::CiFast);
+    assert!(parsed.dry_run);
+    assert!(!parsed.write_witness);
+    let cwd = std::fs::canonicalize(".").expect("canonicalize cwd");
+    assert_eq!(parsed.repo_root, cwd.to_string_lossy());
+    assert!(!parsed.reports);
+    assert_eq!(parsed.witness_dir, None);
+    assert_eq!(parsed.fail_fast, None);
+    assert!(!parsed.capture_full);
+    assert!(!parsed.print_plan);
+
+    let args = vec!["--print-plan".to_string(), "--dry-run".to_string()];
+    let err = parse_gate_cli(&args).expect_err("run flags conflict with --print-plan");
+    assert!(err.contains("--print-plan"));
+}
+
+#[test]
+fn parse_gate_cli_reads_fail_fast_overrides() {
+    let args = vec!["ci".to_string(), "--no-fail-fast".to_string()];
+    let parsed = parse_gate_cli(&args).expect("gate args should parse");
+    assert_eq!(parsed.fail_fast, Some(false));
+
+    let args = vec!["--fail-fast=true".to_string()];
+    let parsed = parse_gate_cli(&args).expect("gate args should parse");
+    assert_eq!(parsed.fail_fast, Some(true));
+
+    let err = parse_gate_cli(&["--fail-fast=maybe".to_string()]).expect_err("bad value");
+    assert!(err.contains("--fail-fast expects true|false"));
+}
+
+#[test]
+fn parse_gate_cli_reads_budget_ms() {
+    let args = vec![
+        "ci".to_string(),
+        "--budget-ms".to_string(),
+        "1500".to_string(),
+    ];
+    let parsed = parse_gate_cli(&args).expect("gate args should parse");
+    assert_eq!(parsed.budget_ms, Some(1500));
+
+    let bad = vec!["--budget-ms".to_string(), "0".to_string()];
+    let err = parse_gate_cli(&bad).expect_err("zero budget must fail");
+    assert!(err.contains("--budget-ms expects"));
+}
+
+#[test]
+fn parse_gate_cli_reads_witness_dir() {
+    let args = vec![
+        "ci".to_string(),
+        "--witness-dir".to_string(),
+        "artifacts/witness".to_string(),
+    ];
+    let parsed = parse_gate_cli(&args).expect("gate args should parse");
+    assert_eq!(parsed.witness_dir.as_deref(), Some("artifacts/witness"));
+
+    let missing = vec!["--witness-dir".to_string(), "--dry-run".to_string()];
+    let err = parse_gate_cli(&missing).expect_err("flag value is required");
+    assert!(err.contains("--witness-dir requires a value"));
+}
+
+#[test]
+fn parse_validate_cli_reads_max_violations() {
+    let args = vec![
+        "warn".to_string(),
+        "--max-violations".to_string(),
+        "25".to_string(),
+    ];
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert_eq!(parsed.max_violations, Some(25));
+    assert!(!parsed.explain);
+
+    let bad = vec!["--max-violations".to_string(), "lots".to_string()];
+    let err = parse_validate_cli(&bad).expect_err("non-numeric limit must fail");
+    assert!(err.contains("--max-violations expects"));
+}
+
+#[test]
+fn parse_validate_cli_rejects_dry_run_with_write_baseline() {
+    let args = vec!["--baseline-dry-run".to_string()];
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert!(parsed.baseline_dry_run);
+
+    let both = vec![
+        "--baseline-dry-run".to_string(),
+        "--write-baseline".to_string(),
+    ];
+    let err = parse_validate_cli(&both).expect_err("conflicting flags must fail");
+    assert!(err.contains("--baseline-dry-run cannot be combined"));
+}
+
+#[test]
+fn parse_validate_cli_rejects_migrate_only_with_other_baseline_flags() {
+    let args = vec!["--baseline-migrate-only".to_string()];
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert!(parsed.baseline_migrate_only);
+
+    let both = vec![
+        "--baseline-migrate-only".to_string(),
+        "--write-baseline".to_string(),
+    ];
+    let err = parse_validate_cli(&both).expect_err("conflicting flags must fail");
+    assert!(err.contains("--baseline-migrate-only only migrates"));
+
+    for extra in [
+        vec!["--explain"],
+        vec!["--max-violations", "5"],
+        vec!["--include-suppressed"],
+        vec!["--timings"],
+        vec![
+            "--baseline-reason",
+            "refresh",
+            "--baseline-owner",
+            "team-lead",
+        ],
+    ] {
+        let mut args = vec!["--baseline-migrate-only".to_string()];
+        args.extend(extra.iter().map(|s| s.to_string()));
+        let err = parse_validate_cli(&args).expect_err("ignored flag must fail");
+        assert!(
+            err.contains("--baseline-migrate-only only migrates"),
+            "{extra:?}: {err}"
+        );
+    }
+}
+
+#[test]
+fn parse_validate_cli_takes_baseline_owner_from_git_identity() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo = dir.path().to_string_lossy().to_string();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-C", &repo])
+            .args(args)
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?}");
+    };
+    git(&["init", "-q"]);
+    git(&["config", "user.email", "owner@example.com"]);
+
+    let args: Vec<String> = [
+        "--write-baseline",
+        "--baseline-reason",
+        "Quarterly baseline refresh after policy change",
+        "--baseline-owner-from-git",
+        "--repo-root",
+        &repo,
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert_eq!(
+        parsed.baseline_maintenance.map(|m| m.owner).as_deref(),
+        Some("owner@example.com")
+    );
+
+    let mut with_owner = args.clone();
+    with_owner.extend(["--baseline-owner".to_string(), "team-lead".to_string()]);
+    let err = parse_validate_cli(&with_owner).expect_err("owner sources conflict");
+    assert!(err.contains("cannot be combined"), "{err}");
+}
+
+#[test]
+fn parse_validate_cli_accepts_quiet_alias_for_summary_only() {
+    let args = vec!["--quiet".to_string()];
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert!(parsed.summary_only);
+
+    let both = vec![
+        "--summary-only".to_string(),
+        "--schema-version".to_string(),
+        "2".to_string(),
+    ];
+    let err = parse_validate_cli(&both).expect_err("conflicting flags must fail");
+    assert!(err.contains("--summary-only"));
+}
+
+#[test]
+fn parse_validate_cli_reads_include_suppressed() {
+    let parsed = parse_validate_cli(&[]).expect("validate args should parse");
+    assert!(!parsed.include_suppressed);
+    let args = vec!["warn".to_string(), "--include-suppressed".to_string()];
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert!(parsed.include_suppressed);
+}
+
+#[test]
+fn parse_validate_cli_reads_errors_only_and_rejects_insight_flags() {
+    let args = vec!["ratchet".to_string(), "--errors-only".to_string()];
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert!(parsed.errors_only);
+    for extra in [
+        "--write-baseline",
+        "--baseline-dry-run",
+        "--include-suppressed",
+    ] {
+        let args = vec!["--errors-only".to_string(), extra.to_string()];
+        let err = parse_validate_cli(&args).expect_err("conflicting flags must fail");
+        assert!(err.contains("--errors-only"), "{extra}: {err}");
+    }
+}
+
+#[test]
+fn parse_validate_cli_reads_check_selection() {
+    let parsed = parse_validate_cli(&[]).expect("validate args should parse");
+    assert!(parsed.selection.is_full());
+    let args: Vec<String> = [
+        "--only",
+        "boundary, loc",
+        "--skip",
+        "loc",
+        "--skip-contract",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let parsed = parse_validate_cli(&args).expect("validate args should parse");
+    assert_eq!(parsed.selection.only, vec!["boundary", "loc"]);
+    assert_eq!(parsed.selection.skip, vec!["loc"]);
+    assert!(parsed.selection.skip_contract);
+
+    let missing = vec!["--only".to_string(), "--timings".to_string()];
+    let err = parse_validate_cli(&missing).expect_err("--only needs a value");
+    assert!(err.contains("--only requires"));
+
+    let with_baseline = vec![
+        "--only".to_string(),
+        "loc".to_string(),
+        "--write-baseline".to_string(),
+    ];
+    let err = parse_validate_cli(&with_baseline).expect_err("partial baseline must fail");
+    assert!(err.contains("partial validate"));
+}
+
+#[test]
+fn take_error_format_strips_flag_before_passthrough() {
+    let mut args: Vec<String> = [
+        "compas",
+        "exec",
+        "--error-format",
+        "json",
+        "tool",
+        "--",
+        "--error-format",
+        "x",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    assert_eq!(take_error_format(&mut args), Ok(ErrorFormat::Json));
+    assert_eq!(
+        args,
+        vec!["compas", "exec", "tool", "--", "--error-format", "x"]
+    );
+
+    let mut bad = vec!["--error-format".to_string(), "xml".to_string()];
+    assert!(take_error_format(&mut bad).is_err());
+}
@@ -0,0 +1,357 @@
+use ai_dx_mcp::api::{
+    BaselineMaintenance, CheckSelection, DecisionStatus, ValidateMode, ValidateOutput,
+};
+use ai_dx_mcp::response::SUPPORTED_VALIDATE_SCHEMA_VERSIONS;
+
+use super::{color, default_repo_root, git_user_email, parse_validate_mode};
+
+#[derive(Debug, Clone)]
+pub(crate) struct ValidateCli {
+    pub(crate) mode: ValidateMode,
+    pub(crate) write_baseline: bool,
+    pub(crate) repo_root: String,
+    pub(crate) baseline_maintenance: Option<BaselineMaintenance>,
+    pub(crate) max_violations: Option<usize>,
+    pub(crate) timings: bool,
+    pub(crate) explain: bool,
+    pub(crate) baseline_dry_run: bool,
+    pub(crate) baseline_migrate_only: bool,
+    pub(crate) schema_version: Option<String>,
+    pub(crate) summary_only: bool,
+    pub(crate) include_suppressed: bool,
+    pub(crate) selection: CheckSelection,
+    pub(crate) digest_out: Option<String>,
+    pub(crate) errors_only: bool,
+}
+
+/// Comma-separated check kinds for `--only`/`--skip`; blanks are dropped.
+fn parse_check_kinds(flag: &str, raw: Option<&String>) -> Result<Vec<String>, String> {
+    let raw = raw
+        .filter(|v| !v.starts_with("--"))
+        .ok_or_else(|| format!("{flag} requires a comma-separated list of check kinds"))?;
+    let kinds: Vec<String> = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(str::to_string)
+        .collect();
+    if kinds.is_empty() {
+        return Err(format!("{flag} requires at least one check kind"));
+    }
+    Ok(kinds)
+}
+
+pub(crate) fn parse_validate_cli(args: &[String]) -> Result<ValidateCli, String> {
+    let mut mode = ValidateMode::Ratchet;
+    let mut mode_set = false;
+    let mut write_baseline = false;
+    let mut repo_root: Option<String> = None;
+    let mut baseline_reason: Option<String> = None;
+    let mut baseline_owner: Option<String> = None;
+    let mut baseline_owner_from_git = false;
+    let mut max_violations: Option<usize> = None;
+    let mut timings = false;
+    let mut explain = false;
+    let mut baseline_dry_run = false;
+    let mut baseline_migrate_only = false;
+    let mut schema_version: Option<String> = None;
+    let mut summary_only = false;
+    let mut include_suppressed = false;
+    let mut selection = CheckSelection::default();
+    let mut digest_out: Option<String> = None;
+    let mut errors_only = false;
+
+    let mut i = 0usize;
+    while i < args.len() {
+        let a = &args[i];
+        match a.as_str() {
+            "--write-baseline" => {
+                write_baseline = true;
+                i += 1;
+            }
+            "--max-violations" => {
+                let v = args
+                    .get(i + 1)
+                    .ok_or_else(|| "--max-violations requires a value".to_string())?;
+                let n = v.parse::<usize>().map_err(|_| {
+                    format!("--max-violations expects a non-negative integer, got {v:?}")
+                })?;
+                max_violations = Some(n);
+                i += 2;
+            }
+            "--timings" => {
+                timings = true;
+                i += 1;
+            }
+            "--explain" => {
+                explain = true;
+                i += 1;
+            }
+            "--baseline-dry-run" => {
+                baseline_dry_run = true;
+                i += 1;
+            }
+            "--baseline-migrate-only" => {
+                baseline_migrate_only = true;
+                i += 1;
+            }
+            "--summary-only" | "--quiet" => {
+                summary_only = true;
+                i += 1;
+            }
+            "--include-suppressed" => {
+                include_suppressed = true;
+                i += 1;
+            }
+            "--errors-only" => {
+                errors_only = true;
+                i += 1;
+            }
+            "--only" => {
+                selection
+                    .only
+                    .extend(parse_check_kinds("--only", args.get(i + 1))?);
+                i += 2;
+            }
+            "--skip" => {
+                selection
+                    .skip
+                    .extend(parse_check_kinds("--skip", args.get(i + 1))?);
+                i += 2;
+            }
+            "--digest-out" => {
+                let v = args
+                    .get(i + 1)
+                    .filter(|v| !v.starts_with("--"))
+                    .ok_or_else(|| "--digest-out requires a path".to_string())?;
+                digest_out = Some(v.clone());
+                i += 2;
+            }
+            "--skip-contract" => {
+                selection.skip_contract = true;
+                i += 1;
+            }
+            "--schema-version" => {
+                let v = args
+                    .get(i + 1)
+                    .ok_or_else(|| "--schema-version requires a value".to_string())?;
+                if !SUPPORTED_VALIDATE_SCHEMA_VERSIONS.contains(&v.as_str()) {
+                    return Err(format!(
+                        "--schema-version {v:?} is not supported (supported: {})",
+                        SUPPORTED_VALIDATE_SCHEMA_VERSIONS.join(", ")
+                    ));
+                }
+                schema_version = Some(v.clone());
+                i += 2;
+            }
+            "--repo-root" => {
+                let v = args
+                    .get(i + 1)
+                    .ok_or_else(|| "--repo-root requires a value".to_string())?;
+                if v.starts_with("--") {
+                    return Err("--repo-root requires a value".to_string());
+                }
+                repo_root = Some(v.clone());
+                i += 2;
+            }
+            "--baseline-reason" => {
+                let v = args
+                    .get(i + 1)
+                    .ok_or_else(|| "--baseline-reason requires a value".to_string())?;
+                if v.starts_with("--") {
+                    return Err("--baseline-reason requires a value".to_string());
+                }
+                baseline_reason = Some(v.clone());
+                i += 2;
+            }
+            "--baseline-owner" => {
+                let v = args
+                    .get(i + 1)
+                    .ok_or_else(|| "--baseline-owner requires a value".to_string())?;
+                if v.starts_with("--") {
+                    return Err("--baseline-owner requires a value".to_string());
+                }
+                baseline_owner = Some(v.clone());
+                i += 2;
+            }
+            "--baseline-owner-from-git" => {
+                baseline_owner_from_git = true;
+                i += 1;
+            }
+            _ if !a.starts_with("--") && !mode_set => {
+                mode =
+                    parse_validate_mode(a).ok_or_else(|| format!("unknown validate mode: {a}"))?;
+                mode_set = true;
+                i += 1;
+            }
+            _ => return Err(format!("unknown argument: {a}")),
+        }
+    }
+
+    let repo_root = default_repo_root(repo_root)?;
+    if baseline_owner_from_git {
+        if baseline_owner.is_some() {
+            return Err(
+                "--baseline-owner-from-git cannot be combined with --baseline-owner".to_string(),
+            );
+        }
+        if baseline_reason.is_none() {
+            return Err("--baseline-owner-from-git requires --baseline-reason".to_string());
+        }
+        baseline_owner = Some(git_user_email(&repo_root)?);
+    }
+    let baseline_maintenance = match (baseline_reason, baseline_owner) {
+        (None, None) => None,
+        (Some(reason), Some(owner)) => Some(BaselineMaintenance { reason, owner }),
+        (Some(_), None) => {
+            return Err(
+                "--baseline-owner (or --baseline-owner-from-git) is required when --baseline-reason is provided".to_string(),
+            );
+        }
+        (None, Some(_)) => {
+            return Err(
+                "--baseline-reason is required when --baseline-owner is provided".to_string(),
+            );
+        }
+    };
+
+    if baseline_dry_run && write_baseline {
+        return Err("--baseline-dry-run cannot be combined with --write-baseline".to_string());
+    }
+    if baseline_migrate_only
+        && (write_baseline
+            || baseline_dry_run
+            || digest_out.is_some()
+            || mode_set
+            || summary_only
+            || errors_only
+            || schema_version.is_some()
+            || !selection.is_full()
+            || explain
+            || max_violations.is_some()
+            || include_suppressed
+            || timings
+            || baseline_maintenance.is_some())
+    {
+        return Err(
+            "--baseline-migrate-only only migrates prior baselines; drop the validate mode and other validate flags"
+                .to_string(),
+        );
+    }
+    if !selection.is_full() && (write_baseline || baseline_dry_run) {
+        return Err(
+            "--only/--skip/--skip-contract run a partial validate; they cannot be combined with --write-baseline or --baseline-dry-run"
+                .to_string(),
+        );
+    }
+    if errors_only && (write_baseline || baseline_dry_run) {
+        return Err(
+            "--errors-only computes pass/fail only; it cannot be combined with --write-baseline or --baseline-dry-run"
+                .to_string(),
+        );
+    }
+    if errors_only && (digest_out.is_some() || include_suppressed) {
+        return Err(
+            "--errors-only omits findings_v2 and agent_digest; drop --digest-out/--include-suppressed"
+                .to_string(),
+        );
+    }
+    if summary_only && schema_version.is_some() {
+        return Err("--summary-only prints no JSON; drop --schema-version".to_string());
+    }
+
+    let timings = timings
+        || std::env::var("COMPAS_TIMINGS")
+            .ok()
+            .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+    Ok(ValidateCli {
+        mode,
+        write_baseline,
+        repo_root,
+        baseline_maintenance,
+        max_violations,
+        timings,
+        explain,
+        baseline_dry_run,
+        baseline_migrate_only,
+        schema_version,
+        summary_only,
+        include_suppressed,
+        selection,
+        digest_out,
+        errors_only,
+    })
+}
+
+fn push_section(out: &mut String, title: &str, items: &[String], numbered: bool) {
+    if items.is_empty() {
+        return;
+    }
+    out.push_str(title);
+    out.push_str(":\n");
+    for (idx, item) in items.iter().enumerate() {
+        if numbered {
+            out.push_str(&format!("  {}. {item}\n", idx + 1));
+        } else {
+            out.push_str(&format!("  - {item}\n"));
+        }
+    }
+}
+
+/// Compact human rendering of a validate run: decision line plus the agent digest. `colored`
+/// comes from [`color_enabled`] and only tints the status word.
+pub(crate) fn format_validate_summary(out: &ValidateOutput, colored: bool) -> String {
+    let mut text = String::new();
+    match out.verdict.as_ref().map(|v| &v.decision) {
+        Some(decision) => {
+            let status = match decision.status {
+                DecisionStatus::Pass => color::paint("pass", color::Color::Green, colored),
+                DecisionStatus::Retryable => {
+                    color::paint("retryable", color::Color::Yellow, colored)
+                }
+                DecisionStatus::Blocked => color::paint("blocked", color::Color::Red, colored),
+            };
+            text.push_str(&format!(
+                "status: {status} (blocking={}, observations={})\n",
+                decision.blocking_count, decision.observation_count
+            ));
+        }
+        None => text.push_str(&format!(
+            "status: {}\n",
+            if out.ok {
+                color::paint("ok", color::Color::Green, colored)
+            } else {
+                color::paint("failed", color::Color::Red, colored)
+            }
+        )),
+    }
+    if let Some(err) = &out.error {
+        text.push_str(&format!("error: {}: {}\n", err.code, err.message));
+    }
+    if let Some(partial) = &out.partial_run {
+        text.push_str(&format!(
+            "partial run: skipped checks [{}]{}\n",
+            partial.skipped_checks.join(", "),
+            if partial.selection.skip_contract {
+                ", contract checks skipped"
+            } else {
+                ""
+            }
+        ));
+    }
+    let check_errors: Vec<String> = out
+        .check_errors
+        .iter()
+        .map(|e| format!("{} (id={}): {}", e.check, e.id, e.message))
+        .collect();
+    push_section(&mut text, "check errors", &check_errors, false);
+    if let Some(digest) = &out.agent_digest {
+        push_section(&mut text, "top blockers", &digest.top_blockers, false);
+        push_section(&mut text, "root causes", &digest.root_causes, false);
+        push_section(&mut text, "fix steps", &digest.minimal_fix_steps, true);
+        if digest.suppressed_count > 0 {
+            text.push_str(&format!("suppressed: {}\n", digest.suppressed_count));
+        }
+    }
+    text
+}
@@ -1,12 +1,14 @@
-use super::super::{PluginsAction, PluginsCli};
-use crate::cli::registry_manifest::{ManifestResolved, RegistryManifestV1, RegistryPluginV1};
+use super::super::{JsonStyle, PluginsAction, PluginsCli, render_json};
+use ai_dx_mcp::registry_manifest::{
+    ManifestResolved, ManifestSourceBytes, RegistryManifestV1, RegistryPluginV1,
+};
 use fs4::fs_std::FileExt;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::{
     collections::{BTreeMap, BTreeSet},
     fs,
-    io::Read,
+    io::{Read, Write},
     path::{Component, Path, PathBuf},
     time::SystemTime,
     time::UNIX_EPOCH,
@@ -15,8 +17,11 @@ use walkdir::WalkDir;
 
 const PLUGINS_LOCKFILE_REL_PATH: &str = ".agents/mcp/compas/plugins.lock.json";
 const PLUGINS_LOCK_REL_PATH: &str = ".agents/mcp/compas/plugins.lock";
+const PLUGINS_LOCK_FROZEN_REL_PATH: &str = ".agents/mcp/compas/plugins.lock.frozen";
+const FLAG_UNFREEZE: &str = "--unfreeze";
 const SUNSET_META_COMPAT_KEY: &str = concat!("deprecat", "ed");
 const FLAG_ALLOW_SUNSET: &str = "--allow-sunset";
+const FLAG_OFFLINE: &str = "--offline";
 const FLAG_ALLOW_SUNSET_COMPAT: &str = concat!("--allow-", "deprecat", "ed");
 const TIER_EXPERIMENTAL: &str = "experimental";
 const TIER_SUNSET: &str = "sunset";
@@ -36,6 +41,9 @@ struct PluginsLockfileV1 {
     packs: Vec<String>,
     #[serde(default)]
     files: Vec<PluginsLockfileEntryV1>,
+    /// Set by `plugins freeze`; changing the locked set then requires `--unfreeze`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    frozen: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -136,6 +144,7 @@ fn acquire_plugins_op_lock(repo_root: &Path) -> Result<PluginsOpLock, String> {
     }
     let file = fs::OpenOptions::new()
         .create(true)
+        .truncate(false)
         .read(true)
         .write(true)
         .open(&lock_path)
@@ -145,26 +154,78 @@ fn acquire_plugins_op_lock(repo_root: &Path) -> Result<PluginsOpLock, String> {
     Ok(PluginsOpLock { _file: file })
 }
 
+/// Writes a fresh file while hashing the same bytes, so an archive is verified in the pass
+/// that stores it. `create_new` refuses to follow a pre-existing file or symlink at `path`.
+struct HashingFileSink {
+    file: fs::File,
+    hasher: Sha256,
+    path: PathBuf,
+}
+
+impl HashingFileSink {
+    fn create(path: &Path) -> Result<Self, String> {
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|e| format!("failed to create cache archive {}: {e}", path.display()))?;
+        Ok(Self {
+            file,
+            hasher: Sha256::new(),
+            path: path.to_path_buf(),
+        })
+    }
+
+    fn write_chunk(&mut self, chunk: &[u8]) -> Result<(), String> {
+        self.file
+            .write_all(chunk)
+            .map_err(|e| format!("failed to write cache archive {}: {e}", self.path.display()))?;
+        self.hasher.update(chunk);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<String, String> {
+        self.file
+            .flush()
+            .map_err(|e| format!("failed to flush cache archive {}: {e}", self.path.display()))?;
+        Ok(format!("{:x}", self.hasher.finalize()))
+    }
+}
+
+/// Copies `src` to `out_path` and returns the sha256 of the copied bytes.
+fn copy_file_hashed(src: &Path, out_path: &Path) -> Result<String, String> {
+    let mut input =
+        fs::File::open(src).map_err(|e| format!("failed to open {}: {e}", src.display()))?;
+    let mut sink = HashingFileSink::create(out_path)?;
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = input
+            .read(&mut buf)
+            .map_err(|e| format!("failed to read {}: {e}", src.display()))?;
+        if n == 0 {
+            break;
+        }
+        sink.write_chunk(&buf[..n])?;
+    }
+    sink.finish()
+}
+
+/// Streams `url` into `out_path` and returns the sha256 of the downloaded bytes. The archive
+/// may not exceed what extraction would accept in total.
 #[cfg(feature = "full")]
-async fn download_url_to_file(url: &str, out_path: &Path) -> Result<(), String> {
-    let response = reqwest::Client::new()
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| format!("failed to download registry from {url}: {e}"))?;
-    let response = response
-        .error_for_status()
-        .map_err(|e| format!("registry download failed for {url}: {e}"))?;
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|e| format!("failed to read registry body from {url}: {e}"))?;
-    fs::write(out_path, &bytes)
-        .map_err(|e| format!("failed to write cache archive {}: {e}", out_path.display()))
+async fn download_url_to_file(url: &str, out_path: &Path) -> Result<String, String> {
+    use ai_dx_mcp::registry_manifest::{get_with_retry, http_client, read_body_capped};
+    let response = get_with_retry(&http_client()?, url).await?;
+    let mut sink = HashingFileSink::create(out_path)?;
+    read_body_capped(response, url, ARCHIVE_MAX_TOTAL_BYTES, |chunk| {
+        sink.write_chunk(chunk)
+    })
+    .await?;
+    sink.finish()
 }
 
 #[cfg(not(feature = "full"))]
-async fn download_url_to_file(url: &str, _out_path: &Path) -> Result<(), String> {
+async fn download_url_to_file(url: &str, _out_path: &Path) -> Result<String, String> {
     Err(format!(
         "URL registry sources are unavailable in lite build ({url}); use local --registry path"
     ))
@@ -226,9 +287,87 @@ fn remove_plugins_lockfile(repo_root: &Path) -> Result<(), String> {
     if path.exists() {
         fs::remove_file(&path).map_err(|e| format!("failed to remove {}: {e}", path.display()))?;
     }
+    remove_plugins_lock_frozen(repo_root)
+}
+
+fn plugins_lock_frozen_path(repo_root: &Path) -> PathBuf {
+    repo_root.join(PLUGINS_LOCK_FROZEN_REL_PATH)
+}
+
+fn remove_plugins_lock_frozen(repo_root: &Path) -> Result<(), String> {
+    let path = plugins_lock_frozen_path(repo_root);
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("failed to remove {}: {e}", path.display()))?;
+    }
     Ok(())
 }
 
+/// True when the proposed lock state differs from `current` (plugins, packs, or file hashes/owners).
+fn lock_set_changed(
+    current: &PluginsLockfileV1,
+    plugins: &[String],
+    packs: &[String],
+    files: &[PluginsLockfileEntryV1],
+) -> bool {
+    fn entry_map(files: &[PluginsLockfileEntryV1]) -> BTreeMap<&str, (&str, BTreeSet<&str>)> {
+        files
+            .iter()
+            .map(|e| {
+                (
+                    e.path.as_str(),
+                    (
+                        e.sha256.as_str(),
+                        e.plugin_ids.iter().map(String::as_str).collect(),
+                    ),
+                )
+            })
+            .collect()
+    }
+    let as_set = |v: &[String]| v.iter().cloned().collect::<BTreeSet<String>>();
+    as_set(&current.plugins) != as_set(plugins)
+        || as_set(&current.packs) != as_set(packs)
+        || entry_map(&current.files) != entry_map(files)
+}
+
+fn frozen_lock_error(repo_root: &Path, action: &str) -> String {
+    format!(
+        "plugins {action} would change the frozen lockfile ({}); pass {FLAG_UNFREEZE} to proceed",
+        plugins_lock_frozen_path(repo_root).display()
+    )
+}
+
+/// Hash-checks every locked file; returns `(missing, modified)` repo-relative paths.
+fn verify_locked_files(
+    repo_root: &Path,
+    lockfile: &PluginsLockfileV1,
+) -> Result<(Vec<String>, Vec<String>), String> {
+    let mut missing: Vec<String> = vec![];
+    let mut modified: Vec<String> = vec![];
+    // Classify with cheap stats first; only regular files are hashed, in parallel.
+    let mut to_hash: Vec<(&PluginsLockfileEntryV1, PathBuf)> = vec![];
+    for entry in &lockfile.files {
+        let abs = repo_file_path(repo_root, &entry.path)?;
+        if !abs.exists() {
+            missing.push(entry.path.clone());
+            continue;
+        }
+        let meta = fs::symlink_metadata(&abs)
+            .map_err(|e| format!("failed to stat {}: {e}", abs.display()))?;
+        if meta.file_type().is_symlink() || !meta.is_file() {
+            modified.push(entry.path.clone());
+            continue;
+        }
+        to_hash.push((entry, abs));
+    }
+    let paths: Vec<PathBuf> = to_hash.iter().map(|(_, abs)| abs.clone()).collect();
+    for ((entry, _), actual) in to_hash.iter().zip(sha256_files(&paths, hash_workers())) {
+        if actual? != entry.sha256 {
+            modified.push(entry.path.clone());
+        }
+    }
+    Ok((missing, modified))
+}
+
 fn parse_csv(raw: &str) -> Vec<String> {
     raw.split(',')
         .map(str::trim)
@@ -263,6 +402,16 @@ fn parse_csv_flag(args: &[String], flag: &str) -> Result<Option<Vec<String>>, St
     }
 }
 
+fn parse_value_flag(args: &[String], flag: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|a| a == flag) else {
+        return Ok(None);
+    };
+    match args.get(i + 1) {
+        Some(v) if !v.starts_with("--") => Ok(Some(v.clone())),
+        _ => Err(format!("{flag} requires a value")),
+    }
+}
+
 fn parse_bool_flag(args: &[String], flag: &str) -> bool {
     args.iter().any(|a| a == flag)
 }
@@ -270,7 +419,10 @@ fn parse_bool_flag(args: &[String], flag: &str) -> bool {
 fn action_requires_admin_lane(action: PluginsAction) -> bool {
     matches!(
         action,
-        PluginsAction::Install | PluginsAction::Update | PluginsAction::Uninstall
+        PluginsAction::Install
+            | PluginsAction::Update
+            | PluginsAction::Uninstall
+            | PluginsAction::Freeze
     )
 }
 
@@ -286,6 +438,8 @@ fn ensure_admin_lane(action: PluginsAction, args: &[String]) -> Result<(), Strin
                 PluginsAction::Packs => "packs",
                 PluginsAction::Info => "info",
                 PluginsAction::Doctor => "doctor",
+                PluginsAction::Freeze => "freeze",
+                PluginsAction::Export => "export",
             }
         ));
     }
@@ -301,22 +455,18 @@ fn with_csv_flag(args: &mut Vec<String>, flag: &str, values: &[String]) {
 }
 
 fn safe_relative_path(raw: &str) -> Result<PathBuf, String> {
-    let path = PathBuf::from(raw);
-    if path.as_os_str().is_empty() {
-        return Err("empty relative path in registry payload".to_string());
-    }
-    if path.is_absolute() {
-        return Err(format!(
-            "absolute paths are forbidden in registry payload: {raw}"
-        ));
-    }
-    for component in path.components() {
-        match component {
-            Component::CurDir | Component::Normal(_) => {}
-            _ => return Err(format!("unsafe path component in registry payload: {raw}")),
-        }
+    ai_dx_mcp::repo::safe_relative_path(raw, "registry payload path")
+}
+
+/// Joins a lockfile path onto `repo_root`, rejecting it when its parent directories resolve
+/// (through symlinks) outside the canonical repo root. The file itself is not followed: a
+/// symlinked managed file is drift, reported by the callers.
+fn repo_file_path(repo_root: &Path, raw: &str) -> Result<PathBuf, String> {
+    let abs = repo_root.join(safe_relative_path(raw)?);
+    if let Some(parent) = abs.parent() {
+        ai_dx_mcp::repo::ensure_within_repo_root(repo_root, parent, &format!("path {raw}"))?;
     }
-    Ok(path)
+    Ok(abs)
 }
 
 fn rollback_moved_paths(moved: &[(PathBuf, PathBuf)], repo_root: &Path) {
@@ -361,6 +511,13 @@ fn prune_empty_parent_dirs(path: &Path, repo_root: &Path) {
 }
 
 include!("cache/manifest_helpers.inc.rs");
+include!("cache/archive_extract.inc.rs");
+
+/// Python bytecode/test caches are never shipped as part of a plugin package.
+fn is_plugin_cache_artifact(path: &Path) -> bool {
+    path.components()
+        .any(|c| matches!(c, Component::Normal(v) if v == "__pycache__" || v == ".pytest_cache"))
+}
 
 fn copy_dir_recursive_filtered(src: &Path, dst: &Path) -> Result<(), String> {
     for entry in WalkDir::new(src) {
@@ -376,14 +533,7 @@ fn copy_dir_recursive_filtered(src: &Path, dst: &Path) -> Result<(), String> {
             .strip_prefix(src)
             .map_err(|e| format!("failed to relativize {}: {e}", path.display()))?;
         let target = dst.join(rel);
-        let parts: BTreeSet<String> = target
-            .components()
-            .filter_map(|c| match c {
-                Component::Normal(v) => Some(v.to_string_lossy().to_string()),
-                _ => None,
-            })
-            .collect();
-        if parts.contains("__pycache__") || parts.contains(".pytest_cache") {
+        if is_plugin_cache_artifact(&target) {
             continue;
         }
         if entry.file_type().is_dir() {
@@ -409,7 +559,11 @@ fn copy_dir_recursive_filtered(src: &Path, dst: &Path) -> Result<(), String> {
     Ok(())
 }
 
+#[path = "cache/file_hash.rs"]
+mod file_hash;
 #[path = "cache/ops.rs"]
 mod ops;
 
+use file_hash::{hash_workers, sha256_file, sha256_files};
+
 pub(super) use ops::run_plugins_cli;
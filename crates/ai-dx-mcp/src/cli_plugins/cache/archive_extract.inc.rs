@@ -0,0 +1,255 @@
+#[cfg(feature = "registry_archives")]
+const ARCHIVE_MAX_ENTRIES: usize = 20_000;
+#[cfg(feature = "registry_archives")]
+const ARCHIVE_MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+#[cfg(feature = "registry_archives")]
+const ARCHIVE_MAX_TOTAL_BYTES: u64 = 200 * 1024 * 1024;
+#[cfg(feature = "registry_archives")]
+const ARCHIVE_MAX_PATH_BYTES: usize = 512;
+
+/// Limits shared by every registry archive format: entry count, per-file and total bytes,
+/// path length, a single top-level directory and no absolute/traversal paths.
+#[cfg(feature = "registry_archives")]
+struct ArchiveBudget {
+    format: &'static str,
+    root_prefix: Option<String>,
+    entries_seen: usize,
+    total_bytes: u64,
+}
+
+#[cfg(feature = "registry_archives")]
+impl ArchiveBudget {
+    fn new(format: &'static str) -> Self {
+        Self {
+            format,
+            root_prefix: None,
+            entries_seen: 0,
+            total_bytes: 0,
+        }
+    }
+
+    fn count_entry(&mut self) -> Result<(), String> {
+        self.entries_seen += 1;
+        if self.entries_seen > ARCHIVE_MAX_ENTRIES {
+            return Err(format!("archive exceeds MAX_ENTRIES={ARCHIVE_MAX_ENTRIES}"));
+        }
+        Ok(())
+    }
+
+    /// Checks the path of an already-counted entry and returns where it extracts to.
+    fn admit(&mut self, out_dir: &Path, path: &Path) -> Result<PathBuf, String> {
+        let format = self.format;
+        if path.as_os_str().is_empty() {
+            return Err(format!("empty {format} entry path"));
+        }
+        if path.is_absolute() {
+            return Err(format!(
+                "absolute {format} path is forbidden: {}",
+                path.display()
+            ));
+        }
+
+        let path_str = path.to_string_lossy();
+        if path_str.len() > ARCHIVE_MAX_PATH_BYTES {
+            return Err(format!(
+                "{format} path too long (> {ARCHIVE_MAX_PATH_BYTES} bytes): {path_str}"
+            ));
+        }
+
+        let mut components = path.components();
+        let Some(Component::Normal(first)) = components.next() else {
+            return Err(format!(
+                "unsafe {format} path component: {}",
+                path.display()
+            ));
+        };
+        let first = first.to_string_lossy().to_string();
+        if self.root_prefix.is_none() {
+            self.root_prefix = Some(first.clone());
+        } else if self.root_prefix.as_deref() != Some(first.as_str()) {
+            return Err(format!(
+                "archive must have single top-level directory; found '{}' and '{}'",
+                self.root_prefix.as_deref().unwrap_or(""),
+                first
+            ));
+        }
+        for c in components {
+            match c {
+                Component::Normal(_) | Component::CurDir => {}
+                _ => {
+                    return Err(format!(
+                        "unsafe {format} path component: {}",
+                        path.display()
+                    ));
+                }
+            }
+        }
+
+        let target = out_dir.join(path);
+        if !target.starts_with(out_dir) {
+            return Err(format!(
+                "{format} extraction escape detected: {}",
+                target.display()
+            ));
+        }
+        Ok(target)
+    }
+
+    /// Adds `size` file bytes to the running total; fails when the file or the archive is over
+    /// its limit.
+    fn charge(&mut self, path: &Path, size: u64) -> Result<(), String> {
+        if size > ARCHIVE_MAX_FILE_BYTES {
+            return Err(format!(
+                "{} entry too large (> {ARCHIVE_MAX_FILE_BYTES} bytes): {}",
+                self.format,
+                path.display()
+            ));
+        }
+        self.total_bytes = self.total_bytes.saturating_add(size);
+        if self.total_bytes > ARCHIVE_MAX_TOTAL_BYTES {
+            return Err(format!(
+                "archive exceeds MAX_TOTAL_BYTES={ARCHIVE_MAX_TOTAL_BYTES}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Most bytes the next file may write before one of the limits is crossed.
+    fn remaining_for_file(&self) -> u64 {
+        ARCHIVE_MAX_FILE_BYTES.min(ARCHIVE_MAX_TOTAL_BYTES.saturating_sub(self.total_bytes))
+    }
+}
+
+#[cfg(feature = "registry_archives")]
+fn create_parent_dir(target: &Path) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create parent dir {}: {e}", parent.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "registry_archives")]
+fn extract_tar_safe(reader: impl Read, out_dir: &Path) -> Result<(), String> {
+    let mut archive = tar::Archive::new(reader);
+    let mut budget = ArchiveBudget::new("tar");
+
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("failed to read tar entries: {e}"))?
+    {
+        let mut entry = entry.map_err(|e| format!("failed to read tar entry: {e}"))?;
+        budget.count_entry()?;
+
+        let entry_type = entry.header().entry_type();
+        if entry_type != tar::EntryType::Regular && entry_type != tar::EntryType::Directory {
+            return Err(format!("unsupported tar entry type: {entry_type:?}"));
+        }
+
+        let path = entry
+            .path()
+            .map_err(|e| format!("failed to read tar entry path: {e}"))?
+            .into_owned();
+        let target = budget.admit(out_dir, &path)?;
+
+        if entry_type == tar::EntryType::Directory {
+            fs::create_dir_all(&target)
+                .map_err(|e| format!("failed to create dir {}: {e}", target.display()))?;
+            continue;
+        }
+
+        // A tar entry's data is exactly the header size, so the header can be charged upfront.
+        let size = entry
+            .header()
+            .size()
+            .map_err(|e| format!("failed to read tar entry size: {e}"))?;
+        budget.charge(&path, size)?;
+        create_parent_dir(&target)?;
+        entry
+            .unpack(&target)
+            .map_err(|e| format!("failed to unpack {}: {e}", target.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "registry_archives")]
+fn extract_zip_safe(file: fs::File, out_dir: &Path) -> Result<(), String> {
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| format!("failed to read zip archive: {e}"))?;
+    let mut budget = ArchiveBudget::new("zip");
+
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| format!("failed to read zip entry: {e}"))?;
+        budget.count_entry()?;
+
+        if entry.is_symlink() {
+            return Err(format!("unsupported zip entry type: symlink {}", entry.name()));
+        }
+
+        let path = PathBuf::from(entry.name());
+        if entry.is_dir() {
+            let target = budget.admit(out_dir, &path)?;
+            fs::create_dir_all(&target)
+                .map_err(|e| format!("failed to create dir {}: {e}", target.display()))?;
+            continue;
+        }
+
+        let target = budget.admit(out_dir, &path)?;
+        create_parent_dir(&target)?;
+        let mut out = fs::File::create(&target)
+            .map_err(|e| format!("failed to create {}: {e}", target.display()))?;
+        // The size a zip header declares is untrusted: charge the bytes actually decompressed,
+        // reading at most one byte past what the limits still allow.
+        let written = std::io::copy(
+            &mut (&mut entry).take(budget.remaining_for_file() + 1),
+            &mut out,
+        )
+        .map_err(|e| format!("failed to unpack {}: {e}", target.display()))?;
+        budget.charge(&path, written)?;
+    }
+    Ok(())
+}
+
+/// Extracts a registry archive (tar.gz, tar.xz or zip, chosen by magic bytes rather than the
+/// file name) and returns its single top-level directory.
+#[cfg(feature = "registry_archives")]
+fn extract_archive_safe(archive_path: &Path, out_dir: &Path) -> Result<PathBuf, String> {
+    const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+    const XZ_MAGIC: &[u8] = &[0xfd, b'7', b'z', b'X', b'Z', 0x00];
+    const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+    fs::create_dir_all(out_dir)
+        .map_err(|e| format!("failed to create extract dir {}: {e}", out_dir.display()))?;
+
+    let mut file = fs::File::open(archive_path)
+        .map_err(|e| format!("failed to open archive {}: {e}", archive_path.display()))?;
+    let mut magic = Vec::with_capacity(XZ_MAGIC.len());
+    (&mut file)
+        .take(XZ_MAGIC.len() as u64)
+        .read_to_end(&mut magic)
+        .map_err(|e| format!("failed to read archive {}: {e}", archive_path.display()))?;
+    let file = fs::File::open(archive_path)
+        .map_err(|e| format!("failed to open archive {}: {e}", archive_path.display()))?;
+
+    if magic.starts_with(GZIP_MAGIC) {
+        extract_tar_safe(flate2::read::GzDecoder::new(file), out_dir)?;
+    } else if magic.starts_with(XZ_MAGIC) {
+        extract_tar_safe(xz2::read::XzDecoder::new(file), out_dir)?;
+    } else if magic.starts_with(ZIP_MAGIC) {
+        extract_zip_safe(file, out_dir)?;
+    } else {
+        return Err(format!(
+            "unsupported registry archive format (expected tar.gz, tar.xz or zip): {}",
+            archive_path.display()
+        ));
+    }
+
+    locate_single_dir(out_dir)
+}
+
+#[cfg(not(feature = "registry_archives"))]
+fn extract_archive_safe(_archive_path: &Path, _out_dir: &Path) -> Result<PathBuf, String> {
+    Err("archive extraction is unavailable in lite build; use full build".to_string())
+}
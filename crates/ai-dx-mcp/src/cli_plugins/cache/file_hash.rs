@@ -0,0 +1,247 @@
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Upper bound on hashing threads; doctor/freeze are disk-bound well before this.
+const HASH_WORKERS_MAX: usize = 8;
+
+/// Files at least this large are hashed through a read-only memory map (`mmap_hash` feature);
+/// below it the mapping setup costs more than the chunked reads it replaces.
+#[cfg(feature = "mmap_hash")]
+const MMAP_MIN_BYTES: u64 = 4 * 1024 * 1024;
+
+/// sha256 of a regular file as lowercase hex. Symlinks and non-files are refused before the
+/// file is opened, whichever read path is used.
+pub(super) fn sha256_file(path: &Path) -> Result<String, String> {
+    let meta = fs::symlink_metadata(path)
+        .map_err(|e| format!("failed to stat {}: {e}", path.display()))?;
+    if meta.file_type().is_symlink() {
+        return Err(format!(
+            "refusing to hash symlink path (unsafe): {}",
+            path.display()
+        ));
+    }
+    if !meta.is_file() {
+        return Err(format!(
+            "refusing to hash non-file path: {}",
+            path.display()
+        ));
+    }
+    let file =
+        fs::File::open(path).map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+    #[cfg(feature = "mmap_hash")]
+    if meta.len() >= MMAP_MIN_BYTES {
+        return sha256_mmap(&file, path);
+    }
+    sha256_chunked(file, path)
+}
+
+/// Hashing threads for `sha256_files`: available cores, capped at `HASH_WORKERS_MAX`.
+pub(super) fn hash_workers() -> usize {
+    std::thread::available_parallelism()
+        .map_or(1, std::num::NonZeroUsize::get)
+        .min(HASH_WORKERS_MAX)
+}
+
+/// `sha256_file` for every path on up to `workers` scoped threads. Results come back in input
+/// order, so callers see the same output as a sequential loop.
+pub(super) fn sha256_files(paths: &[PathBuf], workers: usize) -> Vec<Result<String, String>> {
+    let workers = workers.clamp(1, paths.len().max(1));
+    if workers == 1 {
+        return paths.iter().map(|p| sha256_file(p)).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let mut indexed: Vec<(usize, Result<String, String>)> = Vec::with_capacity(paths.len());
+    let mut worker_panicked = false;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut out = vec![];
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = paths.get(i) else {
+                            break;
+                        };
+                        out.push((i, sha256_file(path)));
+                    }
+                    out
+                })
+            })
+            .collect();
+        for handle in handles {
+            match handle.join() {
+                Ok(done) => indexed.extend(done),
+                Err(_) => worker_panicked = true,
+            }
+        }
+    });
+    if worker_panicked {
+        return paths
+            .iter()
+            .map(|p| Err(format!("failed to hash {}: worker panicked", p.display())))
+            .collect();
+    }
+    indexed.sort_by_key(|(i, _)| *i);
+    indexed.into_iter().map(|(_, r)| r).collect()
+}
+
+fn sha256_chunked(mut file: fs::File, path: &Path) -> Result<String, String> {
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(feature = "mmap_hash")]
+fn sha256_mmap(file: &fs::File, path: &Path) -> Result<String, String> {
+    // SAFETY: the map is read-only and dropped before returning, but nothing stops another
+    // process from changing the file meanwhile: install/verify/doctor/freeze hash repo files that
+    // editors and build tools touch without taking the plugins lock. An in-place rewrite can give
+    // a hash of mixed bytes (reported as modified); a truncation below the mapped length raises
+    // SIGBUS and kills the process. That is acceptable here: hashing only reads and install and
+    // uninstall hash before they write anything, so a crash leaves no partial state behind, and a
+    // file rewritten mid-check would make any verdict stale anyway.
+    // Builds that cannot tolerate the signal drop the `mmap_hash` feature (chunked reads only).
+    let map = unsafe { memmap2::Mmap::map(file) }
+        .map_err(|e| format!("failed to map {}: {e}", path.display()))?;
+    Ok(format!("{:x}", Sha256::digest(&map[..])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_pattern(path: &Path, len: usize) {
+        let mut f = fs::File::create(path).expect("create");
+        let chunk: Vec<u8> = (0..=255u8).cycle().take(64 * 1024).collect();
+        let mut left = len;
+        while left > 0 {
+            let n = left.min(chunk.len());
+            f.write_all(&chunk[..n]).expect("write");
+            left -= n;
+        }
+    }
+
+    #[test]
+    fn chunked_hash_matches_one_shot_digest() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("blob.bin");
+        write_pattern(&path, 8192 * 3 + 17);
+        let expected = format!("{:x}", Sha256::digest(fs::read(&path).expect("read")));
+        assert_eq!(sha256_file(&path).expect("hash"), expected);
+    }
+
+    #[cfg(feature = "mmap_hash")]
+    #[test]
+    fn mmap_and_chunked_hashes_are_identical() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        for (name, len) in [
+            ("empty.bin", 0usize),
+            ("small.bin", 8191),
+            ("threshold.bin", MMAP_MIN_BYTES as usize),
+            ("large.bin", MMAP_MIN_BYTES as usize + 12_345),
+        ] {
+            let path = dir.path().join(name);
+            write_pattern(&path, len);
+            let open = || fs::File::open(&path).expect("open");
+            let chunked = sha256_chunked(open(), &path).expect("chunked");
+            let mapped = sha256_mmap(&open(), &path).expect("mmap");
+            assert_eq!(chunked, mapped, "{name}");
+            assert_eq!(sha256_file(&path).expect("hash"), chunked, "{name}");
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn refuses_symlinks_and_directories() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("target.bin");
+        write_pattern(&target, 16);
+        let link = dir.path().join("link.bin");
+        std::os::unix::fs::symlink(&target, &link).expect("symlink");
+        let err = sha256_file(&link).expect_err("symlink must be refused");
+        assert!(err.contains("symlink"), "{err}");
+        let err = sha256_file(dir.path()).expect_err("dir must be refused");
+        assert!(err.contains("non-file"), "{err}");
+    }
+
+    #[test]
+    fn parallel_hashing_matches_sequential_in_input_order() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut paths: Vec<PathBuf> = (0..40)
+            .map(|i| {
+                let path = dir.path().join(format!("f{i:02}.bin"));
+                write_pattern(&path, 1000 + i * 513);
+                path
+            })
+            .collect();
+        paths.push(dir.path().join("missing.bin"));
+        paths.push(dir.path().to_path_buf());
+
+        let sequential = sha256_files(&paths, 1);
+        let parallel = sha256_files(&paths, 4);
+        assert_eq!(parallel, sequential);
+        assert_eq!(sequential[3], sha256_file(&paths[3]));
+        assert!(sequential[40].is_err() && sequential[41].is_err());
+    }
+
+    /// Sequential vs parallel hashing of 256 files x 1 MiB:
+    /// `cargo test --release --bin ai-dx-mcp sha256_files_bench -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark; run explicitly with --ignored --nocapture"]
+    fn sha256_files_bench() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let paths: Vec<PathBuf> = (0..256)
+            .map(|i| {
+                let path = dir.path().join(format!("f{i}.bin"));
+                write_pattern(&path, 1024 * 1024);
+                path
+            })
+            .collect();
+        let t = std::time::Instant::now();
+        let sequential = sha256_files(&paths, 1);
+        let sequential_ms = t.elapsed().as_millis();
+        let t = std::time::Instant::now();
+        let parallel = sha256_files(&paths, hash_workers());
+        let parallel_ms = t.elapsed().as_millis();
+        assert_eq!(sequential, parallel);
+        eprintln!(
+            "sha256_files 256x1MiB: sequential={sequential_ms}ms parallel({})={parallel_ms}ms",
+            hash_workers()
+        );
+    }
+
+    /// Timing comparison of both read paths on a 256 MiB file:
+    /// `cargo test --release --bin ai-dx-mcp sha256_file_bench -- --ignored --nocapture`.
+    #[cfg(feature = "mmap_hash")]
+    #[test]
+    #[ignore = "benchmark; run explicitly with --ignored --nocapture"]
+    fn sha256_file_bench() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("bench.bin");
+        write_pattern(&path, 256 * 1024 * 1024);
+        let open = || fs::File::open(&path).expect("open");
+        let t = std::time::Instant::now();
+        let chunked = sha256_chunked(open(), &path).expect("chunked");
+        let chunked_ms = t.elapsed().as_millis();
+        let t = std::time::Instant::now();
+        let mapped = sha256_mmap(&open(), &path).expect("mmap");
+        let mmap_ms = t.elapsed().as_millis();
+        assert_eq!(chunked, mapped);
+        eprintln!("sha256_file 256MiB: chunked={chunked_ms}ms mmap={mmap_ms}ms");
+    }
+}
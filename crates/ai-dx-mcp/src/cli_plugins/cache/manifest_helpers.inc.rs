@@ -73,10 +73,10 @@ fn resolve_plugin_ids_from_manifest(
     let mut seen: BTreeSet<String> = BTreeSet::new();
     for raw in expanded_inputs {
         let mut pid = raw.clone();
-        if !by_id.contains(&pid) {
-            if let Some(mapped) = alias_to_id.get(&pid) {
-                pid = mapped.clone();
-            }
+        if !by_id.contains(&pid)
+            && let Some(mapped) = alias_to_id.get(&pid)
+        {
+            pid = mapped.clone();
         }
         if !by_id.contains(&pid) {
             unknown_plugins.push(raw);
@@ -93,20 +93,98 @@ fn resolve_plugin_ids_from_manifest(
     Ok(resolved)
 }
 
+/// Last verified manifest (and signature) fetched from a URL source, replayed by `--offline`.
+fn manifest_source_cache_dir(registry_source: &str) -> PathBuf {
+    plugins_cache_root()
+        .join("sources")
+        .join(sha256_hex(registry_source.trim().as_bytes()))
+}
+
+const CACHED_MANIFEST_FILE: &str = "manifest.json";
+const CACHED_SIGNATURE_FILE: &str = "manifest.json.sig";
+
+fn read_cached_manifest_source(registry_source: &str) -> Result<ManifestSourceBytes, String> {
+    let dir = manifest_source_cache_dir(registry_source);
+    let manifest_path = dir.join(CACHED_MANIFEST_FILE);
+    if !manifest_path.is_file() {
+        return Err(format!(
+            "--offline: registry manifest for {} is not cached ({}); run once without --offline to warm the cache",
+            registry_source.trim(),
+            manifest_path.display()
+        ));
+    }
+    let manifest_bytes = fs::read(&manifest_path)
+        .map_err(|e| format!("failed to read {}: {e}", manifest_path.display()))?;
+    let sig_path = dir.join(CACHED_SIGNATURE_FILE);
+    let signature_b64 = if sig_path.is_file() {
+        Some(
+            fs::read_to_string(&sig_path)
+                .map_err(|e| format!("failed to read {}: {e}", sig_path.display()))?,
+        )
+    } else {
+        None
+    };
+    Ok(ManifestSourceBytes::from_url(
+        registry_source.trim(),
+        manifest_bytes,
+        signature_b64,
+    ))
+}
+
+fn write_cached_manifest_source(
+    registry_source: &str,
+    source: &ManifestSourceBytes,
+) -> Result<(), String> {
+    let dir = manifest_source_cache_dir(registry_source);
+    write_file_atomic(&dir.join(CACHED_MANIFEST_FILE), &source.manifest_bytes)?;
+    let sig_path = dir.join(CACHED_SIGNATURE_FILE);
+    match &source.signature_b64 {
+        Some(sig) => write_file_atomic(&sig_path, sig.as_bytes()),
+        None => match fs::remove_file(&sig_path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("failed to remove {}: {e}", sig_path.display())),
+        },
+    }
+}
+
 async fn load_verified_manifest(parsed: &PluginsCli) -> Result<ManifestResolved, String> {
     let allow_unsigned = parse_bool_flag(&parsed.installer_args, "--allow-unsigned");
+    let offline = parse_bool_flag(&parsed.installer_args, FLAG_OFFLINE);
+    let strict_manifest = parse_bool_flag(&parsed.installer_args, "--strict-manifest");
     let pubkey_override = parse_string_flag(&parsed.installer_args, "--pubkey")?;
     let pubkey_pem = if let Some(path) = pubkey_override {
         Some(fs::read_to_string(&path).map_err(|e| format!("failed to read pubkey {}: {e}", path))?)
     } else {
         None
     };
-    crate::cli::registry_manifest::load_verified_manifest_source(
-        &parsed.registry_source,
+    let expected_version = parse_string_flag(&parsed.installer_args, "--expect-registry-version")?;
+    let is_url = ai_dx_mcp::registry_manifest::is_http_url(parsed.registry_source.trim());
+    let source = if offline && is_url {
+        read_cached_manifest_source(&parsed.registry_source)?
+    } else {
+        ai_dx_mcp::registry_manifest::read_manifest_source(&parsed.registry_source, allow_unsigned)
+            .await?
+    };
+    let resolved = ai_dx_mcp::registry_manifest::verify_manifest_source(
+        &source,
         allow_unsigned,
         pubkey_pem,
-    )
-    .await
+        strict_manifest,
+    )?;
+    // Only bytes that passed verification are kept for later offline runs.
+    if is_url && !offline {
+        write_cached_manifest_source(&parsed.registry_source, &source)?;
+    }
+    if let Some(expected) = expected_version
+        && expected != resolved.manifest.registry_version
+    {
+        return Err(format!(
+            "registry version mismatch: expected {expected}, manifest has {}",
+            resolved.manifest.registry_version
+        ));
+    }
+    Ok(resolved)
 }
 
 fn registry_cache_root_for_manifest(resolved: &ManifestResolved) -> PathBuf {
@@ -138,139 +216,23 @@ fn locate_single_dir(path: &Path) -> Result<PathBuf, String> {
     Ok(dirs.remove(0))
 }
 
-#[cfg(feature = "full")]
-fn extract_tar_gz_safe(archive_path: &Path, out_dir: &Path) -> Result<PathBuf, String> {
-    const MAX_ENTRIES: usize = 20_000;
-    const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
-    const MAX_TOTAL_BYTES: u64 = 200 * 1024 * 1024;
-    const MAX_PATH_BYTES: usize = 512;
-
-    fs::create_dir_all(out_dir)
-        .map_err(|e| format!("failed to create extract dir {}: {e}", out_dir.display()))?;
-
-    let file = fs::File::open(archive_path)
-        .map_err(|e| format!("failed to open archive {}: {e}", archive_path.display()))?;
-    let gz = flate2::read::GzDecoder::new(file);
-    let mut archive = tar::Archive::new(gz);
-
-    let mut root_prefix: Option<String> = None;
-    let mut entries_seen: usize = 0;
-    let mut total_bytes: u64 = 0;
-
-    for entry in archive
-        .entries()
-        .map_err(|e| format!("failed to read tar entries: {e}"))?
-    {
-        let mut entry = entry.map_err(|e| format!("failed to read tar entry: {e}"))?;
-        entries_seen += 1;
-        if entries_seen > MAX_ENTRIES {
-            return Err(format!("archive exceeds MAX_ENTRIES={MAX_ENTRIES}"));
-        }
-
-        let entry_type = entry.header().entry_type();
-        if entry_type != tar::EntryType::Regular && entry_type != tar::EntryType::Directory {
-            return Err(format!("unsupported tar entry type: {entry_type:?}"));
-        }
-
-        let path = entry
-            .path()
-            .map_err(|e| format!("failed to read tar entry path: {e}"))?
-            .into_owned();
-        if path.as_os_str().is_empty() {
-            return Err("empty tar entry path".to_string());
-        }
-        if path.is_absolute() {
-            return Err(format!(
-                "absolute tar path is forbidden: {}",
-                path.display()
-            ));
-        }
-
-        let path_str = path.to_string_lossy();
-        if path_str.as_bytes().len() > MAX_PATH_BYTES {
-            return Err(format!(
-                "tar path too long (> {MAX_PATH_BYTES} bytes): {path_str}"
-            ));
-        }
-
-        let mut components = path.components();
-        let Some(Component::Normal(first)) = components.next() else {
-            return Err(format!("unsafe tar path component: {}", path.display()));
-        };
-        let first = first.to_string_lossy().to_string();
-        if root_prefix.is_none() {
-            root_prefix = Some(first.clone());
-        } else if root_prefix.as_deref() != Some(first.as_str()) {
-            return Err(format!(
-                "archive must have single top-level directory; found '{}' and '{}'",
-                root_prefix.as_deref().unwrap_or(""),
-                first
-            ));
-        }
-
-        for c in components.clone() {
-            match c {
-                Component::Normal(_) => {}
-                Component::CurDir => {}
-                _ => return Err(format!("unsafe tar path component: {}", path.display())),
-            }
-        }
-
-        let size = entry
-            .header()
-            .size()
-            .map_err(|e| format!("failed to read tar entry size: {e}"))?;
-        if entry_type == tar::EntryType::Regular {
-            if size > MAX_FILE_BYTES {
-                return Err(format!(
-                    "tar entry too large (> {MAX_FILE_BYTES} bytes): {}",
-                    path.display()
-                ));
-            }
-            total_bytes = total_bytes.saturating_add(size);
-            if total_bytes > MAX_TOTAL_BYTES {
-                return Err(format!("archive exceeds MAX_TOTAL_BYTES={MAX_TOTAL_BYTES}"));
-            }
-        }
-
-        let target = out_dir.join(&path);
-        if !target.starts_with(out_dir) {
-            return Err(format!(
-                "tar extraction escape detected: {}",
-                target.display()
-            ));
-        }
-
-        if entry_type == tar::EntryType::Directory {
-            fs::create_dir_all(&target)
-                .map_err(|e| format!("failed to create dir {}: {e}", target.display()))?;
-            continue;
-        }
-
-        if let Some(parent) = target.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| format!("failed to create parent dir {}: {e}", parent.display()))?;
-        }
-        entry
-            .unpack(&target)
-            .map_err(|e| format!("failed to unpack {}: {e}", target.display()))?;
-    }
-
-    locate_single_dir(out_dir)
-}
-
-#[cfg(not(feature = "full"))]
-fn extract_tar_gz_safe(_archive_path: &Path, _out_dir: &Path) -> Result<PathBuf, String> {
-    Err("archive extraction is unavailable in lite build; use full build".to_string())
-}
-
-async fn ensure_registry_cached(resolved: &ManifestResolved) -> Result<PathBuf, String> {
+/// Extracted registry archive for `resolved`, fetched on a cache miss; `offline` turns a miss
+/// into an error instead.
+async fn ensure_registry_cached(
+    resolved: &ManifestResolved,
+    offline: bool,
+) -> Result<PathBuf, String> {
     let entry = registry_cache_root_for_manifest(resolved);
     let extract_dir = entry.join("extract");
-    if entry.join(".ready").is_file() {
-        if extract_dir.is_dir() {
-            return locate_single_dir(&extract_dir);
-        }
+    if entry.join(".ready").is_file() && extract_dir.is_dir() {
+        return locate_single_dir(&extract_dir);
+    }
+    if offline {
+        return Err(format!(
+            "--offline: registry archive {} is not cached ({}); run once without --offline to warm the cache",
+            resolved.manifest.archive.name,
+            entry.display()
+        ));
     }
 
     ensure_clean_dir(&entry)?;
@@ -283,9 +245,9 @@ async fn ensure_registry_cached(resolved: &ManifestResolved) -> Result<PathBuf,
 
     let archive_path = entry.join(&resolved.manifest.archive.name);
 
-    if let Some(base_url) = &resolved.base_url {
+    let actual_sha = if let Some(base_url) = &resolved.base_url {
         let url = format!("{base_url}/{}", resolved.manifest.archive.name);
-        download_url_to_file(&url, &archive_path).await?;
+        download_url_to_file(&url, &archive_path).await?
     } else if let Some(base_dir) = &resolved.base_dir {
         let local = base_dir.join(&resolved.manifest.archive.name);
         if !local.is_file() {
@@ -294,19 +256,13 @@ async fn ensure_registry_cached(resolved: &ManifestResolved) -> Result<PathBuf,
                 local.display()
             ));
         }
-        fs::copy(&local, &archive_path).map_err(|e| {
-            format!(
-                "failed to copy archive {} -> {}: {e}",
-                local.display(),
-                archive_path.display()
-            )
-        })?;
+        copy_file_hashed(&local, &archive_path)?
     } else {
         return Err("cannot resolve archive location for registry manifest source".to_string());
-    }
+    };
 
-    let actual_sha = sha256_file(&archive_path)?;
     if actual_sha != resolved.manifest.archive.sha256 {
+        let _ = fs::remove_file(&archive_path);
         return Err(format!(
             "archive sha256 mismatch for {}: expected {}, got {}",
             archive_path.display(),
@@ -315,42 +271,11 @@ async fn ensure_registry_cached(resolved: &ManifestResolved) -> Result<PathBuf,
         ));
     }
 
-    let root = extract_tar_gz_safe(&archive_path, &extract_dir)?;
+    let root = extract_archive_safe(&archive_path, &extract_dir)?;
     mark_ready(&entry)?;
     Ok(root)
 }
 
-fn sha256_file(path: &Path) -> Result<String, String> {
-    let meta = fs::symlink_metadata(path)
-        .map_err(|e| format!("failed to stat {}: {e}", path.display()))?;
-    if meta.file_type().is_symlink() {
-        return Err(format!(
-            "refusing to hash symlink path (unsafe): {}",
-            path.display()
-        ));
-    }
-    if !meta.is_file() {
-        return Err(format!(
-            "refusing to hash non-file path: {}",
-            path.display()
-        ));
-    }
-    let mut file =
-        fs::File::open(path).map_err(|e| format!("failed to open {}: {e}", path.display()))?;
-    let mut hasher = Sha256::new();
-    let mut buf = [0u8; 8192];
-    loop {
-        let n = file
-            .read(&mut buf)
-            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
-        if n == 0 {
-            break;
-        }
-        hasher.update(&buf[..n]);
-    }
-    Ok(format!("{:x}", hasher.finalize()))
-}
-
 fn normalize_repo_rel_path(repo_root: &Path, abs: &Path) -> Result<String, String> {
     let rel = abs
         .strip_prefix(repo_root)
@@ -412,6 +337,54 @@ fn plugin_by_id<'a>(
     manifest.plugins.iter().find(|p| p.id == plugin_id)
 }
 
+fn plugin_requires(plugin: &RegistryPluginV1) -> Vec<&str> {
+    plugin
+        .extra
+        .get("requires")
+        .and_then(|v| v.as_array())
+        .map(|values| values.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default()
+}
+
+/// Orders `plugin_ids` so that every plugin comes after the selected plugins named in its
+/// `requires`; unrelated plugins stay sorted by id. A `requires` cycle is an error.
+fn plugin_install_order(
+    manifest: &RegistryManifestV1,
+    plugin_ids: &[String],
+) -> Result<Vec<String>, String> {
+    let selected: BTreeSet<&str> = plugin_ids.iter().map(String::as_str).collect();
+    let mut pending: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for pid in &selected {
+        let deps = plugin_by_id(manifest, pid)
+            .map(plugin_requires)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|dep| dep != pid && selected.contains(dep))
+            .collect();
+        pending.insert(pid, deps);
+    }
+    let mut order: Vec<String> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let Some(next) = pending
+            .iter()
+            .find(|(_, deps)| deps.is_empty())
+            .map(|(pid, _)| *pid)
+        else {
+            let cycle: Vec<&str> = pending.keys().copied().collect();
+            return Err(format!(
+                "plugin requires cycle among: {}",
+                cycle.join(", ")
+            ));
+        };
+        pending.remove(next);
+        for deps in pending.values_mut() {
+            deps.remove(next);
+        }
+        order.push(next.to_string());
+    }
+    Ok(order)
+}
+
 fn plugin_sunset_marker(plugin: &RegistryPluginV1) -> Option<&serde_json::Value> {
     plugin.extra.get(SUNSET_META_COMPAT_KEY)
 }
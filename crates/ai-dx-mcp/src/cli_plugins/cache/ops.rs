@@ -1,9 +1,51 @@
 use super::*;
 
-fn run_plugins_list_manifest(resolved: &ManifestResolved, json: bool) -> Result<i32, String> {
+/// Lockfile drift per installed plugin id for `list --installed` (ids absent from the map are
+/// not installed): `missing` wins over `modified`, which wins over `ok`.
+fn installed_plugin_drift(repo_root: &Path) -> Result<BTreeMap<String, &'static str>, String> {
+    let Some(lockfile) = read_plugins_lockfile(repo_root)? else {
+        return Ok(BTreeMap::new());
+    };
+    let (missing, modified) = verify_locked_files(repo_root, &lockfile)?;
+    let missing: BTreeSet<&str> = missing.iter().map(String::as_str).collect();
+    let modified: BTreeSet<&str> = modified.iter().map(String::as_str).collect();
+    let mut drift: BTreeMap<String, &'static str> = lockfile
+        .plugins
+        .iter()
+        .map(|id| (id.clone(), "ok"))
+        .collect();
+    for entry in &lockfile.files {
+        let state = if missing.contains(entry.path.as_str()) {
+            "missing"
+        } else if modified.contains(entry.path.as_str()) {
+            "modified"
+        } else {
+            continue;
+        };
+        for id in &entry.plugin_ids {
+            if let Some(current) = drift.get_mut(id)
+                && *current != "missing"
+            {
+                *current = state;
+            }
+        }
+    }
+    Ok(drift)
+}
+
+fn run_plugins_list_manifest(
+    resolved: &ManifestResolved,
+    parsed: &PluginsCli,
+    json: bool,
+) -> Result<i32, String> {
+    let installed = if parse_bool_flag(&parsed.installer_args, "--installed") {
+        Some(installed_plugin_drift(Path::new(&parsed.repo_root))?)
+    } else {
+        None
+    };
     let mut rows: Vec<serde_json::Value> = vec![];
     for plugin in &resolved.manifest.plugins {
-        rows.push(serde_json::json!({
+        let mut row = serde_json::json!({
             "id": plugin.id,
             "aliases": plugin.aliases,
             "version": plugin.package.version,
@@ -15,25 +57,43 @@ fn run_plugins_list_manifest(resolved: &ManifestResolved, json: bool) -> Result<
             "status": plugin.status,
             "description": plugin.description,
             "path": plugin.path,
-        }));
+        });
+        if let Some(installed) = &installed {
+            let drift = installed.get(&plugin.id).copied();
+            row["installed"] = serde_json::json!(drift.is_some());
+            row["drift"] = serde_json::json!(drift);
+        }
+        rows.push(row);
     }
     if json {
         println!(
             "{}",
-            serde_json::to_string_pretty(&rows)
+            render_json(&rows, parsed.json_style)
                 .map_err(|e| format!("failed to serialize plugin list: {e}"))?
         );
     } else {
         for row in &rows {
             let id = row.get("id").and_then(|v| v.as_str()).unwrap_or("-");
             let version = row.get("version").and_then(|v| v.as_str()).unwrap_or("-");
-            println!("{id:<28} {version}");
+            if installed.is_some() {
+                let state = row
+                    .get("drift")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("not_installed");
+                println!("{id:<28} {version:<12} {state}");
+            } else {
+                println!("{id:<28} {version}");
+            }
         }
     }
     Ok(0)
 }
 
-fn run_plugins_packs_manifest(resolved: &ManifestResolved, json: bool) -> Result<i32, String> {
+fn run_plugins_packs_manifest(
+    resolved: &ManifestResolved,
+    json: bool,
+    style: JsonStyle,
+) -> Result<i32, String> {
     let mut rows: Vec<serde_json::Value> = vec![];
     for pack in &resolved.manifest.packs {
         rows.push(serde_json::json!({
@@ -45,7 +105,7 @@ fn run_plugins_packs_manifest(resolved: &ManifestResolved, json: bool) -> Result
     if json {
         println!(
             "{}",
-            serde_json::to_string_pretty(&rows)
+            render_json(&rows, style)
                 .map_err(|e| format!("failed to serialize packs list: {e}"))?
         );
     } else {
@@ -61,27 +121,14 @@ fn run_plugins_packs_manifest(resolved: &ManifestResolved, json: bool) -> Result
     Ok(0)
 }
 
-fn run_plugins_info_manifest(resolved: &ManifestResolved, args: &[String]) -> Result<i32, String> {
-    let plugin_query = args
-        .iter()
-        .find(|a| !a.starts_with("--"))
-        .cloned()
-        .ok_or_else(|| "plugins info requires plugin id".to_string())?;
-    let mut plugin_id = plugin_query.clone();
-    if plugin_by_id(&resolved.manifest, &plugin_id).is_none() {
-        for plugin in &resolved.manifest.plugins {
-            if plugin.aliases.iter().any(|a| a == &plugin_query) {
-                plugin_id = plugin.id.clone();
-                break;
-            }
-        }
-    }
-    let Some(plugin) = plugin_by_id(&resolved.manifest, &plugin_id) else {
-        return Err(format!("unknown plugin: {plugin_query}"));
-    };
-    let payload = serde_json::json!({
+fn plugin_info_payload(
+    resolved: &ManifestResolved,
+    plugin: &RegistryPluginV1,
+    queried_as: &str,
+) -> serde_json::Value {
+    serde_json::json!({
         "id": plugin.id,
-        "queried_as": plugin_query,
+        "queried_as": queried_as,
         "aliases": plugin.aliases,
         "version": plugin.package.version,
         "tier": plugin.tier,
@@ -96,10 +143,164 @@ fn run_plugins_info_manifest(resolved: &ManifestResolved, args: &[String]) -> Re
         "registry_version": resolved.manifest.registry_version,
         "manifest_sha256": resolved.manifest_sha256,
         "signature_key_id": resolved.signature_key_id,
-    });
+    })
+}
+
+/// Lockfile-style `{path -> sha256}` for a plugin's current files in the cached registry.
+fn registry_plugin_file_hashes(
+    src: &Path,
+    plugin_id: &str,
+) -> Result<BTreeMap<String, String>, String> {
+    if !src.is_dir() {
+        return Err(format!(
+            "plugin directory missing in registry cache: {}",
+            src.display()
+        ));
+    }
+    let mut out: BTreeMap<String, String> = BTreeMap::new();
+    for entry in WalkDir::new(src) {
+        let entry = entry.map_err(|e| format!("failed to walk {}: {e}", src.display()))?;
+        if entry.file_type().is_symlink() {
+            return Err(format!(
+                "symlink entries are forbidden inside plugin packages: {}",
+                entry.path().display()
+            ));
+        }
+        let rel = entry
+            .path()
+            .strip_prefix(src)
+            .map_err(|e| format!("failed to relativize {}: {e}", entry.path().display()))?;
+        if !entry.file_type().is_file() || is_plugin_cache_artifact(rel) {
+            continue;
+        }
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        out.insert(
+            format!(".agents/mcp/compas/plugins/{plugin_id}/{rel}"),
+            sha256_file(entry.path())?,
+        );
+    }
+    Ok(out)
+}
+
+/// Compares the locked (installed) files of `plugin` with its files in the current manifest.
+fn installed_diff_payload(
+    resolved: &ManifestResolved,
+    plugin: &RegistryPluginV1,
+    lockfile: Option<&PluginsLockfileV1>,
+    registry_root: Option<&Path>,
+) -> Result<serde_json::Value, String> {
+    let (Some(lockfile), Some(registry_root)) = (
+        lockfile.filter(|l| l.plugins.contains(&plugin.id)),
+        registry_root,
+    ) else {
+        return Ok(serde_json::json!({
+            "status": "not_installed",
+            "manifest_registry_version": resolved.manifest.registry_version,
+            "manifest_version": plugin.package.version,
+        }));
+    };
+
+    let installed: BTreeMap<&str, &str> = lockfile
+        .files
+        .iter()
+        .filter(|e| e.plugin_ids.contains(&plugin.id))
+        .map(|e| (e.path.as_str(), e.sha256.as_str()))
+        .collect();
+    let src = registry_root.join(safe_relative_path(&plugin.path)?);
+    let current = registry_plugin_file_hashes(&src, &plugin.id)?;
+
+    let mut changed: Vec<&str> = vec![];
+    let mut added: Vec<&str> = vec![];
+    for (path, sha) in &current {
+        match installed.get(path.as_str()) {
+            Some(locked) if locked == sha => {}
+            Some(_) => changed.push(path),
+            None => added.push(path),
+        }
+    }
+    let removed: Vec<&str> = installed
+        .keys()
+        .filter(|path| !current.contains_key(**path))
+        .copied()
+        .collect();
+    let up_to_date = changed.is_empty() && added.is_empty() && removed.is_empty();
+
+    Ok(serde_json::json!({
+        "status": if up_to_date { "up_to_date" } else { "outdated" },
+        "installed_registry_version": lockfile.registry_version,
+        "manifest_registry_version": resolved.manifest.registry_version,
+        "manifest_version": plugin.package.version,
+        "changed_files": changed,
+        "added_files": added,
+        "removed_files": removed,
+    }))
+}
+
+async fn run_plugins_info_manifest(
+    resolved: &ManifestResolved,
+    parsed: &PluginsCli,
+) -> Result<i32, String> {
+    let args = &parsed.installer_args;
+    let mut payload = if parse_bool_flag(args, "--all") {
+        serde_json::Value::Array(
+            resolved
+                .manifest
+                .plugins
+                .iter()
+                .map(|plugin| plugin_info_payload(resolved, plugin, &plugin.id))
+                .collect(),
+        )
+    } else {
+        let plugin_query = args
+            .iter()
+            .find(|a| !a.starts_with("--"))
+            .cloned()
+            .ok_or_else(|| "plugins info requires plugin id (or --all)".to_string())?;
+        let mut plugin_id = plugin_query.clone();
+        if plugin_by_id(&resolved.manifest, &plugin_id).is_none() {
+            for plugin in &resolved.manifest.plugins {
+                if plugin.aliases.iter().any(|a| a == &plugin_query) {
+                    plugin_id = plugin.id.clone();
+                    break;
+                }
+            }
+        }
+        let Some(plugin) = plugin_by_id(&resolved.manifest, &plugin_id) else {
+            return Err(format!("unknown plugin: {plugin_query}"));
+        };
+        plugin_info_payload(resolved, plugin, &plugin_query)
+    };
+
+    if parse_bool_flag(args, "--diff-installed") {
+        let lockfile = read_plugins_lockfile(Path::new(&parsed.repo_root))?;
+        let any_installed = lockfile.as_ref().is_some_and(|l| !l.plugins.is_empty());
+        // The registry archive is only fetched when there is something installed to compare.
+        let registry_root = if any_installed {
+            Some(ensure_registry_cached(resolved, parse_bool_flag(args, FLAG_OFFLINE)).await?)
+        } else {
+            None
+        };
+        let rows: Vec<&mut serde_json::Value> = match &mut payload {
+            serde_json::Value::Array(rows) => rows.iter_mut().collect(),
+            row => vec![row],
+        };
+        for row in rows {
+            let id = row["id"].as_str().unwrap_or_default().to_string();
+            let Some(plugin) = plugin_by_id(&resolved.manifest, &id) else {
+                continue;
+            };
+            row["installed"] = installed_diff_payload(
+                resolved,
+                plugin,
+                lockfile.as_ref(),
+                registry_root.as_deref(),
+            )?;
+        }
+    }
+
     println!(
         "{}",
-        serde_json::to_string_pretty(&payload)
+        render_json(&payload, parsed.json_style)
             .map_err(|e| format!("failed to serialize plugin info: {e}"))?
     );
     Ok(0)
@@ -141,16 +342,40 @@ fn governance_block_reason(
     }
 }
 
+/// `compat.min_compas_version` of a plugin this compas build is older than, if any.
+fn compas_version_block_reason(plugin: &RegistryPluginV1, running: &str) -> Option<String> {
+    let min = plugin
+        .compat
+        .as_ref()?
+        .min_compas_version
+        .as_deref()?
+        .trim();
+    match (semver::Version::parse(running), semver::Version::parse(min)) {
+        (Ok(running), Ok(required)) if running >= required => None,
+        _ => Some(min.to_string()),
+    }
+}
+
 include!("ops/install_ops.inc.rs");
+include!("ops/doctor_fix.inc.rs");
 
-fn run_plugins_doctor_manifest(
+async fn run_plugins_doctor_manifest(
     resolved: &ManifestResolved,
     parsed: &PluginsCli,
 ) -> Result<i32, String> {
     let repo_root = PathBuf::from(&parsed.repo_root);
+    let fix = parse_bool_flag(&parsed.installer_args, "--fix");
+    if fix && !parse_bool_flag(&parsed.installer_args, "--admin-lane") {
+        return Err(
+            "plugins doctor --fix requires explicit --admin-lane (fail-closed)".to_string(),
+        );
+    }
+    let _lock = if fix {
+        Some(acquire_plugins_op_lock(&repo_root)?)
+    } else {
+        None
+    };
     let lockfile = read_plugins_lockfile(&repo_root)?;
-    let mut missing: Vec<String> = vec![];
-    let mut modified: Vec<String> = vec![];
     let mut unknown: Vec<String> = vec![];
 
     let Some(lockfile) = lockfile else {
@@ -161,34 +386,21 @@ fn run_plugins_doctor_manifest(
         });
         println!(
             "{}",
-            serde_json::to_string_pretty(&payload).unwrap_or_else(|_| "{}".to_string())
+            render_json(&payload, parsed.json_style).unwrap_or_else(|_| "{}".to_string())
         );
         return Ok(1);
     };
 
-    let mut locked_paths: BTreeSet<String> = BTreeSet::new();
-    for entry in &lockfile.files {
-        locked_paths.insert(entry.path.clone());
-        let rel = safe_relative_path(&entry.path)?;
-        let abs = repo_root.join(rel);
-        if !abs.exists() {
-            missing.push(entry.path.clone());
-            continue;
-        }
-        let meta = fs::symlink_metadata(&abs)
-            .map_err(|e| format!("failed to stat {}: {e}", abs.display()))?;
-        if meta.file_type().is_symlink() {
-            modified.push(entry.path.clone());
-            continue;
-        }
-        if meta.is_file() {
-            let actual = sha256_file(&abs)?;
-            if actual != entry.sha256 {
-                modified.push(entry.path.clone());
-            }
-            continue;
-        }
-        modified.push(entry.path.clone());
+    let locked_paths: BTreeSet<String> = lockfile.files.iter().map(|e| e.path.clone()).collect();
+    let (mut missing, mut modified) = verify_locked_files(&repo_root, &lockfile)?;
+    let mut fix_outcome: Option<DoctorFixOutcome> = None;
+    if fix {
+        let drifted: Vec<String> = missing.iter().chain(&modified).cloned().collect();
+        let offline = parse_bool_flag(&parsed.installer_args, FLAG_OFFLINE);
+        fix_outcome = Some(
+            doctor_fix_locked_files(resolved, &repo_root, &lockfile, &drifted, offline).await?,
+        );
+        (missing, modified) = verify_locked_files(&repo_root, &lockfile)?;
     }
 
     let plugins_root = repo_root.join(".agents/mcp/compas/plugins");
@@ -215,7 +427,7 @@ fn run_plugins_doctor_manifest(
     modified.sort();
     unknown.sort();
     let ok = missing.is_empty() && modified.is_empty() && unknown.is_empty();
-    let payload = serde_json::json!({
+    let mut payload = serde_json::json!({
         "ok": ok,
         "repo_root": repo_root,
         "registry_source": lockfile.registry_source,
@@ -224,31 +436,180 @@ fn run_plugins_doctor_manifest(
         "lockfile_signature_key_id": lockfile.signature_key_id,
         "plugins": lockfile.plugins,
         "packs": lockfile.packs,
+        "frozen": lockfile.frozen,
         "missing_files": missing,
         "modified_files": modified,
         "unknown_files": unknown,
         "resolved_manifest_sha256": resolved.manifest_sha256,
         "resolved_signature_key_id": resolved.signature_key_id,
     });
+    if let Some(outcome) = fix_outcome {
+        payload["fix"] = serde_json::json!({
+            "restored_files": outcome.restored,
+            "unfixable_files": outcome.unfixable,
+        });
+    }
     println!(
         "{}",
-        serde_json::to_string_pretty(&payload)
+        render_json(&payload, parsed.json_style)
             .map_err(|e| format!("failed to serialize doctor summary: {e}"))?
     );
     Ok(if ok { 0 } else { 1 })
 }
 
+fn run_plugins_freeze_manifest(parsed: &PluginsCli) -> Result<i32, String> {
+    let repo_root = PathBuf::from(&parsed.repo_root);
+    let _lock = acquire_plugins_op_lock(&repo_root)?;
+    let mut lockfile = read_plugins_lockfile(&repo_root)?.ok_or_else(|| {
+        format!(
+            "plugins freeze requires lockfile at {}",
+            plugins_lockfile_path(&repo_root).display()
+        )
+    })?;
+
+    let (mut missing, mut modified) = verify_locked_files(&repo_root, &lockfile)?;
+    missing.sort();
+    modified.sort();
+    let ok = missing.is_empty() && modified.is_empty();
+    if ok {
+        lockfile.frozen = true;
+        write_plugins_lockfile(&repo_root, &lockfile)?;
+        let lock_bytes = fs::read(plugins_lockfile_path(&repo_root)).map_err(|e| {
+            format!(
+                "failed to read {}: {e}",
+                plugins_lockfile_path(&repo_root).display()
+            )
+        })?;
+        let marker = serde_json::json!({
+            "schema": "compas.plugins.lock.frozen.v1",
+            "lockfile_sha256": sha256_hex(&lock_bytes),
+            "plugins": lockfile.plugins,
+            "packs": lockfile.packs,
+            "file_count": lockfile.files.len(),
+        });
+        let marker_json = serde_json::to_string_pretty(&marker)
+            .map_err(|e| format!("failed to serialize frozen lock marker: {e}"))?;
+        write_file_atomic(
+            &plugins_lock_frozen_path(&repo_root),
+            format!("{marker_json}\n").as_bytes(),
+        )?;
+    }
+
+    let payload = serde_json::json!({
+        "ok": ok,
+        "frozen": ok,
+        "repo_root": repo_root,
+        "plugins": lockfile.plugins,
+        "packs": lockfile.packs,
+        "missing_files": missing,
+        "modified_files": modified,
+        "lockfile_path": plugins_lockfile_path(&repo_root),
+        "frozen_path": plugins_lock_frozen_path(&repo_root),
+    });
+    println!(
+        "{}",
+        render_json(&payload, parsed.json_style)
+            .map_err(|e| format!("failed to serialize freeze summary: {e}"))?
+    );
+    Ok(if ok { 0 } else { 1 })
+}
+
+fn shell_quote(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:@=,+".contains(c));
+    if plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Install argv (without the program name) that recreates the locked plugin/pack set.
+fn export_install_args(lockfile: &PluginsLockfileV1) -> Vec<String> {
+    let mut args = vec![
+        "plugins".to_string(),
+        "install".to_string(),
+        "--admin-lane".to_string(),
+        "--registry".to_string(),
+        lockfile.registry_source.clone(),
+    ];
+    with_csv_flag(&mut args, "--plugins", &lockfile.plugins);
+    with_csv_flag(&mut args, "--packs", &lockfile.packs);
+    if lockfile.signature_key_id.is_none() {
+        args.push("--allow-unsigned".to_string());
+    }
+    args
+}
+
+fn run_plugins_export(parsed: &PluginsCli) -> Result<i32, String> {
+    let format = parse_value_flag(&parsed.installer_args, "--format")?
+        .unwrap_or_else(|| "requirements".to_string());
+    if format != "requirements" && format != "json" {
+        return Err(format!(
+            "plugins export --format expects requirements|json, got {format:?}"
+        ));
+    }
+    let repo_root = PathBuf::from(&parsed.repo_root);
+    let lockfile = read_plugins_lockfile(&repo_root)?.ok_or_else(|| {
+        format!(
+            "plugins export requires lockfile at {}",
+            plugins_lockfile_path(&repo_root).display()
+        )
+    })?;
+    let args = export_install_args(&lockfile);
+    let command = std::iter::once("compas".to_string())
+        .chain(args.iter().map(|a| shell_quote(a)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if format == "json" {
+        let payload = serde_json::json!({
+            "ok": true,
+            "registry_source": lockfile.registry_source,
+            "registry_version": lockfile.registry_version,
+            "manifest_sha256": lockfile.manifest_sha256,
+            "signature_key_id": lockfile.signature_key_id,
+            "plugins": lockfile.plugins,
+            "packs": lockfile.packs,
+            "args": args,
+            "command": command,
+        });
+        println!(
+            "{}",
+            render_json(&payload, parsed.json_style)
+                .map_err(|e| format!("failed to serialize export payload: {e}"))?
+        );
+    } else {
+        println!("# registry_version: {}", lockfile.registry_version);
+        if let Some(sha) = &lockfile.manifest_sha256 {
+            println!("# manifest_sha256: {sha}");
+        }
+        if let Some(key_id) = &lockfile.signature_key_id {
+            println!("# signature_key_id: {key_id}");
+        }
+        println!("{command}");
+    }
+    Ok(0)
+}
+
 pub(crate) async fn run_plugins_cli(parsed: &PluginsCli) -> Result<i32, String> {
     ensure_admin_lane(parsed.action, &parsed.installer_args)?;
+    if matches!(parsed.action, PluginsAction::Export) {
+        return run_plugins_export(parsed);
+    }
     let resolved = load_verified_manifest(parsed).await?;
     let json = parse_bool_flag(&parsed.installer_args, "--json");
     match parsed.action {
-        PluginsAction::List => run_plugins_list_manifest(&resolved, json),
-        PluginsAction::Packs => run_plugins_packs_manifest(&resolved, json),
-        PluginsAction::Info => run_plugins_info_manifest(&resolved, &parsed.installer_args),
+        PluginsAction::List => run_plugins_list_manifest(&resolved, parsed, json),
+        PluginsAction::Packs => run_plugins_packs_manifest(&resolved, json, parsed.json_style),
+        PluginsAction::Info => run_plugins_info_manifest(&resolved, parsed).await,
         PluginsAction::Install => run_plugins_install_manifest(&resolved, parsed).await,
         PluginsAction::Update => run_plugins_update_manifest(&resolved, parsed).await,
-        PluginsAction::Doctor => run_plugins_doctor_manifest(&resolved, parsed),
+        PluginsAction::Doctor => run_plugins_doctor_manifest(&resolved, parsed).await,
         PluginsAction::Uninstall => run_plugins_uninstall_manifest(&resolved, parsed),
+        PluginsAction::Freeze => run_plugins_freeze_manifest(parsed),
+        PluginsAction::Export => run_plugins_export(parsed),
     }
 }
@@ -0,0 +1,181 @@
+/// Result of `plugins doctor --fix`: restored paths and the drifted paths left for manual review.
+struct DoctorFixOutcome {
+    restored: Vec<String>,
+    unfixable: Vec<serde_json::Value>,
+}
+
+/// Registry-cache source of a locked file, resolved through its first owner still in the manifest.
+fn locked_file_cache_source(
+    resolved: &ManifestResolved,
+    registry_root: &Path,
+    entry: &PluginsLockfileEntryV1,
+) -> Result<PathBuf, String> {
+    for pid in &entry.plugin_ids {
+        let Some(plugin) = plugin_by_id(&resolved.manifest, pid) else {
+            continue;
+        };
+        let prefix = format!(".agents/mcp/compas/plugins/{pid}/");
+        let Some(rel) = entry.path.strip_prefix(&prefix) else {
+            continue;
+        };
+        return Ok(registry_root
+            .join(safe_relative_path(&plugin.path)?)
+            .join(safe_relative_path(rel)?));
+    }
+    Err("no owning plugin in the current registry manifest".to_string())
+}
+
+/// Why a drifted path cannot be replaced in place, if it cannot: directories may hold unknown
+/// files, and symlinked or non-directory parents would redirect the write.
+fn doctor_fix_blocker(repo_root: &Path, rel: &Path) -> Option<String> {
+    let mut cur = repo_root.to_path_buf();
+    let parents: Vec<_> = rel.parent().into_iter().flat_map(Path::components).collect();
+    for component in parents {
+        cur.push(component);
+        match fs::symlink_metadata(&cur) {
+            Ok(meta) if meta.file_type().is_symlink() => {
+                return Some(format!("parent path is a symlink: {}", cur.display()));
+            }
+            Ok(meta) if !meta.is_dir() => {
+                return Some(format!("parent path is not a directory: {}", cur.display()));
+            }
+            _ => {}
+        }
+    }
+    match fs::symlink_metadata(repo_root.join(rel)) {
+        Ok(meta) if meta.is_dir() => {
+            Some("path is a directory; review its contents manually".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Restores `drifted` locked files from the verified registry cache (fetched if needed, unless
+/// `offline`).
+///
+/// Every replacement is staged and hash-checked against the lockfile first; existing drifted
+/// paths are moved to a backup and the staged files renamed into place, rolling back on failure.
+async fn doctor_fix_locked_files(
+    resolved: &ManifestResolved,
+    repo_root: &Path,
+    lockfile: &PluginsLockfileV1,
+    drifted: &[String],
+    offline: bool,
+) -> Result<DoctorFixOutcome, String> {
+    let mut outcome = DoctorFixOutcome {
+        restored: vec![],
+        unfixable: vec![],
+    };
+    if drifted.is_empty() {
+        return Ok(outcome);
+    }
+    let entries: BTreeMap<&str, &PluginsLockfileEntryV1> = lockfile
+        .files
+        .iter()
+        .map(|e| (e.path.as_str(), e))
+        .collect();
+    let registry_root = ensure_registry_cached(resolved, offline).await?;
+    let staging_root = repo_root
+        .join(".agents/mcp/compas/plugins/.staging")
+        .join(format!("doctor-{}", op_nonce()));
+    let staged_root = staging_root.join("files");
+    let backups_root = staging_root.join("backups");
+
+    let mut staged: Vec<(String, PathBuf)> = vec![];
+    for path in drifted {
+        let Some(entry) = entries.get(path.as_str()) else {
+            continue;
+        };
+        let mut skip = |reason: String| {
+            outcome
+                .unfixable
+                .push(serde_json::json!({ "path": path, "reason": reason }));
+        };
+        if !entry.plugin_ids.iter().any(|p| lockfile.plugins.contains(p)) {
+            skip("not owned by a locked plugin".to_string());
+            continue;
+        }
+        let rel = safe_relative_path(&entry.path)?;
+        if let Some(reason) = doctor_fix_blocker(repo_root, &rel) {
+            skip(reason);
+            continue;
+        }
+        let src = match locked_file_cache_source(resolved, &registry_root, entry) {
+            Ok(src) => src,
+            Err(reason) => {
+                skip(reason);
+                continue;
+            }
+        };
+        if !fs::symlink_metadata(&src).is_ok_and(|m| m.is_file()) {
+            skip(format!("missing in registry cache: {}", src.display()));
+            continue;
+        }
+        let stage = staged_root.join(&rel);
+        if let Some(parent) = stage.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+        }
+        let actual = copy_file_hashed(&src, &stage)?;
+        if actual != entry.sha256 {
+            let _ = fs::remove_file(&stage);
+            skip(format!(
+                "registry cache has sha256 {actual}, lockfile expects {}; run plugins update",
+                entry.sha256
+            ));
+            continue;
+        }
+        staged.push((entry.path.clone(), stage));
+    }
+
+    let mut moved: Vec<(PathBuf, PathBuf)> = vec![];
+    let mut created: Vec<PathBuf> = vec![];
+    let swap_result: Result<(), String> = (|| {
+        for (path, stage) in &staged {
+            let dst = repo_root.join(path);
+            if fs::symlink_metadata(&dst).is_ok() {
+                let backup = backups_root.join(path);
+                if let Some(parent) = backup.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+                }
+                fs::rename(&dst, &backup).map_err(|e| {
+                    format!(
+                        "failed to move drifted {} to backup {}: {e}",
+                        dst.display(),
+                        backup.display()
+                    )
+                })?;
+                moved.push((dst.clone(), backup));
+            } else {
+                if let Some(parent) = dst.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+                }
+                created.push(dst.clone());
+            }
+            fs::rename(stage, &dst).map_err(|e| {
+                format!(
+                    "failed to restore {} from staged {}: {e}",
+                    dst.display(),
+                    stage.display()
+                )
+            })?;
+        }
+        Ok(())
+    })();
+    if let Err(swap_err) = swap_result {
+        for dst in created.iter().rev() {
+            let _ = fs::remove_file(dst);
+        }
+        rollback_moved_paths(&moved, repo_root);
+        let _ = fs::remove_dir_all(&staging_root);
+        return Err(format!(
+            "plugins doctor --fix aborted; rollback executed: {swap_err}"
+        ));
+    }
+    let _ = fs::remove_dir_all(&staging_root);
+
+    outcome.restored = staged.into_iter().map(|(path, _)| path).collect();
+    Ok(outcome)
+}
@@ -9,6 +9,7 @@ async fn run_plugins_install_manifest(
     let pack_inputs = parse_csv_flag(&parsed.installer_args, "--packs")?.unwrap_or_default();
     let dry_run = parse_bool_flag(&parsed.installer_args, "--dry-run");
     let force = parse_bool_flag(&parsed.installer_args, "--force");
+    let unfreeze = parse_bool_flag(&parsed.installer_args, FLAG_UNFREEZE);
     let allow_experimental = parse_bool_flag(&parsed.installer_args, "--allow-experimental");
     let allow_sunset_compat = parse_bool_flag(&parsed.installer_args, FLAG_ALLOW_SUNSET_COMPAT);
     let allow_sunset =
@@ -65,12 +66,58 @@ async fn run_plugins_install_manifest(
         });
         println!(
             "{}",
-            serde_json::to_string_pretty(&payload)
+            render_json(&payload, parsed.json_style)
                 .map_err(|e| format!("failed to serialize install summary: {e}"))?
         );
         return Ok(1);
     }
 
+    let compas_version = env!("CARGO_PKG_VERSION");
+    let mut incompatible_plugins: Vec<serde_json::Value> = vec![];
+    for pid in &plugin_ids {
+        if let Some(plugin) = plugin_by_id(&resolved.manifest, pid)
+            && let Some(min) = compas_version_block_reason(plugin, compas_version)
+        {
+            incompatible_plugins.push(serde_json::json!({
+                "id": plugin.id,
+                "min_compas_version": min,
+            }));
+        }
+    }
+    if !incompatible_plugins.is_empty() {
+        if !force {
+            let payload = serde_json::json!({
+                "ok": false,
+                "code": "plugins.incompatible_compas_version",
+                "dry_run": dry_run,
+                "force": force,
+                "blocked": true,
+                "repo_root": repo_root,
+                "registry_version": resolved.manifest.registry_version,
+                "manifest_sha256": resolved.manifest_sha256,
+                "signature_key_id": resolved.signature_key_id,
+                "plugins": plugin_ids,
+                "packs": pack_inputs,
+                "compat": {
+                    "compas_version": compas_version,
+                    "incompatible_plugins": incompatible_plugins,
+                },
+                "hint": "upgrade compas, or rerun with --force to install anyway",
+                "lockfile_path": plugins_lockfile_path(&repo_root),
+            });
+            println!(
+                "{}",
+                render_json(&payload, parsed.json_style)
+                    .map_err(|e| format!("failed to serialize install summary: {e}"))?
+            );
+            return Ok(1);
+        }
+        eprintln!(
+            "compas: --force installs {} plugin(s) requiring a newer compas than {compas_version}.",
+            incompatible_plugins.len()
+        );
+    }
+
     let plugin_target_set: BTreeSet<String> = plugin_ids.iter().cloned().collect();
     let plugins_root = repo_root.join(".agents/mcp/compas/plugins");
     let existing_lockfile = read_plugins_lockfile(&repo_root)?;
@@ -127,8 +174,7 @@ async fn run_plugins_install_manifest(
             {
                 continue;
             }
-            let rel = safe_relative_path(&entry.path)?;
-            let abs = repo_root.join(&rel);
+            let abs = repo_file_path(&repo_root, &entry.path)?;
             if !abs.exists() {
                 missing_files.push(entry.path.clone());
                 continue;
@@ -199,13 +245,18 @@ async fn run_plugins_install_manifest(
         });
         println!(
             "{}",
-            serde_json::to_string_pretty(&payload)
+            render_json(&payload, parsed.json_style)
                 .map_err(|e| format!("failed to serialize install summary: {e}"))?
         );
         return Ok(1);
     }
 
-    let registry_root = ensure_registry_cached(resolved).await?;
+    let install_order = plugin_install_order(&resolved.manifest, &plugin_ids)?;
+    let registry_root = ensure_registry_cached(
+        resolved,
+        parse_bool_flag(&parsed.installer_args, FLAG_OFFLINE),
+    )
+    .await?;
     let plugins_root = repo_root.join(".agents/mcp/compas/plugins");
     let staging_root = repo_root
         .join(".agents/mcp/compas/plugins/.staging")
@@ -219,7 +270,7 @@ async fn run_plugins_install_manifest(
 
     let mut staged_lock_entries: Vec<PluginsLockfileEntryV1> = vec![];
     let mut installed: Vec<String> = vec![];
-    for pid in &plugin_ids {
+    for pid in &install_order {
         let Some(plugin) = plugin_by_id(&resolved.manifest, pid) else {
             return Err(format!("plugin not found in manifest: {pid}"));
         };
@@ -249,7 +300,7 @@ async fn run_plugins_install_manifest(
                 .into_iter()
                 .filter(|p| !plugin_target_set.contains(p)),
         );
-        final_packs.extend(existing.packs.into_iter());
+        final_packs.extend(existing.packs);
         final_lock_entries.extend(
             existing
                 .files
@@ -288,6 +339,15 @@ async fn run_plugins_install_manifest(
     final_packs = dedupe_strings(final_packs);
     final_packs.sort();
 
+    let keep_frozen = existing_lockfile.as_ref().is_some_and(|l| l.frozen) && !unfreeze;
+    if keep_frozen
+        && let Some(existing) = &existing_lockfile
+        && lock_set_changed(existing, &final_plugins, &final_packs, &merged_entries)
+    {
+        let _ = fs::remove_dir_all(&staging_root);
+        return Err(frozen_lock_error(&repo_root, "install/update"));
+    }
+
     if !dry_run {
         fs::create_dir_all(&plugins_root).map_err(|e| {
             format!(
@@ -358,8 +418,16 @@ async fn run_plugins_install_manifest(
             plugins: final_plugins.clone(),
             packs: final_packs.clone(),
             files: merged_entries.clone(),
+            frozen: keep_frozen,
         };
-        if let Err(lock_err) = write_plugins_lockfile(&repo_root, &lockfile) {
+        let lock_result = write_plugins_lockfile(&repo_root, &lockfile).and_then(|()| {
+            if keep_frozen {
+                Ok(())
+            } else {
+                remove_plugins_lock_frozen(&repo_root)
+            }
+        });
+        if let Err(lock_err) = lock_result {
             for pid in installed.iter().rev() {
                 let dst_dir = plugins_root.join(pid);
                 let backup_dir = staging_backups_root.join(pid);
@@ -387,7 +455,8 @@ async fn run_plugins_install_manifest(
         "registry_version": resolved.manifest.registry_version,
         "manifest_sha256": resolved.manifest_sha256,
         "signature_key_id": resolved.signature_key_id,
-        "plugins": installed,
+        "plugins": plugin_ids,
+        "install_order": install_order,
         "packs": final_packs,
         "file_count": merged_entries.len(),
         "preflight": {
@@ -400,7 +469,7 @@ async fn run_plugins_install_manifest(
     });
     println!(
         "{}",
-        serde_json::to_string_pretty(&payload)
+        render_json(&payload, parsed.json_style)
             .map_err(|e| format!("failed to serialize install summary: {e}"))?
     );
     Ok(0)
@@ -415,11 +484,12 @@ async fn run_plugins_update_manifest(
     let mut plugin_inputs =
         parse_csv_flag(&parsed.installer_args, "--plugins")?.unwrap_or_default();
     let mut pack_inputs = parse_csv_flag(&parsed.installer_args, "--packs")?.unwrap_or_default();
-    if plugin_inputs.is_empty() && pack_inputs.is_empty() {
-        if let Some(lockfile) = read_plugins_lockfile(&repo_root)? {
-            plugin_inputs = lockfile.plugins;
-            pack_inputs = lockfile.packs;
-        }
+    if plugin_inputs.is_empty()
+        && pack_inputs.is_empty()
+        && let Some(lockfile) = read_plugins_lockfile(&repo_root)?
+    {
+        plugin_inputs = lockfile.plugins;
+        pack_inputs = lockfile.packs;
     }
     let mut installer_args = parsed.installer_args.clone();
     with_csv_flag(&mut installer_args, "--plugins", &plugin_inputs);
@@ -429,6 +499,7 @@ async fn run_plugins_update_manifest(
         registry_source: parsed.registry_source.clone(),
         repo_root: parsed.repo_root.clone(),
         installer_args,
+        json_style: parsed.json_style,
     };
     run_plugins_install_manifest(resolved, &parsed).await
 }
@@ -442,6 +513,8 @@ fn run_plugins_uninstall_manifest(
 
     let dry_run = parse_bool_flag(&parsed.installer_args, "--dry-run");
     let force = parse_bool_flag(&parsed.installer_args, "--force");
+    let unfreeze = parse_bool_flag(&parsed.installer_args, FLAG_UNFREEZE);
+    let purge_cache = parse_bool_flag(&parsed.installer_args, "--purge-cache");
 
     let plugin_inputs = parse_csv_flag(&parsed.installer_args, "--plugins")?.unwrap_or_default();
     let pack_inputs = parse_csv_flag(&parsed.installer_args, "--packs")?.unwrap_or_default();
@@ -464,6 +537,9 @@ fn run_plugins_uninstall_manifest(
         return Err("no plugins selected for uninstall".to_string());
     }
     let target_set: BTreeSet<String> = target_plugin_ids.iter().cloned().collect();
+    if lockfile.frozen && !unfreeze {
+        return Err(frozen_lock_error(&repo_root, "uninstall"));
+    }
 
     let mut planned_remove: Vec<PluginsLockfileEntryV1> = vec![];
     let mut kept_entries: Vec<PluginsLockfileEntryV1> = vec![];
@@ -491,8 +567,7 @@ fn run_plugins_uninstall_manifest(
     let mut removed_files: Vec<String> = vec![];
 
     for entry in &planned_remove {
-        let rel = safe_relative_path(&entry.path)?;
-        let abs = repo_root.join(&rel);
+        let abs = repo_file_path(&repo_root, &entry.path)?;
         if !abs.exists() {
             missing_files.push(entry.path.clone());
             continue;
@@ -537,7 +612,7 @@ fn run_plugins_uninstall_manifest(
         });
         println!(
             "{}",
-            serde_json::to_string_pretty(&payload)
+            render_json(&payload, parsed.json_style)
                 .map_err(|e| format!("failed to serialize uninstall summary: {e}"))?
         );
         return Ok(1);
@@ -545,6 +620,7 @@ fn run_plugins_uninstall_manifest(
 
     let mut updated = lockfile.clone();
     updated.files = kept_entries;
+    updated.frozen = false;
     if !plugin_inputs.is_empty() || !pack_inputs.is_empty() {
         updated.plugins.retain(|p| !target_set.contains(p));
         updated.packs.retain(|p| !pack_inputs.contains(p));
@@ -565,12 +641,11 @@ fn run_plugins_uninstall_manifest(
 
         let mut moved_paths: Vec<(PathBuf, PathBuf)> = vec![];
         for entry in &planned_remove {
-            let rel = safe_relative_path(&entry.path)?;
-            let abs = repo_root.join(&rel);
+            let abs = repo_file_path(&repo_root, &entry.path)?;
             if !abs.exists() {
                 continue;
             }
-            let backup = backups_root.join(&rel);
+            let backup = backups_root.join(safe_relative_path(&entry.path)?);
             if let Some(parent) = backup.parent() {
                 fs::create_dir_all(parent)
                     .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
@@ -606,6 +681,7 @@ fn run_plugins_uninstall_manifest(
                 remove_plugins_lockfile(&repo_root)?;
             } else {
                 write_plugins_lockfile(&repo_root, &updated)?;
+                remove_plugins_lock_frozen(&repo_root)?;
             }
             Ok(())
         })();
@@ -619,6 +695,19 @@ fn run_plugins_uninstall_manifest(
         let _ = fs::remove_dir_all(&staging_root);
     }
 
+    // The cache is shared across repos, so it is only dropped on request and after the
+    // uninstall itself has been committed.
+    let cache_entry = registry_cache_root_for_manifest(resolved);
+    let cache_purged = purge_cache && !dry_run && cache_entry.exists();
+    if cache_purged {
+        fs::remove_dir_all(&cache_entry).map_err(|e| {
+            format!(
+                "uninstall committed, but failed to purge registry cache {}: {e}",
+                cache_entry.display()
+            )
+        })?;
+    }
+
     removed_files = dedupe_strings(removed_files);
     removed_files.sort();
     let ok = true;
@@ -635,10 +724,12 @@ fn run_plugins_uninstall_manifest(
         "lockfile_path": plugins_lockfile_path(&repo_root),
         "lockfile_updated": !dry_run,
         "force": force,
+        "cache_path": cache_entry,
+        "cache_purged": cache_purged,
     });
     println!(
         "{}",
-        serde_json::to_string_pretty(&payload)
+        render_json(&payload, parsed.json_style)
             .map_err(|e| format!("failed to serialize uninstall summary: {e}"))?
     );
     Ok(if ok { 0 } else { 1 })
@@ -1,6 +1,21 @@
+use crate::api::{GateKind, ViolationTier};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+mod checks;
+
+pub use checks::{
+    ArchLayerConfigV2, ArchLayerRuleConfigV2, ArchLayersCheckConfigV2,
+    BinaryArtifactsCheckConfigV2, BoundaryCheckConfigV2, BoundaryRuleConfigV2, ChecksConfigV2,
+    ComplexityBudgetCheckConfigV2, ContractBreakCheckConfigV2, DeadCodeCheckConfigV2,
+    DuplicatesCheckConfigV2, DuplicatesSeverityConfig, EnvRegistryCheckConfigV2,
+    FileEncodingCheckConfigV2, ImportCyclesCheckConfigV2, LocCheckConfigV2, LocMode,
+    MagicNumbersCheckConfigV2, MaxParamsCheckConfigV2, NamingConventionCheckConfigV2,
+    NamingConventionRule, NamingLanguage, OrphanApiCheckConfigV2, ReuseFirstCheckConfigV2,
+    SupplyChainCheckConfigV2, SurfaceCheckConfigV2, SurfaceRuleConfigV2, TestPresenceCheckConfigV2,
+    TestPresenceRuleConfigV2, ToolBudgetCheckConfigV2,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct PluginConfig {
@@ -11,6 +26,17 @@ pub struct PluginConfig {
     pub(crate) tool_policy: ToolExecutionPolicyConfigV2,
     pub gate: Option<GateConfig>,
     pub checks: Option<ChecksConfigV2>,
+    #[serde(default)]
+    pub canonical_tools: Option<PluginCanonicalToolsConfig>,
+}
+
+/// Plugin-level `[canonical_tools]`: only `aliases` (old `tool_id` -> current `tool_id`).
+/// The wiring lists (`build`, `test`, ...) belong to pack manifests and are rejected here.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginCanonicalToolsConfig {
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +60,9 @@ pub struct ProjectTool {
     pub timeout_ms: Option<u64>,
     pub max_stdout_bytes: Option<usize>,
     pub max_stderr_bytes: Option<usize>,
+    /// Receipt tail size for each stream whose `max_*_bytes` is unset (clamped by the runner);
+    /// tool defaults for `max_*_bytes` do not apply to a tool that sets it.
+    pub max_tail_bytes: Option<usize>,
     #[serde(default)]
     pub report: Option<serde_json::Value>,
     #[serde(default)]
@@ -112,261 +141,47 @@ pub struct GateConfig {
     pub flagship: Vec<String>,
 }
 
-// --- checks.v2 (multi-instance) ---
-//
-// NOTE: v2 is intentionally "boring": purely data-driven, deterministic, and fail-closed.
-// Runtime wiring + merge semantics are implemented elsewhere (see TASK-008 in BranchMind).
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ChecksConfigV2 {
-    #[serde(default)]
-    pub loc: Vec<LocCheckConfigV2>,
-    #[serde(default)]
-    pub env_registry: Vec<EnvRegistryCheckConfigV2>,
-    #[serde(default)]
-    pub boundary: Vec<BoundaryCheckConfigV2>,
-    #[serde(default)]
-    pub surface: Vec<SurfaceCheckConfigV2>,
-    #[serde(default)]
-    pub duplicates: Vec<DuplicatesCheckConfigV2>,
-    #[serde(default)]
-    pub supply_chain: Vec<SupplyChainCheckConfigV2>,
-    #[serde(default)]
-    pub tool_budget: Vec<ToolBudgetCheckConfigV2>,
-    #[serde(default)]
-    pub reuse_first: Vec<ReuseFirstCheckConfigV2>,
-    #[serde(default)]
-    pub arch_layers: Vec<ArchLayersCheckConfigV2>,
-    #[serde(default)]
-    pub dead_code: Vec<DeadCodeCheckConfigV2>,
-    #[serde(default)]
-    pub orphan_api: Vec<OrphanApiCheckConfigV2>,
-    #[serde(default)]
-    pub complexity_budget: Vec<ComplexityBudgetCheckConfigV2>,
-    #[serde(default)]
-    pub contract_break: Vec<ContractBreakCheckConfigV2>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct LocCheckConfigV2 {
-    pub id: String,
-    pub max_loc: usize,
-    #[serde(default)]
-    pub include_globs: Vec<String>,
-    #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    pub baseline_path: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct EnvRegistryCheckConfigV2 {
-    pub id: String,
-    pub registry_path: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct BoundaryCheckConfigV2 {
-    pub id: String,
-    #[serde(default)]
-    pub include_globs: Vec<String>,
-    #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    #[serde(default)]
-    pub strip_rust_cfg_test_blocks: bool,
-    #[serde(default)]
-    pub rules: Vec<BoundaryRuleConfigV2>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct BoundaryRuleConfigV2 {
-    pub id: String,
-    pub message: Option<String>,
-    pub deny_regex: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct SurfaceCheckConfigV2 {
-    pub id: String,
-    pub max_items: usize,
-    #[serde(default)]
-    pub include_globs: Vec<String>,
-    #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    #[serde(default)]
-    pub rules: Vec<SurfaceRuleConfigV2>,
-    pub baseline_path: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct SurfaceRuleConfigV2 {
-    /// Optional fine-grained file filter for this rule.
-    #[serde(default)]
-    pub file_globs: Vec<String>,
-    pub regex: String,
-    pub description: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct DuplicatesCheckConfigV2 {
-    pub id: String,
-    #[serde(default)]
-    pub include_globs: Vec<String>,
-    #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    /// Only consider files <= this size (bytes). Keep it small to stay fast and deterministic.
-    pub max_file_bytes: usize,
-    #[serde(default)]
-    pub allowlist_globs: Vec<String>,
-    pub baseline_path: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct SupplyChainCheckConfigV2 {
-    pub id: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ToolBudgetCheckConfigV2 {
-    pub id: String,
-    pub max_tools_total: usize,
-    pub max_tools_per_plugin: usize,
-    pub max_gate_tools_per_kind: usize,
-    pub max_checks_total: usize,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ReuseFirstCheckConfigV2 {
-    pub id: String,
-    #[serde(default)]
-    pub include_globs: Vec<String>,
-    #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    #[serde(default = "default_reuse_min_block_lines")]
-    pub min_block_lines: usize,
-}
-
-const fn default_reuse_min_block_lines() -> usize {
-    6
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(deny_unknown_fields)]
-pub struct ArchLayersCheckConfigV2 {
-    pub id: String,
+pub struct QualityContractConfig {
     #[serde(default)]
-    pub layers: Vec<ArchLayerConfigV2>,
+    pub quality: QualityThresholds,
     #[serde(default)]
-    pub rules: Vec<ArchLayerRuleConfigV2>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ArchLayerConfigV2 {
-    pub id: String,
+    pub exceptions: ExceptionLimits,
     #[serde(default)]
-    pub include_globs: Vec<String>,
+    pub receipt_defaults: ReceiptDefaults,
     #[serde(default)]
-    pub module_prefixes: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ArchLayerRuleConfigV2 {
-    pub from_layer: String,
+    pub governance: GovernanceConfig,
     #[serde(default)]
-    pub deny_to_layers: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct DeadCodeCheckConfigV2 {
-    pub id: String,
+    pub baseline: BaselineConfig,
     #[serde(default)]
-    pub include_globs: Vec<String>,
+    pub proof: ProofConfig,
     #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    #[serde(default = "default_min_symbol_len")]
-    pub min_symbol_len: usize,
+    pub gate: GateRunConfig,
     #[serde(default)]
-    pub blocking: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct OrphanApiCheckConfigV2 {
-    pub id: String,
+    pub tools: ToolsMetadataConfig,
     #[serde(default)]
-    pub include_globs: Vec<String>,
+    pub impact: ImpactConfig,
     #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    #[serde(default = "default_min_symbol_len")]
-    pub min_symbol_len: usize,
+    pub escalation: Vec<EscalationRule>,
     #[serde(default)]
-    pub blocking: bool,
-}
-
-const fn default_min_symbol_len() -> usize {
-    3
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ComplexityBudgetCheckConfigV2 {
-    pub id: String,
+    pub coverage: CoverageConfig,
     #[serde(default)]
-    pub include_globs: Vec<String>,
+    pub reporting: ReportingConfig,
+    /// Pack ids whose checks validate skips (the pack stays installed and lock-validated).
     #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    pub max_function_lines: usize,
-    pub max_cyclomatic: usize,
-    pub max_cognitive: usize,
+    pub disabled_packs: Vec<String>,
 }
 
+/// Promotes a code that is tolerable once but not in bulk: when at least `threshold`
+/// post-suppression violations carry `code`, validate adds one aggregate violation at `tier`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
-pub struct ContractBreakCheckConfigV2 {
-    pub id: String,
-    #[serde(default)]
-    pub include_globs: Vec<String>,
+pub struct EscalationRule {
+    pub code: String,
+    pub threshold: usize,
     #[serde(default)]
-    pub exclude_globs: Vec<String>,
-    pub baseline_path: String,
-    #[serde(default = "default_allow_contract_additions")]
-    pub allow_additions: bool,
-}
-
-const fn default_allow_contract_additions() -> bool {
-    true
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(deny_unknown_fields)]
-pub struct QualityContractConfig {
-    #[serde(default)]
-    pub quality: QualityThresholds,
-    #[serde(default)]
-    pub exceptions: ExceptionLimits,
-    #[serde(default)]
-    pub receipt_defaults: ReceiptDefaults,
-    #[serde(default)]
-    pub governance: GovernanceConfig,
-    #[serde(default)]
-    pub baseline: BaselineConfig,
-    #[serde(default)]
-    pub proof: ProofConfig,
-    #[serde(default)]
-    pub impact: ImpactConfig,
+    pub tier: ViolationTier,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -412,6 +227,9 @@ pub struct ExceptionLimits {
     pub max_suppressed_ratio: f64,
     #[serde(default = "default_max_exception_window_days")]
     pub max_exception_window_days: u32,
+    /// Cap on entries whose remaining window exceeds 80% of `max_exception_window_days`.
+    #[serde(default)]
+    pub max_long_lived_exceptions: Option<usize>,
 }
 
 const fn default_max_exceptions() -> usize {
@@ -430,6 +248,7 @@ impl Default for ExceptionLimits {
             max_exceptions: default_max_exceptions(),
             max_suppressed_ratio: default_max_suppressed_ratio(),
             max_exception_window_days: default_max_exception_window_days(),
+            max_long_lived_exceptions: None,
         }
     }
 }
@@ -469,12 +288,50 @@ pub struct GovernanceConfig {
     #[serde(default = "default_min_failure_modes")]
     pub min_failure_modes: usize,
     pub config_hash: Option<String>,
+    /// Worst display trust grade ratchet/strict accept (`A` best .. `F` worst).
+    #[serde(default)]
+    pub min_trust_grade: Option<TrustGrade>,
+    /// Track every finding's first sighting in `.agents/mcp/compas/findings_ledger.json`.
+    #[serde(default)]
+    pub findings_ledger: bool,
+    /// Only findings absent from the ledger at baseline time block; implies `findings_ledger`.
+    #[serde(default)]
+    pub block_only_new: bool,
+}
+
+impl GovernanceConfig {
+    pub fn ledger_enabled(&self) -> bool {
+        self.findings_ledger || self.block_only_new
+    }
 }
 
 const fn default_min_failure_modes() -> usize {
     8
 }
 
+/// Ordered best-first, so a grade is worse than the floor when `grade > floor`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TrustGrade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl TrustGrade {
+    pub fn parse(letter: &str) -> Option<Self> {
+        match letter {
+            "A" => Some(Self::A),
+            "B" => Some(Self::B),
+            "C" => Some(Self::C),
+            "D" => Some(Self::D),
+            "F" => Some(Self::F),
+            _ => None,
+        }
+    }
+}
+
 impl Default for GovernanceConfig {
     fn default() -> Self {
         Self {
@@ -482,6 +339,9 @@ impl Default for GovernanceConfig {
             mandatory_failure_modes: vec![],
             min_failure_modes: default_min_failure_modes(),
             config_hash: None,
+            min_trust_grade: None,
+            findings_ledger: false,
+            block_only_new: false,
         }
     }
 }
@@ -511,21 +371,100 @@ impl Default for BaselineConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ProofConfig {
-    #[serde(default = "default_require_witness")]
-    pub require_witness: bool,
+    /// `true` forces a witness for every gate kind; unset defers to `require_witness_kinds`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_witness: Option<bool>,
+    /// Gate kinds that must write a witness when `require_witness` is not `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_witness_kinds: Option<Vec<GateKind>>,
+    /// Repo-relative directory for gate witnesses and their chain; `gate --witness-dir` wins.
+    #[serde(default)]
+    pub witness_dir: Option<String>,
+}
+
+impl ProofConfig {
+    /// Whether a gate of `kind` must write a witness. With neither knob set every kind does.
+    pub fn witness_required(&self, kind: GateKind) -> bool {
+        match (self.require_witness, &self.require_witness_kinds) {
+            (Some(true), _) => true,
+            (_, Some(kinds)) => kinds.contains(&kind),
+            (Some(false), None) => false,
+            (None, None) => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GateRunConfig {
+    /// Stop the tool sequence at the first failing tool; `false` runs every tool so all
+    /// receipts are collected (the verdict still blocks). `gate --no-fail-fast` wins.
+    #[serde(default = "default_fail_fast")]
+    pub fail_fast: bool,
+    #[serde(default)]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// Run the gate's validate(ratchet) preflight without the display insights: the embedded
+    /// `validate` keeps every violation and `findings_v2` (so the verdict is unchanged) but
+    /// skips risk, coverage, trust, quality posture and the agent digest.
+    #[serde(default)]
+    pub preflight_lean: bool,
 }
 
-const fn default_require_witness() -> bool {
+/// `[gate] circuit_breaker = { failures, window_min }`: after `failures` failed runs of one gate
+/// kind within `window_min` minutes, further runs short-circuit with `gate.circuit_open`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CircuitBreakerConfig {
+    pub failures: usize,
+    pub window_min: u64,
+}
+
+const fn default_fail_fast() -> bool {
     true
 }
 
-impl Default for ProofConfig {
+impl Default for GateRunConfig {
     fn default() -> Self {
         Self {
-            require_witness: default_require_witness(),
+            fail_fast: default_fail_fast(),
+            circuit_breaker: None,
+            preflight_lean: false,
+        }
+    }
+}
+
+/// `[tools]`: documentation floor for the tool catalog (`tools.metadata_incomplete`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolsMetadataConfig {
+    /// Minimum trimmed description length in chars; the plugin loader already rejects < 12.
+    #[serde(default = "default_min_description_len")]
+    pub min_description_len: usize,
+    #[serde(default)]
+    pub defaults: ToolDefaultsConfig,
+}
+
+/// `[tools.defaults]`: limits the loader fills in for tools that omit them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolDefaultsConfig {
+    pub timeout_ms: Option<u64>,
+    pub max_stdout_bytes: Option<usize>,
+    pub max_stderr_bytes: Option<usize>,
+}
+
+const fn default_min_description_len() -> usize {
+    12
+}
+
+impl Default for ToolsMetadataConfig {
+    fn default() -> Self {
+        Self {
+            min_description_len: default_min_description_len(),
+            defaults: ToolDefaultsConfig::default(),
         }
     }
 }
@@ -559,6 +498,28 @@ pub struct ImpactConfig {
     pub unmapped_path_policy: ImpactUnmappedPathPolicy,
     #[serde(default)]
     pub rules: Vec<ImpactRule>,
+    /// Extra `merge-base` targets (e.g. `develop`, `trunk`) tried before the built-in list.
+    #[serde(default)]
+    pub extra_base_candidates: Vec<String>,
+}
+
+/// Extra failure-mode coverage on top of the built-in check mapping: a mode counts as covered
+/// when any listed entry names a configured check kind, check id, or tool id. Only custom
+/// catalog modes can be mapped; built-in and governance-mandatory ones are ignored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CoverageConfig {
+    #[serde(default)]
+    pub mappings: BTreeMap<String, Vec<String>>,
+}
+
+/// Output shaping for validate: past `swamped_threshold` post-suppression violations the
+/// payload collapses to per-category counts and a few examples (the verdict is unaffected).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReportingConfig {
+    #[serde(default)]
+    pub swamped_threshold: Option<usize>,
 }
 
 fn default_impact_diff_base() -> String {
@@ -571,6 +532,7 @@ impl Default for ImpactConfig {
             diff_base: default_impact_diff_base(),
             unmapped_path_policy: ImpactUnmappedPathPolicy::default(),
             rules: vec![],
+            extra_base_candidates: vec![],
         }
     }
 }
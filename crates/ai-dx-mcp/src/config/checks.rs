@@ -0,0 +1,519 @@
+use crate::api::{GateKind, ViolationTier};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How `[[checks.loc]]` counts lines: `raw` = non-blank lines, `sloc` = non-blank lines left
+/// after stripping comments (extensions without known comment syntax count as `raw`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LocMode {
+    #[default]
+    Raw,
+    Sloc,
+}
+
+// --- checks.v2 (multi-instance) ---
+//
+// NOTE: v2 is intentionally "boring": purely data-driven, deterministic, and fail-closed.
+// Runtime wiring + merge semantics are implemented elsewhere (see TASK-008 in BranchMind).
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChecksConfigV2 {
+    #[serde(default)]
+    pub loc: Vec<LocCheckConfigV2>,
+    #[serde(default)]
+    pub env_registry: Vec<EnvRegistryCheckConfigV2>,
+    #[serde(default)]
+    pub boundary: Vec<BoundaryCheckConfigV2>,
+    #[serde(default)]
+    pub surface: Vec<SurfaceCheckConfigV2>,
+    #[serde(default)]
+    pub duplicates: Vec<DuplicatesCheckConfigV2>,
+    #[serde(default)]
+    pub supply_chain: Vec<SupplyChainCheckConfigV2>,
+    #[serde(default)]
+    pub tool_budget: Vec<ToolBudgetCheckConfigV2>,
+    #[serde(default)]
+    pub reuse_first: Vec<ReuseFirstCheckConfigV2>,
+    #[serde(default)]
+    pub arch_layers: Vec<ArchLayersCheckConfigV2>,
+    #[serde(default)]
+    pub dead_code: Vec<DeadCodeCheckConfigV2>,
+    #[serde(default)]
+    pub orphan_api: Vec<OrphanApiCheckConfigV2>,
+    #[serde(default)]
+    pub complexity_budget: Vec<ComplexityBudgetCheckConfigV2>,
+    #[serde(default)]
+    pub contract_break: Vec<ContractBreakCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub test_presence: Vec<TestPresenceCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_encoding: Vec<FileEncodingCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub max_params: Vec<MaxParamsCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub binary_artifacts: Vec<BinaryArtifactsCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub import_cycles: Vec<ImportCyclesCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub naming_convention: Vec<NamingConventionCheckConfigV2>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub magic_numbers: Vec<MagicNumbersCheckConfigV2>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LocCheckConfigV2 {
+    pub id: String,
+    pub max_loc: usize,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    /// Skip paths matched by `.gitignore` while walking (init writes `true`; omitted means `false`).
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub respect_gitignore: bool,
+    /// Stop descending below this many path components (`1` = files in the repo root only).
+    /// Directories left unwalked are counted in the result's `skipped_by_depth`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    /// `sloc` skips comment lines too; omitted means `raw`.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub loc_mode: LocMode,
+    pub baseline_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnvRegistryCheckConfigV2 {
+    pub id: String,
+    pub registry_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoundaryCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default)]
+    pub strip_rust_cfg_test_blocks: bool,
+    /// See [`LocCheckConfigV2::respect_gitignore`].
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub respect_gitignore: bool,
+    /// See [`LocCheckConfigV2::max_depth`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    #[serde(default)]
+    pub rules: Vec<BoundaryRuleConfigV2>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoundaryRuleConfigV2 {
+    pub id: String,
+    pub message: Option<String>,
+    pub deny_regex: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurfaceCheckConfigV2 {
+    pub id: String,
+    pub max_items: usize,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<SurfaceRuleConfigV2>,
+    pub baseline_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurfaceRuleConfigV2 {
+    /// Optional fine-grained file filter for this rule.
+    #[serde(default)]
+    pub file_globs: Vec<String>,
+    pub regex: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DuplicatesCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    /// Only consider files <= this size (bytes). Keep it small to stay fast and deterministic.
+    pub max_file_bytes: usize,
+    #[serde(default)]
+    pub allowlist_globs: Vec<String>,
+    /// See [`LocCheckConfigV2::respect_gitignore`].
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub respect_gitignore: bool,
+    /// See [`LocCheckConfigV2::max_depth`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    /// Groups with fewer copies stay in the result's `groups` but do not fire `duplicates.found`.
+    #[serde(
+        default = "default_duplicates_min_group_size",
+        skip_serializing_if = "is_default_duplicates_min_group_size"
+    )]
+    pub min_group_size: usize,
+    /// Hash code files with comments stripped, so copies differing only in comments group
+    /// together. Baseline hashes are then computed over the stripped text.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub ignore_comments: bool,
+    /// When a firing group counts as heavy enough to raise `duplicates.found` to High.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub severity: DuplicatesSeverityConfig,
+    pub baseline_path: String,
+}
+
+/// `duplicates.found` is Medium; it becomes High once any firing group has at least
+/// `high_min_copies` copies of a file of at least `high_min_bytes` bytes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DuplicatesSeverityConfig {
+    #[serde(default = "default_duplicates_high_min_copies")]
+    pub high_min_copies: usize,
+    #[serde(default = "default_duplicates_high_min_bytes")]
+    pub high_min_bytes: u64,
+}
+
+impl Default for DuplicatesSeverityConfig {
+    fn default() -> Self {
+        Self {
+            high_min_copies: default_duplicates_high_min_copies(),
+            high_min_bytes: default_duplicates_high_min_bytes(),
+        }
+    }
+}
+
+const fn default_duplicates_high_min_copies() -> usize {
+    4
+}
+
+const fn default_duplicates_high_min_bytes() -> u64 {
+    2048
+}
+
+const fn default_duplicates_min_group_size() -> usize {
+    2
+}
+
+fn is_default_duplicates_min_group_size(value: &usize) -> bool {
+    *value == default_duplicates_min_group_size()
+}
+
+// Fields added after the checks config was first hashed are left out of its serialization
+// while they hold their default, so configs that do not use them keep their `config_hash`.
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SupplyChainCheckConfigV2 {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolBudgetCheckConfigV2 {
+    pub id: String,
+    pub max_tools_total: usize,
+    pub max_tools_per_plugin: usize,
+    pub max_gate_tools_per_kind: usize,
+    pub max_checks_total: usize,
+    /// Per-kind override of `max_gate_tools_per_kind`, e.g. `{ ci_fast = 4, flagship = 20 }`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_gate_tools: BTreeMap<GateKind, usize>,
+    /// Cap on distinct plugins that contribute at least one tool; unset = unbounded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_plugins_with_tools: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReuseFirstCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default = "default_reuse_min_block_lines")]
+    pub min_block_lines: usize,
+}
+
+const fn default_reuse_min_block_lines() -> usize {
+    6
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchLayersCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub layers: Vec<ArchLayerConfigV2>,
+    #[serde(default)]
+    pub rules: Vec<ArchLayerRuleConfigV2>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchLayerConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub module_prefixes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArchLayerRuleConfigV2 {
+    pub from_layer: String,
+    #[serde(default)]
+    pub deny_to_layers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeadCodeCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default = "default_min_symbol_len")]
+    pub min_symbol_len: usize,
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrphanApiCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default = "default_min_symbol_len")]
+    pub min_symbol_len: usize,
+    /// Intentional exports to skip: a symbol name glob, or `path_glob::name_glob`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_exports: Vec<String>,
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+const fn default_min_symbol_len() -> usize {
+    3
+}
+
+/// Rust, Python and JS/TS signatures only; receivers (`self`, `cls`, `this`) are not counted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaxParamsCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    pub max_params: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileEncodingCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+}
+
+/// Flags committed files larger than `max_file_bytes` or carrying a binary-artifact extension.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BinaryArtifactsCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default = "default_binary_artifacts_max_file_bytes")]
+    pub max_file_bytes: u64,
+    /// Extensions (without the dot, case-insensitive) flagged regardless of size.
+    #[serde(default = "default_binary_artifacts_extensions")]
+    pub binary_extensions: Vec<String>,
+    /// Skip paths matched by `.gitignore` (they are not committed anyway).
+    #[serde(default)]
+    pub respect_gitignore: bool,
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+/// Reports module import cycles among matched Rust (`crate::`/`super::`/`self::` uses) and
+/// Python files; findings are observations unless `blocking` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportCyclesCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    /// Directories absolute Python imports resolve against (`.` is the repo root); a name that
+    /// is not a module under one of them (e.g. stdlib `json`) adds no edge.
+    #[serde(default = "default_import_cycles_python_roots")]
+    pub python_roots: Vec<String>,
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+/// Enforces file-name and type-name conventions per language; each rule applies to the matched
+/// files of its language.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NamingConventionCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    pub rules: Vec<NamingConventionRule>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NamingLanguage {
+    /// `.rs` files; types are `struct`/`enum`/`union`/`trait`/`type` declarations.
+    Rust,
+    /// `.py` files; types are `class` declarations.
+    Python,
+}
+
+/// Omitted regexes fall back to the language default: snake_case file stems and PascalCase
+/// type names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NamingConventionRule {
+    pub language: NamingLanguage,
+    /// Matched against the file name without its extension.
+    #[serde(default)]
+    pub file_name_regex: Option<String>,
+    #[serde(default)]
+    pub type_name_regex: Option<String>,
+    #[serde(default)]
+    pub tier: ViolationTier,
+}
+
+/// Reports bare numeric literals in Rust/Python files that are not named by a constant
+/// definition; findings are observations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MagicNumbersCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    /// Literals whose absolute value is below this are ignored.
+    #[serde(default = "default_magic_numbers_min_magnitude")]
+    pub min_magnitude: u64,
+    /// Values (sign included) that never count as magic.
+    #[serde(default = "default_magic_numbers_allowed_values")]
+    pub allowed_values: Vec<f64>,
+    #[serde(default)]
+    pub allow_powers_of_two: bool,
+}
+
+fn default_magic_numbers_min_magnitude() -> u64 {
+    2
+}
+
+fn default_magic_numbers_allowed_values() -> Vec<f64> {
+    vec![0.0, 1.0, -1.0]
+}
+
+fn default_import_cycles_python_roots() -> Vec<String> {
+    vec![".".to_string(), "src".to_string()]
+}
+
+fn default_binary_artifacts_max_file_bytes() -> u64 {
+    1024 * 1024
+}
+
+fn default_binary_artifacts_extensions() -> Vec<String> {
+    [
+        "exe", "dll", "so", "dylib", "a", "o", "obj", "lib", "class", "jar", "war", "pyc", "wasm",
+        "zip", "tar", "gz", "tgz", "7z", "rar", "bin", "dmg", "iso",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
+/// Gate-only: evaluated against the change_impact diff, not during validate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestPresenceCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub rules: Vec<TestPresenceRuleConfigV2>,
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestPresenceRuleConfigV2 {
+    pub source_glob: String,
+    /// Candidate test paths; `{stem}` expands to the source file stem (e.g. `tests/{stem}*.rs`).
+    pub test_globs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComplexityBudgetCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    pub max_function_lines: usize,
+    pub max_cyclomatic: usize,
+    pub max_cognitive: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractBreakCheckConfigV2 {
+    pub id: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    pub baseline_path: String,
+    #[serde(default = "default_allow_contract_additions")]
+    pub allow_additions: bool,
+}
+
+const fn default_allow_contract_additions() -> bool {
+    true
+}
@@ -120,3 +120,23 @@ fn impact_unmapped_path_policy_deserialize_supports_observe_without_breaking_exi
         ImpactUnmappedPathPolicy::Observe
     );
 }
+
+#[test]
+fn proof_witness_requirement_per_kind() {
+    let proof = |s: &str| -> ProofConfig { toml::from_str(s).expect("deserialize ProofConfig") };
+
+    let all = proof("");
+    assert!(all.witness_required(GateKind::CiFast));
+    assert!(all.witness_required(GateKind::Flagship));
+
+    let flagship_only = proof(r#"require_witness_kinds = ["flagship"]"#);
+    assert!(!flagship_only.witness_required(GateKind::CiFast));
+    assert!(!flagship_only.witness_required(GateKind::Ci));
+    assert!(flagship_only.witness_required(GateKind::Flagship));
+
+    let shortcut = proof("require_witness = true\nrequire_witness_kinds = [\"flagship\"]");
+    assert!(shortcut.witness_required(GateKind::CiFast));
+
+    let none = proof("require_witness = false");
+    assert!(!none.witness_required(GateKind::Flagship));
+}
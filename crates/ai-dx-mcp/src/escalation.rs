@@ -0,0 +1,55 @@
+use crate::api::{Violation, ViolationTier};
+use crate::config::EscalationRule;
+use serde_json::json;
+
+const MAX_EXAMPLE_PATHS: usize = 10;
+
+/// Cross-finding policy from `[[escalation]]`: one aggregate violation per rule whose code
+/// occurs at least `threshold` times. Inputs are left untouched.
+pub(crate) fn escalate_by_count(
+    rules: &[EscalationRule],
+    violations: &[Violation],
+) -> Vec<Violation> {
+    let mut out = vec![];
+    for rule in rules {
+        if rule.threshold == 0 {
+            out.push(Violation::blocking(
+                "config.escalation_threshold_invalid",
+                format!("escalation rule for {} needs threshold >= 1", rule.code),
+                Some(".agents/mcp/compas/quality_contract.toml".to_string()),
+                None,
+            ));
+            continue;
+        }
+        let matching: Vec<&Violation> = violations.iter().filter(|v| v.code == rule.code).collect();
+        if matching.len() < rule.threshold {
+            continue;
+        }
+        let example_paths: Vec<&str> = matching
+            .iter()
+            .filter_map(|v| v.path.as_deref())
+            .take(MAX_EXAMPLE_PATHS)
+            .collect();
+        let mk = match rule.tier {
+            ViolationTier::Blocking => Violation::blocking,
+            ViolationTier::Observation => Violation::observation,
+        };
+        out.push(mk(
+            "escalation.threshold_reached",
+            format!(
+                "{} occurrences of {} reach escalation threshold {}",
+                matching.len(),
+                rule.code,
+                rule.threshold
+            ),
+            None,
+            Some(json!({
+                "code": rule.code,
+                "count": matching.len(),
+                "threshold": rule.threshold,
+                "example_paths": example_paths,
+            })),
+        ));
+    }
+    out
+}
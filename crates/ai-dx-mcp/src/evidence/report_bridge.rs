@@ -469,6 +469,13 @@ mod tests {
                 truncated: false,
                 omitted: BTreeMap::new(),
             }),
+            timings_ms: None,
+            baseline_diff: None,
+            disabled_packs: vec![],
+            partial_run: None,
+            check_errors: vec![],
+            swamped: false,
+            swamped_summary: None,
         }
     }
 
@@ -615,6 +622,8 @@ mod tests {
             job: None,
             job_state: None,
             job_error: None,
+            all_findings: vec![],
+            config_hash: None,
         };
 
         let envelope = build_gate_envelope(&out);
@@ -676,6 +685,8 @@ mod tests {
             job: None,
             job_state: None,
             job_error: None,
+            all_findings: vec![],
+            config_hash: None,
         };
 
         let envelope = build_gate_envelope(&out);
@@ -715,6 +726,8 @@ mod tests {
             job: None,
             job_state: None,
             job_error: None,
+            all_findings: vec![],
+            config_hash: None,
         };
 
         let envelope = build_gate_envelope(&out);
@@ -1,10 +1,37 @@
-use crate::api::Violation;
+use crate::api::{ApiError, Violation};
+use crate::judge::registry::current_code;
 use chrono::{NaiveDate, Utc};
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::{Component, Path};
 
+/// One allowlist entry on the expiry calendar; `days_until_expiry` is negative once expired.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ExceptionExpiry {
+    pub id: String,
+    /// The suppressed violation code (`rule` in allowlist.toml).
+    pub code: String,
+    pub path: String,
+    pub owner: String,
+    pub expires_at: String,
+    pub days_until_expiry: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExceptionsReportOutput {
+    pub ok: bool,
+    pub error: Option<ApiError>,
+    pub repo_root: String,
+    /// UTC date the remaining days are counted from (YYYY-MM-DD).
+    pub today: String,
+    /// Soonest expiry first.
+    pub exceptions: Vec<ExceptionExpiry>,
+}
+
 const ALLOWLIST_REL_PATH: &str = ".agents/mcp/compas/allowlist.toml";
+/// Remaining-window share (percent of the max window) above which an entry counts as long-lived.
+const LONG_LIVED_WINDOW_PERCENT: i64 = 80;
 
 pub struct SuppressionResult {
     pub violations: Vec<Violation>,
@@ -103,6 +130,49 @@ fn window_exceeded_exception(
     )
 }
 
+fn too_many_long_lived(
+    path: &str,
+    long_lived: &[(String, i64)],
+    cap: usize,
+    max_days: u32,
+) -> Violation {
+    Violation::blocking(
+        "exception.too_many_long_lived",
+        format!(
+            "allowlist has {} exceptions with more than {LONG_LIVED_WINDOW_PERCENT}% of max_exception_window_days remaining (max_long_lived_exceptions={cap}, max_days={max_days})",
+            long_lived.len()
+        ),
+        Some(path.to_string()),
+        Some(serde_json::json!({
+            "long_lived": long_lived
+                .iter()
+                .map(|(id, days)| serde_json::json!({ "id": id, "days_ahead": days }))
+                .collect::<Vec<_>>(),
+            "max_long_lived_exceptions": cap,
+            "threshold_percent": LONG_LIVED_WINDOW_PERCENT,
+        })),
+    )
+}
+
+/// Records the matching exception under `details.suppressed_by` so audits can tell why a
+/// violation was suppressed. Non-object `details` are left untouched.
+fn tag_suppressed_by(mut v: Violation, entry: &ExceptionEntry) -> Violation {
+    if let serde_json::Value::Object(map) = v
+        .details
+        .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+    {
+        map.insert(
+            "suppressed_by".to_string(),
+            serde_json::json!({
+                "exception_id": entry.id,
+                "owner": entry.owner,
+                "reason": entry.reason,
+            }),
+        );
+    }
+    v
+}
+
 fn fail_closed(input: &[Violation], violation: Violation) -> SuppressionResult {
     let mut violations = Vec::with_capacity(input.len() + 1);
     violations.push(violation);
@@ -113,18 +183,18 @@ fn fail_closed(input: &[Violation], violation: Violation) -> SuppressionResult {
     }
 }
 
-pub fn apply_allowlist_with_limits(
-    repo_root: &Path,
-    input: Vec<Violation>,
-    max_exception_window_days: Option<u32>,
-) -> SuppressionResult {
+struct LoadedException {
+    entry: ExceptionEntry,
+    expires: NaiveDate,
+}
+
+/// Reads and validates the allowlist; `Ok(None)` when there is none. Any malformed entry fails
+/// the whole file with `exception.allowlist_invalid`.
+fn load_allowlist(repo_root: &Path) -> Result<Option<Vec<LoadedException>>, Violation> {
     let allowlist_rel_path = ALLOWLIST_REL_PATH;
     let allowlist_path = repo_root.join(ALLOWLIST_REL_PATH);
     if !allowlist_path.is_file() {
-        return SuppressionResult {
-            violations: input,
-            suppressed: vec![],
-        };
+        return Ok(None);
     }
 
     let invalid = |msg| allowlist_invalid(allowlist_rel_path, msg);
@@ -132,34 +202,25 @@ pub fn apply_allowlist_with_limits(
     let raw = match std::fs::read_to_string(&allowlist_path) {
         Ok(s) => s,
         Err(e) => {
-            return fail_closed(
-                &input,
-                invalid(format!(
-                    "failed to read allowlist {:?}: {e}",
-                    allowlist_path
-                )),
-            );
+            return Err(invalid(format!(
+                "failed to read allowlist {:?}: {e}",
+                allowlist_path
+            )));
         }
     };
 
     let parsed: AllowlistFile = match toml::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return fail_closed(
-                &input,
-                invalid(format!(
-                    "failed to parse allowlist {:?}: {e}",
-                    allowlist_path
-                )),
-            );
+            return Err(invalid(format!(
+                "failed to parse allowlist {:?}: {e}",
+                allowlist_path
+            )));
         }
     };
 
-    let today = Utc::now().date_naive();
-
     let mut seen_ids: HashSet<String> = HashSet::new();
-    let mut entries: Vec<ExceptionEntry> = vec![];
-    let mut expired: Vec<Violation> = vec![];
+    let mut loaded: Vec<LoadedException> = vec![];
 
     for mut e in parsed.exceptions {
         e.id = e.id.trim().to_string();
@@ -169,60 +230,39 @@ pub fn apply_allowlist_with_limits(
         e.reason = e.reason.trim().to_string();
 
         if e.id.is_empty() {
-            return fail_closed(&input, invalid("exception entry has empty id".to_string()));
+            return Err(invalid("exception entry has empty id".to_string()));
         }
         if !seen_ids.insert(e.id.clone()) {
-            return fail_closed(
-                &input,
-                invalid(format!(
-                    "duplicate exception id={} (ids must be unique)",
-                    e.id
-                )),
-            );
+            return Err(invalid(format!(
+                "duplicate exception id={} (ids must be unique)",
+                e.id
+            )));
         }
 
         if e.rule.is_empty() {
-            return fail_closed(
-                &input,
-                invalid(format!("exception id={} has empty rule", e.id)),
-            );
+            return Err(invalid(format!("exception id={} has empty rule", e.id)));
         }
         if e.path.is_empty() {
-            return fail_closed(
-                &input,
-                invalid(format!("exception id={} has empty path", e.id)),
-            );
+            return Err(invalid(format!("exception id={} has empty path", e.id)));
         }
         if !is_relative_and_safe(&e.path) {
-            return fail_closed(
-                &input,
-                invalid(format!(
-                    "exception id={} has unsafe/absolute path={}",
-                    e.id, e.path
-                )),
-            );
+            return Err(invalid(format!(
+                "exception id={} has unsafe/absolute path={}",
+                e.id, e.path
+            )));
         }
         if has_glob_chars(&e.path) {
-            return fail_closed(
-                &input,
-                invalid(format!(
-                    "exception id={} uses glob characters in path (globs are forbidden): {}",
-                    e.id, e.path
-                )),
-            );
+            return Err(invalid(format!(
+                "exception id={} uses glob characters in path (globs are forbidden): {}",
+                e.id, e.path
+            )));
         }
 
         if e.owner.is_empty() {
-            return fail_closed(
-                &input,
-                invalid(format!("exception id={} has empty owner", e.id)),
-            );
+            return Err(invalid(format!("exception id={} has empty owner", e.id)));
         }
         if e.reason.is_empty() {
-            return fail_closed(
-                &input,
-                invalid(format!("exception id={} has empty reason", e.id)),
-            );
+            return Err(invalid(format!("exception id={} has empty reason", e.id)));
         }
 
         let Some(expires_at) = e
@@ -231,27 +271,58 @@ pub fn apply_allowlist_with_limits(
             .map(str::trim)
             .filter(|v| !v.is_empty())
         else {
-            return fail_closed(
-                &input,
-                invalid(format!(
-                    "exception id={} must include expires_at in YYYY-MM-DD for time-boxed suppression",
-                    e.id
-                )),
-            );
+            return Err(invalid(format!(
+                "exception id={} must include expires_at in YYYY-MM-DD for time-boxed suppression",
+                e.id
+            )));
         };
         let expires_date = match NaiveDate::parse_from_str(expires_at, "%Y-%m-%d") {
             Ok(d) => d,
             Err(err) => {
-                return fail_closed(
-                    &input,
-                    invalid(format!(
-                        "exception id={} has invalid expires_at={expires_at:?}: {err}",
-                        e.id
-                    )),
-                );
+                return Err(invalid(format!(
+                    "exception id={} has invalid expires_at={expires_at:?}: {err}",
+                    e.id
+                )));
             }
         };
 
+        loaded.push(LoadedException {
+            entry: e,
+            expires: expires_date,
+        });
+    }
+    Ok(Some(loaded))
+}
+
+pub fn apply_allowlist_with_limits(
+    repo_root: &Path,
+    input: Vec<Violation>,
+    max_exception_window_days: Option<u32>,
+    max_long_lived_exceptions: Option<usize>,
+) -> SuppressionResult {
+    let allowlist_rel_path = ALLOWLIST_REL_PATH;
+    let loaded = match load_allowlist(repo_root) {
+        Ok(Some(v)) => v,
+        Ok(None) => {
+            return SuppressionResult {
+                violations: input,
+                suppressed: vec![],
+            };
+        }
+        Err(invalid) => return fail_closed(&input, invalid),
+    };
+
+    let today = Utc::now().date_naive();
+
+    let mut entries: Vec<ExceptionEntry> = vec![];
+    let mut expired: Vec<Violation> = vec![];
+    let mut long_lived: Vec<(String, i64)> = vec![];
+
+    for LoadedException {
+        entry: e,
+        expires: expires_date,
+    } in loaded
+    {
         if expires_date < today {
             expired.push(expired_exception(allowlist_rel_path, &e));
             continue;
@@ -268,11 +339,25 @@ pub fn apply_allowlist_with_limits(
                 ));
                 continue;
             }
+            if days_ahead * 100 > i64::from(max_days) * LONG_LIVED_WINDOW_PERCENT {
+                long_lived.push((e.id.clone(), days_ahead));
+            }
         }
 
         entries.push(e);
     }
 
+    if let (Some(cap), Some(max_days)) = (max_long_lived_exceptions, max_exception_window_days)
+        && long_lived.len() > cap
+    {
+        expired.push(too_many_long_lived(
+            allowlist_rel_path,
+            &long_lived,
+            cap,
+            max_days,
+        ));
+    }
+
     let mut violations: Vec<Violation> = vec![];
     let mut suppressed: Vec<Violation> = vec![];
 
@@ -290,10 +375,12 @@ pub fn apply_allowlist_with_limits(
         };
 
         let path = normalize_exception_path(path);
-        let matched = entries.iter().any(|e| e.rule == v.code && e.path == path);
+        let matched = entries
+            .iter()
+            .find(|e| current_code(&e.rule) == v.code && e.path == path);
 
-        if matched {
-            suppressed.push(v);
+        if let Some(entry) = matched {
+            suppressed.push(tag_suppressed_by(v, entry));
         } else {
             violations.push(v);
         }
@@ -305,8 +392,33 @@ pub fn apply_allowlist_with_limits(
     }
 }
 
+/// Every allowlist entry with its days left as of `today`, soonest expiry first (ties by id).
+pub(crate) fn expiry_calendar(
+    repo_root: &Path,
+    today: NaiveDate,
+) -> Result<Vec<ExceptionExpiry>, Violation> {
+    let mut calendar: Vec<ExceptionExpiry> = load_allowlist(repo_root)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|LoadedException { entry, expires }| ExceptionExpiry {
+            id: entry.id,
+            code: entry.rule,
+            path: entry.path,
+            owner: entry.owner,
+            expires_at: expires.format("%Y-%m-%d").to_string(),
+            days_until_expiry: expires.signed_duration_since(today).num_days(),
+        })
+        .collect();
+    calendar.sort_by(|a, b| {
+        a.days_until_expiry
+            .cmp(&b.days_until_expiry)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(calendar)
+}
+
 pub fn apply_allowlist(repo_root: &Path, input: Vec<Violation>) -> SuppressionResult {
-    apply_allowlist_with_limits(repo_root, input, None)
+    apply_allowlist_with_limits(repo_root, input, None, None)
 }
 
 #[cfg(test)]
@@ -344,6 +456,36 @@ expires_at = "2999-01-01"
         assert_eq!(r.suppressed[0].code, "loc.max_exceeded");
     }
 
+    #[test]
+    fn allowlist_entry_with_renamed_code_still_suppresses() {
+        let dir = tempdir().unwrap();
+        let repo_root = dir.path();
+        fs::create_dir_all(repo_root.join(".agents/mcp/compas")).unwrap();
+        fs::write(
+            repo_root.join(ALLOWLIST_REL_PATH),
+            r#"
+[[exceptions]]
+id = "ex-1"
+rule = "tool_budget.max_tools_total_exceeded"
+path = ".agents/mcp/compas/plugins"
+owner = "team"
+reason = "temporary"
+expires_at = "2999-01-01"
+"#,
+        )
+        .unwrap();
+
+        let r = apply_allowlist(
+            repo_root,
+            vec![v(
+                "tool_budget.total_exceeded",
+                ".agents/mcp/compas/plugins",
+            )],
+        );
+        assert!(r.violations.is_empty(), "{:?}", r.violations);
+        assert_eq!(r.suppressed.len(), 1);
+    }
+
     #[test]
     fn allowlist_expired_exception_is_violation_and_does_not_suppress() {
         let dir = tempdir().unwrap();
@@ -417,6 +559,7 @@ expires_at = "2999-01-01"
             repo_root,
             vec![v("loc.max_exceeded", "crates/x/lib.rs")],
             Some(90),
+            None,
         );
         assert!(r.suppressed.is_empty());
         assert!(
@@ -426,4 +569,110 @@ expires_at = "2999-01-01"
         );
         assert!(r.violations.iter().any(|v| v.code == "loc.max_exceeded"));
     }
+
+    fn write_near_max_entries(repo_root: &Path, count: usize, days_ahead: i64) {
+        let expires_at = (Utc::now().date_naive() + chrono::Duration::days(days_ahead))
+            .format("%Y-%m-%d")
+            .to_string();
+        let mut raw = String::new();
+        for idx in 0..count {
+            raw.push_str(&format!(
+                "[[exceptions]]\nid = \"ex-{idx}\"\nrule = \"loc.max_exceeded\"\npath = \"crates/x/f{idx}.rs\"\nowner = \"team\"\nreason = \"temporary\"\nexpires_at = \"{expires_at}\"\n\n"
+            ));
+        }
+        fs::create_dir_all(repo_root.join(".agents/mcp/compas")).unwrap();
+        fs::write(repo_root.join(ALLOWLIST_REL_PATH), raw).unwrap();
+    }
+
+    #[test]
+    fn allowlist_too_many_long_lived_entries_is_violation() {
+        let dir = tempdir().unwrap();
+        write_near_max_entries(dir.path(), 3, 85);
+
+        let r = apply_allowlist_with_limits(
+            dir.path(),
+            vec![v("loc.max_exceeded", "crates/x/f0.rs")],
+            Some(90),
+            Some(2),
+        );
+        let hit = r
+            .violations
+            .iter()
+            .find(|v| v.code == "exception.too_many_long_lived")
+            .expect("long-lived cap must fire");
+        assert_eq!(
+            hit.details.as_ref().unwrap()["long_lived"]
+                .as_array()
+                .map(Vec::len),
+            Some(3)
+        );
+        // Entries themselves stay valid; the cap is a separate policy signal.
+        assert_eq!(r.suppressed.len(), 1);
+    }
+
+    #[test]
+    fn allowlist_long_lived_entries_under_cap_or_short_windows_pass() {
+        let dir = tempdir().unwrap();
+        write_near_max_entries(dir.path(), 2, 85);
+        let r = apply_allowlist_with_limits(dir.path(), vec![], Some(90), Some(2));
+        assert!(r.violations.is_empty(), "{:?}", r.violations);
+
+        let dir = tempdir().unwrap();
+        write_near_max_entries(dir.path(), 5, 30);
+        let r = apply_allowlist_with_limits(dir.path(), vec![], Some(90), Some(2));
+        assert!(r.violations.is_empty(), "{:?}", r.violations);
+    }
+
+    #[test]
+    fn expiry_calendar_sorts_by_remaining_days() {
+        let dir = tempdir().expect("tempdir");
+        let repo_root = dir.path();
+        fs::create_dir_all(repo_root.join(".agents/mcp/compas")).expect("mkdir");
+        fs::write(
+            repo_root.join(ALLOWLIST_REL_PATH),
+            r#"
+[[exceptions]]
+id = "later"
+rule = "loc.max_exceeded"
+path = "src/big.rs"
+owner = "team-a"
+reason = "split planned"
+expires_at = "2026-03-31"
+
+[[exceptions]]
+id = "soon"
+rule = "boundary.rule_violation"
+path = "src/lib.rs"
+owner = "team-b"
+reason = "migration in flight"
+expires_at = "2026-03-05"
+
+[[exceptions]]
+id = "gone"
+rule = "surface.max_items_exceeded"
+path = "src/api.rs"
+owner = "team-c"
+reason = "already expired"
+expires_at = "2026-02-20"
+"#,
+        )
+        .expect("write allowlist");
+
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).expect("date");
+        let calendar = expiry_calendar(repo_root, today).expect("calendar");
+        let rows: Vec<(&str, &str, i64)> = calendar
+            .iter()
+            .map(|e| (e.id.as_str(), e.code.as_str(), e.days_until_expiry))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("gone", "surface.max_items_exceeded", -9),
+                ("soon", "boundary.rule_violation", 4),
+                ("later", "loc.max_exceeded", 30),
+            ]
+        );
+        assert_eq!(calendar[1].owner, "team-b");
+        assert_eq!(calendar[1].path, "src/lib.rs");
+    }
 }
@@ -1,6 +1,7 @@
 use regex::Regex;
 use serde::Deserialize;
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     sync::OnceLock,
 };
@@ -25,6 +26,10 @@ fn mode_id_regex() -> &'static Regex {
     RE.get_or_init(|| Regex::new(r"^[a-z0-9][a-z0-9_-]{1,63}$").expect("static regex is valid"))
 }
 
+pub(crate) fn is_valid_mode_id(id: &str) -> bool {
+    mode_id_regex().is_match(id)
+}
+
 #[derive(Debug)]
 pub(crate) struct FailureModesError {
     pub path: PathBuf,
@@ -49,6 +54,10 @@ pub(crate) fn default_failure_mode_catalog() -> Vec<String> {
     DEFAULT_CATALOG.iter().map(|s| s.to_string()).collect()
 }
 
+pub(crate) fn is_builtin_failure_mode(id: &str) -> bool {
+    DEFAULT_CATALOG.contains(&id)
+}
+
 pub(crate) fn failure_modes_path(repo_root: &Path) -> PathBuf {
     repo_root.join(REL_FAILURE_MODES_PATH)
 }
@@ -71,6 +80,32 @@ pub(crate) fn load_failure_mode_catalog(
     validate_catalog(parsed.catalog, &path)
 }
 
+/// Appends the failure modes packs declare (keyed by pack id) to `catalog`, skipping ids
+/// already present; invalid ids are left out (`validate_packs` reports them). Returns, per
+/// declared mode, the ids of the packs that declare it.
+pub(crate) fn merge_pack_failure_modes(
+    catalog: &mut Vec<String>,
+    pack_modes: &BTreeMap<String, Vec<String>>,
+) -> BTreeMap<String, Vec<String>> {
+    let mut provenance: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (pack_id, modes) in pack_modes {
+        for mode in modes {
+            let mode = mode.trim();
+            if !is_valid_mode_id(mode) {
+                continue;
+            }
+            if !catalog.iter().any(|m| m == mode) {
+                catalog.push(mode.to_string());
+            }
+            let packs = provenance.entry(mode.to_string()).or_default();
+            if !packs.contains(pack_id) {
+                packs.push(pack_id.clone());
+            }
+        }
+    }
+    provenance
+}
+
 fn validate_catalog(catalog: Vec<String>, path: &Path) -> Result<Vec<String>, FailureModesError> {
     if catalog.is_empty() {
         return Err(FailureModesError {
@@ -0,0 +1,165 @@
+use crate::api::{FindingV2, Violation, ViolationTier};
+use crate::validate_insights::finding_fingerprint;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+const LEDGER_REL_PATH: &str = ".agents/mcp/compas/findings_ledger.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct LedgerEntry {
+    pub first_seen: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Present when a baseline was written; only these entries count as pre-existing debt.
+    #[serde(default)]
+    pub baseline: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LedgerFile {
+    version: u32,
+    #[serde(default)]
+    findings: BTreeMap<String, LedgerEntry>,
+}
+
+pub(crate) struct LedgerPass {
+    pub violations: Vec<Violation>,
+    /// Ledger state after this run, keyed by finding fingerprint.
+    pub entries: BTreeMap<String, LedgerEntry>,
+    pub errors: Vec<Violation>,
+}
+
+fn ledger_violation(code: &str, message: String) -> Violation {
+    Violation::blocking(code, message, Some(LEDGER_REL_PATH.to_string()), None)
+}
+
+fn load_ledger(repo_root: &Path) -> Result<BTreeMap<String, LedgerEntry>, Violation> {
+    let path = repo_root.join(LEDGER_REL_PATH);
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => {
+            return Err(ledger_violation(
+                "findings_ledger.invalid",
+                format!("failed to read findings ledger: {e}"),
+            ));
+        }
+    };
+    let file: LedgerFile = serde_json::from_str(&raw).map_err(|e| {
+        ledger_violation(
+            "findings_ledger.invalid",
+            format!("failed to parse findings ledger: {e}"),
+        )
+    })?;
+    if file.version != 1 {
+        return Err(ledger_violation(
+            "findings_ledger.invalid",
+            format!("unsupported findings ledger version {}", file.version),
+        ));
+    }
+    Ok(file.findings)
+}
+
+fn write_ledger(repo_root: &Path, findings: &BTreeMap<String, LedgerEntry>) -> Result<(), String> {
+    let path = repo_root.join(LEDGER_REL_PATH);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("failed to create {parent:?}: {e}"))?;
+    }
+    let file = LedgerFile {
+        version: 1,
+        findings: findings.clone(),
+    };
+    let json = serde_json::to_string_pretty(&file)
+        .map_err(|e| format!("failed to serialize findings ledger: {e}"))?;
+    let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+    std::fs::write(&tmp, json).map_err(|e| format!("failed to write {tmp:?}: {e}"))?;
+    std::fs::rename(&tmp, &path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        format!("failed to rename {path:?}: {e}")
+    })
+}
+
+/// Records first sightings of `violations` and, with `block_only_new`, downgrades findings
+/// already in the ledger at baseline time to observations. A baseline write marks every
+/// current finding as baseline and drops entries for findings that are gone.
+pub(crate) fn apply_findings_ledger(
+    repo_root: &Path,
+    mut violations: Vec<Violation>,
+    block_only_new: bool,
+    write_baseline: bool,
+    persist: bool,
+) -> LedgerPass {
+    let mut entries = match load_ledger(repo_root) {
+        Ok(entries) => entries,
+        Err(v) => {
+            return LedgerPass {
+                violations,
+                entries: BTreeMap::new(),
+                errors: vec![v],
+            };
+        }
+    };
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let mut commit: Option<Option<String>> = None;
+    let mut changed = false;
+    let mut current: BTreeSet<String> = BTreeSet::new();
+    for v in &mut violations {
+        let fingerprint = finding_fingerprint(v);
+        let entry = entries.entry(fingerprint.clone()).or_insert_with(|| {
+            changed = true;
+            LedgerEntry {
+                first_seen: now.clone(),
+                commit: commit
+                    .get_or_insert_with(|| crate::structured_report::current_head_sha(repo_root))
+                    .clone(),
+                code: v.code.clone(),
+                path: v.path.clone(),
+                baseline: false,
+            }
+        });
+        if write_baseline && !entry.baseline {
+            entry.baseline = true;
+            changed = true;
+        }
+        if block_only_new && entry.baseline {
+            v.tier = ViolationTier::Observation;
+        }
+        current.insert(fingerprint);
+    }
+    if write_baseline {
+        let before = entries.len();
+        entries.retain(|fingerprint, _| current.contains(fingerprint));
+        changed |= entries.len() != before;
+    }
+
+    let mut errors = vec![];
+    if changed
+        && persist
+        && let Err(e) = write_ledger(repo_root, &entries)
+    {
+        errors.push(ledger_violation("findings_ledger.write_failed", e));
+    }
+    LedgerPass {
+        violations,
+        entries,
+        errors,
+    }
+}
+
+/// Copies each finding's ledger `first_seen` timestamp and commit onto the finding.
+pub(crate) fn annotate_first_seen(
+    findings: &mut [FindingV2],
+    entries: &BTreeMap<String, LedgerEntry>,
+) {
+    for finding in findings {
+        if let Some(entry) = entries.get(&finding.fingerprint) {
+            finding.first_seen = Some(entry.first_seen.clone());
+            finding.first_seen_commit = entry.commit.clone();
+        }
+    }
+}
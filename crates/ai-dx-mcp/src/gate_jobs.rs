@@ -222,6 +222,13 @@ fn placeholder_validate(repo_root: &str) -> ValidateOutput {
         summary_md: None,
         evidence: crate::api::EvidenceEnvelope::default(),
         payload_meta: None,
+        timings_ms: None,
+        baseline_diff: None,
+        disabled_packs: vec![],
+        partial_run: None,
+        check_errors: vec![],
+        swamped: false,
+        swamped_summary: None,
     }
 }
 
@@ -283,6 +290,8 @@ fn response_from_record(rec: &GateJobRecord) -> GateOutput {
         job: Some(job_info(rec)),
         job_state: Some(rec.state),
         job_error: rec.job_error.clone(),
+        all_findings: vec![],
+        config_hash: None,
     }
 }
 
@@ -307,6 +316,8 @@ fn job_not_found(repo_root: &str, kind: GateKind, job_id: &str) -> GateOutput {
         job: None,
         job_state: None,
         job_error: None,
+        all_findings: vec![],
+        config_hash: None,
     }
 }
 
@@ -452,6 +463,8 @@ pub(crate) async fn start(
                 job: None,
                 job_state: None,
                 job_error: None,
+                all_findings: vec![],
+                config_hash: None,
             };
         }
     };
@@ -465,6 +478,8 @@ pub(crate) async fn start(
             dry_run,
             write_witness,
             gate_budget_ms,
+            None,
+            None,
         )
         .await;
         if let Err(msg) = mark_job_result(&repo_root_owned, &job_id, out) {
@@ -517,6 +532,8 @@ pub(crate) async fn status(
                     job: None,
                     job_state: None,
                     job_error: None,
+                    all_findings: vec![],
+                    config_hash: None,
                 };
             }
         };
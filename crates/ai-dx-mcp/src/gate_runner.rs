@@ -1,24 +1,32 @@
 use crate::{
     api::{ApiError, GateKind, GateOutput, Receipt, ValidateMode, Violation},
-    app::{map_config_error, validate},
-    config::{ImpactUnmappedPathPolicy, QualityContractConfig, ToolReceiptContract},
+    app::{GateRunOptions, map_config_error, validate, validate_gate_preflight},
+    checks::test_presence::run_test_presence_check,
+    config::{ImpactConfig, QualityContractConfig, ToolReceiptContract},
     repo::load_repo_config,
-    runner::run_project_tool_with_timeout_override,
+    runner::{run_project_tool_with_timeout_override, with_full_capture},
     structured_report::ingest_tool_report,
     validate_insights::build_agent_digest,
     witness::maybe_write_gate_witness,
 };
-use globset::{Glob, GlobSetBuilder};
 use regex::Regex;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
-use std::process::Command;
-use std::time::Instant;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+mod circuit_breaker;
+mod impact;
+mod plan;
+
+pub use plan::GatePlanOutput;
+pub(crate) use plan::gate_plan;
+
+use impact::{collect_changed_files, evaluate_change_impact};
 
 fn gate_fail(
     repo_root: &str,
     kind: GateKind,
-    validate: crate::api::ValidateOutput,
+    mut validate: crate::api::ValidateOutput,
     receipts: Vec<Receipt>,
     mut receipt_violations: Vec<Violation>,
     error: ApiError,
@@ -29,6 +37,9 @@ fn gate_fail(
         None,
         None,
     ));
+    validate
+        .check_errors
+        .extend(crate::app::check_errors_from(&receipt_violations));
     let verdict = Some(crate::judge::judge_gate(
         &validate.violations,
         &receipt_violations,
@@ -54,12 +65,38 @@ fn gate_fail(
         job: None,
         job_state: None,
         job_error: None,
+        all_findings: vec![],
+        config_hash: None,
     };
     out.validate.evidence = crate::evidence::build_validate_envelope(&out.validate);
     out.evidence = crate::evidence::build_gate_envelope(&out);
     out
 }
 
+/// Rewrites aliased gate entries to their current tool_id, leaving an observation per rewrite
+/// so the sequence can be updated.
+fn resolve_tool_aliases(
+    aliases: &BTreeMap<String, String>,
+    tool_ids: Vec<String>,
+    violations: &mut Vec<Violation>,
+) -> Vec<String> {
+    tool_ids
+        .into_iter()
+        .map(|tool_id| match aliases.get(&tool_id) {
+            Some(target) => {
+                violations.push(Violation::observation(
+                    "gate.observation.tool_alias_used",
+                    format!("gate references renamed tool_id={tool_id}; use {target} instead"),
+                    None,
+                    Some(serde_json::json!({ "alias": tool_id, "tool_id": target })),
+                ));
+                target.clone()
+            }
+            None => tool_id,
+        })
+        .collect()
+}
+
 fn ensure_gate_sequence_invariants(kind: GateKind, tool_ids: &[String]) -> Result<(), ApiError> {
     if tool_ids.is_empty() {
         return Err(ApiError {
@@ -116,7 +153,7 @@ fn check_receipt_contract(
                 receipt.tool_id, receipt.duration_ms, min_duration_ms
             ),
             None,
-            None,
+            Some(serde_json::json!({ "tool_id": receipt.tool_id })),
         ));
     }
     if let Some(min_stdout_bytes) = contract.min_stdout_bytes
@@ -129,7 +166,7 @@ fn check_receipt_contract(
                 receipt.tool_id, receipt.stdout_bytes, min_stdout_bytes
             ),
             None,
-            None,
+            Some(serde_json::json!({ "tool_id": receipt.tool_id })),
         ));
     }
     if let Some(pattern) = contract
@@ -146,7 +183,7 @@ fn check_receipt_contract(
                     receipt.tool_id
                 ),
                 None,
-                None,
+                Some(serde_json::json!({ "tool_id": receipt.tool_id })),
             )
         })?;
         let combined_tail = if receipt.stdout_tail.is_empty() {
@@ -173,7 +210,7 @@ fn check_receipt_contract(
                     receipt.stderr_bytes
                 ),
                 None,
-                None,
+                Some(serde_json::json!({ "tool_id": receipt.tool_id })),
             ));
         }
     }
@@ -189,7 +226,7 @@ fn check_receipt_contract(
                     receipt.tool_id, receipt.exit_code, expect_codes
                 ),
                 None,
-                None,
+                Some(serde_json::json!({ "tool_id": receipt.tool_id })),
             ));
         }
     }
@@ -229,157 +266,123 @@ fn remaining_budget_ms(started_at: Instant, total_ms: u64) -> u64 {
     total_ms.saturating_sub(started_at.elapsed().as_millis() as u64)
 }
 
-fn run_git(repo_root: &Path, args: &[&str]) -> Result<String, String> {
-    let out = Command::new("git")
-        .current_dir(repo_root)
-        .args(args)
-        .output()
-        .map_err(|e| format!("failed to run git {:?}: {e}", args))?;
-    if !out.status.success() {
-        let err = String::from_utf8_lossy(&out.stderr).trim().to_string();
-        return Err(format!("git {:?} failed: {}", args, err));
-    }
-    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+fn unix_now_s() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
-fn resolve_diff_base(repo_root: &Path, diff_base: &str) -> Result<String, String> {
-    if let Some(target) = diff_base.strip_prefix("merge-base:") {
-        let target = target.trim();
-        let mut candidates: Vec<String> = vec![];
-        if target.eq_ignore_ascii_case("auto") {
-            candidates.extend(
-                ["origin/main", "origin/master", "main", "master"]
-                    .iter()
-                    .map(|s| s.to_string()),
-            );
-        } else {
-            candidates.push(target.to_string());
-            if target == "origin/main" {
-                candidates.extend(
-                    ["origin/master", "main", "master"]
-                        .iter()
-                        .map(|s| s.to_string()),
-                );
-            } else if target == "origin/master" {
-                candidates.extend(
-                    ["origin/main", "main", "master"]
-                        .iter()
-                        .map(|s| s.to_string()),
-                );
-            }
-        }
-        for candidate in candidates {
-            if let Ok(base) = run_git(repo_root, &["merge-base", "HEAD", candidate.as_str()]) {
-                return Ok(base);
-            }
-        }
-        if run_git(repo_root, &["rev-parse", "--verify", "HEAD~1"]).is_ok() {
-            return Ok("HEAD~1".to_string());
-        }
-        if run_git(repo_root, &["rev-parse", "--verify", "HEAD"]).is_ok() {
-            return Ok("HEAD".to_string());
-        }
-        Err(format!(
-            "unable to resolve merge-base target '{}' for change_impact",
-            target
-        ))
+/// Runs the gate behind the optional `[gate] circuit_breaker`: an open breaker short-circuits
+/// with a retryable `gate.circuit_open`, a failed run is recorded and a pass resets the kind.
+/// Dry runs neither trip nor reset it.
+pub(crate) async fn gate(
+    repo_root: &str,
+    kind: GateKind,
+    dry_run: bool,
+    write_witness: bool,
+    options: GateRunOptions<'_>,
+) -> GateOutput {
+    let witness_dir = options.witness_dir;
+    let explain_verdict = options.explain_verdict;
+    let breaker = if dry_run {
+        None
     } else {
-        Ok(diff_base.to_string())
-    }
-}
-
-fn collect_changed_files(repo_root: &Path, diff_base: &str) -> Result<Vec<String>, String> {
-    let base = resolve_diff_base(repo_root, diff_base)?;
-    let out = run_git(
-        repo_root,
-        &["diff", "--name-only", &format!("{base}...HEAD")],
-    )?;
-    let mut files = out
-        .lines()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .map(ToString::to_string)
-        .collect::<Vec<_>>();
-    files.sort();
-    files.dedup();
-    Ok(files)
-}
-
-fn required_tools_for_changes(
-    contract: &QualityContractConfig,
-    changed_files: &[String],
-) -> Result<(BTreeSet<String>, Vec<String>), String> {
-    let mut required: BTreeSet<String> = BTreeSet::new();
-    let mut unmatched: Vec<String> = vec![];
-    for path in changed_files {
-        let mut matched = false;
-        for rule in &contract.impact.rules {
-            if rule.path_globs.is_empty() || rule.required_tools.is_empty() {
-                continue;
-            }
-            let mut b = GlobSetBuilder::new();
-            for p in &rule.path_globs {
-                let g =
-                    Glob::new(p).map_err(|e| format!("invalid change_impact glob {:?}: {e}", p))?;
-                b.add(g);
-            }
-            let set = b
-                .build()
-                .map_err(|e| format!("failed to build change_impact globset: {e}"))?;
-            if set.is_match(path) {
-                matched = true;
-                for t in &rule.required_tools {
-                    required.insert(t.clone());
-                }
-            }
-        }
-        if !matched {
-            unmatched.push(path.clone());
-        }
+        load_repo_config(Path::new(repo_root))
+            .ok()
+            .and_then(|cfg| cfg.quality_contract)
+            .and_then(|contract| contract.gate.circuit_breaker)
+    };
+    let Some(breaker) = breaker else {
+        return run_gate(repo_root, kind, dry_run, write_witness, options).await;
+    };
+    if breaker.failures == 0 || breaker.window_min == 0 {
+        let out = gate_fail(
+            repo_root,
+            kind,
+            crate::gate_jobs::empty_validate_output(repo_root),
+            vec![],
+            vec![],
+            ApiError {
+                code: "gate.circuit_breaker_invalid".to_string(),
+                message: "[gate] circuit_breaker needs failures >= 1 and window_min >= 1"
+                    .to_string(),
+            },
+        );
+        return maybe_write_gate_witness(
+            Path::new(repo_root),
+            kind,
+            write_witness,
+            witness_dir,
+            explain_verdict,
+            out,
+        );
     }
-    Ok((required, unmatched))
-}
 
-fn unmapped_path_violations(
-    policy: ImpactUnmappedPathPolicy,
-    unmatched: &[String],
-) -> Vec<Violation> {
-    let mut out = vec![];
-    for path in unmatched {
-        let message = format!(
-            "changed path '{}' is not mapped in quality_contract [impact].rules",
-            path
+    let now = unix_now_s();
+    if let Some(open) = circuit_breaker::check_open(Path::new(repo_root), kind, &breaker, now) {
+        let out = gate_fail(
+            repo_root,
+            kind,
+            crate::gate_jobs::empty_validate_output(repo_root),
+            vec![],
+            vec![],
+            ApiError {
+                code: "gate.circuit_open".to_string(),
+                message: format!(
+                    "{} failed {kind:?} gate runs within {} min; circuit open for {}s. Investigate the failing tool instead of retrying (or remove {} once fixed)",
+                    open.failures,
+                    breaker.window_min,
+                    open.retry_after_s,
+                    circuit_breaker::CIRCUIT_STATE_REL_PATH
+                ),
+            },
+        );
+        return maybe_write_gate_witness(
+            Path::new(repo_root),
+            kind,
+            write_witness,
+            witness_dir,
+            explain_verdict,
+            out,
         );
-        match policy {
-            ImpactUnmappedPathPolicy::Block => out.push(Violation::blocking(
-                "change_impact.unmapped_path",
-                message,
-                Some(path.clone()),
-                None,
-            )),
-            ImpactUnmappedPathPolicy::Observe => out.push(Violation::observation(
-                "change_impact.unmapped_path",
-                message,
-                Some(path.clone()),
-                None,
-            )),
-            ImpactUnmappedPathPolicy::Ignore => {}
-        }
     }
+
+    let out = run_gate(repo_root, kind, dry_run, write_witness, options).await;
+    // Best-effort bookkeeping: a lost update only delays tripping, it never blocks a gate.
+    let _ = circuit_breaker::record_outcome(Path::new(repo_root), kind, &breaker, now, out.ok);
     out
 }
 
-pub(crate) async fn gate(
+async fn run_gate(
     repo_root: &str,
     kind: GateKind,
     dry_run: bool,
     write_witness: bool,
-    gate_budget_ms: Option<u64>,
+    options: GateRunOptions<'_>,
 ) -> GateOutput {
+    let GateRunOptions {
+        budget_ms: gate_budget_ms,
+        witness_dir,
+        fail_fast,
+        capture_full,
+        explain_verdict,
+    } = options;
     let gate_started_at = Instant::now();
 
+    let cfg = load_repo_config(Path::new(repo_root));
+    let preflight_lean = cfg
+        .as_ref()
+        .ok()
+        .and_then(|c| c.quality_contract.as_ref())
+        .is_some_and(|c| c.gate.preflight_lean);
+
     // Always validate in ratchet mode first (fail-closed).
-    let validate = validate(repo_root, ValidateMode::Ratchet, false, None);
+    let mut validate = if preflight_lean {
+        validate_gate_preflight(repo_root)
+    } else {
+        validate(repo_root, ValidateMode::Ratchet, false, None)
+    };
     let mut receipt_violations: Vec<Violation> = vec![];
 
     if !validate.ok {
@@ -394,10 +397,17 @@ pub(crate) async fn gate(
                 message: "validate(ratchet) failed; gate aborted".to_string(),
             },
         );
-        return maybe_write_gate_witness(Path::new(repo_root), kind, write_witness, out);
+        return maybe_write_gate_witness(
+            Path::new(repo_root),
+            kind,
+            write_witness,
+            witness_dir,
+            explain_verdict,
+            out,
+        );
     }
 
-    let cfg = match load_repo_config(Path::new(repo_root)) {
+    let cfg = match cfg {
         Ok(c) => c,
         Err(e) => {
             let out = gate_fail(
@@ -408,7 +418,14 @@ pub(crate) async fn gate(
                 receipt_violations,
                 map_config_error(repo_root, e),
             );
-            return maybe_write_gate_witness(Path::new(repo_root), kind, write_witness, out);
+            return maybe_write_gate_witness(
+                Path::new(repo_root),
+                kind,
+                write_witness,
+                witness_dir,
+                explain_verdict,
+                out,
+            );
         }
     };
 
@@ -417,52 +434,55 @@ pub(crate) async fn gate(
         GateKind::Ci => cfg.gate.ci.clone(),
         GateKind::Flagship => cfg.gate.flagship.clone(),
     };
+    let tool_ids = resolve_tool_aliases(&cfg.tool_aliases, tool_ids, &mut receipt_violations);
     if let Err(err) = ensure_gate_sequence_invariants(kind, &tool_ids) {
         let out = gate_fail(repo_root, kind, validate, vec![], receipt_violations, err);
-        return maybe_write_gate_witness(Path::new(repo_root), kind, write_witness, out);
+        return maybe_write_gate_witness(
+            Path::new(repo_root),
+            kind,
+            write_witness,
+            witness_dir,
+            explain_verdict,
+            out,
+        );
     }
 
     if let Some(contract) = &cfg.quality_contract
         && !contract.impact.rules.is_empty()
     {
-        match collect_changed_files(Path::new(repo_root), &contract.impact.diff_base) {
-            Ok(changed) => match required_tools_for_changes(contract, &changed) {
-                Ok((required_tools, unmatched)) => {
-                    let selected: BTreeSet<String> = tool_ids.iter().cloned().collect();
-                    for required in required_tools {
-                        if !selected.contains(&required) {
-                            receipt_violations.push(Violation::blocking(
-                                "change_impact.required_tool_missing",
-                                format!(
-                                    "changed files require tool '{}', but it is not in selected gate {:?}",
-                                    required, kind
-                                ),
-                                None,
-                                None,
-                            ));
-                        }
-                    }
-                    receipt_violations.extend(unmapped_path_violations(
-                        contract.impact.unmapped_path_policy,
-                        &unmatched,
-                    ));
+        receipt_violations.extend(
+            evaluate_change_impact(Path::new(repo_root), contract, kind, &tool_ids).violations,
+        );
+    }
+
+    if !cfg.checks.test_presence.is_empty() {
+        let default_impact = ImpactConfig::default();
+        let impact = cfg
+            .quality_contract
+            .as_ref()
+            .map(|c| &c.impact)
+            .unwrap_or(&default_impact);
+        match collect_changed_files(Path::new(repo_root), impact) {
+            Ok(changed) => {
+                for tp_cfg in &cfg.checks.test_presence {
+                    let mut out = run_test_presence_check(Path::new(repo_root), tp_cfg, &changed);
+                    receipt_violations.append(&mut out.violations);
                 }
-                Err(msg) => receipt_violations.push(Violation::blocking(
-                    "change_impact.check_failed",
-                    msg,
-                    None,
-                    None,
-                )),
-            },
+            }
             Err(msg) => receipt_violations.push(Violation::blocking(
-                "change_impact.diff_failed",
-                msg,
+                "test_presence.check_failed",
+                format!("test_presence requires a diff base: {msg}"),
                 None,
                 None,
             )),
         }
     }
 
+    let fail_fast = fail_fast.unwrap_or_else(|| {
+        cfg.quality_contract
+            .as_ref()
+            .is_none_or(|contract| contract.gate.fail_fast)
+    });
     let mut receipts: Vec<Receipt> = vec![];
     for tool_id in tool_ids {
         if let Some(total_ms) = gate_budget_ms
@@ -491,12 +511,26 @@ pub(crate) async fn gate(
                         message: format!("gate references unknown tool_id={tool_id}"),
                     },
                 );
-                return maybe_write_gate_witness(Path::new(repo_root), kind, write_witness, out);
+                return maybe_write_gate_witness(
+                    Path::new(repo_root),
+                    kind,
+                    write_witness,
+                    witness_dir,
+                    explain_verdict,
+                    out,
+                );
             }
         };
 
         let timeout_override_ms =
             gate_budget_ms.map(|total_ms| remaining_budget_ms(gate_started_at, total_ms));
+        let full_capture_tool;
+        let tool = if capture_full {
+            full_capture_tool = with_full_capture(tool);
+            &full_capture_tool
+        } else {
+            tool
+        };
 
         match run_project_tool_with_timeout_override(
             Path::new(repo_root),
@@ -515,6 +549,8 @@ pub(crate) async fn gate(
                         Path::new(repo_root),
                         kind,
                         write_witness,
+                        witness_dir,
+                        explain_verdict,
                         out,
                     );
                 }
@@ -529,15 +565,16 @@ pub(crate) async fn gate(
                     receipt_violations.push(v);
                 }
                 if !dry_run && let Some(report_cfg) = &tool.report {
-                    let (report, mut violations) =
+                    let (report, mut violations, mut suppressed) =
                         ingest_tool_report(Path::new(repo_root), &tool.id, report_cfg);
                     r.structured_report = report;
                     receipt_violations.append(&mut violations);
+                    validate.suppressed.append(&mut suppressed);
                 }
 
                 let success = r.success;
                 receipts.push(r);
-                if !success {
+                if !success && fail_fast {
                     break;
                 }
             }
@@ -546,13 +583,18 @@ pub(crate) async fn gate(
                     classify_run_failed(&e),
                     format!("tool_id={tool_id}: {e}"),
                     None,
-                    None,
+                    Some(serde_json::json!({ "tool_id": tool_id })),
                 ));
-                break;
+                if fail_fast {
+                    break;
+                }
             }
         }
     }
 
+    validate
+        .check_errors
+        .extend(crate::app::check_errors_from(&receipt_violations));
     let verdict = crate::judge::judge_gate(&validate.violations, &receipt_violations, &receipts);
     let ok = matches!(verdict.decision.status, crate::api::DecisionStatus::Pass);
     let error = match verdict.decision.status {
@@ -571,7 +613,7 @@ pub(crate) async fn gate(
     let effective_write_witness = if dry_run {
         write_witness
     } else if let Some(contract) = &cfg.quality_contract {
-        write_witness || contract.proof.require_witness
+        write_witness || contract.proof.witness_required(kind)
     } else {
         write_witness
     };
@@ -598,10 +640,19 @@ pub(crate) async fn gate(
         job: None,
         job_state: None,
         job_error: None,
+        all_findings: vec![],
+        config_hash: None,
     };
     out.validate.evidence = crate::evidence::build_validate_envelope(&out.validate);
     out.evidence = crate::evidence::build_gate_envelope(&out);
-    maybe_write_gate_witness(Path::new(repo_root), kind, effective_write_witness, out)
+    maybe_write_gate_witness(
+        Path::new(repo_root),
+        kind,
+        effective_write_witness,
+        witness_dir,
+        explain_verdict,
+        out,
+    )
 }
 
 #[cfg(test)]
@@ -0,0 +1,94 @@
+use crate::api::GateKind;
+use crate::config::CircuitBreakerConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub(super) const CIRCUIT_STATE_REL_PATH: &str = ".agents/mcp/compas/gate_circuit.json";
+
+/// Recent failure times (unix seconds) per gate kind. A pass removes the kind's entry.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CircuitState {
+    #[serde(default)]
+    failures: BTreeMap<GateKind, Vec<u64>>,
+}
+
+pub(super) struct CircuitOpen {
+    pub failures: usize,
+    /// Seconds until the oldest counted failure leaves the window.
+    pub retry_after_s: u64,
+}
+
+fn window_s(cfg: &CircuitBreakerConfig) -> u64 {
+    cfg.window_min.saturating_mul(60)
+}
+
+/// An unreadable or corrupt state file counts as closed: the breaker only damps retry loops.
+fn load_state(repo_root: &Path) -> CircuitState {
+    std::fs::read_to_string(repo_root.join(CIRCUIT_STATE_REL_PATH))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn write_state(repo_root: &Path, state: &CircuitState) -> Result<(), String> {
+    let path = repo_root.join(CIRCUIT_STATE_REL_PATH);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("failed to create {parent:?}: {e}"))?;
+    }
+    let json = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("failed to serialize circuit state: {e}"))?;
+    let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+    std::fs::write(&tmp, json).map_err(|e| format!("failed to write {tmp:?}: {e}"))?;
+    std::fs::rename(&tmp, &path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        format!("failed to rename {path:?}: {e}")
+    })
+}
+
+fn recent(failures: &[u64], cfg: &CircuitBreakerConfig, now: u64) -> Vec<u64> {
+    let cutoff = now.saturating_sub(window_s(cfg));
+    failures.iter().copied().filter(|&t| t > cutoff).collect()
+}
+
+pub(super) fn check_open(
+    repo_root: &Path,
+    kind: GateKind,
+    cfg: &CircuitBreakerConfig,
+    now: u64,
+) -> Option<CircuitOpen> {
+    let state = load_state(repo_root);
+    let failures = recent(state.failures.get(&kind)?, cfg, now);
+    if failures.len() < cfg.failures {
+        return None;
+    }
+    let oldest = failures.iter().copied().min().unwrap_or(now);
+    Some(CircuitOpen {
+        failures: failures.len(),
+        retry_after_s: (oldest + window_s(cfg)).saturating_sub(now),
+    })
+}
+
+pub(super) fn record_outcome(
+    repo_root: &Path,
+    kind: GateKind,
+    cfg: &CircuitBreakerConfig,
+    now: u64,
+    passed: bool,
+) -> Result<(), String> {
+    let mut state = load_state(repo_root);
+    if passed {
+        if state.failures.remove(&kind).is_none() {
+            return Ok(());
+        }
+    } else {
+        let mut failures = recent(
+            state.failures.get(&kind).map(Vec::as_slice).unwrap_or(&[]),
+            cfg,
+            now,
+        );
+        failures.push(now);
+        state.failures.insert(kind, failures);
+    }
+    write_state(repo_root, &state)
+}
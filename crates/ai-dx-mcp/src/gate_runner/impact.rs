@@ -0,0 +1,257 @@
+use crate::{
+    api::{GateKind, Violation},
+    config::{ImpactConfig, ImpactUnmappedPathPolicy, QualityContractConfig},
+};
+use globset::{Glob, GlobSetBuilder};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::process::Command;
+
+pub(super) fn run_git(repo_root: &Path, args: &[&str]) -> Result<String, String> {
+    let out = Command::new("git")
+        .current_dir(repo_root)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git {:?}: {e}", args))?;
+    if !out.status.success() {
+        let err = String::from_utf8_lossy(&out.stderr).trim().to_string();
+        return Err(format!("git {:?} failed: {}", args, err));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+const DIFF_BASE_ENV: &str = "COMPAS_DIFF_BASE";
+
+pub(super) fn diff_base_candidates(
+    target: &str,
+    env_override: Option<&str>,
+    extra_candidates: &[String],
+) -> Vec<String> {
+    let mut candidates: Vec<String> = vec![];
+    if let Some(env_base) = env_override.map(str::trim).filter(|s| !s.is_empty()) {
+        candidates.push(env_base.to_string());
+    }
+    let auto = target.eq_ignore_ascii_case("auto");
+    if !auto {
+        candidates.push(target.to_string());
+    }
+    candidates.extend(
+        extra_candidates
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(ToString::to_string),
+    );
+    let builtin: &[&str] = if auto {
+        &["origin/main", "origin/master", "main", "master"]
+    } else if target == "origin/main" {
+        &["origin/master", "main", "master"]
+    } else if target == "origin/master" {
+        &["origin/main", "main", "master"]
+    } else {
+        &[]
+    };
+    candidates.extend(builtin.iter().map(|s| s.to_string()));
+
+    let mut seen: BTreeSet<String> = BTreeSet::new();
+    candidates.retain(|c| seen.insert(c.clone()));
+    candidates
+}
+
+/// `env_override` is the `COMPAS_DIFF_BASE` value; callers read the environment so that
+/// resolution itself stays deterministic.
+pub(super) fn resolve_diff_base(
+    repo_root: &Path,
+    diff_base: &str,
+    env_override: Option<&str>,
+    extra_candidates: &[String],
+) -> Result<String, String> {
+    if let Some(target) = diff_base.strip_prefix("merge-base:") {
+        let target = target.trim();
+        let candidates = diff_base_candidates(target, env_override, extra_candidates);
+        for candidate in candidates {
+            if let Ok(base) = run_git(repo_root, &["merge-base", "HEAD", candidate.as_str()]) {
+                return Ok(base);
+            }
+        }
+        if run_git(repo_root, &["rev-parse", "--verify", "HEAD~1"]).is_ok() {
+            return Ok("HEAD~1".to_string());
+        }
+        if run_git(repo_root, &["rev-parse", "--verify", "HEAD"]).is_ok() {
+            return Ok("HEAD".to_string());
+        }
+        Err(format!(
+            "unable to resolve merge-base target '{}' for change_impact",
+            target
+        ))
+    } else {
+        Ok(diff_base.to_string())
+    }
+}
+
+pub(super) fn collect_changed_files(
+    repo_root: &Path,
+    impact: &ImpactConfig,
+) -> Result<Vec<String>, String> {
+    let env_override = std::env::var(DIFF_BASE_ENV).ok();
+    let base = resolve_diff_base(
+        repo_root,
+        &impact.diff_base,
+        env_override.as_deref(),
+        &impact.extra_base_candidates,
+    )?;
+    let out = run_git(
+        repo_root,
+        &["diff", "--name-only", &format!("{base}...HEAD")],
+    )?;
+    let mut files = out
+        .lines()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+pub(super) fn required_tools_for_changes(
+    contract: &QualityContractConfig,
+    changed_files: &[String],
+) -> Result<(BTreeSet<String>, Vec<String>), String> {
+    let mut required: BTreeSet<String> = BTreeSet::new();
+    let mut unmatched: Vec<String> = vec![];
+    for path in changed_files {
+        let mut matched = false;
+        for rule in &contract.impact.rules {
+            if rule.path_globs.is_empty() || rule.required_tools.is_empty() {
+                continue;
+            }
+            let mut b = GlobSetBuilder::new();
+            for p in &rule.path_globs {
+                let g =
+                    Glob::new(p).map_err(|e| format!("invalid change_impact glob {:?}: {e}", p))?;
+                b.add(g);
+            }
+            let set = b
+                .build()
+                .map_err(|e| format!("failed to build change_impact globset: {e}"))?;
+            if set.is_match(path) {
+                matched = true;
+                for t in &rule.required_tools {
+                    required.insert(t.clone());
+                }
+            }
+        }
+        if !matched {
+            unmatched.push(path.clone());
+        }
+    }
+    Ok((required, unmatched))
+}
+
+/// What `[impact].rules` make of the branch diff for one gate sequence.
+pub(super) struct ChangeImpact {
+    pub(super) changed_files: Vec<String>,
+    /// Required tool id -> the changed files that require it.
+    pub(super) required_by: BTreeMap<String, Vec<String>>,
+    /// Required tools absent from the sequence, unmapped paths and diff/rule failures.
+    pub(super) violations: Vec<Violation>,
+}
+
+pub(super) fn evaluate_change_impact(
+    repo_root: &Path,
+    contract: &QualityContractConfig,
+    kind: GateKind,
+    tool_ids: &[String],
+) -> ChangeImpact {
+    let mut impact = ChangeImpact {
+        changed_files: vec![],
+        required_by: BTreeMap::new(),
+        violations: vec![],
+    };
+    impact.changed_files = match collect_changed_files(repo_root, &contract.impact) {
+        Ok(changed) => changed,
+        Err(msg) => {
+            impact.violations.push(Violation::blocking(
+                "change_impact.diff_failed",
+                msg,
+                None,
+                None,
+            ));
+            return impact;
+        }
+    };
+    let mut unmatched: Vec<String> = vec![];
+    for path in &impact.changed_files {
+        match required_tools_for_changes(contract, std::slice::from_ref(path)) {
+            Ok((required_tools, path_unmatched)) => {
+                for tool_id in required_tools {
+                    impact
+                        .required_by
+                        .entry(tool_id)
+                        .or_default()
+                        .push(path.clone());
+                }
+                unmatched.extend(path_unmatched);
+            }
+            Err(msg) => {
+                impact.required_by.clear();
+                impact.violations = vec![Violation::blocking(
+                    "change_impact.check_failed",
+                    msg,
+                    None,
+                    None,
+                )];
+                return impact;
+            }
+        }
+    }
+    for required in impact.required_by.keys() {
+        if !tool_ids.contains(required) {
+            impact.violations.push(Violation::blocking(
+                "change_impact.required_tool_missing",
+                format!(
+                    "changed files require tool '{}', but it is not in selected gate {:?}",
+                    required, kind
+                ),
+                None,
+                None,
+            ));
+        }
+    }
+    impact.violations.extend(unmapped_path_violations(
+        contract.impact.unmapped_path_policy,
+        &unmatched,
+    ));
+    impact
+}
+
+pub(super) fn unmapped_path_violations(
+    policy: ImpactUnmappedPathPolicy,
+    unmatched: &[String],
+) -> Vec<Violation> {
+    let mut out = vec![];
+    for path in unmatched {
+        let message = format!(
+            "changed path '{}' is not mapped in quality_contract [impact].rules",
+            path
+        );
+        match policy {
+            ImpactUnmappedPathPolicy::Block => out.push(Violation::blocking(
+                "change_impact.unmapped_path",
+                message,
+                Some(path.clone()),
+                None,
+            )),
+            ImpactUnmappedPathPolicy::Observe => out.push(Violation::observation(
+                "change_impact.unmapped_path",
+                message,
+                Some(path.clone()),
+                None,
+            )),
+            ImpactUnmappedPathPolicy::Ignore => {}
+        }
+    }
+    out
+}
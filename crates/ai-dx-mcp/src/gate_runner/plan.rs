@@ -0,0 +1,140 @@
+use super::{ensure_gate_sequence_invariants, evaluate_change_impact, resolve_tool_aliases};
+use crate::api::{ApiError, GateKind, Violation, ViolationTier};
+use crate::app::map_config_error;
+use crate::repo::load_repo_config;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// One tool of a `gate --print-plan` sequence, in run order (`position` is 1-based).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct GatePlanStep {
+    pub position: usize,
+    pub tool_id: String,
+    /// Why the tool runs: its `[gate]` slot, a renamed id it was resolved from, and the changed
+    /// files whose change_impact rules require it.
+    pub reasons: Vec<String>,
+}
+
+/// The resolved tool sequence a gate would run; nothing is executed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GatePlanOutput {
+    /// No config error and no blocking plan violation.
+    pub ok: bool,
+    pub error: Option<ApiError>,
+    pub repo_root: String,
+    pub kind: GateKind,
+    pub steps: Vec<GatePlanStep>,
+    /// Files change_impact diffed against its base (empty without `[impact].rules`).
+    pub changed_files: Vec<String>,
+    /// Tools change_impact requires that the sequence lacks; the gate would block on them.
+    pub missing_required_tools: Vec<String>,
+    /// Alias and change_impact findings the gate would raise before running any tool.
+    pub violations: Vec<Violation>,
+}
+
+/// Changed files listed in a change_impact reason before the rest is summarized as a count.
+const REASON_PATHS_MAX: usize = 3;
+
+fn kind_label(kind: GateKind) -> &'static str {
+    match kind {
+        GateKind::CiFast => "ci_fast",
+        GateKind::Ci => "ci",
+        GateKind::Flagship => "flagship",
+    }
+}
+
+fn change_impact_reason(paths: &[String]) -> String {
+    let shown = paths
+        .iter()
+        .take(REASON_PATHS_MAX)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    match paths.len().saturating_sub(REASON_PATHS_MAX) {
+        0 => format!("change_impact: required by {shown}"),
+        rest => format!("change_impact: required by {shown} (+{rest} more)"),
+    }
+}
+
+/// Resolves the tool sequence `gate <kind>` would run (aliases, sequence invariants, known tool
+/// ids, change_impact) without running validate or any tool.
+pub(crate) fn gate_plan(repo_root: &str, kind: GateKind) -> GatePlanOutput {
+    let mut out = GatePlanOutput {
+        ok: false,
+        error: None,
+        repo_root: repo_root.to_string(),
+        kind,
+        steps: vec![],
+        changed_files: vec![],
+        missing_required_tools: vec![],
+        violations: vec![],
+    };
+    let cfg = match load_repo_config(Path::new(repo_root)) {
+        Ok(c) => c,
+        Err(e) => {
+            out.error = Some(map_config_error(repo_root, e));
+            return out;
+        }
+    };
+
+    let configured = match kind {
+        GateKind::CiFast => cfg.gate.ci_fast.clone(),
+        GateKind::Ci => cfg.gate.ci.clone(),
+        GateKind::Flagship => cfg.gate.flagship.clone(),
+    };
+    let tool_ids = resolve_tool_aliases(&cfg.tool_aliases, configured.clone(), &mut out.violations);
+    out.steps = configured
+        .iter()
+        .zip(&tool_ids)
+        .enumerate()
+        .map(|(idx, (raw, tool_id))| {
+            let mut reasons = vec![format!("[gate] {} #{}", kind_label(kind), idx + 1)];
+            if raw != tool_id {
+                reasons.push(format!("resolved from renamed tool_id={raw}"));
+            }
+            GatePlanStep {
+                position: idx + 1,
+                tool_id: tool_id.clone(),
+                reasons,
+            }
+        })
+        .collect();
+
+    if let Err(err) = ensure_gate_sequence_invariants(kind, &tool_ids) {
+        out.error = Some(err);
+        return out;
+    }
+    if let Some(unknown) = tool_ids.iter().find(|id| !cfg.tools.contains_key(*id)) {
+        out.error = Some(ApiError {
+            code: "gate.unknown_tool_id".to_string(),
+            message: format!("gate references unknown tool_id={unknown}"),
+        });
+        return out;
+    }
+
+    if let Some(contract) = &cfg.quality_contract
+        && !contract.impact.rules.is_empty()
+    {
+        let impact = evaluate_change_impact(Path::new(repo_root), contract, kind, &tool_ids);
+        for step in &mut out.steps {
+            if let Some(paths) = impact.required_by.get(&step.tool_id) {
+                step.reasons.push(change_impact_reason(paths));
+            }
+        }
+        out.missing_required_tools = impact
+            .required_by
+            .keys()
+            .filter(|id| !tool_ids.contains(id))
+            .cloned()
+            .collect();
+        out.changed_files = impact.changed_files;
+        out.violations.extend(impact.violations);
+    }
+
+    out.ok = !out
+        .violations
+        .iter()
+        .any(|v| v.tier == ViolationTier::Blocking);
+    out
+}
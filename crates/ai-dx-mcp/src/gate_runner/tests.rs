@@ -1,7 +1,8 @@
-use super::{
-    check_receipt_contract, classify_run_failed, effective_receipt_contract, gate_fail,
-    required_tools_for_changes, unmapped_path_violations,
+use super::impact::{
+    diff_base_candidates, required_tools_for_changes, resolve_diff_base, run_git,
+    unmapped_path_violations,
 };
+use super::{check_receipt_contract, classify_run_failed, effective_receipt_contract, gate_fail};
 use crate::{
     api::{
         ApiError, DecisionStatus, GateKind, Receipt, ValidateMode, ValidateOutput, ViolationTier,
@@ -51,6 +52,13 @@ fn mk_validate_output(ok: bool) -> ValidateOutput {
         summary_md: None,
         evidence: crate::api::EvidenceEnvelope::default(),
         payload_meta: None,
+        timings_ms: None,
+        baseline_diff: None,
+        disabled_packs: vec![],
+        partial_run: None,
+        check_errors: vec![],
+        swamped: false,
+        swamped_summary: None,
     }
 }
 
@@ -191,3 +199,57 @@ fn unmapped_path_violations_respect_policy() {
     let ignore = unmapped_path_violations(ImpactUnmappedPathPolicy::Ignore, &unmatched);
     assert!(ignore.is_empty());
 }
+
+#[test]
+fn diff_base_candidates_try_env_and_config_before_builtins() {
+    let extra = vec!["develop".to_string(), "trunk".to_string()];
+    let got = diff_base_candidates("auto", Some("release"), &extra);
+    assert_eq!(
+        got,
+        vec![
+            "release",
+            "develop",
+            "trunk",
+            "origin/main",
+            "origin/master",
+            "main",
+            "master"
+        ]
+    );
+
+    let got = diff_base_candidates("origin/main", None, &["main".to_string()]);
+    assert_eq!(got, vec!["origin/main", "main", "origin/master", "master"]);
+}
+
+#[test]
+fn resolve_diff_base_uses_configured_trunk_candidate() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let repo = dir.path();
+    let git = |args: &[&str]| run_git(repo, args).expect("git command");
+    git(&["init", "-q"]);
+    git(&["checkout", "-q", "-b", "trunk"]);
+    git(&["config", "user.email", "ci@example.com"]);
+    git(&["config", "user.name", "CI"]);
+    std::fs::write(repo.join("a.txt"), "a\n").expect("write a.txt");
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "trunk base"]);
+    let trunk_head = git(&["rev-parse", "HEAD"]);
+
+    git(&["checkout", "-q", "-b", "feature"]);
+    for name in ["b.txt", "c.txt"] {
+        std::fs::write(repo.join(name), "x\n").expect("write feature file");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", name]);
+    }
+
+    let without = resolve_diff_base(repo, "merge-base:auto", None, &[]).expect("fallback base");
+    assert_eq!(without, "HEAD~1");
+
+    let with = resolve_diff_base(repo, "merge-base:auto", None, &["trunk".to_string()])
+        .expect("configured base");
+    assert_eq!(with, trunk_head);
+
+    let from_env =
+        resolve_diff_base(repo, "merge-base:auto", Some("trunk"), &[]).expect("env base");
+    assert_eq!(from_env, trunk_head);
+}
@@ -13,6 +13,7 @@ fn apply_plan_writes_files_under_allowlist() {
             content_utf8: "hello".to_string(),
         }],
         deletes: vec![],
+        deletes_preview: vec![],
     };
 
     apply_plan(repo, &plan).expect("apply ok");
@@ -39,6 +40,7 @@ fn apply_plan_allows_ai_first_scaffold_files() {
             },
         ],
         deletes: vec![],
+        deletes_preview: vec![],
     };
 
     apply_plan(repo, &plan).expect("apply ok");
@@ -64,6 +66,7 @@ fn apply_plan_rejects_conflicting_ai_first_scaffold_file() {
             content_utf8: "router".to_string(),
         }],
         deletes: vec![],
+        deletes_preview: vec![],
     };
 
     let err = apply_plan(repo, &plan).unwrap_err();
@@ -85,6 +88,7 @@ fn apply_plan_rejects_paths_outside_allowlist() {
             content_utf8: "nope".to_string(),
         }],
         deletes: vec![],
+        deletes_preview: vec![],
     };
 
     let err = apply_plan(repo, &plan).unwrap_err();
@@ -108,6 +112,7 @@ fn apply_plan_rejects_symlink_path_component() {
             content_utf8: "x".to_string(),
         }],
         deletes: vec![],
+        deletes_preview: vec![],
     };
 
     let err = apply_plan(repo, &plan).unwrap_err();
@@ -0,0 +1,121 @@
+use super::{NetAllowed, block_on_init_future, download_pack_archive_http};
+use crate::api::{ApiError, ExternalPackRef};
+use crate::packs::StagedPack;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+fn api_err(code: &str, message: impl Into<String>) -> ApiError {
+    ApiError {
+        code: code.to_string(),
+        message: message.into(),
+    }
+}
+
+/// An external pack archive whose bytes already match the sha256 pin.
+pub(super) struct FetchedPack {
+    source: String,
+    sha256: String,
+    bytes: Vec<u8>,
+}
+
+/// Downloads each external pack and checks it against its sha256 pin.
+///
+/// Runs before the plan is applied, so a download failure, pin mismatch or compas-lite build
+/// leaves the repo untouched; the first failure stops the run.
+pub(super) fn fetch_external_packs(
+    net: NetAllowed,
+    refs: &[ExternalPackRef],
+) -> Result<Vec<FetchedPack>, ApiError> {
+    let mut fetched = vec![];
+    for r in refs {
+        let source = r.source.trim().to_string();
+        let url = source.clone();
+        let bytes = block_on_init_future(async move {
+            download_pack_archive_http(net, &url)
+                .await
+                .map_err(|e| api_err("init.external_pack_download_failed", e))
+        })?;
+        let expected = r.sha256.trim().to_ascii_lowercase();
+        let actual = crate::hash::sha256_hex(&bytes);
+        if actual != expected {
+            return Err(api_err(
+                "init.external_pack_sha256_mismatch",
+                format!(
+                    "sha256 mismatch for pack source={source:?}: expected={expected}, actual={actual}"
+                ),
+            ));
+        }
+        fetched.push(FetchedPack {
+            source,
+            sha256: expected,
+            bytes,
+        });
+    }
+    Ok(fetched)
+}
+
+/// Verifies and extracts each fetched pack into the staging dir, before the plan is applied.
+///
+/// Any failure (malformed archive, missing or invalid pack.toml) removes what was staged so far,
+/// so the repo is left as it was.
+pub(super) fn stage_external_packs(
+    repo_root: &Path,
+    fetched: &[FetchedPack],
+) -> Result<Vec<StagedPack>, ApiError> {
+    let mut staged: Vec<StagedPack> = vec![];
+    let mut seen_sha: BTreeSet<&str> = BTreeSet::new();
+    for pack in fetched {
+        // The staging dir is keyed by sha256; the same archive listed twice is staged once.
+        if !seen_sha.insert(pack.sha256.as_str()) {
+            continue;
+        }
+        match crate::packs::stage_pack_archive_bytes(
+            repo_root,
+            &pack.source,
+            &pack.sha256,
+            &pack.bytes,
+        ) {
+            Ok(s) => staged.push(s),
+            Err(e) => {
+                discard_staged_packs(repo_root, staged);
+                return Err(api_err("init.external_pack_vendor_failed", e));
+            }
+        }
+    }
+    Ok(staged)
+}
+
+pub(super) fn discard_staged_packs(repo_root: &Path, staged: Vec<StagedPack>) {
+    for s in staged {
+        crate::packs::discard_staged_pack(repo_root, s);
+    }
+}
+
+/// Moves each staged pack under `.agents/mcp/compas/packs/vendor/` and records it in
+/// packs.lock. Returns the vendored pack ids.
+///
+/// Runs after the plan is applied so the lock entries land on top of the freshly written lock.
+pub(super) fn promote_external_packs(
+    repo_root: &Path,
+    staged: Vec<StagedPack>,
+) -> Result<Vec<String>, ApiError> {
+    let mut vendored = vec![];
+    let mut pending = staged.into_iter();
+    while let Some(s) = pending.next() {
+        let promoted = crate::packs::promote_staged_pack(repo_root, s)
+            .map_err(|e| api_err("init.external_pack_vendor_failed", e))
+            .and_then(|(manifest, entry)| {
+                crate::packs::upsert_packs_lock(repo_root, entry)
+                    .map_err(|e| api_err("init.packs_lock_write_failed", e))?;
+                Ok(manifest.pack.id)
+            });
+        match promoted {
+            Ok(id) => vendored.push(id),
+            Err(e) => {
+                discard_staged_packs(repo_root, pending.collect());
+                return Err(e);
+            }
+        }
+    }
+    Ok(vendored)
+}
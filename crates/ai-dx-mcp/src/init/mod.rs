@@ -8,25 +8,26 @@ use std::{
 };
 
 mod apply;
+mod external;
 mod planner;
 mod recommendations;
+mod summary;
 
 /// Capability token that authorizes network I/O.
 ///
 /// Guardrail: token is constructible only inside `crate::init` (tuple field is private).
 #[derive(Debug, Clone, Copy)]
-#[allow(dead_code)]
 pub(crate) struct NetAllowed(());
 
 /// Acquire the network capability token.
 ///
 /// Intended usage: only `init` is allowed to download external packs; validate/gate must stay
 /// network-free.
-#[allow(dead_code)]
 fn allow_network_for_init() -> NetAllowed {
     NetAllowed(())
 }
 
+pub use planner::InitDeletePreview;
 #[allow(unused_imports)] // Used by compas.init (and later by apply+vendoring slices).
 pub(crate) use planner::plan_init;
 
@@ -63,8 +64,30 @@ where
 }
 
 pub(crate) fn init(repo_root: &str, req: crate::api::InitRequest) -> crate::api::InitOutput {
-    let plan = match planner::plan_init(Path::new(repo_root), &req) {
-        Ok(p) => p,
+    let root = Path::new(repo_root);
+    let apply = req.apply.unwrap_or(false);
+    let external_packs = req.external_packs.as_deref().unwrap_or_default();
+    // External packs are fetched and staged before planning so their tools and gate wiring are
+    // part of the plan; nothing lands in the repo until the plan is applied.
+    let prepared = if apply {
+        planner::check_external_pack_refs(external_packs)
+            .and_then(|()| external::fetch_external_packs(allow_network_for_init(), external_packs))
+            .and_then(|fetched| external::stage_external_packs(root, &fetched))
+            .and_then(|staged| {
+                let manifests = staged.iter().map(|s| s.manifest().clone()).collect();
+                match planner::plan_init_with_external(root, &req, manifests) {
+                    Ok(plan) => Ok((plan, staged)),
+                    Err(e) => {
+                        external::discard_staged_packs(root, staged);
+                        Err(e)
+                    }
+                }
+            })
+    } else {
+        planner::plan_init(root, &req).map(|plan| (plan, vec![]))
+    };
+    let (plan, staged) = match prepared {
+        Ok(v) => v,
         Err(e) => {
             return crate::api::InitOutput {
                 ok: false,
@@ -102,20 +125,49 @@ pub(crate) fn init(repo_root: &str, req: crate::api::InitRequest) -> crate::api:
         }
     };
 
-    let apply = req.apply.unwrap_or(false);
-    if apply && let Err(e) = apply::apply_plan(Path::new(repo_root), &plan) {
-        return crate::api::InitOutput {
-            ok: false,
-            error: Some(e),
-            warnings,
-            repo_root: repo_root.to_string(),
-            applied: false,
-            plan: Some(plan),
-            recommendations,
-            summary_md: None,
-            payload_meta: None,
-        };
-    }
+    let applied = if apply {
+        match apply::apply_plan(root, &plan) {
+            Ok(()) => external::promote_external_packs(root, staged),
+            Err(e) => {
+                external::discard_staged_packs(root, staged);
+                Err(e)
+            }
+        }
+    } else {
+        if !external_packs.is_empty() {
+            warnings.push(crate::api::ApiError {
+                code: "init.external_packs_not_applied".to_string(),
+                message: "external packs are downloaded and vendored only with apply=true"
+                    .to_string(),
+            });
+        }
+        Ok(vec![])
+    };
+    let vendored = match applied {
+        Ok(ids) => ids,
+        Err(e) => {
+            return crate::api::InitOutput {
+                ok: false,
+                error: Some(e),
+                warnings,
+                repo_root: repo_root.to_string(),
+                applied: false,
+                plan: Some(plan),
+                recommendations,
+                summary_md: None,
+                payload_meta: None,
+            };
+        }
+    };
+
+    let summary_md = req.summary.unwrap_or(false).then(|| {
+        let mut pack_ids: Vec<String> =
+            planner::selected_packs_for_init(Path::new(repo_root), &req)
+                .map(|packs| packs.into_iter().map(|p| p.pack.id).collect())
+                .unwrap_or_default();
+        pack_ids.extend(vendored);
+        summary::render_summary_md(&plan, &pack_ids, apply)
+    });
 
     let plan_for_output = if apply {
         // Output budget-safety: on apply success, avoid echoing full file contents back to the caller.
@@ -132,6 +184,7 @@ pub(crate) fn init(repo_root: &str, req: crate::api::InitRequest) -> crate::api:
                 })
                 .collect(),
             deletes: plan.deletes,
+            deletes_preview: plan.deletes_preview,
         }
     } else {
         plan
@@ -145,7 +198,7 @@ pub(crate) fn init(repo_root: &str, req: crate::api::InitRequest) -> crate::api:
         applied: apply,
         plan: Some(plan_for_output),
         recommendations,
-        summary_md: None,
+        summary_md,
         payload_meta: None,
     }
 }
@@ -156,8 +209,7 @@ pub(crate) fn init(repo_root: &str, req: crate::api::InitRequest) -> crate::api:
 ///
 /// Notes:
 /// - Fail-closed on non-2xx, missing scheme, or oversized downloads.
-/// - Uses bounded chunked read to avoid unbounded memory growth.
-#[allow(dead_code)]
+/// - Shares the registry client (capped redirects, request timeout) and its bounded read.
 #[cfg(feature = "external_packs")]
 pub(crate) async fn download_pack_archive_http(
     _net: NetAllowed,
@@ -171,12 +223,8 @@ pub(crate) async fn download_pack_archive_http(
 
     const MAX_BYTES: u64 = 20 * 1024 * 1024; // 20 MiB
 
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(60))
-        .build()
-        .map_err(|e| format!("failed to build http client: {e}"))?;
-
-    let mut resp = client
+    let client = crate::registry_manifest::http_client()?;
+    let resp = client
         .get(url)
         .send()
         .await
@@ -187,32 +235,15 @@ pub(crate) async fn download_pack_archive_http(
         return Err(format!("GET {url:?} failed: http status {status}"));
     }
 
-    if let Some(len) = resp.content_length()
-        && len > MAX_BYTES
-    {
-        return Err(format!(
-            "pack archive too large for {url:?}: content_length={len} > max={MAX_BYTES}"
-        ));
-    }
-
     let mut out: Vec<u8> = Vec::new();
-    while let Some(chunk) = resp
-        .chunk()
-        .await
-        .map_err(|e| format!("failed to read response body for {url:?}: {e}"))?
-    {
-        if (out.len() as u64) + (chunk.len() as u64) > MAX_BYTES {
-            return Err(format!(
-                "pack archive too large for {url:?}: exceeded max={MAX_BYTES} bytes"
-            ));
-        }
-        out.extend_from_slice(&chunk);
-    }
-
+    crate::registry_manifest::read_body_capped(resp, url, MAX_BYTES, |chunk| {
+        out.extend_from_slice(chunk);
+        Ok(())
+    })
+    .await?;
     Ok(out)
 }
 
-#[allow(dead_code)]
 #[cfg(not(feature = "external_packs"))]
 pub(crate) async fn download_pack_archive_http(
     _net: NetAllowed,
@@ -228,7 +259,7 @@ pub(crate) async fn download_pack_archive_http(
 
 #[cfg(test)]
 mod tests {
-    use super::{allow_network_for_init, block_on_init_future, download_pack_archive_http};
+    use super::{allow_network_for_init, block_on_init_future, download_pack_archive_http, init};
     use crate::api::ApiError;
 
     #[test]
@@ -254,4 +285,281 @@ mod tests {
         .expect("block_on future");
         assert_eq!(result, "ready");
     }
+
+    /// Serves `body` as a single HTTP 200 response to the first connection.
+    #[cfg(feature = "external_packs")]
+    fn serve_once(body: Vec<u8>) -> String {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let url = format!("http://{}/pack.tar.gz", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let Ok((mut stream, _)) = listener.accept() else {
+                return;
+            };
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf);
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = stream.write_all(&body);
+        });
+        url
+    }
+
+    /// A gzipped tar holding one regular file.
+    #[cfg(feature = "external_packs")]
+    fn tar_gz(path: &str, content: &[u8]) -> Vec<u8> {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_mode(0o644);
+        header.set_size(content.len() as u64);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, path, content)
+            .expect("append entry");
+        let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+        gz.write_all(&builder.into_inner().expect("tar bytes"))
+            .expect("gzip write");
+        gz.finish().expect("gzip finish")
+    }
+
+    #[cfg(feature = "external_packs")]
+    #[test]
+    fn apply_vendors_external_pack_and_records_lock_entry() {
+        let archive = tar_gz(
+            "pack/pack.toml",
+            br#"
+[pack]
+id = "org/custom"
+version = "1.2.3"
+description = "External pack vendored by init"
+languages = ["rust"]
+"#,
+        );
+        let sha = crate::hash::sha256_hex(&archive);
+        let url = serve_once(archive);
+
+        let repo = tempfile::tempdir().expect("temp repo");
+        let req = crate::api::InitRequest {
+            repo_root: None,
+            apply: Some(true),
+            profile: None,
+            registry_source: None,
+            packs: None,
+            packs_from: None,
+            external_packs: Some(vec![crate::api::ExternalPackRef {
+                source: url.clone(),
+                sha256: sha.clone(),
+            }]),
+            summary: None,
+        };
+        let out = init(repo.path().to_str().expect("utf-8 path"), req);
+        assert!(out.ok, "{:?}", out.error);
+
+        let vendored = repo
+            .path()
+            .join(".agents/mcp/compas/packs/vendor/org/custom/pack.toml");
+        assert!(vendored.is_file());
+        let raw = std::fs::read_to_string(repo.path().join(".agents/mcp/compas/packs.lock"))
+            .expect("read packs.lock");
+        let lock: crate::packs::schema::PacksLockV1 =
+            toml::from_str(&raw).expect("parse packs.lock");
+        let entry = lock
+            .packs
+            .iter()
+            .find(|p| p.id == "org/custom")
+            .unwrap_or_else(|| panic!("external pack missing from lock: {raw}"));
+        assert_eq!(entry.source, url);
+        assert_eq!(entry.sha256.as_deref(), Some(sha.as_str()));
+        assert_eq!(entry.version.as_deref(), Some("1.2.3"));
+        assert_eq!(
+            entry.resolved_path.as_deref(),
+            Some(".agents/mcp/compas/packs/vendor/org/custom")
+        );
+        assert!(
+            !repo
+                .path()
+                .join(".agents/mcp/compas/packs/vendor/_staging")
+                .exists()
+        );
+
+        // A re-run without external packs keeps the vendored entry in the lock.
+        let rerun = crate::api::InitRequest {
+            repo_root: None,
+            apply: Some(true),
+            profile: None,
+            registry_source: None,
+            packs: None,
+            packs_from: None,
+            external_packs: None,
+            summary: None,
+        };
+        let out = init(repo.path().to_str().expect("utf-8 path"), rerun);
+        assert!(out.ok, "{:?}", out.error);
+        let after = std::fs::read_to_string(repo.path().join(".agents/mcp/compas/packs.lock"))
+            .expect("read packs.lock");
+        assert_eq!(after, raw);
+    }
+
+    #[cfg(feature = "external_packs")]
+    #[test]
+    fn init_selected_external_pack_appends_its_tool_to_flagship() {
+        let archive = tar_gz(
+            "pack/pack.toml",
+            br#"
+[pack]
+id = "security"
+version = "0.1.0"
+description = "Security scans wired into the slower gates"
+
+[[tools]]
+[tools.tool]
+id = "sec-audit"
+description = "Audit dependencies for known advisories"
+command = "echo"
+args = ["audit"]
+
+[gates.tools]
+flagship = ["sec-audit"]
+"#,
+        );
+        let sha = crate::hash::sha256_hex(&archive);
+        let url = serve_once(archive);
+
+        let repo = tempfile::tempdir().expect("temp repo");
+        std::fs::write(repo.path().join("Cargo.toml"), "x").expect("write Cargo.toml");
+        let req = |external_packs| crate::api::InitRequest {
+            repo_root: None,
+            apply: Some(true),
+            profile: None,
+            registry_source: None,
+            packs: None,
+            packs_from: None,
+            external_packs,
+            summary: None,
+        };
+        let root = repo.path().to_str().expect("utf-8 path");
+        let out = init(
+            root,
+            req(Some(vec![crate::api::ExternalPackRef {
+                source: url,
+                sha256: sha,
+            }])),
+        );
+        assert!(out.ok, "{:?}", out.error);
+
+        let cfg = crate::repo::load_repo_config(repo.path()).expect("effective config");
+        assert_eq!(cfg.gate.ci, vec!["rust-test"]);
+        assert_eq!(cfg.gate.flagship, vec!["rust-test", "sec-audit"]);
+        assert!(cfg.tools.contains_key("sec-audit"));
+        let rust_pack =
+            std::fs::read_to_string(repo.path().join(".agents/mcp/compas/packs/rust/pack.toml"))
+                .expect("read rust pack.toml");
+        assert!(!rust_pack.contains("gates.tools"), "{rust_pack}");
+
+        // A re-run selects the vendored pack from packs.lock and keeps the wiring.
+        let out = init(root, req(None));
+        assert!(out.ok, "{:?}", out.error);
+        let cfg = crate::repo::load_repo_config(repo.path()).expect("effective config");
+        assert_eq!(cfg.gate.flagship, vec!["rust-test", "sec-audit"]);
+    }
+
+    #[cfg(feature = "external_packs")]
+    #[test]
+    fn malformed_external_pack_leaves_repo_untouched() {
+        // Pinned correctly, but the archive holds no pack.toml.
+        let archive = tar_gz("pack/README", b"hello");
+        let sha = crate::hash::sha256_hex(&archive);
+        let url = serve_once(archive);
+
+        let repo = tempfile::tempdir().expect("temp repo");
+        std::fs::write(repo.path().join("Cargo.toml"), "x").expect("write Cargo.toml");
+        let req = crate::api::InitRequest {
+            repo_root: None,
+            apply: Some(true),
+            profile: None,
+            registry_source: None,
+            packs: None,
+            packs_from: None,
+            external_packs: Some(vec![crate::api::ExternalPackRef {
+                source: url,
+                sha256: sha,
+            }]),
+            summary: None,
+        };
+        let out = init(repo.path().to_str().expect("utf-8 path"), req);
+        assert!(!out.ok);
+        assert!(!out.applied);
+        let err = out.error.expect("malformed pack must fail");
+        assert_eq!(err.code, "init.external_pack_vendor_failed");
+        assert!(
+            err.message.contains("pack.toml not found"),
+            "{}",
+            err.message
+        );
+        assert!(!repo.path().join(".agents").exists());
+        assert!(!repo.path().join("tools").exists());
+    }
+
+    #[cfg(feature = "external_packs")]
+    #[test]
+    fn external_pack_sha256_mismatch_leaves_repo_untouched() {
+        let archive = b"not the pinned archive".to_vec();
+        let url = serve_once(archive);
+
+        let repo = tempfile::tempdir().expect("temp repo");
+        std::fs::write(repo.path().join("Cargo.toml"), "x").expect("write Cargo.toml");
+        let req = crate::api::InitRequest {
+            repo_root: None,
+            apply: Some(true),
+            profile: None,
+            registry_source: None,
+            packs: None,
+            packs_from: None,
+            external_packs: Some(vec![crate::api::ExternalPackRef {
+                source: url,
+                sha256: "0".repeat(64),
+            }]),
+            summary: None,
+        };
+        let out = init(repo.path().to_str().expect("utf-8 path"), req);
+        assert!(!out.ok);
+        assert!(!out.applied);
+        let err = out.error.expect("pin mismatch must fail");
+        assert_eq!(err.code, "init.external_pack_sha256_mismatch");
+        assert!(!repo.path().join(".agents").exists());
+        assert!(!repo.path().join("tools").exists());
+    }
+
+    #[test]
+    fn external_pack_with_malformed_pin_is_rejected_before_download() {
+        let repo = tempfile::tempdir().expect("temp repo");
+        let req = crate::api::InitRequest {
+            repo_root: None,
+            apply: Some(true),
+            profile: None,
+            registry_source: None,
+            packs: None,
+            packs_from: None,
+            external_packs: Some(vec![crate::api::ExternalPackRef {
+                source: "https://example.invalid/pack.tar.gz".to_string(),
+                sha256: "abc".to_string(),
+            }]),
+            summary: None,
+        };
+        let out = init(repo.path().to_str().expect("utf-8 path"), req);
+        let err = out.error.expect("malformed pin must fail");
+        let expected = if cfg!(feature = "external_packs") {
+            "init.external_pack_invalid"
+        } else {
+            "init.external_packs_unsupported"
+        };
+        assert_eq!(err.code, expected);
+        assert!(!repo.path().join(".agents").exists());
+    }
 }
@@ -1,20 +1,30 @@
 use crate::api::{ApiError, CanonicalToolId, InitPlan, InitRequest, InitWriteFile};
 use crate::config::{
-    ChecksConfigV2, DuplicatesCheckConfigV2, GateConfig, LocCheckConfigV2, PluginConfig,
+    ChecksConfigV2, DuplicatesCheckConfigV2, GateConfig, LocCheckConfigV2, LocMode, PluginConfig,
     PluginMeta, SupplyChainCheckConfigV2, ToolBudgetCheckConfigV2, ToolExecutionPolicyConfigV2,
 };
-use crate::packs::schema::{PackLockEntryV1, PacksLockV1};
+use crate::packs::schema::{PackLockEntryV1, PackManifestV1, PacksLockV1};
 use crate::packs::{load_builtin_packs, pack_matches_repo};
-use std::collections::BTreeSet;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
 mod profile_docs;
 
 use profile_docs::{profile_docs_writes, quality_contract_toml};
 
+/// A path the plan would remove, with the reason it is no longer wanted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct InitDeletePreview {
+    pub path: String,
+    pub reason: String,
+}
+
 const DEFAULT_PLUGIN_ID: &str = "default";
 const DEFAULT_PLUGIN_DESC: &str = "Generated by compas.init (language packs + minimal gate wiring)";
 const TOOL_IMPORT_GLOB: &str = "tools/custom/**/tool.toml";
+const PACKS_LOCK_PATH: &str = ".agents/mcp/compas/packs.lock";
 
 fn api_err(code: &str, message: impl Into<String>) -> ApiError {
     ApiError {
@@ -65,6 +75,19 @@ fn resolve_canonical_tool_ids(
     }
 }
 
+fn pack_gate_tool_ids(
+    tools: &crate::packs::schema::PackGateToolsV1,
+    kind: crate::api::GateKind,
+) -> &[String] {
+    match kind {
+        crate::api::GateKind::CiFast => &tools.ci_fast,
+        crate::api::GateKind::Ci => &tools.ci,
+        crate::api::GateKind::Flagship => &tools.flagship,
+    }
+}
+
+/// Packs contribute in id order: canonical tools first, then the pack's own `gates.tools`;
+/// a tool id already placed by an earlier pack keeps its first position.
 fn resolve_gate_tools_for_packs(
     packs: &[crate::packs::schema::PackManifestV1],
     kind: crate::api::GateKind,
@@ -77,20 +100,37 @@ fn resolve_gate_tools_for_packs(
             Some(g) => g,
             None => continue,
         };
-        let canonical = p.canonical_tools.as_ref().ok_or_else(|| {
-            api_err(
-                "init.pack_missing_canonical_tools",
-                format!("pack {:?} has gates but missing canonical_tools", p.pack.id),
-            )
-        })?;
-
-        for canon_id in canonical_tool_ids_for_gate(gates, kind).iter().copied() {
-            for tool_id in resolve_canonical_tool_ids(canonical, canon_id) {
-                if seen.insert(tool_id.clone()) {
-                    out.push(tool_id.clone());
+        let canonical_ids = canonical_tool_ids_for_gate(gates, kind);
+        if !canonical_ids.is_empty() {
+            let canonical = p.canonical_tools.as_ref().ok_or_else(|| {
+                api_err(
+                    "init.pack_missing_canonical_tools",
+                    format!("pack {:?} has gates but missing canonical_tools", p.pack.id),
+                )
+            })?;
+            for canon_id in canonical_ids.iter().copied() {
+                for tool_id in resolve_canonical_tool_ids(canonical, canon_id) {
+                    if seen.insert(tool_id.clone()) {
+                        out.push(tool_id.clone());
+                    }
                 }
             }
         }
+
+        for tool_id in pack_gate_tool_ids(&gates.tools, kind) {
+            if !p.tools.iter().any(|t| &t.tool.id == tool_id) {
+                return Err(api_err(
+                    "init.pack_gate_tool_unknown",
+                    format!(
+                        "pack {:?} adds tool_id={tool_id:?} to a gate but does not declare it",
+                        p.pack.id
+                    ),
+                ));
+            }
+            if seen.insert(tool_id.clone()) {
+                out.push(tool_id.clone());
+            }
+        }
     }
 
     Ok(out)
@@ -163,6 +203,9 @@ fn default_checks_for_packs(packs: &[crate::packs::schema::PackManifestV1]) -> C
                 "**/node_modules/**".to_string(),
                 ".git/**".to_string(),
             ],
+            respect_gitignore: true,
+            max_depth: None,
+            loc_mode: LocMode::Raw,
             baseline_path: ".agents/mcp/compas/baselines/loc.json".to_string(),
         }],
         env_registry: vec![],
@@ -178,6 +221,11 @@ fn default_checks_for_packs(packs: &[crate::packs::schema::PackManifestV1]) -> C
             ],
             max_file_bytes: 256 * 1024,
             allowlist_globs: vec![],
+            respect_gitignore: true,
+            max_depth: None,
+            min_group_size: 2,
+            ignore_comments: false,
+            severity: Default::default(),
             baseline_path: ".agents/mcp/compas/baselines/duplicates.json".to_string(),
         }],
         supply_chain: vec![SupplyChainCheckConfigV2 {
@@ -189,6 +237,8 @@ fn default_checks_for_packs(packs: &[crate::packs::schema::PackManifestV1]) -> C
             max_tools_per_plugin: 32,
             max_gate_tools_per_kind: 16,
             max_checks_total: 24,
+            max_gate_tools: BTreeMap::new(),
+            max_plugins_with_tools: None,
         }],
         reuse_first: vec![],
         arch_layers: vec![],
@@ -196,6 +246,13 @@ fn default_checks_for_packs(packs: &[crate::packs::schema::PackManifestV1]) -> C
         orphan_api: vec![],
         complexity_budget: vec![],
         contract_break: vec![],
+        test_presence: vec![],
+        file_encoding: vec![],
+        max_params: vec![],
+        binary_artifacts: vec![],
+        import_cycles: vec![],
+        naming_convention: vec![],
+        magic_numbers: vec![],
     }
 }
 
@@ -210,6 +267,7 @@ fn plugin_toml(gate: GateConfig, checks: ChecksConfigV2) -> Result<String, ApiEr
         tool_policy: ToolExecutionPolicyConfigV2::default(),
         gate: Some(gate),
         checks: Some(checks),
+        canonical_tools: None,
     };
 
     toml::to_string_pretty(&plugin).map_err(|e| {
@@ -233,21 +291,121 @@ fn packs_lock_toml(entries: Vec<PackLockEntryV1>) -> Result<String, ApiError> {
     })
 }
 
-pub(crate) fn selected_packs_for_init(
+/// `builtin:<id>` refs recorded in a packs.lock, after checking its schema and that each
+/// recorded version is the one this build ships.
+fn pack_refs_from_lock(
     repo_root: &Path,
-    req: &InitRequest,
-) -> Result<Vec<crate::packs::schema::PackManifestV1>, ApiError> {
-    if req.external_packs.as_ref().is_some_and(|v| !v.is_empty()) {
+    raw_path: &str,
+    builtin: &BTreeMap<String, PackManifestV1>,
+) -> Result<Vec<String>, ApiError> {
+    let lock_path = repo_root.join(raw_path);
+    let raw = std::fs::read_to_string(&lock_path).map_err(|e| {
+        api_err(
+            "init.packs_lock_read_failed",
+            format!("failed to read {:?}: {e}", lock_path),
+        )
+    })?;
+    let lock: PacksLockV1 = toml::from_str(&raw).map_err(|e| {
+        api_err(
+            "init.packs_lock_invalid",
+            format!("failed to parse {:?}: {e}", lock_path),
+        )
+    })?;
+    if lock.version != 1 {
+        return Err(api_err(
+            "init.packs_lock_invalid",
+            format!(
+                "unsupported packs.lock version={} in {:?}",
+                lock.version, lock_path
+            ),
+        ));
+    }
+    let mut refs = Vec::with_capacity(lock.packs.len());
+    for entry in &lock.packs {
+        if entry.source != format!("builtin:{}", entry.id) {
+            return Err(api_err(
+                "init.packs_lock_invalid",
+                format!(
+                    "pack {:?} has source {:?}; only builtin packs can be reproduced",
+                    entry.id, entry.source
+                ),
+            ));
+        }
+        let pack = builtin.get(&entry.id).ok_or_else(|| {
+            api_err(
+                "init.unknown_builtin_pack",
+                format!("unknown builtin pack id={:?}", entry.id),
+            )
+        })?;
+        if let Some(version) = &entry.version
+            && version != &pack.pack.version
+        {
+            return Err(api_err(
+                "init.packs_lock_version_mismatch",
+                format!(
+                    "pack {:?} is locked at version {version}, this build ships {}",
+                    entry.id, pack.pack.version
+                ),
+            ));
+        }
+        refs.push(entry.source.clone());
+    }
+    Ok(refs)
+}
+
+/// Rejects malformed external pack refs before anything is downloaded; lite builds reject any.
+pub(crate) fn check_external_pack_refs(
+    refs: &[crate::api::ExternalPackRef],
+) -> Result<(), ApiError> {
+    if refs.is_empty() {
+        return Ok(());
+    }
+    if !cfg!(feature = "external_packs") {
         return Err(api_err(
             "init.external_packs_unsupported",
-            "external_packs are not supported in this slice (dry-run only)",
+            "external_packs feature is disabled (compas-lite); rebuild with default-features or --features external_packs",
         ));
     }
+    for r in refs {
+        let source = r.source.trim();
+        if !(source.starts_with("http://") || source.starts_with("https://")) {
+            return Err(api_err(
+                "init.external_pack_invalid",
+                format!("external pack source must be an http(s) URL: {source:?}"),
+            ));
+        }
+        let sha = r.sha256.trim();
+        if sha.len() != 64 || !sha.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(api_err(
+                "init.external_pack_invalid",
+                format!("external pack sha256 must be 64 hex chars: {source:?}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn selected_packs_for_init(
+    repo_root: &Path,
+    req: &InitRequest,
+) -> Result<Vec<crate::packs::schema::PackManifestV1>, ApiError> {
+    check_external_pack_refs(req.external_packs.as_deref().unwrap_or_default())?;
 
     let builtin = load_builtin_packs().map_err(|e| api_err("init.load_builtin_failed", e))?;
 
+    let locked_refs = match req.packs_from.as_deref() {
+        Some(_) if req.packs.is_some() => {
+            return Err(api_err(
+                "init.packs_from_conflict",
+                "packs_from reproduces a recorded pack set; drop packs",
+            ));
+        }
+        Some(raw) => Some(pack_refs_from_lock(repo_root, raw, &builtin)?),
+        None => None,
+    };
+
     let mut selected_ids: Vec<String> = vec![];
-    if let Some(overrides) = &req.packs {
+    if let Some(overrides) = locked_refs.as_ref().or(req.packs.as_ref()) {
         for p in overrides.iter().map(|s| s.trim()).filter(|s| !s.is_empty()) {
             let id = p.strip_prefix("builtin:").ok_or_else(|| {
                 api_err(
@@ -307,16 +465,71 @@ pub(crate) fn detected_repo_languages(
 /// This slice intentionally does not apply the plan and does not perform any external I/O
 /// (network download / vendoring).
 pub(crate) fn plan_init(repo_root: &Path, req: &InitRequest) -> Result<InitPlan, ApiError> {
+    plan_init_with_external(repo_root, req, vec![])
+}
+
+/// Plans init for the selected builtin packs plus every external pack: those already vendored
+/// (recorded in packs.lock) and `staged` ones about to be vendored by this run, which replace a
+/// vendored pack with the same id. External packs contribute tools, gate wiring and checks; their
+/// pack.toml and lock entry are written by vendoring, not by the plan.
+pub(crate) fn plan_init_with_external(
+    repo_root: &Path,
+    req: &InitRequest,
+    staged: Vec<PackManifestV1>,
+) -> Result<InitPlan, ApiError> {
     // Universal bootstrap: when no builtin pack is detected, still scaffold compas files
     // (plugin + quality contract + empty packs.lock). Gate remains fail-closed with
     // gate.empty_sequence until project tools are wired explicitly.
 
-    let packs = selected_packs_for_init(repo_root, req)?;
+    let builtin = selected_packs_for_init(repo_root, req)?;
+    let mut external: BTreeMap<String, PackManifestV1> = vendored_packs(repo_root)?
+        .into_iter()
+        .map(|p| (p.pack.id.clone(), p))
+        .collect();
+    external.extend(staged.into_iter().map(|p| (p.pack.id.clone(), p)));
+    let external: Vec<PackManifestV1> = external.into_values().collect();
+    plan_init_for_packs(repo_root, req, &builtin, &external)
+}
 
+/// Manifests of the external packs packs.lock records as vendored (non-`builtin:` entries with a
+/// `resolved_path`).
+fn vendored_packs(repo_root: &Path) -> Result<Vec<PackManifestV1>, ApiError> {
+    let Some(lock) = current_packs_lock(repo_root)? else {
+        return Ok(vec![]);
+    };
+    let mut out = vec![];
+    for entry in &lock.packs {
+        let Some(resolved) = entry.resolved_path.as_deref() else {
+            continue;
+        };
+        if entry.source.starts_with("builtin:") {
+            continue;
+        }
+        let invalid = |message: String| api_err("init.vendored_pack_invalid", message);
+        let rel = crate::repo::safe_relative_path(resolved, "packs.lock resolved_path")
+            .map_err(invalid)?;
+        let path = repo_root.join(rel).join("pack.toml");
+        let raw = std::fs::read_to_string(&path)
+            .map_err(|e| invalid(format!("failed to read {:?}: {e}", path)))?;
+        let manifest: PackManifestV1 = toml::from_str(&raw)
+            .map_err(|e| invalid(format!("failed to parse {:?}: {e}", path)))?;
+        out.push(manifest);
+    }
+    Ok(out)
+}
+
+fn plan_init_for_packs(
+    repo_root: &Path,
+    req: &InitRequest,
+    builtin: &[PackManifestV1],
+    external: &[PackManifestV1],
+) -> Result<InitPlan, ApiError> {
+    let all: Vec<PackManifestV1> = builtin.iter().chain(external).cloned().collect();
+    let packs = all.as_slice();
     // tools/custom/*/tool.toml
     let mut writes: Vec<InitWriteFile> = vec![];
     let mut seen_tool_ids: BTreeSet<String> = BTreeSet::new();
-    for pack in &packs {
+    for pack in packs {
         for t in &pack.tools {
             if !seen_tool_ids.insert(t.tool.id.clone()) {
                 return Err(api_err(
@@ -332,7 +545,7 @@ pub(crate) fn plan_init(repo_root: &Path, req: &InitRequest) -> Result<InitPlan,
 
     // .agents/mcp/compas/packs/*/pack.toml + packs.lock
     let mut lock_entries: Vec<PackLockEntryV1> = vec![];
-    for pack in &packs {
+    for pack in builtin {
         let pack_toml = toml::to_string_pretty(pack).map_err(|e| {
             api_err(
                 "init.serialize_pack_failed",
@@ -351,21 +564,32 @@ pub(crate) fn plan_init(repo_root: &Path, req: &InitRequest) -> Result<InitPlan,
             version: Some(pack.pack.version.clone()),
         });
     }
+    // External packs get their lock entries from vendoring; keep the recorded ones so a re-run
+    // does not drop them from the lock.
+    if let Some(current) = current_packs_lock(repo_root)? {
+        let selected: BTreeSet<String> = lock_entries.iter().map(|e| e.id.clone()).collect();
+        lock_entries.extend(
+            current
+                .packs
+                .into_iter()
+                .filter(|e| !e.source.starts_with("builtin:") && !selected.contains(&e.id)),
+        );
+    }
     lock_entries.sort_by(|a, b| a.id.cmp(&b.id));
     writes.push(InitWriteFile {
-        path: ".agents/mcp/compas/packs.lock".to_string(),
+        path: PACKS_LOCK_PATH.to_string(),
         content_utf8: packs_lock_toml(lock_entries)?,
     });
 
     // .agents/mcp/compas/plugins/default/plugin.toml
     let gate_cfg = GateConfig {
-        ci_fast: resolve_gate_tools_for_packs(&packs, crate::api::GateKind::CiFast)?,
-        ci: resolve_gate_tools_for_packs(&packs, crate::api::GateKind::Ci)?,
-        flagship: resolve_gate_tools_for_packs(&packs, crate::api::GateKind::Flagship)?,
+        ci_fast: resolve_gate_tools_for_packs(packs, crate::api::GateKind::CiFast)?,
+        ci: resolve_gate_tools_for_packs(packs, crate::api::GateKind::Ci)?,
+        flagship: resolve_gate_tools_for_packs(packs, crate::api::GateKind::Flagship)?,
     };
     writes.push(InitWriteFile {
         path: ".agents/mcp/compas/plugins/default/plugin.toml".to_string(),
-        content_utf8: plugin_toml(gate_cfg, default_checks_for_packs(&packs))?,
+        content_utf8: plugin_toml(gate_cfg, default_checks_for_packs(packs))?,
     });
 
     writes.push(InitWriteFile {
@@ -376,11 +600,89 @@ pub(crate) fn plan_init(repo_root: &Path, req: &InitRequest) -> Result<InitPlan,
 
     writes.sort_by(|a, b| a.path.cmp(&b.path));
 
+    // Preview only: packs.lock records no per-file hashes for builtin packs, so init cannot
+    // tell a stale file from one the user edited and never deletes either.
+    let deletes_preview = stale_pack_deletes(repo_root, builtin, &writes)?;
     Ok(InitPlan {
         writes,
         deletes: vec![],
+        deletes_preview,
+    })
+}
+
+/// The repo's current packs.lock, if any.
+fn current_packs_lock(repo_root: &Path) -> Result<Option<PacksLockV1>, ApiError> {
+    let lock_path = repo_root.join(PACKS_LOCK_PATH);
+    if !lock_path.is_file() {
+        return Ok(None);
+    }
+    let raw = std::fs::read_to_string(&lock_path).map_err(|e| {
+        api_err(
+            "init.packs_lock_read_failed",
+            format!("failed to read {:?}: {e}", lock_path),
+        )
+    })?;
+    toml::from_str(&raw).map(Some).map_err(|e| {
+        api_err(
+            "init.packs_lock_invalid",
+            format!("failed to parse {:?}: {e}", lock_path),
+        )
     })
 }
 
+/// Files left behind by builtin packs recorded in the current packs.lock but no longer selected.
+///
+/// Only paths init itself would have written are considered (pack.toml + the pack's tool.toml
+/// files), and only when they still exist and are not re-written by the new plan. They are
+/// reported, never added to `deletes`.
+fn stale_pack_deletes(
+    repo_root: &Path,
+    packs: &[PackManifestV1],
+    writes: &[InitWriteFile],
+) -> Result<Vec<InitDeletePreview>, ApiError> {
+    let Some(lock) = current_packs_lock(repo_root)? else {
+        return Ok(vec![]);
+    };
+
+    let selected: BTreeSet<&str> = packs.iter().map(|p| p.pack.id.as_str()).collect();
+    let written: BTreeSet<&str> = writes.iter().map(|w| w.path.as_str()).collect();
+    let mut out: Vec<InitDeletePreview> = vec![];
+    for entry in &lock.packs {
+        if selected.contains(entry.id.as_str()) || !entry.source.starts_with("builtin:") {
+            continue;
+        }
+        let pack_rel = format!(".agents/mcp/compas/packs/{}/pack.toml", entry.id);
+        let pack_path = repo_root.join(&pack_rel);
+        if !pack_path.is_file() {
+            continue;
+        }
+        let manifest: Option<PackManifestV1> = std::fs::read_to_string(&pack_path)
+            .ok()
+            .and_then(|raw| toml::from_str(&raw).ok());
+        for t in manifest.iter().flat_map(|m| &m.tools) {
+            let tool_rel = format!("tools/custom/{}/tool.toml", t.tool.id);
+            if written.contains(tool_rel.as_str()) || !repo_root.join(&tool_rel).is_file() {
+                continue;
+            }
+            out.push(InitDeletePreview {
+                path: tool_rel,
+                reason: format!(
+                    "tool {:?} of pack {:?} no longer provided by selected packs",
+                    t.tool.id, entry.id
+                ),
+            });
+        }
+        if !written.contains(pack_rel.as_str()) {
+            out.push(InitDeletePreview {
+                path: pack_rel,
+                reason: format!("pack {:?} no longer provided by selected packs", entry.id),
+            });
+        }
+    }
+    out.sort_by(|a, b| a.path.cmp(&b.path));
+    out.dedup_by(|a, b| a.path == b.path);
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests;
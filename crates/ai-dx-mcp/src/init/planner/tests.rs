@@ -1,4 +1,4 @@
-use super::{plan_init, resolve_gate_tools_for_packs};
+use super::{plan_init, plan_init_for_packs, resolve_gate_tools_for_packs};
 use crate::api::{CanonicalToolId, CanonicalToolsConfig, GateKind, InitRequest, ValidateMode};
 use crate::config::PluginConfig;
 use crate::config::ProjectTool;
@@ -23,6 +23,8 @@ fn init_req() -> InitRequest {
         registry_source: None,
         packs: None,
         external_packs: None,
+        packs_from: None,
+        summary: None,
     }
 }
 
@@ -303,6 +305,57 @@ fn init_apply_is_idempotent_and_validate_ok() {
     );
 }
 
+#[test]
+fn plan_init_previews_deletes_when_switching_packs() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+
+    write(repo, "Cargo.toml", "x");
+    let repo_root = repo.to_string_lossy().to_string();
+    let applied = crate::init::init(
+        &repo_root,
+        InitRequest {
+            repo_root: Some(repo_root.clone()),
+            apply: Some(true),
+            ..init_req()
+        },
+    );
+    assert!(applied.ok, "apply ok=false; error={:?}", applied.error);
+    assert!(applied.plan.expect("plan").deletes_preview.is_empty());
+
+    let out = crate::init::init(
+        &repo_root,
+        InitRequest {
+            repo_root: Some(repo_root.clone()),
+            packs: Some(vec!["builtin:go".to_string()]),
+            ..init_req()
+        },
+    );
+    assert!(out.ok, "dry-run ok=false; error={:?}", out.error);
+    assert!(!out.applied);
+    let plan = out.plan.expect("plan");
+    let paths: Vec<&str> = plan
+        .deletes_preview
+        .iter()
+        .map(|d| d.path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            ".agents/mcp/compas/packs/rust/pack.toml",
+            "tools/custom/rust-test/tool.toml",
+        ]
+    );
+    assert!(
+        plan.deletes_preview
+            .iter()
+            .all(|d| d.reason.contains("no longer provided by selected packs"))
+    );
+    assert!(plan.deletes.is_empty(), "{:?}", plan.deletes);
+    // Dry-run must not touch the tree.
+    assert!(repo.join("tools/custom/rust-test/tool.toml").is_file());
+}
+
 #[test]
 fn init_apply_fails_closed_on_conflicting_existing_file() {
     let dir = tempdir().unwrap();
@@ -352,6 +405,7 @@ fn resolve_gate_tools_maps_canonical_ids_and_honors_gate_kinds() {
         timeout_ms: None,
         max_stdout_bytes: None,
         max_stderr_bytes: None,
+        max_tail_bytes: None,
         report: None,
         receipt_contract: None,
         env: BTreeMap::new(),
@@ -390,8 +444,10 @@ fn resolve_gate_tools_maps_canonical_ids_and_honors_gate_kinds() {
             ci_fast: vec![CanonicalToolId::Test],
             ci: vec![CanonicalToolId::Build, CanonicalToolId::Test],
             flagship: vec![CanonicalToolId::Build],
+            tools: Default::default(),
         }),
         checks_v2: None,
+        failure_modes: vec![],
     };
 
     let ci_fast =
@@ -405,6 +461,52 @@ fn resolve_gate_tools_maps_canonical_ids_and_honors_gate_kinds() {
     assert_eq!(flagship, vec!["t-build"]);
 }
 
+#[test]
+fn pack_gate_tools_append_to_named_sequences_in_effective_config() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    write(repo, "Cargo.toml", "x");
+
+    let security: PackManifestV1 = toml::from_str(
+        r#"
+[pack]
+id = "security"
+version = "0.1.0"
+description = "Security scans wired into the slower gates"
+
+[[tools]]
+[tools.tool]
+id = "sec-audit"
+description = "Audit dependencies for known advisories"
+command = "echo"
+args = ["audit"]
+
+[gates.tools]
+ci = ["sec-audit"]
+flagship = ["sec-audit"]
+"#,
+    )
+    .expect("parse security pack");
+    let rust = crate::packs::load_builtin_packs()
+        .expect("builtin packs")
+        .remove("rust")
+        .expect("rust pack");
+
+    let plan = plan_init_for_packs(repo, &init_req(), &[rust], std::slice::from_ref(&security))
+        .expect("plan");
+    crate::init::apply::apply_plan(repo, &plan).expect("apply plan");
+    let cfg = crate::repo::load_repo_config(repo).expect("effective config");
+    assert_eq!(cfg.gate.ci_fast, vec!["rust-test"]);
+    assert_eq!(cfg.gate.ci, vec!["rust-test", "sec-audit"]);
+    assert_eq!(cfg.gate.flagship, vec!["rust-test", "sec-audit"]);
+    assert!(cfg.tools.contains_key("sec-audit"));
+
+    let mut undeclared = security;
+    undeclared.tools.clear();
+    let err = resolve_gate_tools_for_packs(&[undeclared], GateKind::Flagship).unwrap_err();
+    assert_eq!(err.code, "init.pack_gate_tool_unknown");
+}
+
 #[test]
 fn init_e2e_polyglot_validate_then_gate_ci_fast_dry_run_ok() {
     let dir = tempdir().unwrap();
@@ -463,3 +565,53 @@ fn init_e2e_polyglot_validate_then_gate_ci_fast_dry_run_ok() {
         ]
     );
 }
+
+#[test]
+fn plan_init_packs_from_validates_recorded_versions_and_sources() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    let plan_from = |lock: &str| {
+        write(repo, "locks/packs.lock", lock);
+        plan_init(
+            repo,
+            &InitRequest {
+                packs_from: Some("locks/packs.lock".to_string()),
+                ..init_req()
+            },
+        )
+    };
+
+    let plan = plan_from("version = 1\n\n[[packs]]\nid = \"go\"\nsource = \"builtin:go\"\n")
+        .expect("unversioned builtin entry should plan");
+    assert!(
+        plan.writes
+            .iter()
+            .any(|w| w.path == ".agents/mcp/compas/packs/go/pack.toml")
+    );
+
+    let err = plan_from(
+        "version = 1\n\n[[packs]]\nid = \"go\"\nsource = \"builtin:go\"\nversion = \"999.0.0\"\n",
+    )
+    .expect_err("version mismatch must fail");
+    assert_eq!(err.code, "init.packs_lock_version_mismatch");
+
+    let err = plan_from(
+        "version = 1\n\n[[packs]]\nid = \"go\"\nsource = \"https://example.invalid/go.tar.gz\"\n",
+    )
+    .expect_err("non-builtin source must fail");
+    assert_eq!(err.code, "init.packs_lock_invalid");
+
+    let err = plan_from("version = 2\n").expect_err("unknown lock version must fail");
+    assert_eq!(err.code, "init.packs_lock_invalid");
+
+    let err = plan_init(
+        repo,
+        &InitRequest {
+            packs: Some(vec!["builtin:go".to_string()]),
+            packs_from: Some("locks/packs.lock".to_string()),
+            ..init_req()
+        },
+    )
+    .expect_err("packs + packs_from must conflict");
+    assert_eq!(err.code, "init.packs_from_conflict");
+}
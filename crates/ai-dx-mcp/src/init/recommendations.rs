@@ -74,10 +74,8 @@ fn matches_recommendation(
     languages: &[String],
     repo_signals: &[String],
 ) -> bool {
-    if rec.when_no_languages {
-        if !languages.is_empty() {
-            return false;
-        }
+    if rec.when_no_languages && !languages.is_empty() {
+        return false;
     }
     if !rec.languages_any.is_empty()
         && !languages.iter().any(|language| {
@@ -285,6 +285,8 @@ fn plan_init_ignores_registry_source_and_stays_advisory_only() {
             registry_source: None,
             packs: None,
             external_packs: None,
+            packs_from: None,
+            summary: None,
         },
     )
     .unwrap();
@@ -297,6 +299,8 @@ fn plan_init_ignores_registry_source_and_stays_advisory_only() {
             registry_source: Some("https://example.com/registry.manifest.v1.json".to_string()),
             packs: None,
             external_packs: None,
+            packs_from: None,
+            summary: None,
         },
     )
     .unwrap();
@@ -0,0 +1,46 @@
+use crate::api::InitPlan;
+use std::fmt::Write;
+
+/// Human-readable rendering of an init plan for `summary_md`: selected packs, then every
+/// planned write and delete by path, then stale files init leaves in place (with the reason).
+pub(super) fn render_summary_md(plan: &InitPlan, pack_ids: &[String], applied: bool) -> String {
+    let mut md = String::new();
+    let mode = if applied { "applied" } else { "dry-run" };
+    let _ = writeln!(md, "# compas init plan ({mode})\n");
+
+    let _ = writeln!(md, "## Packs ({})\n", pack_ids.len());
+    if pack_ids.is_empty() {
+        let _ = writeln!(md, "- none detected (universal bootstrap)");
+    }
+    for id in pack_ids {
+        let _ = writeln!(md, "- `builtin:{id}`");
+    }
+
+    let _ = writeln!(md, "\n## Writes ({})\n", plan.writes.len());
+    if plan.writes.is_empty() {
+        let _ = writeln!(md, "- none");
+    }
+    for write in &plan.writes {
+        let _ = writeln!(md, "- `{}`", write.path);
+    }
+
+    let _ = writeln!(md, "\n## Deletes ({})\n", plan.deletes.len());
+    if plan.deletes.is_empty() {
+        let _ = writeln!(md, "- none");
+    }
+    for path in &plan.deletes {
+        let _ = writeln!(md, "- `{path}`");
+    }
+
+    if !plan.deletes_preview.is_empty() {
+        let _ = writeln!(
+            md,
+            "\n## Stale files, not deleted ({})\n",
+            plan.deletes_preview.len()
+        );
+        for stale in &plan.deletes_preview {
+            let _ = writeln!(md, "- `{}` — {}", stale.path, stale.reason);
+        }
+    }
+    md
+}
@@ -17,6 +17,31 @@ fn reason_from_violation(v: &Violation) -> DecisionReason {
         code: v.code.clone(),
         class,
         tier,
+        source: None,
+        origin: None,
+    }
+}
+
+/// Gate reasons carry their provenance: validate findings, tool-attributed receipt findings
+/// (`details.tool_id`), or the gate runner itself.
+fn gate_reason_from_violation(v: &Violation, from_validate: bool) -> DecisionReason {
+    let source = if from_validate {
+        "validate".to_string()
+    } else {
+        match v
+            .details
+            .as_ref()
+            .and_then(|d| d.get("tool_id"))
+            .and_then(|t| t.as_str())
+        {
+            Some(tool_id) => format!("tool:{tool_id}"),
+            None => "gate".to_string(),
+        }
+    };
+    DecisionReason {
+        source: Some(source),
+        origin: v.path.clone(),
+        ..reason_from_violation(v)
     }
 }
 
@@ -80,9 +105,13 @@ pub fn judge_gate(
 ) -> Verdict {
     let mut reasons: Vec<DecisionReason> = validate_violations
         .iter()
-        .map(reason_from_violation)
+        .map(|v| gate_reason_from_violation(v, true))
         .collect();
-    reasons.extend(receipt_violations.iter().map(reason_from_violation));
+    reasons.extend(
+        receipt_violations
+            .iter()
+            .map(|v| gate_reason_from_violation(v, false)),
+    );
 
     // Tool business failure (non-timeout, non-success) => ContractBreak.
     // Timeout => TransientTool.
@@ -99,6 +128,8 @@ pub fn judge_gate(
             code: format!("gate.tool_failed.{}", r.tool_id),
             class,
             tier: ViolationTier::Blocking,
+            source: Some(format!("tool:{}", r.tool_id)),
+            origin: None,
         });
     }
 
@@ -142,6 +173,8 @@ mod tests {
             code: "loc.max_exceeded".to_string(),
             class: ErrorClass::ContractBreak,
             tier: ViolationTier::Observation,
+            source: None,
+            origin: None,
         }];
         assert_eq!(decide_gate(&reasons), DecisionStatus::Pass);
     }
@@ -152,6 +185,8 @@ mod tests {
             code: "gate.run_failed".to_string(),
             class: ErrorClass::TransientTool,
             tier: ViolationTier::Blocking,
+            source: None,
+            origin: None,
         }];
         assert_eq!(decide_gate(&reasons), DecisionStatus::Retryable);
     }
@@ -163,11 +198,15 @@ mod tests {
                 code: "gate.run_failed".to_string(),
                 class: ErrorClass::TransientTool,
                 tier: ViolationTier::Blocking,
+                source: None,
+                origin: None,
             },
             DecisionReason {
                 code: "boundary.rule_violation".to_string(),
                 class: ErrorClass::ContractBreak,
                 tier: ViolationTier::Blocking,
+                source: None,
+                origin: None,
             },
         ];
         assert_eq!(decide_gate(&reasons), DecisionStatus::Blocked);
@@ -179,6 +218,8 @@ mod tests {
             code: "boundary.rule_violation".to_string(),
             class: ErrorClass::ContractBreak,
             tier: ViolationTier::Blocking,
+            source: None,
+            origin: None,
         }];
         let status_before = decide_gate(&base);
         let mut extended = base.clone();
@@ -186,6 +227,8 @@ mod tests {
             code: "loc.max_exceeded".to_string(),
             class: ErrorClass::ContractBreak,
             tier: ViolationTier::Observation,
+            source: None,
+            origin: None,
         });
         let status_after = decide_gate(&extended);
         assert!(severity_ord(status_after) >= severity_ord(status_before));
@@ -197,6 +240,8 @@ mod tests {
             code: "boundary.rule_violation".to_string(),
             class: ErrorClass::ContractBreak,
             tier: ViolationTier::Blocking,
+            source: None,
+            origin: None,
         }];
         assert_eq!(
             decide_validate(&reasons, ValidateMode::Warn),
@@ -53,12 +53,23 @@ pub static VIOLATION_REGISTRY: &[ViolationClassEntry] = &[
     entry(Exact("exception.expired"), ContractBreak, Blocking),
     entry(Exact("exception.window_exceeded"), ContractBreak, Blocking),
     entry(Exact("exception.budget_exceeded"), ContractBreak, Blocking),
+    entry(
+        Exact("exception.too_many_long_lived"),
+        ContractBreak,
+        Blocking,
+    ),
     entry(Prefix("tools.duplicate_exact"), ContractBreak, Blocking),
     entry(
         Prefix("tools.duplicate_semantic"),
         ContractBreak,
         Observation,
     ),
+    entry(
+        Exact("tools.metadata_incomplete"),
+        ContractBreak,
+        Observation,
+    ),
+    entry(Exact("tools.timeout_defaulted"), ContractBreak, Observation),
     // Observations
     entry(Prefix("loc."), ContractBreak, Observation),
     entry(Prefix("surface."), ContractBreak, Observation),
@@ -70,20 +81,52 @@ pub static VIOLATION_REGISTRY: &[ViolationClassEntry] = &[
     // Architecture / reuse / complexity / contracts
     entry(Prefix("reuse_first."), ContractBreak, Blocking),
     entry(Prefix("arch_layers."), ContractBreak, Blocking),
+    entry(Prefix("import_cycles."), ContractBreak, Blocking),
     entry(Prefix("complexity_budget."), ContractBreak, Blocking),
+    entry(Prefix("max_params."), ContractBreak, Blocking),
     entry(Prefix("contract_break."), ContractBreak, Blocking),
     entry(Prefix("change_impact."), ContractBreak, Blocking),
+    entry(Prefix("test_presence."), ContractBreak, Blocking),
+    entry(Prefix("file_encoding."), ContractBreak, Blocking),
+    entry(Prefix("binary_artifacts."), ContractBreak, Blocking),
+    entry(Prefix("naming_convention."), ContractBreak, Blocking),
+    entry(Prefix("magic_numbers."), ContractBreak, Observation),
+    entry(Prefix("governance."), ContractBreak, Blocking),
+    entry(Prefix("escalation."), ContractBreak, Blocking),
     // Gate execution
     entry(Prefix("gate.receipt_contract"), RuntimeRisk, Blocking),
     entry(Prefix("gate.tool_failed"), ContractBreak, Blocking),
     entry(Exact("gate.run_failed_transient"), TransientTool, Blocking),
+    entry(Exact("gate.circuit_open"), TransientTool, Blocking),
     entry(Prefix("gate.run_failed"), RuntimeRisk, Blocking),
     entry(Prefix("gate.observation."), ContractBreak, Observation),
     entry(Prefix("gate."), SchemaConfig, Blocking),
     entry(Prefix("witness."), RuntimeRisk, Blocking),
 ];
 
+/// Переименованные коды: старый код -> текущий. Старый код классифицируется как текущий и
+/// продолжает матчить записи allowlist, написанные под него.
+pub static CODE_ALIASES: &[(&str, &str)] = &[
+    (
+        "tool_budget.max_tools_total_exceeded",
+        "tool_budget.total_exceeded",
+    ),
+    (
+        "tool_budget.max_gate_tools_exceeded",
+        "tool_budget.gate_exceeded",
+    ),
+];
+
+/// Текущее имя кода (сам код, если он не переименован).
+pub fn current_code(code: &str) -> &str {
+    CODE_ALIASES
+        .iter()
+        .find(|(old, _)| *old == code)
+        .map_or(code, |(_, current)| current)
+}
+
 pub fn classify(code: &str) -> (ErrorClass, ViolationTier) {
+    let code = current_code(code);
     // 1) suffix (most specific)
     for item in VIOLATION_REGISTRY {
         if let ViolationPattern::Suffix(s) = item.pattern
@@ -122,6 +165,15 @@ mod tests {
         assert_eq!(tier, ViolationTier::Blocking);
     }
 
+    #[test]
+    fn renamed_codes_classify_like_their_current_name() {
+        for (old, current) in CODE_ALIASES {
+            assert_eq!(current_code(old), *current);
+            assert_eq!(classify(old), classify(current), "{old}");
+        }
+        assert_eq!(current_code("loc.max_exceeded"), "loc.max_exceeded");
+    }
+
     #[test]
     fn suffix_has_priority_over_prefix() {
         let (class, tier) = classify("loc.read_failed");
@@ -169,4 +221,11 @@ mod tests {
         assert_eq!(class, ErrorClass::ContractBreak);
         assert_eq!(tier, ViolationTier::Blocking);
     }
+
+    #[test]
+    fn exception_too_many_long_lived_is_contract_break_blocking() {
+        let (class, tier) = classify("exception.too_many_long_lived");
+        assert_eq!(class, ErrorClass::ContractBreak);
+        assert_eq!(tier, ViolationTier::Blocking);
+    }
 }
@@ -1,17 +1,20 @@
 pub mod api;
 pub mod app;
+pub mod canonical_json;
 pub mod checks;
 pub mod config;
+mod escalation;
 mod evidence;
 mod exceptions;
 mod failure_modes;
+mod findings_ledger;
 mod gate_jobs;
 mod gate_runner;
 mod hash;
 mod init;
 pub mod judge;
 pub mod packs;
-mod registry_manifest;
+pub mod registry_manifest;
 pub mod repo;
 mod repo_import;
 mod repo_strict;
@@ -21,6 +24,8 @@ pub mod runner;
 pub mod server;
 mod server_catalog;
 mod structured_report;
+mod swamped;
+mod textutil;
 mod validate_insights;
 mod wasm;
 mod witness;
@@ -1,6 +1,10 @@
 use ai_dx_mcp::{
     api::ResponseMode,
-    response::{finalize_exec, finalize_gate, finalize_init, finalize_validate},
+    response::{
+        collect_tool_reports, explain_validate, finalize_exec, finalize_gate, finalize_init,
+        finalize_validate_with_limit, include_suppressed_findings, validate_payload_for_schema,
+        without_verdict_sources,
+    },
     server::AiDxServer,
 };
 use rmcp::ServiceExt;
@@ -13,7 +17,14 @@ fn print_version() {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+    let error_format = match cli::take_error_format(&mut args) {
+        Ok(v) => v,
+        Err(e) => cli::exit_with_error(cli::ErrorFormat::Human, "cli.invalid_args", e, 2),
+    };
+    let json_style = cli::take_json_style(&mut args);
+    let no_color = cli::take_no_color(&mut args);
+    let exit_zero = cli::take_exit_zero(&mut args);
     let command = args.get(1).map(String::as_str);
 
     match command {
@@ -30,97 +41,250 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 cli::print_plugins_help();
                 return Ok(());
             }
-            let parsed = match cli::parse_plugins_cli(&args[2..]) {
+            let mut parsed = match cli::parse_plugins_cli(&args[2..]) {
                 Ok(v) => v,
-                Err(e) => {
-                    eprintln!("compas: {e}");
-                    std::process::exit(2);
-                }
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
             };
+            parsed.json_style = json_style;
             let code = match cli::run_plugins_cli(parsed).await {
                 Ok(code) => code,
-                Err(e) => {
-                    eprintln!("compas: {e}");
-                    1
-                }
+                Err(e) => cli::exit_with_error(error_format, "plugins.failed", e, 2),
             };
-            if code != 0 {
+            if code != 0 && !exit_zero {
                 std::process::exit(code);
             }
             return Ok(());
         }
-        Some(flag) if cli::is_v1_flag(flag) => {
-            eprintln!(
-                "compas: v1-style CLI flag `{flag}` removed in v2; use subcommands: init|validate|gate"
-            );
-            std::process::exit(2);
-        }
+        Some(flag) if cli::is_v1_flag(flag) => cli::exit_with_error(
+            error_format,
+            "cli.v1_flag_removed",
+            format!(
+                "v1-style CLI flag `{flag}` removed in v2; use subcommands: init|validate|gate"
+            ),
+            2,
+        ),
         Some("init") => {
             let (req, repo_root) = match cli::parse_init_cli(&args[2..]) {
                 Ok(v) => v,
-                Err(e) => {
-                    eprintln!("compas: {e}");
-                    std::process::exit(2);
-                }
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
             };
             let out = ai_dx_mcp::app::compas_init(&repo_root, req);
             let out = finalize_init(out);
-            println!("{}", serde_json::to_string_pretty(&out)?);
-            if !out.ok {
+            println!("{}", cli::render_json(&out, json_style)?);
+            if !out.ok && !exit_zero {
                 std::process::exit(1);
             }
             return Ok(());
         }
         Some("validate") => {
-            let (mode, write_baseline, repo_root, baseline_maintenance) =
-                match cli::parse_validate_cli(&args[2..]) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        eprintln!("compas: {e}");
-                        std::process::exit(2);
-                    }
-                };
-            let out = ai_dx_mcp::app::validate(
-                &repo_root,
-                mode,
-                write_baseline,
-                baseline_maintenance.as_ref(),
-            );
-            let out = finalize_validate(out, ResponseMode::Compact);
-            println!("{}", serde_json::to_string_pretty(&out)?);
-            if !out.ok {
+            let parsed = match cli::parse_validate_cli(&args[2..]) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            if parsed.baseline_migrate_only {
+                let out = ai_dx_mcp::app::validate_baseline_migrate_only(&parsed.repo_root);
+                println!("{}", cli::render_json(&out, json_style)?);
+                if !out.ok && !exit_zero {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            let mut out = if parsed.baseline_dry_run {
+                ai_dx_mcp::app::validate_baseline_dry_run(
+                    &parsed.repo_root,
+                    parsed.mode,
+                    parsed.timings,
+                )
+            } else if parsed.errors_only {
+                ai_dx_mcp::app::validate_errors_only(
+                    &parsed.repo_root,
+                    parsed.mode,
+                    parsed.timings,
+                    &parsed.selection,
+                )
+            } else if !parsed.selection.is_full() {
+                ai_dx_mcp::app::validate_selected(
+                    &parsed.repo_root,
+                    parsed.mode,
+                    parsed.timings,
+                    &parsed.selection,
+                )
+            } else {
+                ai_dx_mcp::app::validate_with_timings(
+                    &parsed.repo_root,
+                    parsed.mode,
+                    parsed.write_baseline,
+                    parsed.baseline_maintenance.as_ref(),
+                    parsed.timings,
+                )
+            };
+            if parsed.explain {
+                out = explain_validate(out);
+            }
+            if parsed.include_suppressed {
+                out = include_suppressed_findings(out);
+            }
+            let out =
+                finalize_validate_with_limit(out, ResponseMode::Compact, parsed.max_violations);
+            if let Some(path) = &parsed.digest_out
+                && let Err(e) = cli::write_digest_out(path, out.agent_digest.as_ref(), json_style)
+            {
+                cli::exit_with_error(error_format, "cli.digest_out_failed", e, 2);
+            }
+            if parsed.summary_only {
+                print!(
+                    "{}",
+                    cli::format_validate_summary(&out, cli::color_enabled(no_color))
+                );
+                if !out.ok && !exit_zero {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            let payload = match validate_payload_for_schema(&out, parsed.schema_version.as_deref())
+            {
+                Ok(v) => v,
+                Err(e) => {
+                    cli::exit_with_error(error_format, "cli.unsupported_schema_version", e, 2)
+                }
+            };
+            println!("{}", cli::render_json(&payload, json_style)?);
+            if !out.ok && !exit_zero {
                 std::process::exit(1);
             }
             return Ok(());
         }
         Some("gate") => {
-            let (kind, dry_run, write_witness, repo_root) = match cli::parse_gate_cli(&args[2..]) {
+            let parsed = match cli::parse_gate_cli(&args[2..]) {
                 Ok(v) => v,
-                Err(e) => {
-                    eprintln!("compas: {e}");
-                    std::process::exit(2);
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            if parsed.print_plan {
+                let out = ai_dx_mcp::app::gate_plan(&parsed.repo_root, parsed.kind);
+                println!("{}", cli::render_json(&out, json_style)?);
+                if !out.ok && !exit_zero {
+                    std::process::exit(1);
                 }
+                return Ok(());
+            }
+            let out = ai_dx_mcp::app::gate_with_options(
+                &parsed.repo_root,
+                parsed.kind,
+                parsed.dry_run,
+                parsed.write_witness,
+                ai_dx_mcp::app::GateRunOptions {
+                    budget_ms: parsed.budget_ms,
+                    witness_dir: parsed.witness_dir.as_deref(),
+                    fail_fast: parsed.fail_fast,
+                    capture_full: parsed.capture_full,
+                    explain_verdict: parsed.explain_verdict,
+                },
+            )
+            .await;
+            let tool_reports = parsed.reports.then(|| collect_tool_reports(&out.receipts));
+            let out = if parsed.explain_verdict {
+                out
+            } else {
+                without_verdict_sources(out)
             };
-            let out = ai_dx_mcp::app::gate(&repo_root, kind, dry_run, write_witness).await;
             let out = finalize_gate(out, ResponseMode::Compact);
-            println!("{}", serde_json::to_string_pretty(&out)?);
-            if !out.ok {
+            if let Some(path) = &parsed.digest_out
+                && let Err(e) = cli::write_digest_out(path, out.agent_digest.as_ref(), json_style)
+            {
+                cli::exit_with_error(error_format, "cli.digest_out_failed", e, 2);
+            }
+            let mut payload = serde_json::to_value(&out)?;
+            if let (Some(tool_reports), Some(obj)) = (tool_reports, payload.as_object_mut()) {
+                obj.insert("tool_reports".to_string(), tool_reports.into());
+            }
+            println!("{}", cli::render_json(&payload, json_style)?);
+            if !out.ok && !exit_zero {
                 std::process::exit(1);
             }
             return Ok(());
         }
+        Some("gate-diff") => {
+            let (a_path, b_path) = match cli::parse_gate_diff_cli(&args[2..]) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            let out = ai_dx_mcp::app::gate_diff(&a_path, &b_path);
+            println!("{}", cli::render_json(&out, json_style)?);
+            if !out.ok && !exit_zero {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some("boundary-test") => {
+            let (rule_id, input, repo_root) = match cli::parse_boundary_test_cli(&args[2..]) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            let out = ai_dx_mcp::app::boundary_test(&repo_root, &rule_id, &input);
+            println!("{}", cli::render_json(&out, json_style)?);
+            if !out.ok && !exit_zero {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some("exceptions") => {
+            let repo_root = match cli::parse_exceptions_cli(&args[2..]) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            let out = ai_dx_mcp::app::exceptions_report(&repo_root);
+            println!("{}", cli::render_json(&out, json_style)?);
+            if !out.ok && !exit_zero {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some("tools") => {
+            let (tool_id, resolved_env, repo_root) = match cli::parse_tools_cli(&args[2..]) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            let out = ai_dx_mcp::app::tools_describe(&repo_root, &tool_id, resolved_env);
+            println!("{}", cli::render_json(&out, json_style)?);
+            if !out.ok && !exit_zero {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some("canonicalize") => {
+            let input = match cli::parse_canonicalize_cli(&args[2..]) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
+            };
+            let raw = match std::fs::read_to_string(&input) {
+                Ok(v) => v,
+                Err(e) => cli::exit_with_error(
+                    error_format,
+                    "canonicalize.read_failed",
+                    format!("failed to read {input}: {e}"),
+                    2,
+                ),
+            };
+            match ai_dx_mcp::canonical_json::canonicalize_str(&raw) {
+                Ok(canonical) => print!("{canonical}"),
+                Err(e) => cli::exit_with_error(
+                    error_format,
+                    "canonicalize.invalid_json",
+                    format!("{input} is not valid JSON: {e}"),
+                    2,
+                ),
+            }
+            return Ok(());
+        }
         Some("exec") => {
             let (tool_id, extra_args, dry_run, repo_root) = match cli::parse_exec_cli(&args[2..]) {
                 Ok(v) => v,
-                Err(e) => {
-                    eprintln!("compas: {e}");
-                    std::process::exit(2);
-                }
+                Err(e) => cli::exit_with_error(error_format, "cli.invalid_args", e, 2),
             };
             let out = ai_dx_mcp::app::exec_tool(&repo_root, tool_id, extra_args, dry_run).await;
             let out = finalize_exec(out);
-            println!("{}", serde_json::to_string_pretty(&out)?);
-            if !out.ok {
+            println!("{}", cli::render_json(&out, json_style)?);
+            if !out.ok && !exit_zero {
                 std::process::exit(1);
             }
             return Ok(());
@@ -129,12 +293,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             if matches!(other, "--stdio" | "stdio" | "--mcp" | "mcp")
                 || (other == "--transport" && args.get(2).is_some_and(|v| v == "stdio"))
                 || other.starts_with("--") => {}
-        Some(other) => {
-            eprintln!(
-                "compas: unknown command `{other}`; use init|validate|gate|exec|plugins, or no args to start MCP server"
-            );
-            std::process::exit(2);
-        }
+        Some(other) => cli::exit_with_error(
+            error_format,
+            "cli.unknown_command",
+            format!(
+                "unknown command `{other}`; use init|validate|gate|gate-diff|boundary-test|exceptions|canonicalize|exec|tools|plugins, or no args to start MCP server"
+            ),
+            2,
+        ),
         None => {}
     }
 
@@ -0,0 +1,124 @@
+use crate::api::{DisabledPackSummary, Violation};
+use crate::config::ChecksConfigV2;
+use crate::packs::schema::PackManifestV1;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use walkdir::WalkDir;
+
+const PACKS_DIR_REL: &str = ".agents/mcp/compas/packs";
+
+/// Vendored pack manifests under `packs/`, keyed by `pack.id`. Unreadable manifests are left
+/// to `validate_packs`, which reports them regardless of `disabled_packs`.
+fn vendored_pack_manifests(repo_root: &Path) -> BTreeMap<String, PackManifestV1> {
+    let mut out = BTreeMap::new();
+    for entry in WalkDir::new(repo_root.join(PACKS_DIR_REL))
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file() && e.file_name() == "pack.toml")
+    {
+        let Ok(raw) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        if let Ok(manifest) = toml::from_str::<PackManifestV1>(&raw) {
+            out.insert(manifest.pack.id.clone(), manifest);
+        }
+    }
+    out
+}
+
+/// `failure_modes` of every vendored pack not listed in `disabled`, keyed by pack id.
+pub(crate) fn enabled_pack_failure_modes(
+    repo_root: &Path,
+    disabled: &[String],
+) -> BTreeMap<String, Vec<String>> {
+    vendored_pack_manifests(repo_root)
+        .into_iter()
+        .filter(|(id, m)| !disabled.contains(id) && !m.failure_modes.is_empty())
+        .map(|(id, m)| (id, m.failure_modes))
+        .collect()
+}
+
+/// Every `id` declared under `checks_v2`, whatever the check kind.
+fn declared_check_ids(checks: &ChecksConfigV2) -> BTreeSet<String> {
+    let Ok(serde_json::Value::Object(kinds)) = serde_json::to_value(checks) else {
+        return BTreeSet::new();
+    };
+    kinds
+        .values()
+        .filter_map(serde_json::Value::as_array)
+        .flatten()
+        .filter_map(|c| c.get("id").and_then(|id| id.as_str()).map(str::to_string))
+        .collect()
+}
+
+/// Drops every check whose id `drop` contains, across all check kinds.
+fn drop_checks(checks: &mut ChecksConfigV2, drop: &BTreeSet<String>) -> Result<(), String> {
+    let mut value = serde_json::to_value(&*checks).map_err(|e| e.to_string())?;
+    if let Some(kinds) = value.as_object_mut() {
+        for list in kinds
+            .values_mut()
+            .filter_map(serde_json::Value::as_array_mut)
+        {
+            list.retain(|c| {
+                c.get("id")
+                    .and_then(|id| id.as_str())
+                    .is_none_or(|id| !drop.contains(id))
+            });
+        }
+    }
+    *checks = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Skips the checks contributed by each disabled pack (the check ids its vendored
+/// `pack.toml` declares in `checks_v2`). Pack manifest and lock validation are unaffected.
+pub(crate) fn apply_disabled_packs(
+    repo_root: &Path,
+    disabled: &[String],
+    checks: &mut ChecksConfigV2,
+) -> (Vec<DisabledPackSummary>, Vec<Violation>) {
+    if disabled.is_empty() {
+        return (vec![], vec![]);
+    }
+    let manifests = vendored_pack_manifests(repo_root);
+    let active = declared_check_ids(checks);
+    let mut summaries: Vec<DisabledPackSummary> = vec![];
+    let mut violations: Vec<Violation> = vec![];
+    let mut drop: BTreeSet<String> = BTreeSet::new();
+    for pack_id in disabled.iter().collect::<BTreeSet<_>>() {
+        let Some(manifest) = manifests.get(pack_id) else {
+            violations.push(Violation::observation(
+                "packs.disabled_unknown",
+                format!("disabled_packs lists {pack_id:?}, but no vendored pack has that id"),
+                Some(PACKS_DIR_REL.to_string()),
+                None,
+            ));
+            continue;
+        };
+        let skipped: Vec<String> = manifest
+            .checks_v2
+            .as_ref()
+            .map(declared_check_ids)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| active.contains(id))
+            .collect();
+        drop.extend(skipped.iter().cloned());
+        summaries.push(DisabledPackSummary {
+            id: pack_id.clone(),
+            skipped_check_ids: skipped,
+        });
+    }
+    if !drop.is_empty()
+        && let Err(e) = drop_checks(checks, &drop)
+    {
+        violations.push(Violation::blocking(
+            "packs.disable_failed",
+            format!("failed to skip checks of disabled packs: {e}"),
+            None,
+            None,
+        ));
+    }
+    (summaries, violations)
+}
@@ -1,5 +1,3 @@
-#![allow(dead_code)] // Wired by init (TASK-010); keep implementation in-place until used.
-
 use crate::hash::sha256_hex;
 use crate::packs::schema::{PackLockEntryV1, PackManifestV1, PacksLockV1};
 use flate2::read::GzDecoder;
@@ -7,7 +5,7 @@ use regex::Regex;
 use std::fs;
 use std::io::{Cursor, Read};
 use std::path::{Component, Path, PathBuf};
-use tar::Archive;
+use tar::{Archive, EntryType};
 use walkdir::WalkDir;
 
 const PACKS_LOCK_REL: &str = ".agents/mcp/compas/packs.lock";
@@ -24,11 +22,33 @@ fn pack_id_regex() -> Regex {
         .expect("valid pack id regex")
 }
 
+// Same bounds as the registry extractor (`extract_archive_safe`).
+const MAX_ENTRIES: usize = 20_000;
+const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+const MAX_TOTAL_BYTES: u64 = 200 * 1024 * 1024;
+const MAX_PATH_BYTES: usize = 512;
+
+/// Error prefix for archives rejected before anything is written outside staging.
+const UNSAFE_ARCHIVE: &str = "packs.unsafe_archive";
+
+fn unsafe_archive(msg: String) -> String {
+    format!("{UNSAFE_ARCHIVE}: {msg}")
+}
+
 fn safe_unpack_entry_path(path: &Path) -> Result<(), String> {
+    if path.as_os_str().is_empty() {
+        return Err(unsafe_archive("empty tar entry path".to_string()));
+    }
+    if path.to_string_lossy().len() > MAX_PATH_BYTES {
+        return Err(unsafe_archive(format!(
+            "tar path too long (> {MAX_PATH_BYTES} bytes): {:?}",
+            path
+        )));
+    }
     for c in path.components() {
         match c {
             Component::Prefix(_) | Component::RootDir | Component::ParentDir => {
-                return Err(format!("unsafe tar entry path: {:?}", path));
+                return Err(unsafe_archive(format!("unsafe tar entry path: {:?}", path)));
             }
             Component::CurDir | Component::Normal(_) => {}
         }
@@ -40,6 +60,8 @@ fn is_gzip(bytes: &[u8]) -> bool {
     bytes.len() >= 2 && bytes[0] == 0x1f && bytes[1] == 0x8b
 }
 
+/// Unpacks only regular files and directories; links, devices and oversized or escaping
+/// entries fail the whole archive.
 fn extract_pack_archive_to_dir(bytes: &[u8], dest: &Path) -> Result<(), String> {
     fs::create_dir_all(dest).map_err(|e| format!("failed to create staging dir: {e}"))?;
 
@@ -55,18 +77,54 @@ fn extract_pack_archive_to_dir(bytes: &[u8], dest: &Path) -> Result<(), String>
         .entries()
         .map_err(|e| format!("failed to read tar entries: {e}"))?;
 
+    let mut entries_seen = 0usize;
+    let mut total_bytes = 0u64;
     for entry in entries {
         let mut entry = entry.map_err(|e| format!("failed to read tar entry: {e}"))?;
+        entries_seen += 1;
+        if entries_seen > MAX_ENTRIES {
+            return Err(unsafe_archive(format!(
+                "archive exceeds MAX_ENTRIES={MAX_ENTRIES}"
+            )));
+        }
+
+        let entry_type = entry.header().entry_type();
+        if entry_type != EntryType::Regular && entry_type != EntryType::Directory {
+            return Err(unsafe_archive(format!(
+                "unsupported tar entry type: {:?}",
+                entry_type
+            )));
+        }
+
         let path = entry
             .path()
             .map_err(|e| format!("failed to read tar entry path: {e}"))?
             .to_path_buf();
         safe_unpack_entry_path(&path)?;
+        if !dest.join(&path).starts_with(dest) {
+            return Err(unsafe_archive(format!(
+                "tar extraction escape detected: {:?}",
+                path
+            )));
+        }
 
-        let entry_type = entry.header().entry_type();
-        let is_ok = entry_type.is_dir() || entry_type.is_file();
-        if !is_ok {
-            return Err(format!("unsupported tar entry type: {:?}", entry_type));
+        if entry_type == EntryType::Regular {
+            let size = entry
+                .header()
+                .size()
+                .map_err(|e| format!("failed to read tar entry size: {e}"))?;
+            if size > MAX_FILE_BYTES {
+                return Err(unsafe_archive(format!(
+                    "tar entry too large (> {MAX_FILE_BYTES} bytes): {:?}",
+                    path
+                )));
+            }
+            total_bytes = total_bytes.saturating_add(size);
+            if total_bytes > MAX_TOTAL_BYTES {
+                return Err(unsafe_archive(format!(
+                    "archive exceeds MAX_TOTAL_BYTES={MAX_TOTAL_BYTES}"
+                )));
+            }
         }
 
         entry
@@ -117,12 +175,39 @@ fn pack_id_to_vendor_dir(repo_root: &Path, pack_id: &str) -> Result<(PathBuf, St
     Ok((dest, rel))
 }
 
-pub(crate) fn vendor_pack_archive_bytes(
+/// A pack archive that passed its sha256 pin and was extracted and parsed under the staging
+/// dir; nothing outside staging has been written yet.
+pub(crate) struct StagedPack {
+    staging_root: PathBuf,
+    pack_root: PathBuf,
+    vendor_dir: PathBuf,
+    manifest: PackManifestV1,
+    entry: PackLockEntryV1,
+}
+
+impl StagedPack {
+    pub(crate) fn manifest(&self) -> &PackManifestV1 {
+        &self.manifest
+    }
+}
+
+/// Removes a staging dir plus the parents it created, stopping at the first non-empty one, so a
+/// failed stage leaves no trace in a repo that had no `.agents/` before.
+fn remove_staging(repo_root: &Path, staging_root: &Path) {
+    let _ = fs::remove_dir_all(staging_root);
+    for dir in staging_root.ancestors().skip(1) {
+        if dir == repo_root || !dir.starts_with(repo_root) || fs::remove_dir(dir).is_err() {
+            break;
+        }
+    }
+}
+
+pub(crate) fn stage_pack_archive_bytes(
     repo_root: &Path,
     source: &str,
     expected_sha256: &str,
     archive_bytes: &[u8],
-) -> Result<(PackManifestV1, PackLockEntryV1), String> {
+) -> Result<StagedPack, String> {
     let expected = expected_sha256.trim().to_ascii_lowercase();
     if !is_valid_sha256_hex(&expected) {
         return Err(format!("invalid expected sha256 hex: {expected_sha256:?}"));
@@ -141,45 +226,88 @@ pub(crate) fn vendor_pack_archive_bytes(
             .map_err(|e| format!("failed to clear staging dir {:?}: {e}", staging_root))?;
     }
 
-    extract_pack_archive_to_dir(archive_bytes, &staging_root)?;
-
-    let pack_toml = find_single_pack_toml(&staging_root)?;
-    let pack_root = pack_toml
-        .parent()
-        .ok_or_else(|| "pack.toml has no parent dir".to_string())?;
-    let raw = fs::read_to_string(&pack_toml)
-        .map_err(|e| format!("failed to read extracted pack.toml {:?}: {e}", pack_toml))?;
-    let manifest: PackManifestV1 =
-        toml::from_str(&raw).map_err(|e| format!("failed to parse pack.toml: {e}"))?;
-
-    let (vendor_dir, resolved_rel) = pack_id_to_vendor_dir(repo_root, &manifest.pack.id)?;
-    if vendor_dir.exists() {
-        fs::remove_dir_all(&vendor_dir)
-            .map_err(|e| format!("failed to remove existing vendor dir {:?}: {e}", vendor_dir))?;
-    }
-    if let Some(parent) = vendor_dir.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("failed to create vendor parent dir {:?}: {e}", parent))?;
+    let staged = extract_pack_archive_to_dir(archive_bytes, &staging_root).and_then(|()| {
+        let pack_toml = find_single_pack_toml(&staging_root)?;
+        let pack_root = pack_toml
+            .parent()
+            .ok_or_else(|| "pack.toml has no parent dir".to_string())?
+            .to_path_buf();
+        let raw = fs::read_to_string(&pack_toml)
+            .map_err(|e| format!("failed to read extracted pack.toml {:?}: {e}", pack_toml))?;
+        let manifest: PackManifestV1 =
+            toml::from_str(&raw).map_err(|e| format!("failed to parse pack.toml: {e}"))?;
+        let (vendor_dir, resolved_rel) = pack_id_to_vendor_dir(repo_root, &manifest.pack.id)?;
+        let entry = PackLockEntryV1 {
+            id: manifest.pack.id.clone(),
+            source: source.to_string(),
+            sha256: Some(actual.clone()),
+            resolved_path: Some(resolved_rel),
+            version: Some(manifest.pack.version.clone()),
+        };
+        Ok((pack_root, vendor_dir, manifest, entry))
+    });
+    match staged {
+        Ok((pack_root, vendor_dir, manifest, entry)) => Ok(StagedPack {
+            staging_root,
+            pack_root,
+            vendor_dir,
+            manifest,
+            entry,
+        }),
+        Err(e) => {
+            remove_staging(repo_root, &staging_root);
+            Err(e)
+        }
     }
+}
 
-    fs::rename(pack_root, &vendor_dir).map_err(|e| {
-        format!(
-            "failed to move extracted pack to vendor dir (from={:?}, to={:?}): {e}",
-            pack_root, vendor_dir
-        )
-    })?;
+/// Moves a staged pack into its vendor dir, replacing any previous copy.
+pub(crate) fn promote_staged_pack(
+    repo_root: &Path,
+    staged: StagedPack,
+) -> Result<(PackManifestV1, PackLockEntryV1), String> {
+    let StagedPack {
+        staging_root,
+        pack_root,
+        vendor_dir,
+        manifest,
+        entry,
+    } = staged;
+    let moved = (|| {
+        if vendor_dir.exists() {
+            fs::remove_dir_all(&vendor_dir).map_err(|e| {
+                format!("failed to remove existing vendor dir {:?}: {e}", vendor_dir)
+            })?;
+        }
+        if let Some(parent) = vendor_dir.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("failed to create vendor parent dir {:?}: {e}", parent))?;
+        }
+        fs::rename(&pack_root, &vendor_dir).map_err(|e| {
+            format!(
+                "failed to move extracted pack to vendor dir (from={:?}, to={:?}): {e}",
+                pack_root, vendor_dir
+            )
+        })
+    })();
+    remove_staging(repo_root, &staging_root);
+    moved.map(|()| (manifest, entry))
+}
 
-    // Clean staging root (best-effort).
-    let _ = fs::remove_dir_all(&staging_root);
+/// Drops a staged pack that will not be promoted.
+pub(crate) fn discard_staged_pack(repo_root: &Path, staged: StagedPack) {
+    remove_staging(repo_root, &staged.staging_root);
+}
 
-    let entry = PackLockEntryV1 {
-        id: manifest.pack.id.clone(),
-        source: source.to_string(),
-        sha256: Some(actual),
-        resolved_path: Some(resolved_rel),
-        version: Some(manifest.pack.version.clone()),
-    };
-    Ok((manifest, entry))
+#[cfg(test)]
+pub(crate) fn vendor_pack_archive_bytes(
+    repo_root: &Path,
+    source: &str,
+    expected_sha256: &str,
+    archive_bytes: &[u8],
+) -> Result<(PackManifestV1, PackLockEntryV1), String> {
+    let staged = stage_pack_archive_bytes(repo_root, source, expected_sha256, archive_bytes)?;
+    promote_staged_pack(repo_root, staged)
 }
 
 pub(crate) fn upsert_packs_lock(repo_root: &Path, entry: PackLockEntryV1) -> Result<(), String> {
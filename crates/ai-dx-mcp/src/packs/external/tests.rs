@@ -1,4 +1,7 @@
-use super::{PACKS_LOCK_REL, VENDOR_DIR_REL, upsert_packs_lock, vendor_pack_archive_bytes};
+use super::{
+    MAX_FILE_BYTES, PACKS_LOCK_REL, STAGING_DIR_REL, VENDOR_DIR_REL, upsert_packs_lock,
+    vendor_pack_archive_bytes,
+};
 use crate::hash::sha256_hex;
 use crate::packs::schema::{PackLockEntryV1, PacksLockV1};
 use flate2::{Compression, write::GzEncoder};
@@ -165,6 +168,76 @@ fn extract_pack_archive_to_dir_rejects_symlink_entries() {
     assert!(err.contains("unsupported tar entry type"), "{err}");
 }
 
+/// Writes a raw header so names the `tar` builder refuses (e.g. `../x`) can still be tested.
+fn append_raw(builder: &mut Builder<Vec<u8>>, name: &str, entry_type: EntryType, data: &[u8]) {
+    let mut header = Header::new_gnu();
+    header.set_entry_type(entry_type);
+    header.set_mode(0o644);
+    header.set_size(data.len() as u64);
+    let raw_name = &mut header.as_old_mut().name;
+    raw_name[..name.len()].copy_from_slice(name.as_bytes());
+    header.set_cksum();
+    builder.append(&header, data).expect("append raw entry");
+}
+
+fn vendor_crafted(entries: &[(&str, EntryType, &[u8])]) -> (tempfile::TempDir, String) {
+    let repo = tempdir().expect("temp repo");
+    let mut builder = Builder::new(Vec::new());
+    append_raw(
+        &mut builder,
+        "pack/pack.toml",
+        EntryType::Regular,
+        b"[pack]\nid = \"evil\"\nversion = \"0.0.1\"\ndescription = \"crafted\"\n",
+    );
+    for (name, entry_type, data) in entries {
+        append_raw(&mut builder, name, *entry_type, data);
+    }
+    builder.finish().expect("finish tar");
+    let gz = gzip(&builder.into_inner().expect("tar bytes"));
+    let sha = sha256_hex(&gz);
+    let err = vendor_pack_archive_bytes(repo.path(), "src", &sha, &gz).unwrap_err();
+    (repo, err)
+}
+
+#[test]
+fn vendor_pack_archive_bytes_rejects_parent_dir_entry() {
+    let (repo, err) = vendor_crafted(&[("pack/../../escape.txt", EntryType::Regular, b"x")]);
+    assert!(err.starts_with("packs.unsafe_archive:"), "{err}");
+    assert!(!repo.path().join(".agents/mcp/compas/escape.txt").exists());
+    assert!(!repo.path().join(VENDOR_DIR_REL).join("evil").exists());
+    assert!(
+        fs::read_dir(repo.path().join(STAGING_DIR_REL))
+            .map(|mut d| d.next().is_none())
+            .unwrap_or(true)
+    );
+}
+
+#[test]
+fn vendor_pack_archive_bytes_rejects_symlink_and_hardlink_entries() {
+    for entry_type in [EntryType::Symlink, EntryType::Link] {
+        let (repo, err) = vendor_crafted(&[("pack/link", entry_type, b"")]);
+        assert!(err.starts_with("packs.unsafe_archive:"), "{err}");
+        assert!(err.contains("unsupported tar entry type"), "{err}");
+        assert!(!repo.path().join(VENDOR_DIR_REL).join("evil").exists());
+    }
+}
+
+#[test]
+fn extract_pack_archive_to_dir_rejects_oversized_entry() {
+    let repo = tempdir().expect("temp repo");
+    let mut header = Header::new_gnu();
+    header.set_entry_type(EntryType::Regular);
+    header.set_mode(0o644);
+    header.set_size(MAX_FILE_BYTES + 1);
+    header.set_path("pack/huge.bin").expect("set path");
+    header.set_cksum();
+    let mut tar = header.as_bytes().to_vec();
+    tar.extend_from_slice(&[0u8; 1024]);
+
+    let err = super::extract_pack_archive_to_dir(&tar, &repo.path().join("out")).unwrap_err();
+    assert!(err.contains("tar entry too large"), "{err}");
+}
+
 #[test]
 fn vendor_pack_archive_bytes_fails_on_multiple_pack_toml() {
     let repo = tempdir().expect("temp repo");
@@ -1,31 +1,47 @@
 pub mod schema;
 
 mod builtin;
+mod disabled;
 mod engine;
 #[cfg(feature = "external_packs")]
 mod external;
 mod validate;
 
+pub(crate) use disabled::{apply_disabled_packs, enabled_pack_failure_modes};
 #[allow(unused_imports)] // Wired by init (TASK-010); keep exports stable meanwhile.
 pub(crate) use engine::{
     NodePackageManager, detect_node_package_manager, load_builtin_packs, pack_matches_repo,
 };
 pub(crate) use validate::validate_packs;
 
-/// Vendor a pack archive into the repo (`.agents/mcp/compas/packs/vendor/*`) and return the parsed
-/// manifest + lock entry.
+/// A verified and extracted external pack waiting in the staging dir
+/// (`.agents/mcp/compas/packs/vendor/_staging/*`). compas-lite never produces one.
+#[cfg(feature = "external_packs")]
+pub(crate) use external::StagedPack;
+#[cfg(not(feature = "external_packs"))]
+#[derive(Debug)]
+pub(crate) enum StagedPack {}
+
+#[cfg(not(feature = "external_packs"))]
+impl StagedPack {
+    pub(crate) fn manifest(&self) -> &schema::PackManifestV1 {
+        match *self {}
+    }
+}
+
+/// Verify a pack archive against its sha256 pin, extract it into the staging dir and parse its
+/// manifest; a failure removes the staging dir again.
 ///
 /// compas-lite (`--no-default-features`) fails closed with a clear error.
-#[allow(dead_code)] // Wired by future init slice (external packs).
-pub(crate) fn vendor_pack_archive_bytes(
+pub(crate) fn stage_pack_archive_bytes(
     repo_root: &std::path::Path,
     source: &str,
     expected_sha256: &str,
     archive_bytes: &[u8],
-) -> Result<(schema::PackManifestV1, schema::PackLockEntryV1), String> {
+) -> Result<StagedPack, String> {
     #[cfg(feature = "external_packs")]
     {
-        external::vendor_pack_archive_bytes(repo_root, source, expected_sha256, archive_bytes)
+        external::stage_pack_archive_bytes(repo_root, source, expected_sha256, archive_bytes)
     }
     #[cfg(not(feature = "external_packs"))]
     {
@@ -34,10 +50,39 @@ pub(crate) fn vendor_pack_archive_bytes(
     }
 }
 
+/// Move a staged pack into `.agents/mcp/compas/packs/vendor/*` and return the parsed manifest +
+/// lock entry.
+pub(crate) fn promote_staged_pack(
+    repo_root: &std::path::Path,
+    staged: StagedPack,
+) -> Result<(schema::PackManifestV1, schema::PackLockEntryV1), String> {
+    #[cfg(feature = "external_packs")]
+    {
+        external::promote_staged_pack(repo_root, staged)
+    }
+    #[cfg(not(feature = "external_packs"))]
+    {
+        let _ = repo_root;
+        match staged {}
+    }
+}
+
+/// Remove a staged pack that will not be promoted.
+pub(crate) fn discard_staged_pack(repo_root: &std::path::Path, staged: StagedPack) {
+    #[cfg(feature = "external_packs")]
+    {
+        external::discard_staged_pack(repo_root, staged)
+    }
+    #[cfg(not(feature = "external_packs"))]
+    {
+        let _ = repo_root;
+        match staged {}
+    }
+}
+
 /// Upsert an entry in `.agents/mcp/compas/packs.lock` (sorted by pack id).
 ///
 /// compas-lite (`--no-default-features`) fails closed with a clear error.
-#[allow(dead_code)] // Wired by future init slice (external packs).
 pub(crate) fn upsert_packs_lock(
     repo_root: &std::path::Path,
     entry: schema::PackLockEntryV1,
@@ -61,7 +106,7 @@ mod lite_tests {
     #[test]
     fn lite_build_fails_closed_on_external_pack_vendoring() {
         let dir = tempdir().unwrap();
-        let err = vendor_pack_archive_bytes(dir.path(), "src", "00", b"").unwrap_err();
+        let err = stage_pack_archive_bytes(dir.path(), "src", "00", b"").unwrap_err();
         assert!(err.contains("external_packs feature is disabled"), "{err}");
     }
 }
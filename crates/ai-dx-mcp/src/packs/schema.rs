@@ -15,6 +15,9 @@ pub struct PackManifestV1 {
     pub canonical_tools: Option<CanonicalToolsConfig>,
     pub gates: Option<PackGatesV1>,
     pub checks_v2: Option<ChecksConfigV2>,
+    /// Failure-mode ids this pack addresses; validate merges them into the coverage catalog.
+    #[serde(default)]
+    pub failure_modes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +63,27 @@ pub struct PackGatesV1 {
     pub ci: Vec<CanonicalToolId>,
     #[serde(default)]
     pub flagship: Vec<CanonicalToolId>,
+    /// Pack tool ids appended to the named sequences after the canonical ones, with no
+    /// `ci_fast` → `ci` → `flagship` fallback (e.g. a security scan only in `flagship`).
+    #[serde(default, skip_serializing_if = "PackGateToolsV1::is_empty")]
+    pub tools: PackGateToolsV1,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackGateToolsV1 {
+    #[serde(default)]
+    pub ci_fast: Vec<String>,
+    #[serde(default)]
+    pub ci: Vec<String>,
+    #[serde(default)]
+    pub flagship: Vec<String>,
+}
+
+impl PackGateToolsV1 {
+    pub fn is_empty(&self) -> bool {
+        self.ci_fast.is_empty() && self.ci.is_empty() && self.flagship.is_empty()
+    }
 }
 
 /// `packs.lock` — records pack sources used by init (v1).
@@ -47,6 +47,17 @@ pub(super) fn validate_canonical_tools_config(
         }
     }
 
+    for (alias, target) in &cfg.aliases {
+        if known_tool_ids.contains(alias) {
+            problems.push(format!("alias {alias:?} shadows an existing tool_id"));
+        }
+        if !known_tool_ids.contains(target) {
+            problems.push(format!(
+                "alias {alias:?} references unknown tool_id={target:?}"
+            ));
+        }
+    }
+
     problems
 }
 
@@ -75,3 +86,29 @@ pub(super) fn validate_pack_gates(
 
     problems
 }
+
+/// Gate `tools` entries must name tools this pack declares; each sequence must be unique.
+pub(super) fn validate_pack_gate_tools(
+    gates: &PackGatesV1,
+    known_tool_ids: &BTreeSet<String>,
+) -> Vec<String> {
+    let mut problems: Vec<String> = vec![];
+    for (kind, ids) in [
+        ("ci_fast", &gates.tools.ci_fast),
+        ("ci", &gates.tools.ci),
+        ("flagship", &gates.tools.flagship),
+    ] {
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        for id in ids {
+            if !known_tool_ids.contains(id) {
+                problems.push(format!(
+                    "gates.tools.{kind} references unknown tool_id={id:?}"
+                ));
+            }
+            if !seen.insert(id.as_str()) {
+                problems.push(format!("gates.tools.{kind} lists tool_id={id:?} twice"));
+            }
+        }
+    }
+    problems
+}
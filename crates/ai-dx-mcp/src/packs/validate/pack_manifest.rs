@@ -1,4 +1,6 @@
-use super::canonical_tools::{validate_canonical_tools_config, validate_pack_gates};
+use super::canonical_tools::{
+    validate_canonical_tools_config, validate_pack_gate_tools, validate_pack_gates,
+};
 use crate::api::Violation;
 use crate::packs::schema::PackManifestV1;
 use regex::Regex;
@@ -101,6 +103,16 @@ pub(super) fn validate_pack_manifests(
             ));
         }
 
+        for mode in &manifest.failure_modes {
+            if !crate::failure_modes::is_valid_mode_id(mode.trim()) {
+                violations.push(mk_violation(
+                    "packs.pack_failure_mode_invalid",
+                    format!("invalid failure_modes id in pack: {mode:?}"),
+                    Some(rel.clone()),
+                ));
+            }
+        }
+
         let tool_ids = tool_ids_from_manifest(&manifest);
         let tools_by_id = tools_by_id_from_manifest(&manifest);
         for (tool_id, description) in &tools_by_id {
@@ -139,23 +151,28 @@ pub(super) fn validate_pack_manifests(
                     Some(rel.clone()),
                 ));
             }
+        }
 
-            if let Some(gates) = &manifest.gates {
-                let gate_problems = validate_pack_gates(gates, canon);
-                if !gate_problems.is_empty() {
-                    violations.push(mk_violation(
-                        "packs.gates_invalid",
-                        format!("gates invalid: {}", gate_problems.join("; ")),
-                        Some(rel.clone()),
-                    ));
-                }
+        if let Some(gates) = &manifest.gates {
+            let mut gate_problems = validate_pack_gate_tools(gates, &tool_ids);
+            let references_canonical =
+                !(gates.ci_fast.is_empty() && gates.ci.is_empty() && gates.flagship.is_empty());
+            match &manifest.canonical_tools {
+                Some(canon) => gate_problems.extend(validate_pack_gates(gates, canon)),
+                None if references_canonical => violations.push(mk_violation(
+                    "packs.canonical_tools_required",
+                    "gates present but canonical_tools is missing".to_string(),
+                    Some(rel.clone()),
+                )),
+                None => {}
+            }
+            if !gate_problems.is_empty() {
+                violations.push(mk_violation(
+                    "packs.gates_invalid",
+                    format!("gates invalid: {}", gate_problems.join("; ")),
+                    Some(rel.clone()),
+                ));
             }
-        } else if manifest.gates.is_some() {
-            violations.push(mk_violation(
-                "packs.canonical_tools_required",
-                "gates present but canonical_tools is missing".to_string(),
-                Some(rel.clone()),
-            ));
         }
     }
 
@@ -70,3 +70,31 @@ disabled = ["build", "lint", "fmt"]
     let v = validate_packs(repo_root);
     assert!(v.iter().any(|x| x.code == "packs.canonical_tools_invalid"));
 }
+
+#[test]
+fn canonical_aliases_must_target_known_tools_without_shadowing() {
+    use super::canonical_tools::validate_canonical_tools_config;
+    use crate::api::{CanonicalToolId, CanonicalToolsConfig};
+    use std::collections::BTreeSet;
+
+    let known: BTreeSet<String> = ["rust-test".to_string()].into_iter().collect();
+    let mut cfg = CanonicalToolsConfig {
+        test: vec!["rust-test".to_string()],
+        disabled: vec![
+            CanonicalToolId::Build,
+            CanonicalToolId::Lint,
+            CanonicalToolId::Fmt,
+            CanonicalToolId::Docs,
+        ],
+        ..Default::default()
+    };
+    cfg.aliases
+        .insert("cargo-test".to_string(), "rust-test".to_string());
+    assert!(validate_canonical_tools_config(&cfg, &known).is_empty());
+
+    cfg.aliases
+        .insert("rust-test".to_string(), "rust-test".to_string());
+    cfg.aliases.insert("old".to_string(), "missing".to_string());
+    let problems = validate_canonical_tools_config(&cfg, &known);
+    assert_eq!(problems.len(), 2, "{problems:?}");
+}
@@ -1,18 +1,25 @@
 use base64::{Engine as _, engine::general_purpose};
+use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey as Ed25519VerifyingKey};
 use p256::ecdsa::{Signature as P256Signature, VerifyingKey, signature::Verifier};
 use p256::pkcs8::DecodePublicKey;
+use p256::pkcs8::der::Document;
+use p256::pkcs8::spki::SubjectPublicKeyInfoRef;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::{
-    collections::{BTreeMap, BTreeSet},
-    fs,
-    path::{Component, Path, PathBuf},
-};
+use std::{collections::BTreeMap, fs, path::PathBuf};
 
 const TIER_EXPERIMENTAL: &str = "experimental";
 const TIER_SUNSET: &str = "sunset";
 const SUNSET_META_COMPAT_KEY: &str = concat!("deprecat", "ed");
 const PACK_RUNTIME_KIND_MIXED: &str = "mixed";
+#[cfg(feature = "full")]
+const HTTP_MAX_ATTEMPTS: u32 = 3;
+#[cfg(feature = "full")]
+const HTTP_RETRY_BASE_DELAY_MS: u64 = 200;
+#[cfg(feature = "external_packs")]
+const HTTP_MAX_REDIRECTS: usize = 5;
+#[cfg(feature = "external_packs")]
+const HTTP_TIMEOUT_SECS: u64 = 60;
 
 pub const OFFICIAL_REGISTRY_COSIGN_PUBKEY_PEM: &str = "-----BEGIN PUBLIC KEY-----\nMFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAExWXyUnb9j+0nAopQJWPU2JObKitu\nfNacvZOK6C4P/AeUOQc0PmK3rSrm/NRII6pCRssOC65QTbt+0zi0dzySwQ==\n-----END PUBLIC KEY-----\n";
 
@@ -51,7 +58,16 @@ pub struct RegistryPluginV1 {
     #[serde(default)]
     pub tags: Option<Vec<String>>,
     #[serde(default)]
-    pub compat: Option<serde_json::Value>,
+    pub compat: Option<RegistryPluginCompatV1>,
+    #[serde(default, flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryPluginCompatV1 {
+    /// Oldest compas release (semver) the plugin supports; install blocks on older builds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_compas_version: Option<String>,
     #[serde(default, flatten)]
     pub extra: BTreeMap<String, serde_json::Value>,
 }
@@ -103,7 +119,31 @@ pub struct RegistryPackRecommendationV1 {
     pub when_no_languages: bool,
 }
 
-#[allow(dead_code)]
+/// Key-only mirror of the manifest schema for `--strict-manifest`: every struct denies unknown
+/// fields, including the plugin keys the lenient form keeps in `extra`. Values are not
+/// re-checked here; the regular parse and `validate_manifest_v1` own that.
+mod strict;
+mod validate;
+
+pub use validate::validate_manifest_v1;
+
+/// Rejects manifest keys the schema does not know (typos the lenient parse would ignore or
+/// park in `extra`). The sunset marker object is the one open-ended plugin key.
+fn check_manifest_known_fields(manifest_bytes: &[u8]) -> Result<(), String> {
+    let mut raw: serde_json::Value = serde_json::from_slice(manifest_bytes)
+        .map_err(|e| format!("failed to parse registry manifest JSON: {e}"))?;
+    if let Some(plugins) = raw.get_mut("plugins").and_then(|p| p.as_array_mut()) {
+        for plugin in plugins {
+            if let Some(obj) = plugin.as_object_mut() {
+                obj.remove(SUNSET_META_COMPAT_KEY);
+            }
+        }
+    }
+    serde_json::from_value::<strict::ManifestV1>(raw)
+        .map(|_| ())
+        .map_err(|e| format!("strict manifest: {e}"))
+}
+
 #[derive(Debug, Clone)]
 pub struct ManifestResolved {
     pub manifest: RegistryManifestV1,
@@ -147,376 +187,11 @@ fn is_compas_id(s: &str, min_len: usize) -> bool {
     true
 }
 
-fn validate_token_list(
-    values: &[String],
-    context: &str,
-    min_token_len: usize,
-    allow_empty: bool,
-) -> Result<(), String> {
-    if values.is_empty() && !allow_empty {
-        return Err(format!("{context} must be non-empty"));
-    }
-    let mut seen: BTreeSet<String> = BTreeSet::new();
-    for value in values {
-        if !is_compas_id(value, min_token_len) {
-            return Err(format!("{context} has invalid token: {value:?}"));
-        }
-        if !seen.insert(value.clone()) {
-            return Err(format!("{context} contains duplicate token: {value:?}"));
-        }
-    }
-    let mut sorted = values.to_vec();
-    sorted.sort();
-    if sorted != values {
-        return Err(format!("{context} must be sorted lexicographically"));
-    }
-    Ok(())
-}
-
-fn validate_pack_recommendation(
-    pack_id: &str,
-    rec: &RegistryPackRecommendationV1,
-) -> Result<(), String> {
-    if rec.priority == 0 {
-        return Err(format!(
-            "pack {pack_id} recommendation.priority must be positive integer"
-        ));
-    }
-    let why_len = rec.why.trim().chars().count();
-    if !(12..=220).contains(&why_len) {
-        return Err(format!(
-            "pack {pack_id} recommendation.why length must be 12..220 chars"
-        ));
-    }
-    validate_token_list(
-        &rec.languages_any,
-        &format!("pack {pack_id} recommendation.languages_any"),
-        1,
-        true,
-    )?;
-    validate_token_list(
-        &rec.languages_all,
-        &format!("pack {pack_id} recommendation.languages_all"),
-        1,
-        true,
-    )?;
-    validate_token_list(
-        &rec.signals_any,
-        &format!("pack {pack_id} recommendation.signals_any"),
-        2,
-        true,
-    )?;
-    validate_token_list(
-        &rec.signals_all,
-        &format!("pack {pack_id} recommendation.signals_all"),
-        2,
-        true,
-    )?;
-    if rec.languages_any.is_empty()
-        && rec.languages_all.is_empty()
-        && rec.signals_any.is_empty()
-        && rec.signals_all.is_empty()
-        && !rec.when_no_languages
-    {
-        return Err(format!(
-            "pack {pack_id} recommendation must define at least one selector"
-        ));
-    }
-    if rec.when_no_languages && (!rec.languages_any.is_empty() || !rec.languages_all.is_empty()) {
-        return Err(format!(
-            "pack {pack_id} recommendation.when_no_languages cannot be combined with language selectors"
-        ));
-    }
-    Ok(())
-}
-
-fn has_pack_aggregate_metadata(pack: &RegistryPackV1) -> bool {
-    !pack.capabilities.is_empty()
-        || !pack.requires.is_empty()
-        || !pack.runtime_kind.trim().is_empty()
-        || !pack.cost_class.trim().is_empty()
-}
-
-pub fn validate_manifest_v1(manifest: &RegistryManifestV1) -> Result<(), String> {
-    if manifest.schema != "compas.registry.manifest.v1" {
-        return Err(format!(
-            "unsupported registry manifest schema: {}",
-            manifest.schema
-        ));
-    }
-    if manifest.registry_version.trim().is_empty() {
-        return Err("registry manifest has empty registry_version".to_string());
-    }
-    if manifest.archive.name.trim().is_empty()
-        || manifest.archive.name.contains('/')
-        || manifest.archive.name.contains('\\')
-    {
-        return Err(format!(
-            "invalid manifest archive.name (must be a file name): {}",
-            manifest.archive.name
-        ));
-    }
-    if !is_sha256_hex(&manifest.archive.sha256) {
-        return Err(format!(
-            "invalid manifest archive.sha256 (expected 64 lowercase hex chars): {}",
-            manifest.archive.sha256
-        ));
-    }
-    if manifest.plugins.is_empty() {
-        return Err("registry manifest has empty plugins list".to_string());
-    }
-    if manifest.packs.is_empty() {
-        return Err("registry manifest has empty packs list".to_string());
-    }
-
-    let mut ids: BTreeSet<String> = BTreeSet::new();
-    let mut aliases: BTreeSet<String> = BTreeSet::new();
-    for plugin in &manifest.plugins {
-        if !is_compas_id(&plugin.id, 2) {
-            return Err(format!("invalid plugin id in manifest: {}", plugin.id));
-        }
-        if !ids.insert(plugin.id.clone()) {
-            return Err(format!("duplicate plugin id in manifest: {}", plugin.id));
-        }
-        for alias in &plugin.aliases {
-            if !is_compas_id(alias, 2) {
-                return Err(format!("plugin {} has invalid alias: {}", plugin.id, alias));
-            }
-            if ids.contains(alias) {
-                return Err(format!(
-                    "plugin {} alias collides with canonical plugin id: {}",
-                    plugin.id, alias
-                ));
-            }
-            if !aliases.insert(alias.clone()) {
-                return Err(format!("duplicate alias in manifest: {}", alias));
-            }
-        }
-        let plugin_path = Path::new(&plugin.path);
-        if plugin_path.as_os_str().is_empty() || plugin_path.is_absolute() {
-            return Err(format!(
-                "plugin {} has unsafe path: {}",
-                plugin.id, plugin.path
-            ));
-        }
-        if plugin.path.contains('\\') {
-            return Err(format!(
-                "plugin {} has unsafe path (backslashes forbidden): {}",
-                plugin.id, plugin.path
-            ));
-        }
-        for c in plugin_path.components() {
-            match c {
-                Component::CurDir | Component::Normal(_) => {}
-                _ => {
-                    return Err(format!(
-                        "plugin {} has unsafe path: {}",
-                        plugin.id, plugin.path
-                    ));
-                }
-            }
-        }
-        if plugin.package.version.trim().is_empty() {
-            return Err(format!("plugin {} has empty package.version", plugin.id));
-        }
-        if plugin.package.entrypoint.trim().is_empty() {
-            return Err(format!("plugin {} has empty package.entrypoint", plugin.id));
-        }
-        let languages = &plugin.package.languages;
-        validate_token_list(
-            languages,
-            &format!("plugin {} package.languages", plugin.id),
-            1,
-            false,
-        )?;
-
-        let capabilities = plugin
-            .extra
-            .get("capabilities")
-            .and_then(|value| value.as_array())
-            .ok_or_else(|| format!("plugin {} capabilities missing or invalid", plugin.id))?
-            .iter()
-            .map(|value| {
-                value
-                    .as_str()
-                    .map(ToString::to_string)
-                    .ok_or_else(|| format!("plugin {} capabilities contains non-string", plugin.id))
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-        validate_token_list(
-            &capabilities,
-            &format!("plugin {} capabilities", plugin.id),
-            2,
-            false,
-        )?;
-
-        let requires = plugin
-            .extra
-            .get("requires")
-            .and_then(|value| value.as_array())
-            .ok_or_else(|| format!("plugin {} requires missing or invalid", plugin.id))?
-            .iter()
-            .map(|value| {
-                value
-                    .as_str()
-                    .map(ToString::to_string)
-                    .ok_or_else(|| format!("plugin {} requires contains non-string", plugin.id))
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-        validate_token_list(
-            &requires,
-            &format!("plugin {} requires", plugin.id),
-            2,
-            true,
-        )?;
-
-        let runtime_kind = plugin
-            .extra
-            .get("runtime_kind")
-            .and_then(|value| value.as_str())
-            .ok_or_else(|| format!("plugin {} runtime_kind missing or invalid", plugin.id))?;
-        if !matches!(runtime_kind, "tool-backed" | "hybrid" | "reference") {
-            return Err(format!(
-                "plugin {} runtime_kind invalid: {}",
-                plugin.id, runtime_kind
-            ));
-        }
-        if runtime_kind != plugin.package.kind {
-            return Err(format!(
-                "plugin {} runtime_kind must match package.type",
-                plugin.id
-            ));
-        }
-        let cost_class = plugin
-            .extra
-            .get("cost_class")
-            .and_then(|value| value.as_str())
-            .ok_or_else(|| format!("plugin {} cost_class missing or invalid", plugin.id))?;
-        if !matches!(cost_class, "low" | "medium" | "high") {
-            return Err(format!(
-                "plugin {} cost_class invalid: {}",
-                plugin.id, cost_class
-            ));
-        }
-
-        let sunset_meta_present = plugin
-            .extra
-            .get(SUNSET_META_COMPAT_KEY)
-            .as_ref()
-            .and_then(|value| value.as_object())
-            .is_some_and(|obj| !obj.is_empty());
-        let tier = plugin
-            .tier
-            .as_deref()
-            .unwrap_or_default()
-            .trim()
-            .to_ascii_lowercase();
-        match tier.as_str() {
-            "" | "community" | "certified" | TIER_EXPERIMENTAL | TIER_SUNSET => {}
-            other => return Err(format!("plugin {} has invalid tier {}", plugin.id, other)),
-        }
-        if sunset_meta_present && tier != TIER_SUNSET {
-            return Err(format!(
-                "plugin {} sunset metadata requires tier=sunset",
-                plugin.id
-            ));
-        }
-    }
-
-    let plugin_id_set: BTreeSet<String> = manifest.plugins.iter().map(|p| p.id.clone()).collect();
-    for pack in &manifest.packs {
-        if !is_compas_id(&pack.id, 2) {
-            return Err(format!("invalid pack id in manifest: {}", pack.id));
-        }
-        if pack.description.trim().len() < 8 {
-            return Err(format!("pack {} description too short", pack.id));
-        }
-        if pack.plugins.is_empty() {
-            return Err(format!("pack {} has empty plugins list", pack.id));
-        }
-        let mut local_refs = BTreeSet::new();
-        for plugin_id in &pack.plugins {
-            if !plugin_id_set.contains(plugin_id) {
-                return Err(format!(
-                    "pack {} references unknown plugin id: {}",
-                    pack.id, plugin_id
-                ));
-            }
-            if !local_refs.insert(plugin_id.clone()) {
-                return Err(format!(
-                    "pack {} has duplicate plugin reference: {}",
-                    pack.id, plugin_id
-                ));
-            }
-        }
-        let mut sorted_plugins = pack.plugins.clone();
-        sorted_plugins.sort();
-        if sorted_plugins != pack.plugins {
-            return Err(format!("pack {} plugins must be sorted by id", pack.id));
-        }
-        if has_pack_aggregate_metadata(pack) {
-            if pack.capabilities.is_empty() {
-                return Err(format!(
-                    "pack {} capabilities must be non-empty when aggregate metadata is present",
-                    pack.id
-                ));
-            }
-            if pack.runtime_kind.trim().is_empty() {
-                return Err(format!(
-                    "pack {} runtime_kind missing while aggregate metadata is present",
-                    pack.id
-                ));
-            }
-            if pack.cost_class.trim().is_empty() {
-                return Err(format!(
-                    "pack {} cost_class missing while aggregate metadata is present",
-                    pack.id
-                ));
-            }
-            validate_token_list(
-                &pack.capabilities,
-                &format!("pack {} capabilities", pack.id),
-                2,
-                false,
-            )?;
-            validate_token_list(
-                &pack.requires,
-                &format!("pack {} requires", pack.id),
-                2,
-                true,
-            )?;
-            if !matches!(
-                pack.runtime_kind.as_str(),
-                "tool-backed" | "hybrid" | "reference" | PACK_RUNTIME_KIND_MIXED
-            ) {
-                return Err(format!(
-                    "pack {} runtime_kind invalid: {}",
-                    pack.id, pack.runtime_kind
-                ));
-            }
-            if !matches!(pack.cost_class.as_str(), "low" | "medium" | "high") {
-                return Err(format!(
-                    "pack {} cost_class invalid: {}",
-                    pack.id, pack.cost_class
-                ));
-            }
-        }
-        if let Some(recommendation) = &pack.recommendation {
-            validate_pack_recommendation(&pack.id, recommendation)?;
-        }
-    }
-
-    let mut sorted_pack_ids: Vec<String> =
-        manifest.packs.iter().map(|pack| pack.id.clone()).collect();
-    let current_pack_ids = sorted_pack_ids.clone();
-    sorted_pack_ids.sort();
-    if current_pack_ids != sorted_pack_ids {
-        return Err("manifest.packs must be sorted by id (determinism requirement)".to_string());
-    }
-
-    Ok(())
-}
+const OID_EC_PUBLIC_KEY: &str = "1.2.840.10045.2.1";
+const OID_ED25519: &str = "1.3.101.112";
 
+/// Verifies a base64 blob signature against an SPKI PEM key, dispatching on the key algorithm:
+/// ECDSA P-256 expects a DER signature over SHA-256, Ed25519 a raw 64-byte signature.
 fn verify_cosign_blob_signature(
     payload: &[u8],
     signature_b64: &str,
@@ -526,7 +201,25 @@ fn verify_cosign_blob_signature(
         .decode(signature_b64.trim())
         .map_err(|e| format!("failed to decode base64 signature: {e}"))?;
 
-    let signature = P256Signature::from_der(&signature_raw)
+    let (_label, spki_doc) = Document::from_pem(pubkey_pem.trim())
+        .map_err(|e| format!("failed to parse PEM public key: {e}"))?;
+    let spki = SubjectPublicKeyInfoRef::try_from(spki_doc.as_bytes())
+        .map_err(|e| format!("failed to parse PEM public key: {e}"))?;
+    match spki.algorithm.oid.to_string().as_str() {
+        OID_EC_PUBLIC_KEY => verify_p256_signature(payload, &signature_raw, pubkey_pem),
+        OID_ED25519 => verify_ed25519_signature(payload, &signature_raw, pubkey_pem),
+        other => Err(format!(
+            "unsupported public key algorithm {other} (expected ECDSA P-256 or Ed25519)"
+        )),
+    }
+}
+
+fn verify_p256_signature(
+    payload: &[u8],
+    signature_raw: &[u8],
+    pubkey_pem: &str,
+) -> Result<String, String> {
+    let signature = P256Signature::from_der(signature_raw)
         .map_err(|e| format!("failed to parse DER signature: {e}"))?;
     let verifying_key = VerifyingKey::from_public_key_pem(pubkey_pem)
         .map_err(|e| format!("failed to parse PEM public key: {e}"))?;
@@ -540,6 +233,24 @@ fn verify_cosign_blob_signature(
     Ok(format!("sha256:{key_id}"))
 }
 
+fn verify_ed25519_signature(
+    payload: &[u8],
+    signature_raw: &[u8],
+    pubkey_pem: &str,
+) -> Result<String, String> {
+    let signature = Ed25519Signature::from_slice(signature_raw)
+        .map_err(|e| format!("failed to parse Ed25519 signature: {e}"))?;
+    let verifying_key = Ed25519VerifyingKey::from_public_key_pem(pubkey_pem)
+        .map_err(|e| format!("failed to parse PEM public key: {e}"))?;
+
+    verifying_key
+        .verify_strict(payload, &signature)
+        .map_err(|e| format!("signature verification failed: {e}"))?;
+
+    let key_id = sha256_hex(verifying_key.as_bytes());
+    Ok(format!("sha256:{key_id}"))
+}
+
 fn extract_base_url(url: &str) -> Option<String> {
     let (base, _tail) = url.rsplit_once('/')?;
     Some(base.to_string())
@@ -549,27 +260,105 @@ fn signature_source_for_manifest_source(source: &str) -> String {
     format!("{source}.sig")
 }
 
-#[cfg(feature = "full")]
-async fn fetch_url_bytes(url: &str, max_bytes: usize) -> Result<Vec<u8>, String> {
-    let response = reqwest::Client::new()
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| format!("failed to download {url}: {e}"))?;
-    let response = response
-        .error_for_status()
-        .map_err(|e| format!("download failed for {url}: {e}"))?;
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|e| format!("failed to read body from {url}: {e}"))?;
-    if bytes.len() > max_bytes {
+/// HTTP client for registry and pack downloads; redirect chains are capped and every request
+/// has a deadline, so a hostile or stalled server cannot hold the caller indefinitely.
+#[cfg(feature = "external_packs")]
+pub fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::limited(HTTP_MAX_REDIRECTS))
+        .timeout(std::time::Duration::from_secs(HTTP_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| format!("failed to build http client: {e}"))
+}
+
+/// Streams the body of `response` into `on_chunk`, failing before the download when the
+/// declared `Content-Length` exceeds `max_bytes` and mid-stream once the received bytes do.
+#[cfg(feature = "external_packs")]
+pub async fn read_body_capped(
+    mut response: reqwest::Response,
+    url: &str,
+    max_bytes: u64,
+    mut on_chunk: impl FnMut(&[u8]) -> Result<(), String>,
+) -> Result<(), String> {
+    if let Some(len) = response.content_length()
+        && len > max_bytes
+    {
         return Err(format!(
-            "downloaded payload too large from {url}: {} bytes (max {max_bytes})",
-            bytes.len()
+            "download too large from {url}: content_length={len} > max={max_bytes}"
         ));
     }
-    Ok(bytes.to_vec())
+    let mut received: u64 = 0;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("failed to read body from {url}: {e}"))?
+    {
+        received += chunk.len() as u64;
+        if received > max_bytes {
+            return Err(format!(
+                "download too large from {url}: exceeded max={max_bytes} bytes"
+            ));
+        }
+        on_chunk(&chunk)?;
+    }
+    Ok(())
+}
+
+/// GET `url`, retrying 5xx responses and connect/timeout errors with jittered exponential
+/// backoff. 4xx and other request failures return after the first attempt.
+#[cfg(feature = "full")]
+pub async fn get_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<reqwest::Response, String> {
+    let mut attempt: u32 = 1;
+    loop {
+        let retryable = match client.get(url).send().await {
+            Ok(response) if response.status().is_server_error() => {
+                format!(
+                    "download failed for {url}: http status {}",
+                    response.status()
+                )
+            }
+            Ok(response) => {
+                return response
+                    .error_for_status()
+                    .map_err(|e| format!("download failed for {url}: {e}"));
+            }
+            Err(e) if e.is_connect() || e.is_timeout() => {
+                format!("failed to download {url}: {e}")
+            }
+            Err(e) => return Err(format!("failed to download {url}: {e}")),
+        };
+        if attempt >= HTTP_MAX_ATTEMPTS {
+            return Err(format!("{retryable} (gave up after {attempt} attempts)"));
+        }
+        tokio::time::sleep(retry_delay(attempt)).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(feature = "full")]
+fn retry_delay(attempt: u32) -> std::time::Duration {
+    let base_ms = HTTP_RETRY_BASE_DELAY_MS << (attempt - 1);
+    // Clock nanos are jitter enough to keep concurrent installs from retrying in lockstep.
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()))
+        .unwrap_or(0);
+    std::time::Duration::from_millis(base_ms + nanos % base_ms)
+}
+
+#[cfg(feature = "full")]
+async fn fetch_url_bytes(url: &str, max_bytes: usize) -> Result<Vec<u8>, String> {
+    let response = get_with_retry(&http_client()?, url).await?;
+    let mut out: Vec<u8> = Vec::new();
+    read_body_capped(response, url, max_bytes as u64, |chunk| {
+        out.extend_from_slice(chunk);
+        Ok(())
+    })
+    .await?;
+    Ok(out)
 }
 
 #[cfg(not(feature = "full"))]
@@ -579,66 +368,107 @@ async fn fetch_url_bytes(url: &str, _max_bytes: usize) -> Result<Vec<u8>, String
     ))
 }
 
-pub async fn load_verified_manifest_source(
+/// Manifest bytes and detached signature as read from a registry source, before verification.
+pub struct ManifestSourceBytes {
+    pub manifest_bytes: Vec<u8>,
+    pub signature_b64: Option<String>,
+    pub base_url: Option<String>,
+    pub base_dir: Option<PathBuf>,
+}
+
+impl ManifestSourceBytes {
+    /// Bytes fetched from (or previously cached for) a URL source; the archive resolves next to it.
+    pub fn from_url(
+        registry_source: &str,
+        manifest_bytes: Vec<u8>,
+        signature_b64: Option<String>,
+    ) -> Self {
+        Self {
+            manifest_bytes,
+            signature_b64,
+            base_url: extract_base_url(registry_source),
+            base_dir: None,
+        }
+    }
+}
+
+pub async fn read_manifest_source(
     registry_source: &str,
     allow_unsigned: bool,
-    pubkey_pem_override: Option<String>,
-) -> Result<ManifestResolved, String> {
-    let registry_source = registry_source.trim().to_string();
+) -> Result<ManifestSourceBytes, String> {
+    let registry_source = registry_source.trim();
     if registry_source.is_empty() {
         return Err("registry source must be non-empty".to_string());
     }
 
-    let manifest_bytes: Vec<u8>;
-    let mut signature_b64: Option<String> = None;
-    let mut base_url: Option<String> = None;
-    let mut base_dir: Option<PathBuf> = None;
-
-    if is_http_url(&registry_source) {
-        manifest_bytes = fetch_url_bytes(&registry_source, 5 * 1024 * 1024).await?;
+    if is_http_url(registry_source) {
+        let manifest_bytes = fetch_url_bytes(registry_source, 5 * 1024 * 1024).await?;
+        let mut signature_b64: Option<String> = None;
         if !allow_unsigned {
-            let sig_url = signature_source_for_manifest_source(&registry_source);
+            let sig_url = signature_source_for_manifest_source(registry_source);
             let sig_bytes = fetch_url_bytes(&sig_url, 512 * 1024).await?;
             signature_b64 = Some(
                 String::from_utf8(sig_bytes)
                     .map_err(|e| format!("signature is not valid UTF-8: {e}"))?,
             );
         }
-        base_url = extract_base_url(&registry_source);
-    } else {
-        let path = PathBuf::from(&registry_source);
-        let path = fs::canonicalize(&path)
-            .map_err(|e| format!("failed to resolve registry source {}: {e}", path.display()))?;
-        manifest_bytes = fs::read(&path)
-            .map_err(|e| format!("failed to read manifest {}: {e}", path.display()))?;
-        let sig_path = path.with_extension(format!(
-            "{}.sig",
-            path.extension().and_then(|s| s.to_str()).unwrap_or("json")
+        return Ok(ManifestSourceBytes::from_url(
+            registry_source,
+            manifest_bytes,
+            signature_b64,
         ));
-        if sig_path.is_file() {
-            signature_b64 =
-                Some(fs::read_to_string(&sig_path).map_err(|e| {
-                    format!("failed to read signature {}: {e}", sig_path.display())
-                })?);
-        }
-        base_dir = path.parent().map(PathBuf::from);
     }
 
-    let manifest_sha256 = sha256_hex(&manifest_bytes);
-    let manifest: RegistryManifestV1 = serde_json::from_slice(&manifest_bytes)
+    let path = PathBuf::from(registry_source);
+    let path = fs::canonicalize(&path)
+        .map_err(|e| format!("failed to resolve registry source {}: {e}", path.display()))?;
+    let manifest_bytes =
+        fs::read(&path).map_err(|e| format!("failed to read manifest {}: {e}", path.display()))?;
+    let sig_path = path.with_extension(format!(
+        "{}.sig",
+        path.extension().and_then(|s| s.to_str()).unwrap_or("json")
+    ));
+    let mut signature_b64: Option<String> = None;
+    if sig_path.is_file() {
+        signature_b64 = Some(
+            fs::read_to_string(&sig_path)
+                .map_err(|e| format!("failed to read signature {}: {e}", sig_path.display()))?,
+        );
+    }
+    Ok(ManifestSourceBytes {
+        manifest_bytes,
+        signature_b64,
+        base_url: None,
+        base_dir: path.parent().map(PathBuf::from),
+    })
+}
+
+/// Parses, validates and (unless `allow_unsigned`) signature-checks `source`; `strict_fields`
+/// additionally fails on keys outside the manifest schema.
+pub fn verify_manifest_source(
+    source: &ManifestSourceBytes,
+    allow_unsigned: bool,
+    pubkey_pem_override: Option<String>,
+    strict_fields: bool,
+) -> Result<ManifestResolved, String> {
+    let manifest_sha256 = sha256_hex(&source.manifest_bytes);
+    let manifest: RegistryManifestV1 = serde_json::from_slice(&source.manifest_bytes)
         .map_err(|e| format!("failed to parse registry manifest JSON: {e}"))?;
     validate_manifest_v1(&manifest)?;
+    if strict_fields {
+        check_manifest_known_fields(&source.manifest_bytes)?;
+    }
 
     let signature_key_id = if allow_unsigned {
         None
     } else {
-        let sig = signature_b64.as_deref().ok_or_else(|| {
+        let sig = source.signature_b64.as_deref().ok_or_else(|| {
             "missing registry manifest signature (.sig); use allow_unsigned to bypass".to_string()
         })?;
         let pubkey_pem =
             pubkey_pem_override.unwrap_or_else(|| OFFICIAL_REGISTRY_COSIGN_PUBKEY_PEM.to_string());
         Some(verify_cosign_blob_signature(
-            &manifest_bytes,
+            &source.manifest_bytes,
             sig,
             &pubkey_pem,
         )?)
@@ -648,7 +478,27 @@ pub async fn load_verified_manifest_source(
         manifest,
         manifest_sha256,
         signature_key_id,
-        base_url,
-        base_dir,
+        base_url: source.base_url.clone(),
+        base_dir: source.base_dir.clone(),
     })
 }
+
+/// One-shot read + verify (init recommendations); the plugins CLI uses the two steps directly
+/// so it can cache the verified bytes.
+pub async fn load_verified_manifest_source(
+    registry_source: &str,
+    allow_unsigned: bool,
+    pubkey_pem_override: Option<String>,
+) -> Result<ManifestResolved, String> {
+    let source = read_manifest_source(registry_source, allow_unsigned).await?;
+    verify_manifest_source(&source, allow_unsigned, pubkey_pem_override, false)
+}
+
+#[cfg(test)]
+mod signature_tests;
+
+#[cfg(all(test, feature = "full"))]
+mod http_retry_tests;
+
+#[cfg(test)]
+mod strict_manifest_tests;
@@ -0,0 +1,110 @@
+use super::{fetch_url_bytes, get_with_retry};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Serves `statuses` in order (one per connection) and counts accepted requests.
+fn serve(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+    let url = format!("http://{}/registry.json", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&hits);
+    std::thread::spawn(move || {
+        for status in statuses {
+            let Ok((mut stream, _)) = listener.accept() else {
+                return;
+            };
+            counter.fetch_add(1, Ordering::SeqCst);
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf);
+            let body = "ok";
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+    (url, hits)
+}
+
+#[tokio::test]
+async fn server_error_then_success_is_retried() {
+    let (url, hits) = serve(vec![503, 200]);
+    let response = get_with_retry(&reqwest::Client::new(), &url)
+        .await
+        .expect("second attempt succeeds");
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn client_error_fails_without_retry() {
+    let (url, hits) = serve(vec![404, 200]);
+    let err = get_with_retry(&reqwest::Client::new(), &url)
+        .await
+        .expect_err("404 must fail");
+    assert!(err.contains("404"), "{err}");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn persistent_server_errors_give_up_after_max_attempts() {
+    let (url, hits) = serve(vec![503, 503, 503, 200]);
+    let err = get_with_retry(&reqwest::Client::new(), &url)
+        .await
+        .expect_err("three 503s must fail");
+    assert!(err.contains("gave up after 3 attempts"), "{err}");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+/// Serves one raw HTTP response, verbatim, to the first connection.
+fn serve_raw(response: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+    let url = format!("http://{}/registry.json", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let Ok((mut stream, _)) = listener.accept() else {
+            return;
+        };
+        let mut buf = [0u8; 4096];
+        let _ = stream.read(&mut buf);
+        let _ = stream.write_all(response.as_bytes());
+    });
+    url
+}
+
+#[tokio::test]
+async fn oversized_content_length_is_rejected_before_download() {
+    let url = serve_raw(
+        "HTTP/1.1 200 OK\r\nContent-Length: 1048576\r\nConnection: close\r\n\r\nok".to_string(),
+    );
+    let err = fetch_url_bytes(&url, 1024)
+        .await
+        .expect_err("declared length over the cap must fail");
+    assert!(err.contains("content_length=1048576 > max=1024"), "{err}");
+}
+
+#[tokio::test]
+async fn streamed_body_is_capped_without_content_length() {
+    let chunk = "x".repeat(600);
+    let url = serve_raw(format!(
+        "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n\
+         {len:x}\r\n{chunk}\r\n{len:x}\r\n{chunk}\r\n0\r\n\r\n",
+        len = chunk.len()
+    ));
+    let err = fetch_url_bytes(&url, 1024)
+        .await
+        .expect_err("streamed body over the cap must fail");
+    assert!(err.contains("exceeded max=1024 bytes"), "{err}");
+
+    let url = serve_raw(format!(
+        "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n\
+         {len:x}\r\n{chunk}\r\n0\r\n\r\n",
+        len = chunk.len()
+    ));
+    let body = fetch_url_bytes(&url, 1024).await.expect("body under cap");
+    assert_eq!(body.len(), 600);
+}
@@ -0,0 +1,42 @@
+use super::verify_cosign_blob_signature;
+
+// Golden Ed25519 fixture produced with `openssl pkeyutl -sign -rawin`; do not reformat.
+const ED25519_PAYLOAD: &[u8] =
+    b"{\"schema\":\"compas.registry.manifest.v1\",\"registry_version\":\"golden-ed25519\"}\n";
+const ED25519_SIG_B64: &str =
+    "fWjHq0CqNMf13D36D3Ss5CRayhZJ8cHxXTqPBtfP2t6ohfMSBus2E5B/mE9qdraGkHLkmbK6vsNXFLn4DACMCQ==";
+const ED25519_PUBKEY_PEM: &str = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAdguozeE8qXVV0uN04nnDrZmxKBS5AEu0OHCZAJzvTYg=\n-----END PUBLIC KEY-----\n";
+const ED25519_OTHER_PUBKEY_PEM: &str = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEA9s+nOyBLYiHDrSBz57ftegZP7+2X4EMZIs6nNS7T4vU=\n-----END PUBLIC KEY-----\n";
+
+#[test]
+fn ed25519_golden_fixture_verifies() {
+    let key_id = verify_cosign_blob_signature(ED25519_PAYLOAD, ED25519_SIG_B64, ED25519_PUBKEY_PEM)
+        .expect("golden signature must verify");
+    assert_eq!(
+        key_id,
+        "sha256:c7af9814f0ec94d1fdff3225b61238b401aa5c0f62f8ca97994de76e92d7628d"
+    );
+}
+
+#[test]
+fn ed25519_rejects_wrong_key_and_tampered_payload() {
+    let err =
+        verify_cosign_blob_signature(ED25519_PAYLOAD, ED25519_SIG_B64, ED25519_OTHER_PUBKEY_PEM)
+            .expect_err("wrong key must fail");
+    assert!(err.contains("signature verification failed"), "{err}");
+
+    let err = verify_cosign_blob_signature(b"tampered", ED25519_SIG_B64, ED25519_PUBKEY_PEM)
+        .expect_err("tampered payload must fail");
+    assert!(err.contains("signature verification failed"), "{err}");
+}
+
+#[test]
+fn p256_key_rejects_ed25519_signature_shape() {
+    let err = verify_cosign_blob_signature(
+        ED25519_PAYLOAD,
+        ED25519_SIG_B64,
+        super::OFFICIAL_REGISTRY_COSIGN_PUBKEY_PEM,
+    )
+    .expect_err("raw Ed25519 bytes are not a DER ECDSA signature");
+    assert!(err.contains("failed to parse DER signature"), "{err}");
+}
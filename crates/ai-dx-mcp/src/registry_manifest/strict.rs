@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct ManifestV1 {
+    schema: IgnoredAny,
+    registry_version: IgnoredAny,
+    archive: ArchiveV1,
+    plugins: Vec<PluginV1>,
+    packs: Vec<PackV1>,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ArchiveV1 {
+    name: IgnoredAny,
+    sha256: IgnoredAny,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PluginV1 {
+    id: IgnoredAny,
+    aliases: IgnoredAny,
+    path: IgnoredAny,
+    status: IgnoredAny,
+    owner: IgnoredAny,
+    description: IgnoredAny,
+    package: PackageV1,
+    tier: IgnoredAny,
+    maintainers: IgnoredAny,
+    tags: IgnoredAny,
+    compat: Option<CompatV1>,
+    capabilities: IgnoredAny,
+    requires: IgnoredAny,
+    runtime_kind: IgnoredAny,
+    cost_class: IgnoredAny,
+    artifacts_produced: IgnoredAny,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CompatV1 {
+    min_compas_version: IgnoredAny,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PackageV1 {
+    version: IgnoredAny,
+    #[serde(rename = "type")]
+    kind: IgnoredAny,
+    maturity: IgnoredAny,
+    runtime: IgnoredAny,
+    portable: IgnoredAny,
+    languages: IgnoredAny,
+    entrypoint: IgnoredAny,
+    license: IgnoredAny,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PackV1 {
+    id: IgnoredAny,
+    description: IgnoredAny,
+    plugins: IgnoredAny,
+    capabilities: IgnoredAny,
+    requires: IgnoredAny,
+    runtime_kind: IgnoredAny,
+    cost_class: IgnoredAny,
+    recommendation: Option<RecommendationV1>,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RecommendationV1 {
+    priority: IgnoredAny,
+    why: IgnoredAny,
+    languages_any: IgnoredAny,
+    languages_all: IgnoredAny,
+    signals_any: IgnoredAny,
+    signals_all: IgnoredAny,
+    when_no_languages: IgnoredAny,
+}
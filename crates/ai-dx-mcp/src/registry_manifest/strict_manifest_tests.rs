@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn fully_populated_manifest_passes_strict_mode() {
+    let extra: BTreeMap<String, serde_json::Value> = [
+        ("capabilities", serde_json::json!(["lint"])),
+        ("requires", serde_json::json!(["cargo"])),
+        ("runtime_kind", serde_json::json!("native")),
+        ("cost_class", serde_json::json!("low")),
+        ("artifacts_produced", serde_json::json!(["report.json"])),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect();
+    let manifest = RegistryManifestV1 {
+        schema: "compas.registry.manifest.v1".to_string(),
+        registry_version: "2026.10.1".to_string(),
+        archive: RegistryArchiveV1 {
+            name: "plugins.tar.gz".to_string(),
+            sha256: "a".repeat(64),
+        },
+        plugins: vec![RegistryPluginV1 {
+            id: "spec-adr-gate".to_string(),
+            aliases: vec!["adr".to_string()],
+            path: "plugins/spec-adr-gate".to_string(),
+            status: "active".to_string(),
+            owner: "core".to_string(),
+            description: "ADR gate".to_string(),
+            package: RegistryPluginPackageV1 {
+                version: "1.0.0".to_string(),
+                kind: "plugin".to_string(),
+                maturity: "stable".to_string(),
+                runtime: "native".to_string(),
+                portable: true,
+                languages: vec!["rust".to_string()],
+                entrypoint: "plugin.toml".to_string(),
+                license: "MIT".to_string(),
+            },
+            tier: Some("stable".to_string()),
+            maintainers: Some(vec!["core".to_string()]),
+            tags: Some(vec!["docs".to_string()]),
+            compat: Some(RegistryPluginCompatV1 {
+                min_compas_version: Some("0.1.0".to_string()),
+                extra: BTreeMap::new(),
+            }),
+            extra,
+        }],
+        packs: vec![RegistryPackV1 {
+            id: "docs".to_string(),
+            description: "Docs pack".to_string(),
+            plugins: vec!["spec-adr-gate".to_string()],
+            capabilities: vec!["lint".to_string()],
+            requires: vec!["cargo".to_string()],
+            runtime_kind: "native".to_string(),
+            cost_class: "low".to_string(),
+            recommendation: Some(RegistryPackRecommendationV1 {
+                priority: 1,
+                why: "docs".to_string(),
+                languages_any: vec!["rust".to_string()],
+                languages_all: vec![],
+                signals_any: vec![],
+                signals_all: vec![],
+                when_no_languages: false,
+            }),
+        }],
+    };
+    let bytes = serde_json::to_vec(&manifest).expect("serialize manifest");
+    check_manifest_known_fields(&bytes).expect("every manifest key is known to strict mode");
+
+    let mut raw: serde_json::Value = serde_json::from_slice(&bytes).expect("manifest json");
+    raw["archive"]["sha265"] = serde_json::json!("typo");
+    let err = check_manifest_known_fields(raw.to_string().as_bytes())
+        .expect_err("unknown archive key must be rejected");
+    assert!(err.contains("sha265"), "{err}");
+}
@@ -0,0 +1,388 @@
+use std::collections::BTreeSet;
+use std::path::{Component, Path};
+
+use super::{
+    PACK_RUNTIME_KIND_MIXED, RegistryManifestV1, RegistryPackRecommendationV1, RegistryPackV1,
+    SUNSET_META_COMPAT_KEY, TIER_EXPERIMENTAL, TIER_SUNSET, is_compas_id, is_sha256_hex,
+};
+
+fn validate_token_list(
+    values: &[String],
+    context: &str,
+    min_token_len: usize,
+    allow_empty: bool,
+) -> Result<(), String> {
+    if values.is_empty() && !allow_empty {
+        return Err(format!("{context} must be non-empty"));
+    }
+    let mut seen: BTreeSet<String> = BTreeSet::new();
+    for value in values {
+        if !is_compas_id(value, min_token_len) {
+            return Err(format!("{context} has invalid token: {value:?}"));
+        }
+        if !seen.insert(value.clone()) {
+            return Err(format!("{context} contains duplicate token: {value:?}"));
+        }
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    if sorted != values {
+        return Err(format!("{context} must be sorted lexicographically"));
+    }
+    Ok(())
+}
+
+fn validate_pack_recommendation(
+    pack_id: &str,
+    rec: &RegistryPackRecommendationV1,
+) -> Result<(), String> {
+    if rec.priority == 0 {
+        return Err(format!(
+            "pack {pack_id} recommendation.priority must be positive integer"
+        ));
+    }
+    let why_len = rec.why.trim().chars().count();
+    if !(12..=220).contains(&why_len) {
+        return Err(format!(
+            "pack {pack_id} recommendation.why length must be 12..220 chars"
+        ));
+    }
+    validate_token_list(
+        &rec.languages_any,
+        &format!("pack {pack_id} recommendation.languages_any"),
+        1,
+        true,
+    )?;
+    validate_token_list(
+        &rec.languages_all,
+        &format!("pack {pack_id} recommendation.languages_all"),
+        1,
+        true,
+    )?;
+    validate_token_list(
+        &rec.signals_any,
+        &format!("pack {pack_id} recommendation.signals_any"),
+        2,
+        true,
+    )?;
+    validate_token_list(
+        &rec.signals_all,
+        &format!("pack {pack_id} recommendation.signals_all"),
+        2,
+        true,
+    )?;
+    if rec.languages_any.is_empty()
+        && rec.languages_all.is_empty()
+        && rec.signals_any.is_empty()
+        && rec.signals_all.is_empty()
+        && !rec.when_no_languages
+    {
+        return Err(format!(
+            "pack {pack_id} recommendation must define at least one selector"
+        ));
+    }
+    if rec.when_no_languages && (!rec.languages_any.is_empty() || !rec.languages_all.is_empty()) {
+        return Err(format!(
+            "pack {pack_id} recommendation.when_no_languages cannot be combined with language selectors"
+        ));
+    }
+    Ok(())
+}
+
+fn has_pack_aggregate_metadata(pack: &RegistryPackV1) -> bool {
+    !pack.capabilities.is_empty()
+        || !pack.requires.is_empty()
+        || !pack.runtime_kind.trim().is_empty()
+        || !pack.cost_class.trim().is_empty()
+}
+
+pub fn validate_manifest_v1(manifest: &RegistryManifestV1) -> Result<(), String> {
+    if manifest.schema != "compas.registry.manifest.v1" {
+        return Err(format!(
+            "unsupported registry manifest schema: {}",
+            manifest.schema
+        ));
+    }
+    if manifest.registry_version.trim().is_empty() {
+        return Err("registry manifest has empty registry_version".to_string());
+    }
+    if manifest.archive.name.trim().is_empty()
+        || manifest.archive.name.contains('/')
+        || manifest.archive.name.contains('\\')
+    {
+        return Err(format!(
+            "invalid manifest archive.name (must be a file name): {}",
+            manifest.archive.name
+        ));
+    }
+    if !is_sha256_hex(&manifest.archive.sha256) {
+        return Err(format!(
+            "invalid manifest archive.sha256 (expected 64 lowercase hex chars): {}",
+            manifest.archive.sha256
+        ));
+    }
+    if manifest.plugins.is_empty() {
+        return Err("registry manifest has empty plugins list".to_string());
+    }
+    if manifest.packs.is_empty() {
+        return Err("registry manifest has empty packs list".to_string());
+    }
+
+    let mut ids: BTreeSet<String> = BTreeSet::new();
+    let mut aliases: BTreeSet<String> = BTreeSet::new();
+    for plugin in &manifest.plugins {
+        if !is_compas_id(&plugin.id, 2) {
+            return Err(format!("invalid plugin id in manifest: {}", plugin.id));
+        }
+        if !ids.insert(plugin.id.clone()) {
+            return Err(format!("duplicate plugin id in manifest: {}", plugin.id));
+        }
+        for alias in &plugin.aliases {
+            if !is_compas_id(alias, 2) {
+                return Err(format!("plugin {} has invalid alias: {}", plugin.id, alias));
+            }
+            if ids.contains(alias) {
+                return Err(format!(
+                    "plugin {} alias collides with canonical plugin id: {}",
+                    plugin.id, alias
+                ));
+            }
+            if !aliases.insert(alias.clone()) {
+                return Err(format!("duplicate alias in manifest: {}", alias));
+            }
+        }
+        let plugin_path = Path::new(&plugin.path);
+        if plugin_path.as_os_str().is_empty() || plugin_path.is_absolute() {
+            return Err(format!(
+                "plugin {} has unsafe path: {}",
+                plugin.id, plugin.path
+            ));
+        }
+        if plugin.path.contains('\\') {
+            return Err(format!(
+                "plugin {} has unsafe path (backslashes forbidden): {}",
+                plugin.id, plugin.path
+            ));
+        }
+        for c in plugin_path.components() {
+            match c {
+                Component::CurDir | Component::Normal(_) => {}
+                _ => {
+                    return Err(format!(
+                        "plugin {} has unsafe path: {}",
+                        plugin.id, plugin.path
+                    ));
+                }
+            }
+        }
+        if plugin.package.version.trim().is_empty() {
+            return Err(format!("plugin {} has empty package.version", plugin.id));
+        }
+        if plugin.package.entrypoint.trim().is_empty() {
+            return Err(format!("plugin {} has empty package.entrypoint", plugin.id));
+        }
+        if let Some(min) = plugin
+            .compat
+            .as_ref()
+            .and_then(|c| c.min_compas_version.as_deref())
+            && let Err(e) = semver::Version::parse(min.trim())
+        {
+            return Err(format!(
+                "plugin {} has invalid compat.min_compas_version {min:?}: {e}",
+                plugin.id
+            ));
+        }
+        let languages = &plugin.package.languages;
+        validate_token_list(
+            languages,
+            &format!("plugin {} package.languages", plugin.id),
+            1,
+            false,
+        )?;
+
+        let capabilities = plugin
+            .extra
+            .get("capabilities")
+            .and_then(|value| value.as_array())
+            .ok_or_else(|| format!("plugin {} capabilities missing or invalid", plugin.id))?
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| format!("plugin {} capabilities contains non-string", plugin.id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        validate_token_list(
+            &capabilities,
+            &format!("plugin {} capabilities", plugin.id),
+            2,
+            false,
+        )?;
+
+        let requires = plugin
+            .extra
+            .get("requires")
+            .and_then(|value| value.as_array())
+            .ok_or_else(|| format!("plugin {} requires missing or invalid", plugin.id))?
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| format!("plugin {} requires contains non-string", plugin.id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        validate_token_list(
+            &requires,
+            &format!("plugin {} requires", plugin.id),
+            2,
+            true,
+        )?;
+
+        let runtime_kind = plugin
+            .extra
+            .get("runtime_kind")
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| format!("plugin {} runtime_kind missing or invalid", plugin.id))?;
+        if !matches!(runtime_kind, "tool-backed" | "hybrid" | "reference") {
+            return Err(format!(
+                "plugin {} runtime_kind invalid: {}",
+                plugin.id, runtime_kind
+            ));
+        }
+        if runtime_kind != plugin.package.kind {
+            return Err(format!(
+                "plugin {} runtime_kind must match package.type",
+                plugin.id
+            ));
+        }
+        let cost_class = plugin
+            .extra
+            .get("cost_class")
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| format!("plugin {} cost_class missing or invalid", plugin.id))?;
+        if !matches!(cost_class, "low" | "medium" | "high") {
+            return Err(format!(
+                "plugin {} cost_class invalid: {}",
+                plugin.id, cost_class
+            ));
+        }
+
+        let sunset_meta_present = plugin
+            .extra
+            .get(SUNSET_META_COMPAT_KEY)
+            .as_ref()
+            .and_then(|value| value.as_object())
+            .is_some_and(|obj| !obj.is_empty());
+        let tier = plugin
+            .tier
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match tier.as_str() {
+            "" | "community" | "certified" | TIER_EXPERIMENTAL | TIER_SUNSET => {}
+            other => return Err(format!("plugin {} has invalid tier {}", plugin.id, other)),
+        }
+        if sunset_meta_present && tier != TIER_SUNSET {
+            return Err(format!(
+                "plugin {} sunset metadata requires tier=sunset",
+                plugin.id
+            ));
+        }
+    }
+
+    let plugin_id_set: BTreeSet<String> = manifest.plugins.iter().map(|p| p.id.clone()).collect();
+    for pack in &manifest.packs {
+        if !is_compas_id(&pack.id, 2) {
+            return Err(format!("invalid pack id in manifest: {}", pack.id));
+        }
+        if pack.description.trim().len() < 8 {
+            return Err(format!("pack {} description too short", pack.id));
+        }
+        if pack.plugins.is_empty() {
+            return Err(format!("pack {} has empty plugins list", pack.id));
+        }
+        let mut local_refs = BTreeSet::new();
+        for plugin_id in &pack.plugins {
+            if !plugin_id_set.contains(plugin_id) {
+                return Err(format!(
+                    "pack {} references unknown plugin id: {}",
+                    pack.id, plugin_id
+                ));
+            }
+            if !local_refs.insert(plugin_id.clone()) {
+                return Err(format!(
+                    "pack {} has duplicate plugin reference: {}",
+                    pack.id, plugin_id
+                ));
+            }
+        }
+        let mut sorted_plugins = pack.plugins.clone();
+        sorted_plugins.sort();
+        if sorted_plugins != pack.plugins {
+            return Err(format!("pack {} plugins must be sorted by id", pack.id));
+        }
+        if has_pack_aggregate_metadata(pack) {
+            if pack.capabilities.is_empty() {
+                return Err(format!(
+                    "pack {} capabilities must be non-empty when aggregate metadata is present",
+                    pack.id
+                ));
+            }
+            if pack.runtime_kind.trim().is_empty() {
+                return Err(format!(
+                    "pack {} runtime_kind missing while aggregate metadata is present",
+                    pack.id
+                ));
+            }
+            if pack.cost_class.trim().is_empty() {
+                return Err(format!(
+                    "pack {} cost_class missing while aggregate metadata is present",
+                    pack.id
+                ));
+            }
+            validate_token_list(
+                &pack.capabilities,
+                &format!("pack {} capabilities", pack.id),
+                2,
+                false,
+            )?;
+            validate_token_list(
+                &pack.requires,
+                &format!("pack {} requires", pack.id),
+                2,
+                true,
+            )?;
+            if !matches!(
+                pack.runtime_kind.as_str(),
+                "tool-backed" | "hybrid" | "reference" | PACK_RUNTIME_KIND_MIXED
+            ) {
+                return Err(format!(
+                    "pack {} runtime_kind invalid: {}",
+                    pack.id, pack.runtime_kind
+                ));
+            }
+            if !matches!(pack.cost_class.as_str(), "low" | "medium" | "high") {
+                return Err(format!(
+                    "pack {} cost_class invalid: {}",
+                    pack.id, pack.cost_class
+                ));
+            }
+        }
+        if let Some(recommendation) = &pack.recommendation {
+            validate_pack_recommendation(&pack.id, recommendation)?;
+        }
+    }
+
+    let mut sorted_pack_ids: Vec<String> =
+        manifest.packs.iter().map(|pack| pack.id.clone()).collect();
+    let current_pack_ids = sorted_pack_ids.clone();
+    sorted_pack_ids.sort();
+    if current_pack_ids != sorted_pack_ids {
+        return Err("manifest.packs must be sorted by id (determinism requirement)".to_string());
+    }
+
+    Ok(())
+}
@@ -1,16 +1,16 @@
 use crate::config::{
     ChecksConfigV2, GateConfig, PluginConfig, ProjectTool, QualityContractConfig,
-    ToolExecutionPolicyMode,
+    ToolDefaultsConfig, ToolExecutionPolicyMode,
 };
 use crate::repo_import::load_imported_tools;
 use crate::repo_strict::{
-    enforce_tool_execution_policy, ensure_known_gate_tools, id_regex, validate_description,
-    validate_tool, validate_tool_policy,
+    enforce_tool_execution_policy, ensure_known_gate_tools, ensure_valid_tool_aliases, id_regex,
+    validate_description, validate_tool, validate_tool_policy,
 };
 use crate::repo_view::RepoPlugin;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 mod checks_merge;
 mod errors;
@@ -26,6 +26,89 @@ pub struct RepoConfig {
     pub checks: ChecksConfigV2,
     pub quality_contract: Option<QualityContractConfig>,
     pub allow_any_plugins: Vec<String>,
+    /// Old `tool_id` -> current `tool_id`, merged from plugin `[canonical_tools.aliases]`.
+    pub tool_aliases: BTreeMap<String, String>,
+    /// Tools whose `timeout_ms` came from the contract's `[tools.defaults]`.
+    pub(crate) timeout_defaulted_tools: BTreeSet<String>,
+}
+
+/// Resolves a `--repo-root` / `repo_root` value to its canonical path, so symlinked roots and
+/// relative spellings name the same tree and repo-relative guards compare against real paths.
+///
+/// A path that does not exist is returned unchanged (callers report their own not-found
+/// errors). A root that resolves to a non-directory or to the filesystem root is rejected.
+pub fn resolve_repo_root(raw: &str) -> Result<String, String> {
+    let path = Path::new(raw);
+    if fs::symlink_metadata(path).is_err() {
+        return Ok(raw.to_string());
+    }
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve repo root {raw:?}: {e}"))?;
+    if !canonical.is_dir() {
+        return Err(format!(
+            "repo root {raw:?} resolves to {} which is not a directory",
+            canonical.display()
+        ));
+    }
+    if canonical.parent().is_none() {
+        return Err(format!(
+            "repo root {raw:?} resolves to the filesystem root {}",
+            canonical.display()
+        ));
+    }
+    canonical
+        .to_str()
+        .map(str::to_string)
+        .ok_or_else(|| format!("repo root {raw:?} resolves to a non UTF-8 path"))
+}
+
+/// Plain relative components only, so `raw` joined onto the repo root cannot leave it. Shared by
+/// repo settings (witness dir, tool cwd) and plugin registry payload paths; `what` names the
+/// source in errors.
+pub fn safe_relative_path(raw: &str, what: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(raw);
+    if path.as_os_str().is_empty() {
+        return Err(format!("{what} must be non-empty"));
+    }
+    if path.is_absolute() {
+        return Err(format!("{what} must be repo-relative: {raw:?}"));
+    }
+    for component in path.components() {
+        match component {
+            Component::CurDir | Component::Normal(_) => {}
+            _ => return Err(format!("unsafe path component in {what}: {raw:?}")),
+        }
+    }
+    Ok(path)
+}
+
+/// Rejects `path` when its deepest existing ancestor (`path` itself when it exists) resolves
+/// through symlinks outside the canonical `repo_root`; components that do not exist yet are
+/// created under that ancestor, so they cannot escape. `what` names the path in errors.
+pub fn ensure_within_repo_root(repo_root: &Path, path: &Path, what: &str) -> Result<(), String> {
+    let root = repo_root
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve repo root {}: {e}", repo_root.display()))?;
+    let mut existing = Some(path);
+    while let Some(dir) = existing
+        && fs::symlink_metadata(dir).is_err()
+    {
+        existing = dir.parent();
+    }
+    let Some(dir) = existing else {
+        return Ok(());
+    };
+    let resolved = dir
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve {}: {e}", dir.display()))?;
+    if !resolved.starts_with(&root) {
+        return Err(format!(
+            "{what} resolves outside the repo root via {}",
+            dir.display()
+        ));
+    }
+    Ok(())
 }
 
 pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError> {
@@ -58,6 +141,8 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
     let mut tools: BTreeMap<String, ProjectTool> = BTreeMap::new();
     let mut plugins: BTreeMap<String, RepoPlugin> = BTreeMap::new();
     let mut tool_owners: BTreeMap<String, String> = BTreeMap::new();
+    let mut tool_aliases: BTreeMap<String, String> = BTreeMap::new();
+    let mut alias_owners: BTreeMap<String, String> = BTreeMap::new();
     let mut allow_any_plugins: Vec<String> = vec![];
     let mut gate: GateConfig = GateConfig {
         ci_fast: vec![],
@@ -78,6 +163,13 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
         orphan_api: vec![],
         complexity_budget: vec![],
         contract_break: vec![],
+        test_presence: vec![],
+        file_encoding: vec![],
+        max_params: vec![],
+        binary_artifacts: vec![],
+        import_cycles: vec![],
+        naming_convention: vec![],
+        magic_numbers: vec![],
     };
 
     let mut any_config = false;
@@ -95,6 +187,13 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
     let mut orphan_api_check_ids: BTreeMap<String, String> = BTreeMap::new();
     let mut complexity_budget_check_ids: BTreeMap<String, String> = BTreeMap::new();
     let mut contract_break_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut test_presence_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut file_encoding_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut max_params_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut binary_artifacts_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut import_cycles_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut naming_convention_check_ids: BTreeMap<String, String> = BTreeMap::new();
+    let mut magic_numbers_check_ids: BTreeMap<String, String> = BTreeMap::new();
 
     for path in plugin_tomls {
         any_config = true;
@@ -157,6 +256,23 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
             }
         }
 
+        for (alias, target) in plugin
+            .canonical_tools
+            .map(|c| c.aliases)
+            .unwrap_or_default()
+        {
+            if let Some(previous) = alias_owners.get(&alias) {
+                return Err(RepoConfigError::InvalidToolAlias {
+                    plugin_id: plugin_id.clone(),
+                    alias,
+                    target,
+                    message: format!("alias already defined in plugin {previous}"),
+                });
+            }
+            alias_owners.insert(alias.clone(), plugin_id.clone());
+            tool_aliases.insert(alias, target);
+        }
+
         let gate_cfg = plugin.gate.unwrap_or(GateConfig {
             ci_fast: vec![],
             ci: vec![],
@@ -184,7 +300,14 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
                 && c.dead_code.is_empty()
                 && c.orphan_api.is_empty()
                 && c.complexity_budget.is_empty()
-                && c.contract_break.is_empty())
+                && c.contract_break.is_empty()
+                && c.test_presence.is_empty()
+                && c.file_encoding.is_empty()
+                && c.max_params.is_empty()
+                && c.binary_artifacts.is_empty()
+                && c.import_cycles.is_empty()
+                && c.naming_convention.is_empty()
+                && c.magic_numbers.is_empty())
         });
         if let Some(c) = checks_cfg {
             // Merge strategy: append in plugin order (deterministic by path sorting).
@@ -331,6 +454,83 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
                     |x| &x.id,
                 )?;
             }
+            for v in c.test_presence {
+                push_check_with_unique_id(
+                    &mut checks.test_presence,
+                    v,
+                    "test_presence",
+                    &plugin_id,
+                    &id_re,
+                    &mut test_presence_check_ids,
+                    |x| &x.id,
+                )?;
+            }
+            for v in c.file_encoding {
+                push_check_with_unique_id(
+                    &mut checks.file_encoding,
+                    v,
+                    "file_encoding",
+                    &plugin_id,
+                    &id_re,
+                    &mut file_encoding_check_ids,
+                    |x| &x.id,
+                )?;
+            }
+            for v in c.max_params {
+                push_check_with_unique_id(
+                    &mut checks.max_params,
+                    v,
+                    "max_params",
+                    &plugin_id,
+                    &id_re,
+                    &mut max_params_check_ids,
+                    |x| &x.id,
+                )?;
+            }
+            for v in c.binary_artifacts {
+                push_check_with_unique_id(
+                    &mut checks.binary_artifacts,
+                    v,
+                    "binary_artifacts",
+                    &plugin_id,
+                    &id_re,
+                    &mut binary_artifacts_check_ids,
+                    |x| &x.id,
+                )?;
+            }
+            for v in c.import_cycles {
+                push_check_with_unique_id(
+                    &mut checks.import_cycles,
+                    v,
+                    "import_cycles",
+                    &plugin_id,
+                    &id_re,
+                    &mut import_cycles_check_ids,
+                    |x| &x.id,
+                )?;
+            }
+            for v in c.naming_convention {
+                push_check_with_unique_id(
+                    &mut checks.naming_convention,
+                    v,
+                    "naming_convention",
+                    &plugin_id,
+                    &id_re,
+                    &mut naming_convention_check_ids,
+                    |x| &x.id,
+                )?;
+            }
+            for v in c.magic_numbers {
+                push_check_with_unique_id(
+                    &mut checks.magic_numbers,
+                    v,
+                    "magic_numbers",
+                    &plugin_id,
+                    &id_re,
+                    &mut magic_numbers_check_ids,
+                    |x| &x.id,
+                )?;
+            }
         }
         let has_gate = !(gate_cfg.ci_fast.is_empty()
             && gate_cfg.ci.is_empty()
@@ -381,10 +581,20 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
         None
     };
 
+    let timeout_defaulted_tools = quality_contract
+        .as_ref()
+        .map(|c| apply_tool_defaults(&mut tools, &c.tools.defaults))
+        .unwrap_or_default();
+
+    ensure_valid_tool_aliases(&tool_aliases, &alias_owners, &tools)?;
     for plugin in plugins.values() {
-        ensure_known_gate_tools(&plugin.id, "ci_fast", &plugin.gate_ci_fast, &tools)?;
-        ensure_known_gate_tools(&plugin.id, "ci", &plugin.gate_ci, &tools)?;
-        ensure_known_gate_tools(&plugin.id, "flagship", &plugin.gate_flagship, &tools)?;
+        for (gate_kind, tool_ids) in [
+            ("ci_fast", &plugin.gate_ci_fast),
+            ("ci", &plugin.gate_ci),
+            ("flagship", &plugin.gate_flagship),
+        ] {
+            ensure_known_gate_tools(&plugin.id, gate_kind, tool_ids, &tools, &tool_aliases)?;
+        }
     }
     for tool_id in tools.keys() {
         if !tool_owners.contains_key(tool_id) {
@@ -402,5 +612,27 @@ pub fn load_repo_config(repo_root: &Path) -> Result<RepoConfig, RepoConfigError>
         checks,
         quality_contract,
         allow_any_plugins,
+        tool_aliases,
+        timeout_defaulted_tools,
     })
 }
+
+/// Fills limits a tool leaves unset from `[tools.defaults]`; returns the ids of tools that
+/// took the default `timeout_ms`.
+fn apply_tool_defaults(
+    tools: &mut BTreeMap<String, ProjectTool>,
+    defaults: &ToolDefaultsConfig,
+) -> BTreeSet<String> {
+    let mut timeout_defaulted = BTreeSet::new();
+    for (tool_id, tool) in tools.iter_mut() {
+        if tool.timeout_ms.is_none() && defaults.timeout_ms.is_some() {
+            tool.timeout_ms = defaults.timeout_ms;
+            timeout_defaulted.insert(tool_id.clone());
+        }
+        if tool.max_tail_bytes.is_none() {
+            tool.max_stdout_bytes = tool.max_stdout_bytes.or(defaults.max_stdout_bytes);
+            tool.max_stderr_bytes = tool.max_stderr_bytes.or(defaults.max_stderr_bytes);
+        }
+    }
+    timeout_defaulted
+}
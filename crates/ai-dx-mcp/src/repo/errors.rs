@@ -117,6 +117,13 @@ pub enum RepoConfigError {
         gate_kind: String,
         tool_id: String,
     },
+    #[error("invalid tool alias {alias} -> {target} (plugin {plugin_id}): {message}")]
+    InvalidToolAlias {
+        plugin_id: String,
+        alias: String,
+        target: String,
+        message: String,
+    },
     #[error("missing tool owner mapping for tool: {tool_id}")]
     MissingToolOwner { tool_id: String },
     #[error(
@@ -159,6 +166,7 @@ impl RepoConfigError {
             RepoConfigError::UnknownGateTool { .. } => "config.unknown_gate_tool",
             RepoConfigError::GateMutatingTool { .. } => "config.gate_mutating_tool",
             RepoConfigError::GateIncompatibleTool { .. } => "config.gate_incompatible_tool",
+            RepoConfigError::InvalidToolAlias { .. } => "config.invalid_tool_alias",
             RepoConfigError::MissingToolOwner { .. } => "config.missing_tool_owner",
             RepoConfigError::EmptyConfig => "config.empty",
         }
@@ -224,14 +224,43 @@ pub(crate) fn enforce_tool_execution_policy(
     }
 }
 
+/// Aliases must point at a real tool and must not shadow one; chains are rejected to keep
+/// resolution single-step.
+pub(crate) fn ensure_valid_tool_aliases(
+    tool_aliases: &BTreeMap<String, String>,
+    alias_owners: &BTreeMap<String, String>,
+    tools: &BTreeMap<String, ProjectTool>,
+) -> Result<(), RepoConfigError> {
+    for (alias, target) in tool_aliases {
+        let message = if tools.contains_key(alias) {
+            "alias shadows an existing tool_id"
+        } else if tool_aliases.contains_key(target) {
+            "alias target is itself an alias"
+        } else if !tools.contains_key(target) {
+            "alias target is not a known tool_id"
+        } else {
+            continue;
+        };
+        return Err(RepoConfigError::InvalidToolAlias {
+            plugin_id: alias_owners.get(alias).cloned().unwrap_or_default(),
+            alias: alias.clone(),
+            target: target.clone(),
+            message: message.to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub(crate) fn ensure_known_gate_tools(
     plugin_id: &str,
     gate_kind: &str,
     tool_ids: &[String],
     tools: &BTreeMap<String, ProjectTool>,
+    tool_aliases: &BTreeMap<String, String>,
 ) -> Result<(), RepoConfigError> {
     for tool_id in tool_ids {
-        let Some(tool) = tools.get(tool_id) else {
+        let resolved = tool_aliases.get(tool_id).unwrap_or(tool_id);
+        let Some(tool) = tools.get(resolved) else {
             return Err(RepoConfigError::UnknownGateTool {
                 plugin_id: plugin_id.to_string(),
                 gate_kind: gate_kind.to_string(),
@@ -31,6 +31,7 @@ pub(crate) fn to_public_tool_spec_with_owner(
         timeout_ms: tool.timeout_ms.unwrap_or(600_000),
         max_stdout_bytes: tool.max_stdout_bytes.unwrap_or(20_000),
         max_stderr_bytes: tool.max_stderr_bytes.unwrap_or(20_000),
+        max_tail_bytes: tool.max_tail_bytes.map(crate::runner::clamp_tail_bytes),
         mutability: mutability.to_string(),
         compatible_gate_kinds: tool
             .compatible_gate_kinds
@@ -1,14 +1,38 @@
 use crate::{
     api::{
-        DecisionStatus, GateJobState, GateOutput, InitOutput, PayloadMeta, ResponseMode,
-        ToolsRunOutput, ValidateOutput,
+        DecisionStatus, FindingSeverity, FindingV2, GateJobState, GateOutput, InitOutput,
+        PayloadMeta, Receipt, ResponseMode, ToolsRunOutput, ValidateOutput, Violation,
     },
     server_catalog::CatalogOutput,
+    validate_insights::{
+        attach_fix_recipes, severity_rank, to_suppressed_findings_v2, violation_severity,
+    },
 };
-use std::collections::BTreeMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One entry of `GateOutput.all_findings`: a validate finding or a tool report finding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GateFinding {
+    /// `validate` or `tool:<tool_id>`.
+    pub source: String,
+    pub code: String,
+    pub message: String,
+    pub path: Option<String>,
+    pub line: Option<u64>,
+    pub severity: FindingSeverity,
+    pub category: String,
+}
 
 const DEFAULT_COMPACT_TOP_N: usize = 20;
 
+/// Validate payload shapes a client may request; the first entry is what this build emits.
+pub const SUPPORTED_VALIDATE_SCHEMA_VERSIONS: &[&str] = &["4", "2"];
+
+/// Fields a v2 consumer does not know about (CIM findings and the agent digest).
+const FIELDS_ABSENT_IN_V2: &[&str] = &["findings_v2", "agent_digest"];
+
 fn compact_top_n() -> usize {
     std::env::var("AI_DX_COMPACT_TOP_N")
         .ok()
@@ -50,6 +74,27 @@ fn compact_validate_payload(out: &mut ValidateOutput, top_n: usize) -> PayloadMe
     }
 }
 
+/// Keeps the `max` highest-severity violations/findings (ties broken by code).
+/// Verdict and risk totals are computed upstream and stay untouched.
+fn cap_validate_violations(
+    out: &mut ValidateOutput,
+    max: usize,
+    omitted: &mut BTreeMap<String, usize>,
+) {
+    out.violations.sort_by(|a, b| {
+        severity_rank(violation_severity(a))
+            .cmp(&severity_rank(violation_severity(b)))
+            .then_with(|| a.code.cmp(&b.code))
+    });
+    out.findings_v2.sort_by(|a, b| {
+        severity_rank(a.details.severity)
+            .cmp(&severity_rank(b.details.severity))
+            .then_with(|| a.code.cmp(&b.code))
+    });
+    truncate_vec("violations", &mut out.violations, max, omitted);
+    truncate_vec("findings_v2", &mut out.findings_v2, max, omitted);
+}
+
 fn status_from_decision(status: DecisionStatus) -> &'static str {
     match status {
         DecisionStatus::Pass => "pass",
@@ -79,13 +124,13 @@ fn validate_summary(out: &ValidateOutput) -> String {
         Some(DecisionStatus::Pass) if out.ok => "run compas.gate kind=ci_fast.",
         Some(DecisionStatus::Retryable) => "retry compas.gate after transient issue.",
         _ => {
-            if let Some(top) = out.violations.first() {
-                if !top.code.trim().is_empty() {
-                    return format!(
-                        "**Status:** {status}\n**Why:** {why}\n**Next:** fix `{}` and rerun compas.validate mode=ratchet.",
-                        top.code
-                    );
-                }
+            if let Some(top) = out.violations.first()
+                && !top.code.trim().is_empty()
+            {
+                return format!(
+                    "**Status:** {status}\n**Why:** {why}\n**Next:** fix `{}` and rerun compas.validate mode=ratchet.",
+                    top.code
+                );
             }
             "fix top violation and rerun compas.validate mode=ratchet."
         }
@@ -232,16 +277,195 @@ fn init_summary(out: &InitOutput) -> String {
     format!("**Status:** {status}\n**Why:** {why}\n**Next:** {next}")
 }
 
-pub fn finalize_validate(mut out: ValidateOutput, mode: ResponseMode) -> ValidateOutput {
+/// Inlines fix recipes into raw `violations` for consumers that skip `findings_v2`.
+pub fn explain_validate(mut out: ValidateOutput) -> ValidateOutput {
+    attach_fix_recipes(&mut out.violations);
+    out
+}
+
+/// Drops the per-reason `source`/`origin` provenance from the gate verdict; callers keep it
+/// only when the verdict explanation was requested.
+pub fn without_verdict_sources(mut out: GateOutput) -> GateOutput {
+    if let Some(verdict) = out.verdict.as_mut() {
+        for reason in &mut verdict.decision.reasons {
+            reason.source = None;
+            reason.origin = None;
+        }
+    }
+    out
+}
+
+/// Appends allowlist-suppressed findings to `findings_v2` (tagged `details.suppressed`) for
+/// audits; verdict, risk summary and digest keep counting active findings only.
+pub fn include_suppressed_findings(mut out: ValidateOutput) -> ValidateOutput {
+    let suppressed = to_suppressed_findings_v2(&out.suppressed);
+    out.findings_v2.extend(suppressed);
+    out
+}
+
+/// Drops the `details.suppressed_by` tag the allowlist puts on `suppressed` entries: it only
+/// surfaces on the findings `include_suppressed_findings` lists, so plain output stays as is.
+fn strip_suppression_tags(suppressed: &mut [Violation]) {
+    for v in suppressed {
+        let Some(serde_json::Value::Object(map)) = v.details.as_mut() else {
+            continue;
+        };
+        map.remove("suppressed_by");
+        if map.is_empty() {
+            v.details = None;
+        }
+    }
+}
+
+/// Serializes `out` in the requested schema shape (current when `None`); unsupported
+/// versions are rejected instead of guessed.
+pub fn validate_payload_for_schema(
+    out: &ValidateOutput,
+    requested: Option<&str>,
+) -> Result<serde_json::Value, String> {
+    let mut payload = serde_json::to_value(out)
+        .map_err(|e| format!("failed to serialize validate output: {e}"))?;
+    match requested {
+        None | Some("4") => {}
+        Some("2") => {
+            if let Some(obj) = payload.as_object_mut() {
+                for field in FIELDS_ABSENT_IN_V2 {
+                    obj.remove(*field);
+                }
+                obj.insert("schema_version".to_string(), serde_json::json!("2"));
+            }
+        }
+        Some(other) => {
+            return Err(format!(
+                "unsupported validate schema_version {other:?} (supported: {})",
+                SUPPORTED_VALIDATE_SCHEMA_VERSIONS.join(", ")
+            ));
+        }
+    }
+    Ok(payload)
+}
+
+pub fn finalize_validate(out: ValidateOutput, mode: ResponseMode) -> ValidateOutput {
+    finalize_validate_with_limit(out, mode, None)
+}
+
+pub fn finalize_validate_with_limit(
+    mut out: ValidateOutput,
+    mode: ResponseMode,
+    max_violations: Option<usize>,
+) -> ValidateOutput {
+    crate::swamped::collapse_swamped(&mut out);
+    strip_suppression_tags(&mut out.suppressed);
+    let mut capped = BTreeMap::new();
+    if let Some(max) = max_violations {
+        cap_validate_violations(&mut out, max, &mut capped);
+    }
     out.payload_meta = match mode {
         ResponseMode::Compact => Some(compact_validate_payload(&mut out, compact_top_n())),
         ResponseMode::Full => None,
     };
+    if !capped.is_empty() {
+        let meta = out.payload_meta.get_or_insert_with(|| PayloadMeta {
+            mode,
+            truncated: false,
+            omitted: BTreeMap::new(),
+        });
+        for (key, count) in capped {
+            *meta.omitted.entry(key).or_insert(0) += count;
+        }
+        meta.truncated = true;
+    }
     out.summary_md = Some(validate_summary(&out));
     out.evidence = crate::evidence::build_validate_envelope(&out);
     out
 }
 
+/// `[{tool_id, findings}]` for every receipt carrying a structured report; collect before
+/// compact truncation so no tool is dropped.
+pub fn collect_tool_reports(receipts: &[Receipt]) -> Vec<serde_json::Value> {
+    receipts
+        .iter()
+        .filter_map(|r| {
+            let report = r.structured_report.as_ref()?;
+            Some(serde_json::json!({
+                "tool_id": r.tool_id,
+                "findings": report.get("findings").cloned().unwrap_or_else(|| serde_json::json!([])),
+            }))
+        })
+        .collect()
+}
+
+/// Merges validate `findings_v2` with every receipt's structured report findings, tagging each
+/// with its `source`. The first occurrence of a code/path/line wins, so validate comes first.
+/// Tool findings without a code or a known severity are counted into one low-severity
+/// `gate.all_findings.unmapped` entry (source `gate`) instead of vanishing.
+pub fn collect_all_findings(validate: &[FindingV2], receipts: &[Receipt]) -> Vec<GateFinding> {
+    let validate_findings = validate.iter().map(|f| GateFinding {
+        source: "validate".to_string(),
+        code: f.code.clone(),
+        message: f.message.clone(),
+        path: f.path.clone(),
+        line: f
+            .details
+            .legacy_details
+            .as_ref()
+            .and_then(|d| d.get("line"))
+            .and_then(serde_json::Value::as_u64),
+        severity: f.details.severity,
+        category: f.details.category.clone(),
+    });
+    let mut unmapped = 0usize;
+    let mut tool_findings = vec![];
+    for r in receipts {
+        let findings = r
+            .structured_report
+            .as_ref()
+            .and_then(|report| report.get("findings"))
+            .and_then(serde_json::Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for f in findings {
+            let text = |key: &str| f.get(key).and_then(|v| v.as_str()).map(str::to_string);
+            let severity = f
+                .get("severity")
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let (Some(code), Some(severity)) = (text("code"), severity) else {
+                unmapped += 1;
+                continue;
+            };
+            tool_findings.push(GateFinding {
+                source: format!("tool:{}", r.tool_id),
+                code,
+                message: text("message").unwrap_or_default(),
+                path: text("path"),
+                line: f.get("line").and_then(serde_json::Value::as_u64),
+                severity,
+                category: text("category").unwrap_or_default(),
+            });
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut out: Vec<GateFinding> = validate_findings
+        .chain(tool_findings)
+        .filter(|f| seen.insert((f.code.clone(), f.path.clone(), f.line)))
+        .collect();
+    if unmapped > 0 {
+        out.push(GateFinding {
+            source: "gate".to_string(),
+            code: "gate.all_findings.unmapped".to_string(),
+            message: format!(
+                "{unmapped} tool report finding(s) had no code or an unknown severity and are not listed"
+            ),
+            path: None,
+            line: None,
+            severity: FindingSeverity::Low,
+            category: "gate".to_string(),
+        });
+    }
+    out
+}
+
 pub fn finalize_gate(mut out: GateOutput, mode: ResponseMode) -> GateOutput {
     let has_final_payload = out.verdict.is_some()
         || !out.receipts.is_empty()
@@ -253,6 +477,7 @@ pub fn finalize_gate(mut out: GateOutput, mode: ResponseMode) -> GateOutput {
         let mut omitted = BTreeMap::new();
         let top_n = compact_top_n();
         truncate_vec("receipts", &mut out.receipts, top_n, &mut omitted);
+        truncate_vec("all_findings", &mut out.all_findings, top_n, &mut omitted);
         if has_final_payload {
             out.validate = finalize_validate(out.validate, ResponseMode::Compact);
         }
@@ -304,7 +529,57 @@ pub fn finalize_exec(mut out: ToolsRunOutput) -> ToolsRunOutput {
     out
 }
 
+/// The status block is always set; a plan summary requested via `InitRequest.summary` follows it.
 pub fn finalize_init(mut out: InitOutput) -> InitOutput {
-    out.summary_md = Some(init_summary(&out));
+    let status = init_summary(&out);
+    out.summary_md = Some(match out.summary_md.take() {
+        Some(plan_md) => format!("{status}\n\n{plan_md}"),
+        None => status,
+    });
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(structured_report: serde_json::Value) -> Receipt {
+        Receipt {
+            tool_id: "lint".to_string(),
+            success: true,
+            exit_code: Some(0),
+            timed_out: false,
+            duration_ms: 0,
+            command: "lint".to_string(),
+            args: vec![],
+            stdout_tail: String::new(),
+            stderr_tail: String::new(),
+            stdout_bytes: 0,
+            stderr_bytes: 0,
+            stdout_sha256: String::new(),
+            stderr_sha256: String::new(),
+            structured_report: Some(structured_report),
+        }
+    }
+
+    #[test]
+    fn unmapped_tool_findings_are_counted_in_one_observation() {
+        let receipts = vec![receipt(serde_json::json!({"findings": [
+            {"code": "lint.ok", "severity": "low", "message": "kept"},
+            {"severity": "high", "message": "no code"},
+            {"code": "lint.odd", "severity": "blocker"},
+        ]}))];
+
+        let all = collect_all_findings(&[], &receipts);
+        let codes: Vec<&str> = all.iter().map(|f| f.code.as_str()).collect();
+        assert_eq!(codes, vec!["lint.ok", "gate.all_findings.unmapped"]);
+        let unmapped = &all[1];
+        assert_eq!(unmapped.source, "gate");
+        assert_eq!(unmapped.severity, FindingSeverity::Low);
+        assert!(
+            unmapped.message.starts_with("2 tool report"),
+            "{}",
+            unmapped.message
+        );
+    }
+}
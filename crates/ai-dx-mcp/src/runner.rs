@@ -2,10 +2,40 @@ use crate::api::Receipt;
 use crate::config::ProjectTool;
 use crate::hash::sha256_hex;
 use sha2::{Digest, Sha256};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 use tokio::io::AsyncReadExt;
 
+/// Hard ceiling for `max_tail_bytes` so a single receipt cannot balloon the payload.
+pub const MAX_TAIL_BYTES_CEILING: usize = 1_000_000;
+
+pub(crate) fn clamp_tail_bytes(v: usize) -> usize {
+    v.min(MAX_TAIL_BYTES_CEILING)
+}
+
+/// `gate --capture-full`: a copy of `tool` whose stdout/stderr caps are raised to
+/// [`MAX_TAIL_BYTES_CEILING`] (caps already above it are kept). The stream sha256 is unaffected.
+pub(crate) fn with_full_capture(tool: &ProjectTool) -> ProjectTool {
+    let raised = |configured: Option<usize>| {
+        let effective = stream_tail_bytes(tool, configured);
+        Some(effective.max(MAX_TAIL_BYTES_CEILING))
+    };
+    ProjectTool {
+        max_tail_bytes: None,
+        max_stdout_bytes: raised(tool.max_stdout_bytes),
+        max_stderr_bytes: raised(tool.max_stderr_bytes),
+        ..tool.clone()
+    }
+}
+
+/// Tail cap for one stream: its own `max_*_bytes` when set, else the (clamped) `max_tail_bytes`,
+/// else 20000.
+fn stream_tail_bytes(tool: &ProjectTool, per_stream: Option<usize>) -> usize {
+    per_stream
+        .or(tool.max_tail_bytes.map(clamp_tail_bytes))
+        .unwrap_or(20_000)
+}
+
 #[derive(Debug, Clone)]
 pub struct RunnerLimits {
     pub timeout_ms: u64,
@@ -112,6 +142,21 @@ async fn finalize_capture_task(
     }
 }
 
+/// Working directory for `tool`: its `cwd` joined onto `repo_root` (plain relative components
+/// only), rejected when it (or its deepest existing ancestor) resolves outside the root.
+fn resolve_tool_cwd(repo_root: &Path, tool: &ProjectTool) -> std::io::Result<PathBuf> {
+    let Some(raw) = &tool.cwd else {
+        return Ok(repo_root.to_path_buf());
+    };
+    let invalid = |msg: String| std::io::Error::new(std::io::ErrorKind::InvalidInput, msg);
+    let rel = crate::repo::safe_relative_path(raw.trim(), &format!("tool {} cwd", tool.id))
+        .map_err(invalid)?;
+    let dir = repo_root.join(rel);
+    crate::repo::ensure_within_repo_root(repo_root, &dir, &format!("tool {} cwd {raw:?}", tool.id))
+        .map_err(invalid)?;
+    Ok(dir)
+}
+
 pub async fn run_project_tool(
     repo_root: &Path,
     tool: &ProjectTool,
@@ -135,13 +180,14 @@ pub async fn run_project_tool_with_timeout_override(
         .unwrap_or(base_timeout_ms);
     let limits = RunnerLimits {
         timeout_ms,
-        max_stdout_bytes: tool.max_stdout_bytes.unwrap_or(20_000),
-        max_stderr_bytes: tool.max_stderr_bytes.unwrap_or(20_000),
+        max_stdout_bytes: stream_tail_bytes(tool, tool.max_stdout_bytes),
+        max_stderr_bytes: stream_tail_bytes(tool, tool.max_stderr_bytes),
     };
 
     let mut argv: Vec<String> = vec![];
     argv.extend(tool.args.clone());
     argv.extend(extra_args.iter().cloned());
+    let cwd = resolve_tool_cwd(repo_root, tool)?;
 
     if dry_run {
         let stdout = b"[dry_run]";
@@ -167,10 +213,7 @@ pub async fn run_project_tool_with_timeout_override(
     let start = Instant::now();
     let mut cmd = tokio::process::Command::new(&tool.command);
     cmd.args(&argv);
-    cmd.current_dir(match &tool.cwd {
-        Some(cwd) => repo_root.join(cwd),
-        None => repo_root.to_path_buf(),
-    });
+    cmd.current_dir(cwd);
     if !tool.env.is_empty() {
         cmd.envs(tool.env.clone());
     }
@@ -239,6 +282,7 @@ mod tests {
             timeout_ms: None,
             max_stdout_bytes: None,
             max_stderr_bytes: None,
+            max_tail_bytes: None,
             report: None,
             receipt_contract: None,
             env: BTreeMap::new(),
@@ -258,6 +302,54 @@ mod tests {
         assert_eq!(receipt.stderr_sha256, sha256_hex(b""));
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn relative_cwd_runs_in_subdir_and_escapes_are_rejected() {
+        let dir = tempfile::tempdir().expect("temp repo");
+        std::fs::create_dir_all(dir.path().join("subdir")).expect("mkdir subdir");
+        let mk_tool = |cwd: &str| ProjectTool {
+            id: "pwd".to_string(),
+            description: "Prints its working directory".to_string(),
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "pwd -P".to_string()],
+            cwd: Some(cwd.to_string()),
+            timeout_ms: Some(10_000),
+            max_stdout_bytes: None,
+            max_stderr_bytes: None,
+            max_tail_bytes: None,
+            report: None,
+            receipt_contract: None,
+            env: BTreeMap::new(),
+            mutability: Default::default(),
+            compatible_gate_kinds: vec![],
+            evidence_kinds: vec![],
+        };
+
+        let receipt = run_project_tool(dir.path(), &mk_tool("subdir"), &[], false)
+            .await
+            .expect("tool runs in subdir");
+        assert!(receipt.success, "{receipt:?}");
+        let expected = dir
+            .path()
+            .join("subdir")
+            .canonicalize()
+            .expect("canonical subdir");
+        assert_eq!(receipt.stdout_tail.trim(), expected.to_string_lossy());
+
+        for escape in ["../outside", "/tmp"] {
+            let err = run_project_tool(dir.path(), &mk_tool(escape), &[], true)
+                .await
+                .expect_err("cwd outside the repo must be rejected");
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{err}");
+        }
+
+        std::os::unix::fs::symlink("/", dir.path().join("up")).expect("symlink");
+        let err = run_project_tool(dir.path(), &mk_tool("up"), &[], false)
+            .await
+            .expect_err("symlinked cwd outside the repo must be rejected");
+        assert!(err.to_string().contains("outside the repo root"), "{err}");
+    }
+
     #[tokio::test]
     async fn read_stream_reports_total_bytes_tail_and_hash() {
         let payload = b"abcdef".to_vec();
@@ -272,6 +364,66 @@ mod tests {
         assert_eq!(capture.sha256, sha256_hex(b"abcdef"));
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn max_tail_bytes_keeps_longer_tail_and_full_stream_hash() {
+        let mk_tool =
+            |max_stdout_bytes: Option<usize>, max_tail_bytes: Option<usize>| ProjectTool {
+                id: "long-output".to_string(),
+                description: "Long output fixture tool".to_string(),
+                command: "bash".to_string(),
+                args: vec![
+                    "-c".to_string(),
+                    "head -c 60000 /dev/zero | tr '\\0' 'a'".to_string(),
+                ],
+                cwd: None,
+                timeout_ms: Some(10_000),
+                max_stdout_bytes,
+                max_stderr_bytes: None,
+                max_tail_bytes,
+                report: None,
+                receipt_contract: None,
+                env: BTreeMap::new(),
+                mutability: Default::default(),
+                compatible_gate_kinds: vec![],
+                evidence_kinds: vec![],
+            };
+        let full = vec![b'a'; 60_000];
+
+        let default_receipt = run_project_tool(Path::new("."), &mk_tool(None, None), &[], false)
+            .await
+            .expect("default receipt");
+        let wide_receipt =
+            run_project_tool(Path::new("."), &mk_tool(None, Some(50_000)), &[], false)
+                .await
+                .expect("wide receipt");
+        // An explicit per-stream cap wins over max_tail_bytes.
+        let explicit_receipt = run_project_tool(
+            Path::new("."),
+            &mk_tool(Some(1_000), Some(50_000)),
+            &[],
+            false,
+        )
+        .await
+        .expect("explicit receipt");
+
+        assert_eq!(default_receipt.stdout_tail.len(), 20_000);
+        assert_eq!(wide_receipt.stdout_tail.len(), 50_000);
+        assert_eq!(explicit_receipt.stdout_tail.len(), 1_000);
+        assert_eq!(wide_receipt.stdout_bytes, 60_000);
+        assert_eq!(wide_receipt.stdout_sha256, sha256_hex(&full));
+        assert_eq!(wide_receipt.stdout_sha256, default_receipt.stdout_sha256);
+    }
+
+    #[test]
+    fn clamp_tail_bytes_caps_at_ceiling() {
+        assert_eq!(clamp_tail_bytes(512), 512);
+        assert_eq!(
+            clamp_tail_bytes(MAX_TAIL_BYTES_CEILING * 4),
+            MAX_TAIL_BYTES_CEILING
+        );
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn timeout_returns_even_if_descendants_keep_pipes_open() {
@@ -287,6 +439,7 @@ mod tests {
             timeout_ms: Some(100),
             max_stdout_bytes: None,
             max_stderr_bytes: None,
+            max_tail_bytes: None,
             report: None,
             receipt_contract: None,
             env: BTreeMap::new(),
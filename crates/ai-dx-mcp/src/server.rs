@@ -1,5 +1,5 @@
 use crate::api::*;
-use crate::server_catalog::{CatalogOutput, CatalogRequest, catalog, exec};
+use crate::server_catalog::{CatalogOutput, CatalogRequest, catalog, catalog_err, exec};
 use rmcp::{
     Json, ServerHandler,
     handler::server::{tool::ToolRouter, wrapper::Parameters},
@@ -14,6 +14,12 @@ pub struct AiDxServer {
     tool_router: ToolRouter<Self>,
 }
 
+impl Default for AiDxServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[tool_router]
 impl AiDxServer {
     pub fn new() -> Self {
@@ -22,12 +28,21 @@ impl AiDxServer {
         }
     }
 
-    fn resolve_repo_root(repo_root: &Option<String>) -> String {
-        repo_root
+    /// Canonical repo root like the CLI's; a root the CLI would reject (filesystem root,
+    /// non-directory) is returned as the error each tool reports in its own output.
+    fn resolve_repo_root(repo_root: &Option<String>) -> Result<String, (String, ApiError)> {
+        let raw = repo_root
             .clone()
             .or_else(|| std::env::var("AI_DX_REPO_ROOT").ok())
             .filter(|s| !s.trim().is_empty())
-            .unwrap_or_else(|| ".".to_string())
+            .unwrap_or_else(|| ".".to_string());
+        crate::repo::resolve_repo_root(&raw).map_err(|message| {
+            let error = ApiError {
+                code: "compas.invalid_repo_root".to_string(),
+                message,
+            };
+            (raw, error)
+        })
     }
 
     fn resolve_gate_call_budget_ms() -> Option<u64> {
@@ -47,10 +62,18 @@ impl AiDxServer {
         description = "Validate repo: checks + config (ratchet/strict/warn). Fail-closed; default is ratchet."
     )]
     async fn validate(&self, params: Parameters<ValidateRequest>) -> Json<ValidateOutput> {
-        let repo_root = Self::resolve_repo_root(&params.0.repo_root);
-        let write_baseline = params.0.write_baseline.unwrap_or(false);
         let response_mode = params.0.response_mode.unwrap_or(ResponseMode::Compact);
-        Json(crate::response::finalize_validate(
+        let repo_root = match Self::resolve_repo_root(&params.0.repo_root) {
+            Ok(root) => root,
+            Err((raw, error)) => {
+                return Json(crate::response::finalize_validate(
+                    crate::app::empty_output_with_error(&raw, params.0.mode, error, None),
+                    response_mode,
+                ));
+            }
+        };
+        let write_baseline = params.0.write_baseline.unwrap_or(false);
+        Json(crate::response::finalize_validate_with_limit(
             crate::app::validate(
                 &repo_root,
                 params.0.mode,
@@ -58,6 +81,7 @@ impl AiDxServer {
                 params.0.baseline_maintenance.as_ref(),
             ),
             response_mode,
+            params.0.max_violations,
         ))
     }
 
@@ -66,7 +90,22 @@ impl AiDxServer {
         description = "Bootstrap compas config via language packs; apply=true writes files (conflicts fail-closed)."
     )]
     async fn compas_init(&self, params: Parameters<InitRequest>) -> Json<InitOutput> {
-        let repo_root = Self::resolve_repo_root(&params.0.repo_root);
+        let repo_root = match Self::resolve_repo_root(&params.0.repo_root) {
+            Ok(root) => root,
+            Err((raw, error)) => {
+                return Json(crate::response::finalize_init(InitOutput {
+                    ok: false,
+                    error: Some(error),
+                    warnings: vec![],
+                    repo_root: raw,
+                    applied: false,
+                    plan: None,
+                    recommendations: None,
+                    summary_md: None,
+                    payload_meta: None,
+                }));
+            }
+        };
         Json(crate::response::finalize_init(crate::init::init(
             &repo_root, params.0,
         )))
@@ -77,12 +116,12 @@ impl AiDxServer {
         description = "Catalog browser for plugins/tools (view=all|plugins|plugin|tools|tool)."
     )]
     async fn compas_catalog(&self, params: Parameters<CatalogRequest>) -> Json<CatalogOutput> {
-        let repo_root = Self::resolve_repo_root(&params.0.repo_root);
         let response_mode = params.0.response_mode.unwrap_or(ResponseMode::Compact);
-        Json(crate::response::finalize_catalog(
-            catalog(&repo_root, &params.0),
-            response_mode,
-        ))
+        let out = match Self::resolve_repo_root(&params.0.repo_root) {
+            Ok(repo_root) => catalog(&repo_root, &params.0),
+            Err((raw, error)) => catalog_err(&raw, error),
+        };
+        Json(crate::response::finalize_catalog(out, response_mode))
     }
 
     #[tool(
@@ -90,10 +129,19 @@ impl AiDxServer {
         description = "Run tool_id with optional extra args (no shell). Returns receipt with bounded stdout/stderr tails."
     )]
     async fn compas_exec(&self, params: Parameters<ToolsRunRequest>) -> Json<ToolsRunOutput> {
-        let repo_root = Self::resolve_repo_root(&params.0.repo_root);
-        Json(crate::response::finalize_exec(
-            exec(&repo_root, &params.0).await,
-        ))
+        let out = match Self::resolve_repo_root(&params.0.repo_root) {
+            Ok(repo_root) => exec(&repo_root, &params.0).await,
+            Err((raw, error)) => ToolsRunOutput {
+                ok: false,
+                error: Some(error),
+                repo_root: raw,
+                receipt: None,
+                summary_md: None,
+                evidence: crate::api::EvidenceEnvelope::default(),
+                payload_meta: None,
+            },
+        };
+        Json(crate::response::finalize_exec(out))
     }
 
     #[tool(
@@ -101,7 +149,10 @@ impl AiDxServer {
         description = "Run gate kind=ci_fast|ci|flagship: validate + wired toolchain; op=run|start|status enables stable long-run jobs."
     )]
     async fn gate(&self, params: Parameters<GateRequest>) -> Json<GateOutput> {
-        let repo_root = Self::resolve_repo_root(&params.0.repo_root);
+        let (repo_root, root_error) = match Self::resolve_repo_root(&params.0.repo_root) {
+            Ok(root) => (root, None),
+            Err((raw, error)) => (raw, Some(error)),
+        };
         let dry_run = params.0.dry_run.unwrap_or(false);
         let response_mode = params.0.response_mode.unwrap_or(ResponseMode::Compact);
         let op = params.0.op.unwrap_or(GateOp::Run);
@@ -110,9 +161,11 @@ impl AiDxServer {
                 .ok()
                 .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
         });
-        if let Err(err) =
-            crate::gate_jobs::validate_gate_status_args(op, params.0.job_id.as_deref())
-        {
+        let early_error = match root_error {
+            Some(error) => Err(error),
+            None => crate::gate_jobs::validate_gate_status_args(op, params.0.job_id.as_deref()),
+        };
+        if let Err(err) = early_error {
             return Json(crate::response::finalize_gate(
                 GateOutput {
                     ok: false,
@@ -131,6 +184,8 @@ impl AiDxServer {
                     job: None,
                     job_state: None,
                     job_error: None,
+                    all_findings: vec![],
+                    config_hash: None,
                 },
                 response_mode,
             ));
@@ -138,12 +193,16 @@ impl AiDxServer {
 
         let raw = match op {
             GateOp::Run => {
-                crate::app::gate_with_budget(
+                crate::app::gate_with_options(
                     &repo_root,
                     params.0.kind,
                     dry_run,
                     write_witness,
-                    Self::resolve_gate_call_budget_ms(),
+                    crate::app::GateRunOptions {
+                        budget_ms: Self::resolve_gate_call_budget_ms(),
+                        explain_verdict: params.0.explain_verdict.unwrap_or(false),
+                        ..crate::app::GateRunOptions::default()
+                    },
                 )
                 .await
             }
@@ -168,6 +227,11 @@ impl AiDxServer {
             }
         };
 
+        let raw = if params.0.explain_verdict.unwrap_or(false) {
+            raw
+        } else {
+            crate::response::without_verdict_sources(raw)
+        };
         Json(crate::response::finalize_gate(raw, response_mode))
     }
 }
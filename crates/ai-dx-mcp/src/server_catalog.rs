@@ -81,7 +81,7 @@ fn collect_plugins(cfg: &RepoConfig) -> Vec<PluginInfo> {
     plugins
 }
 
-fn catalog_err(repo_root: &str, error: ApiError) -> CatalogOutput {
+pub(crate) fn catalog_err(repo_root: &str, error: ApiError) -> CatalogOutput {
     CatalogOutput {
         ok: false,
         error: Some(error),
@@ -276,7 +276,7 @@ pub(crate) async fn exec(repo_root: &str, req: &ToolsRunRequest) -> ToolsRunOutp
             let mut report_blocking = false;
             let mut report_violations = 0usize;
             if !dry_run && let Some(report_cfg) = &tool.report {
-                let (report, violations) =
+                let (report, violations, _suppressed) =
                     ingest_tool_report(std::path::Path::new(repo_root), &tool.id, report_cfg);
                 report_blocking = violations
                     .iter()
@@ -1,11 +1,12 @@
 use crate::api::{FindingSeverity, Violation, ViolationTier};
 use crate::hash::sha256_hex;
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+mod formats;
 mod payload;
+use formats::{parse_junit_report, parse_sarif_report, parse_trivy_report};
 use payload::project_summary;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -15,6 +16,7 @@ pub(crate) enum ToolReportKind {
     Json,
     Sarif,
     Junit,
+    Trivy,
     Auto,
 }
 
@@ -72,6 +74,8 @@ struct ParsedFinding {
     line: Option<u64>,
     severity_raw: String,
     evidence_ref: Option<String>,
+    /// Set when the producing tool already suppressed the result (reason text).
+    suppression: Option<String>,
 }
 
 #[derive(Debug)]
@@ -199,6 +203,7 @@ fn parse_json_report(
             severity_raw: first_text(item, &["severity", "level", "priority", "impact"])
                 .unwrap_or_else(|| "medium".to_string()),
             evidence_ref: first_text(item, &["evidence_ref", "url", "uri"]),
+            suppression: None,
         });
     }
 
@@ -213,134 +218,6 @@ fn parse_json_report(
     })
 }
 
-fn parse_sarif_report(tool_id: &str, payload: &Value) -> Result<ParsedReport, String> {
-    let runs = payload
-        .get("runs")
-        .and_then(Value::as_array)
-        .ok_or_else(|| format!("tool={tool_id}: missing runs array"))?;
-
-    let mut findings = Vec::new();
-    for run in runs {
-        let tool_name = run
-            .get("tool")
-            .and_then(|t| t.get("driver"))
-            .and_then(|d| d.get("name"))
-            .and_then(text);
-
-        if let Some(results) = run.get("results").and_then(Value::as_array) {
-            for result in results {
-                let location = result
-                    .get("locations")
-                    .and_then(Value::as_array)
-                    .and_then(|v| v.first());
-
-                findings.push(ParsedFinding {
-                    code: first_text(result, &["ruleId", "rule_id", "id"]).unwrap_or_default(),
-                    category: first_text(result, &["category"]).or_else(|| tool_name.clone()),
-                    message: message(result),
-                    path: location
-                        .and_then(|loc| loc.get("physicalLocation"))
-                        .and_then(|pl| pl.get("artifactLocation"))
-                        .and_then(|al| al.get("uri"))
-                        .and_then(text),
-                    line: location
-                        .and_then(|loc| loc.get("physicalLocation"))
-                        .and_then(|pl| pl.get("region"))
-                        .and_then(|r| r.get("startLine"))
-                        .and_then(u64_value),
-                    severity_raw: first_text(result, &["level", "severity"])
-                        .unwrap_or_else(|| "medium".to_string()),
-                    evidence_ref: None,
-                });
-            }
-        }
-    }
-
-    if findings.is_empty() {
-        return Err(format!("tool={tool_id}: SARIF report has no findings"));
-    }
-
-    Ok(ParsedReport {
-        findings,
-        version: payload
-            .get("version")
-            .and_then(text)
-            .or_else(|| payload.get("$schema").and_then(text)),
-        commit_sha: None,
-    })
-}
-
-fn xml_attr(input: &str, key: &str) -> Option<String> {
-    let patterns = [format!("{key}=\""), format!("{key}='")];
-    for pattern in patterns {
-        let Some(start) = input.find(&pattern) else {
-            continue;
-        };
-        let quote = pattern.chars().last().unwrap_or('"');
-        let rest = &input[start + pattern.len()..];
-        let Some(end) = rest.find(quote) else {
-            continue;
-        };
-        let value = rest[..end].trim();
-        if !value.is_empty() {
-            return Some(value.to_string());
-        }
-    }
-    None
-}
-
-fn parse_junit_report(tool_id: &str, input: &str) -> Result<ParsedReport, String> {
-    let testcase_re = Regex::new(r"(?s)<testcase\\b([^>]*)>(.*?)</testcase>")
-        .map_err(|e| format!("tool={tool_id}: regex compile failed: {e}"))?;
-    let event_re = Regex::new(r"(?s)<(failure|error)\\b([^>]*)>(.*?)</(?:failure|error)>")
-        .map_err(|e| format!("tool={tool_id}: regex compile failed: {e}"))?;
-
-    let mut findings = Vec::new();
-    for case in testcase_re.captures_iter(input) {
-        let attrs = case.get(1).map(|m| m.as_str()).unwrap_or_default();
-        let inner = case.get(2).map(|m| m.as_str()).unwrap_or_default();
-        let Some(event) = event_re.captures(inner) else {
-            continue;
-        };
-
-        let class_name = xml_attr(attrs, "classname");
-        let test_name = xml_attr(attrs, "name").unwrap_or_else(|| "testcase".to_string());
-        let code = class_name
-            .as_ref()
-            .map(|class| format!("{class}.{test_name}"))
-            .unwrap_or_else(|| test_name.clone());
-
-        let event_attrs = event.get(2).map(|m| m.as_str()).unwrap_or_default();
-        let event_body = event.get(3).map(|m| m.as_str()).unwrap_or_default();
-        let event_tag = event.get(1).map(|m| m.as_str()).unwrap_or("failure");
-
-        findings.push(ParsedFinding {
-            code,
-            category: Some("test".to_string()),
-            message: xml_attr(event_attrs, "message")
-                .or_else(|| {
-                    let text = event_body.trim();
-                    (!text.is_empty()).then_some(text.to_string())
-                })
-                .unwrap_or_else(|| "JUnit failure".to_string()),
-            path: xml_attr(attrs, "file").or_else(|| class_name.clone()),
-            line: xml_attr(attrs, "line").and_then(|n| n.parse::<u64>().ok()),
-            severity_raw: event_tag.to_string(),
-            evidence_ref: None,
-        });
-    }
-
-    if findings.is_empty() {
-        return Err(format!("tool={tool_id}: junit report has no failures"));
-    }
-
-    Ok(ParsedReport {
-        findings,
-        version: None,
-        commit_sha: None,
-    })
-}
-
 fn parse_report(
     tool_id: &str,
     input: &str,
@@ -358,6 +235,11 @@ fn parse_report(
                 .map_err(|err| format!("tool={tool_id}: invalid SARIF report: {err}"))?;
             parse_sarif_report(tool_id, &value)
         }
+        ToolReportKind::Trivy => {
+            let value: Value = serde_json::from_str(input)
+                .map_err(|err| format!("tool={tool_id}: invalid Trivy report: {err}"))?;
+            parse_trivy_report(tool_id, &value)
+        }
         ToolReportKind::Auto => {
             let trimmed = input.trim_start();
             if trimmed.starts_with('<') {
@@ -368,6 +250,8 @@ fn parse_report(
             })?;
             if value.get("runs").is_some() {
                 parse_sarif_report(tool_id, &value)
+            } else if value.get("Results").is_some() {
+                parse_trivy_report(tool_id, &value)
             } else {
                 parse_json_report(tool_id, &value, cfg)
             }
@@ -375,7 +259,7 @@ fn parse_report(
     }
 }
 
-fn current_head_sha(repo_root: &Path) -> Option<String> {
+pub(crate) fn current_head_sha(repo_root: &Path) -> Option<String> {
     let output = Command::new("git")
         .current_dir(repo_root)
         .args(["rev-parse", "--verify", "HEAD"])
@@ -406,7 +290,8 @@ fn canonical_severity(
         "critical" => Ok(FindingSeverity::Critical),
         "high" | "error" | "fatal" | "failure" => Ok(FindingSeverity::High),
         "medium" | "warning" | "warn" => Ok(FindingSeverity::Medium),
-        "low" | "info" | "note" | "minor" => Ok(FindingSeverity::Low),
+        // Trivy's `UNKNOWN` (no score yet) is surfaced as an observation, not a blocker.
+        "low" | "info" | "note" | "minor" | "unknown" => Ok(FindingSeverity::Low),
         _ => Err(violation(
             "tools.structured_report.invalid_severity",
             format!("tool={tool_id}: unknown severity {raw}"),
@@ -498,11 +383,13 @@ fn validate_commit(
     }
 }
 
+/// Returns `(report, violations, suppressed)`; producer-suppressed findings land in `suppressed`.
+#[allow(clippy::type_complexity)]
 pub(crate) fn ingest_tool_report(
     repo_root: &Path,
     tool_id: &str,
     cfg_raw: &Value,
-) -> (Option<Value>, Vec<Violation>) {
+) -> (Option<Value>, Vec<Violation>, Vec<Violation>) {
     let cfg: ToolReportConfig = match serde_json::from_value(cfg_raw.clone()) {
         Ok(cfg) => cfg,
         Err(err) => {
@@ -514,6 +401,7 @@ pub(crate) fn ingest_tool_report(
                     None,
                     None,
                 )],
+                vec![],
             );
         }
     };
@@ -532,9 +420,10 @@ pub(crate) fn ingest_tool_report(
                     Some(report_path.display().to_string()),
                     None,
                 )],
+                vec![],
             );
         }
-        return (None, vec![]);
+        return (None, vec![], vec![]);
     }
 
     let bytes = match std::fs::read(&report_path) {
@@ -548,6 +437,7 @@ pub(crate) fn ingest_tool_report(
                     Some(report_path.display().to_string()),
                     None,
                 )],
+                vec![],
             );
         }
     };
@@ -566,6 +456,7 @@ pub(crate) fn ingest_tool_report(
                 Some(report_path.display().to_string()),
                 None,
             )],
+            vec![],
         );
     }
 
@@ -580,6 +471,7 @@ pub(crate) fn ingest_tool_report(
                     Some(report_path.display().to_string()),
                     None,
                 )],
+                vec![],
             );
         }
     };
@@ -595,6 +487,7 @@ pub(crate) fn ingest_tool_report(
                     Some(report_path.display().to_string()),
                     None,
                 )],
+                vec![],
             );
         }
     };
@@ -610,6 +503,8 @@ pub(crate) fn ingest_tool_report(
         .to_string();
 
     let mut findings_json = Vec::new();
+    let mut suppressed_json = Vec::new();
+    let mut suppressed = vec![];
     for finding in parsed.findings.drain(..) {
         if finding.code.trim().is_empty() {
             violations.push(violation(
@@ -635,6 +530,31 @@ pub(crate) fn ingest_tool_report(
                 }
             };
 
+        if let Some(reason) = finding.suppression {
+            suppressed_json.push(json!({
+                "code": finding.code,
+                "severity": severity_label(severity),
+                "path": finding.path,
+                "line": finding.line,
+                "reason": reason,
+            }));
+            suppressed.push(Violation {
+                code: finding.code,
+                message: format!("tool={tool_id}: finding suppressed by producer: {reason}"),
+                path: finding.path,
+                details: Some(json!({
+                    "tool_id": tool_id,
+                    "line": finding.line,
+                    "severity": severity_label(severity),
+                    "category": category,
+                    "suppression_reason": reason,
+                    "evidence_ref": finding.evidence_ref,
+                })),
+                tier: finding_tier(severity),
+            });
+            continue;
+        }
+
         findings_json.push(json!({
             "code": finding.code,
             "severity": severity_label(severity),
@@ -658,6 +578,7 @@ pub(crate) fn ingest_tool_report(
                 "line": finding.line,
                 "severity": severity_label(severity),
                 "category": category,
+                "evidence_ref": finding.evidence_ref,
             })),
             tier: finding_tier(severity),
         });
@@ -672,6 +593,7 @@ pub(crate) fn ingest_tool_report(
 
     let report = json!({
         "findings": findings_json,
+        "suppressed": suppressed_json,
         "summary": {
             "compact": compact_summary,
             "top_findings": top_findings,
@@ -686,7 +608,7 @@ pub(crate) fn ingest_tool_report(
         }
     });
 
-    (Some(report), violations)
+    (Some(report), violations, suppressed)
 }
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,194 @@
+use regex::Regex;
+use serde_json::Value;
+
+use super::{ParsedFinding, ParsedReport, first_text, message, text, u64_value};
+
+/// SARIF `result.suppressions`: any entry means the producer already suppressed the result.
+fn sarif_suppression(result: &Value) -> Option<String> {
+    let first = result
+        .get("suppressions")
+        .and_then(Value::as_array)
+        .and_then(|s| s.first())?;
+    Some(
+        first_text(first, &["justification", "kind"])
+            .unwrap_or_else(|| "suppressed by producer".to_string()),
+    )
+}
+
+pub(super) fn parse_sarif_report(tool_id: &str, payload: &Value) -> Result<ParsedReport, String> {
+    let runs = payload
+        .get("runs")
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("tool={tool_id}: missing runs array"))?;
+
+    let mut findings = Vec::new();
+    for run in runs {
+        let tool_name = run
+            .get("tool")
+            .and_then(|t| t.get("driver"))
+            .and_then(|d| d.get("name"))
+            .and_then(text);
+
+        if let Some(results) = run.get("results").and_then(Value::as_array) {
+            for result in results {
+                let location = result
+                    .get("locations")
+                    .and_then(Value::as_array)
+                    .and_then(|v| v.first());
+
+                findings.push(ParsedFinding {
+                    code: first_text(result, &["ruleId", "rule_id", "id"]).unwrap_or_default(),
+                    category: first_text(result, &["category"]).or_else(|| tool_name.clone()),
+                    message: message(result),
+                    path: location
+                        .and_then(|loc| loc.get("physicalLocation"))
+                        .and_then(|pl| pl.get("artifactLocation"))
+                        .and_then(|al| al.get("uri"))
+                        .and_then(text),
+                    line: location
+                        .and_then(|loc| loc.get("physicalLocation"))
+                        .and_then(|pl| pl.get("region"))
+                        .and_then(|r| r.get("startLine"))
+                        .and_then(u64_value),
+                    severity_raw: first_text(result, &["level", "severity"])
+                        .unwrap_or_else(|| "medium".to_string()),
+                    evidence_ref: None,
+                    suppression: sarif_suppression(result),
+                });
+            }
+        }
+    }
+
+    if findings.is_empty() {
+        return Err(format!("tool={tool_id}: SARIF report has no findings"));
+    }
+
+    Ok(ParsedReport {
+        findings,
+        version: payload
+            .get("version")
+            .and_then(text)
+            .or_else(|| payload.get("$schema").and_then(text)),
+        commit_sha: None,
+    })
+}
+
+/// Trivy JSON (`Results[].Vulnerabilities[]`). A scan without vulnerabilities is a clean
+/// report, not a parse failure, so only a missing `Results` array is rejected.
+pub(super) fn parse_trivy_report(tool_id: &str, payload: &Value) -> Result<ParsedReport, String> {
+    let results = payload
+        .get("Results")
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("tool={tool_id}: missing Results array"))?;
+
+    let mut findings = Vec::new();
+    for result in results {
+        let target = result.get("Target").and_then(text);
+        let Some(vulns) = result.get("Vulnerabilities").and_then(Value::as_array) else {
+            continue;
+        };
+        for vuln in vulns {
+            let package = match (
+                first_text(vuln, &["PkgName"]),
+                first_text(vuln, &["InstalledVersion"]),
+            ) {
+                (Some(name), Some(version)) => format!("{name}@{version}"),
+                (Some(name), None) => name,
+                (None, _) => "<unknown package>".to_string(),
+            };
+            let summary = first_text(vuln, &["Title", "Description"])
+                .unwrap_or_else(|| "vulnerability".to_string());
+            findings.push(ParsedFinding {
+                code: first_text(vuln, &["VulnerabilityID"]).unwrap_or_default(),
+                category: Some("vulnerability".to_string()),
+                message: format!("{package}: {summary}"),
+                path: target.clone(),
+                line: None,
+                severity_raw: first_text(vuln, &["Severity"])
+                    .unwrap_or_else(|| "medium".to_string()),
+                evidence_ref: first_text(vuln, &["PrimaryURL"]),
+                suppression: None,
+            });
+        }
+    }
+
+    Ok(ParsedReport {
+        findings,
+        version: payload
+            .get("SchemaVersion")
+            .and_then(|v| text(v).or_else(|| v.as_u64().map(|n| n.to_string()))),
+        commit_sha: None,
+    })
+}
+
+fn xml_attr(input: &str, key: &str) -> Option<String> {
+    let patterns = [format!("{key}=\""), format!("{key}='")];
+    for pattern in patterns {
+        let Some(start) = input.find(&pattern) else {
+            continue;
+        };
+        let quote = pattern.chars().last().unwrap_or('"');
+        let rest = &input[start + pattern.len()..];
+        let Some(end) = rest.find(quote) else {
+            continue;
+        };
+        let value = rest[..end].trim();
+        if !value.is_empty() {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+pub(super) fn parse_junit_report(tool_id: &str, input: &str) -> Result<ParsedReport, String> {
+    let testcase_re = Regex::new(r"(?s)<testcase\\b([^>]*)>(.*?)</testcase>")
+        .map_err(|e| format!("tool={tool_id}: regex compile failed: {e}"))?;
+    let event_re = Regex::new(r"(?s)<(failure|error)\\b([^>]*)>(.*?)</(?:failure|error)>")
+        .map_err(|e| format!("tool={tool_id}: regex compile failed: {e}"))?;
+
+    let mut findings = Vec::new();
+    for case in testcase_re.captures_iter(input) {
+        let attrs = case.get(1).map(|m| m.as_str()).unwrap_or_default();
+        let inner = case.get(2).map(|m| m.as_str()).unwrap_or_default();
+        let Some(event) = event_re.captures(inner) else {
+            continue;
+        };
+
+        let class_name = xml_attr(attrs, "classname");
+        let test_name = xml_attr(attrs, "name").unwrap_or_else(|| "testcase".to_string());
+        let code = class_name
+            .as_ref()
+            .map(|class| format!("{class}.{test_name}"))
+            .unwrap_or_else(|| test_name.clone());
+
+        let event_attrs = event.get(2).map(|m| m.as_str()).unwrap_or_default();
+        let event_body = event.get(3).map(|m| m.as_str()).unwrap_or_default();
+        let event_tag = event.get(1).map(|m| m.as_str()).unwrap_or("failure");
+
+        findings.push(ParsedFinding {
+            code,
+            category: Some("test".to_string()),
+            message: xml_attr(event_attrs, "message")
+                .or_else(|| {
+                    let text = event_body.trim();
+                    (!text.is_empty()).then_some(text.to_string())
+                })
+                .unwrap_or_else(|| "JUnit failure".to_string()),
+            path: xml_attr(attrs, "file").or_else(|| class_name.clone()),
+            line: xml_attr(attrs, "line").and_then(|n| n.parse::<u64>().ok()),
+            severity_raw: event_tag.to_string(),
+            evidence_ref: None,
+            suppression: None,
+        });
+    }
+
+    if findings.is_empty() {
+        return Err(format!("tool={tool_id}: junit report has no failures"));
+    }
+
+    Ok(ParsedReport {
+        findings,
+        version: None,
+        commit_sha: None,
+    })
+}
@@ -44,7 +44,7 @@ fn ingest_tool_report_preserves_adapter_summary_top_findings_and_remediation() {
         "path": "reports/custom.json",
         "required": true
     });
-    let (report, violations) = ingest_tool_report(repo, "lint-tool", &cfg);
+    let (report, violations, _) = ingest_tool_report(repo, "lint-tool", &cfg);
     assert_eq!(violations.len(), 1);
     let report = report.expect("report");
     assert_eq!(
@@ -100,7 +100,7 @@ fn ingest_tool_report_keeps_fallbacks_for_adapter_reports_without_summary_fields
         "path": "reports/fallback.json",
         "required": true
     });
-    let (report, violations) = ingest_tool_report(repo, "compat-tool", &cfg);
+    let (report, violations, _) = ingest_tool_report(repo, "compat-tool", &cfg);
     assert_eq!(violations.len(), 1);
     let report = report.expect("report");
     assert_eq!(
@@ -126,3 +126,166 @@ fn ingest_tool_report_keeps_fallbacks_for_adapter_reports_without_summary_fields
         Some(0)
     );
 }
+
+#[test]
+fn ingest_sarif_routes_producer_suppressed_results_to_suppressed() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    let report_path = repo.join("reports/lint.sarif");
+    std::fs::create_dir_all(report_path.parent().unwrap()).unwrap();
+    std::fs::write(
+        &report_path,
+        serde_json::to_string_pretty(&json!({
+            "version": "2.1.0",
+            "runs": [{
+                "tool": { "driver": { "name": "lint" } },
+                "results": [
+                    {
+                        "ruleId": "lint.live",
+                        "level": "error",
+                        "message": { "text": "live finding" },
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": "src/live.rs" },
+                                "region": { "startLine": 3 }
+                            }
+                        }]
+                    },
+                    {
+                        "ruleId": "lint.waived",
+                        "level": "error",
+                        "message": { "text": "waived finding" },
+                        "suppressions": [{
+                            "kind": "inSource",
+                            "justification": "false positive on generated code"
+                        }],
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": "src/gen.rs" }
+                            }
+                        }]
+                    }
+                ]
+            }]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let cfg = json!({ "kind": "sarif", "path": "reports/lint.sarif" });
+    let (report, violations, suppressed) = ingest_tool_report(repo, "lint-tool", &cfg);
+
+    let codes: Vec<&str> = violations.iter().map(|v| v.code.as_str()).collect();
+    assert_eq!(codes, vec!["lint.live"]);
+    assert_eq!(suppressed.len(), 1);
+    assert_eq!(suppressed[0].code, "lint.waived");
+    assert_eq!(suppressed[0].path.as_deref(), Some("src/gen.rs"));
+    assert_eq!(
+        suppressed[0]
+            .details
+            .as_ref()
+            .and_then(|d| d.get("suppression_reason"))
+            .and_then(|v| v.as_str()),
+        Some("false positive on generated code")
+    );
+
+    let report = report.expect("report");
+    assert_eq!(report["findings"].as_array().map(Vec::len), Some(1));
+    assert_eq!(report["suppressed"][0]["code"], "lint.waived");
+}
+
+#[test]
+fn ingest_trivy_maps_severity_package_and_target() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    std::fs::create_dir_all(repo.join("reports")).unwrap();
+    std::fs::write(
+        repo.join("reports/trivy.json"),
+        include_str!("fixtures/trivy.json"),
+    )
+    .unwrap();
+
+    let cfg = json!({ "kind": "trivy", "path": "reports/trivy.json" });
+    let (report, violations, suppressed) = ingest_tool_report(repo, "trivy", &cfg);
+
+    assert!(suppressed.is_empty());
+    let summary: Vec<(&str, ViolationTier, Option<&str>)> = violations
+        .iter()
+        .map(|v| (v.code.as_str(), v.tier, v.path.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("CVE-2024-0001", ViolationTier::Blocking, Some("Cargo.lock")),
+            (
+                "CVE-2024-0002",
+                ViolationTier::Observation,
+                Some("Cargo.lock")
+            ),
+            (
+                "GHSA-0000-0000-0003",
+                ViolationTier::Observation,
+                Some("Cargo.lock")
+            ),
+        ]
+    );
+
+    let report = report.expect("report");
+    let findings = report["findings"].as_array().expect("findings");
+    assert_eq!(findings.len(), 3);
+    assert_eq!(findings[0]["severity"], "critical");
+    assert_eq!(findings[1]["severity"], "low");
+    assert_eq!(findings[2]["severity"], "low");
+    assert_eq!(
+        findings[0]["message"],
+        "openssl@0.10.55: openssl: use-after-free in X509 name handling"
+    );
+    assert_eq!(
+        findings[0]["evidence_ref"],
+        "https://avd.aquasec.com/nvd/cve-2024-0001"
+    );
+    assert_eq!(report["evidence"]["report_version"], "2");
+}
+
+#[test]
+fn report_url_becomes_finding_evidence_ref() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    std::fs::create_dir_all(repo.join("reports")).unwrap();
+    std::fs::write(
+        repo.join("reports/lint.json"),
+        serde_json::to_string(&json!({
+            "findings": [
+                {
+                    "code": "lint.linked",
+                    "severity": "high",
+                    "message": "see docs",
+                    "path": "src/lib.rs",
+                    "url": "https://lint.example/rules/linked"
+                },
+                { "code": "lint.plain", "severity": "low", "path": "src/lib.rs" }
+            ]
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let cfg = json!({ "kind": "json", "path": "reports/lint.json" });
+    let (_, violations, _) = ingest_tool_report(repo, "lint-tool", &cfg);
+    let findings = crate::validate_insights::to_findings_v2(&violations);
+
+    let refs: Vec<(&str, &[String])> = findings
+        .iter()
+        .map(|f| (f.code.as_str(), f.details.evidence_refs.as_slice()))
+        .collect();
+    assert_eq!(
+        refs,
+        vec![
+            (
+                "finding.lint.linked",
+                &["https://lint.example/rules/linked".to_string()][..]
+            ),
+            ("finding.lint.plain", &[][..]),
+        ]
+    );
+}
@@ -0,0 +1,79 @@
+use crate::api::{ValidateOutput, Violation, ViolationTier};
+use crate::validate_insights::{finding_category, severity_rank, violation_severity};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Per-category counts of a validate payload past `[reporting] swamped_threshold`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SwampedSummary {
+    pub threshold: usize,
+    pub violations_total: usize,
+    pub by_category: BTreeMap<String, usize>,
+    pub by_code: BTreeMap<String, usize>,
+    /// Examples left after the output layer collapsed the payload; absent while uncollapsed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub examples_kept: Option<usize>,
+}
+
+const SWAMPED_EXAMPLES: usize = 5;
+
+/// Per-category/per-code counts when `violations` exceed `threshold`. Validate attaches this
+/// and keeps every violation, so gate verdicts and witnesses see the whole list.
+pub(crate) fn swamped_summary(
+    violations: &[Violation],
+    threshold: usize,
+) -> Option<SwampedSummary> {
+    if violations.len() <= threshold {
+        return None;
+    }
+    let mut by_category: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_code: BTreeMap<String, usize> = BTreeMap::new();
+    for v in violations {
+        *by_category
+            .entry(finding_category(v).to_string())
+            .or_insert(0) += 1;
+        *by_code.entry(v.code.clone()).or_insert(0) += 1;
+    }
+    Some(SwampedSummary {
+        threshold,
+        violations_total: violations.len(),
+        by_category,
+        by_code,
+        examples_kept: None,
+    })
+}
+
+/// Output-layer half: once validate attached a `swamped_summary`, keep only the few most
+/// severe examples (blocking first). The verdict status and its counts stay untouched.
+pub(crate) fn collapse_swamped(out: &mut ValidateOutput) {
+    let Some(summary) = out.swamped_summary.as_mut() else {
+        return;
+    };
+    out.violations.sort_by(|a, b| {
+        (a.tier != ViolationTier::Blocking)
+            .cmp(&(b.tier != ViolationTier::Blocking))
+            .then_with(|| {
+                severity_rank(violation_severity(a)).cmp(&severity_rank(violation_severity(b)))
+            })
+            .then_with(|| a.code.cmp(&b.code))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    out.violations.truncate(SWAMPED_EXAMPLES);
+    out.findings_v2.sort_by(|a, b| {
+        severity_rank(a.details.severity)
+            .cmp(&severity_rank(b.details.severity))
+            .then_with(|| a.code.cmp(&b.code))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    out.findings_v2.truncate(SWAMPED_EXAMPLES);
+    if let Some(verdict) = out.verdict.as_mut() {
+        verdict
+            .decision
+            .reasons
+            .sort_by_key(|r| r.tier != ViolationTier::Blocking);
+        verdict.decision.reasons.truncate(SWAMPED_EXAMPLES);
+    }
+    summary.examples_kept = Some(out.violations.len());
+    out.swamped = true;
+}
@@ -0,0 +1,249 @@
+//! Comment-aware text helpers shared by the source-scanning checks.
+
+use std::path::Path;
+
+/// Comment syntax family of a source file, keyed by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CommentLang {
+    /// `//` and nestable `/* */`; `'` may open a char literal or a lifetime.
+    Rust,
+    /// `//` and `/* */` (JS/TS, Go, C-family, Java/Kotlin/Swift).
+    CLike,
+    /// `#` line comments and `"""` / `'''` blocks.
+    Python,
+    /// `#` line comments only (shell, TOML, YAML, Ruby).
+    Hash,
+}
+
+impl CommentLang {
+    pub(crate) fn for_path(rel: &str) -> Option<Self> {
+        match Path::new(rel).extension().and_then(|s| s.to_str())? {
+            "rs" => Some(Self::Rust),
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "go" | "c" | "h" | "cc" | "cpp"
+            | "cxx" | "hpp" | "cs" | "java" | "kt" | "swift" => Some(Self::CLike),
+            "py" => Some(Self::Python),
+            "sh" | "bash" | "toml" | "yaml" | "yml" | "rb" => Some(Self::Hash),
+            _ => None,
+        }
+    }
+
+    fn has_slash_comments(self) -> bool {
+        matches!(self, Self::Rust | Self::CLike)
+    }
+}
+
+/// Removes comments from `src` while keeping string literals intact.
+///
+/// Newlines inside removed comments are kept, so line numbers of the remaining code do not
+/// shift. Python triple-quoted blocks (docstrings) count as comments.
+pub(crate) fn strip_comments(lang: CommentLang, src: &str) -> String {
+    let chars: Vec<char> = src.chars().collect();
+    let mut out = String::with_capacity(src.len());
+    let mut i = 0usize;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if lang.has_slash_comments() && c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if lang.has_slash_comments() && c == '/' && next == Some('*') {
+            i = skip_block_comment(&chars, i, lang == CommentLang::Rust, &mut out);
+            continue;
+        }
+        if matches!(lang, CommentLang::Python | CommentLang::Hash) && c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if lang == CommentLang::Python
+            && (c == '"' || c == '\'')
+            && next == Some(c)
+            && chars.get(i + 2) == Some(&c)
+        {
+            i = skip_triple_quoted(&chars, i, c, &mut out);
+            continue;
+        }
+        if lang == CommentLang::Rust && c == 'r' && is_raw_string_start(&chars, i) {
+            i = copy_raw_string(&chars, i, &mut out);
+            continue;
+        }
+        if c == '\'' && lang == CommentLang::Rust {
+            i = copy_rust_quote(&chars, i, &mut out);
+            continue;
+        }
+        if c == '"' || c == '\'' || (c == '`' && lang == CommentLang::CLike) {
+            i = copy_quoted(&chars, i, c, &mut out);
+            continue;
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+fn push_newlines(chars: &[char], out: &mut String) {
+    out.extend(chars.iter().filter(|c| **c == '\n'));
+}
+
+fn skip_block_comment(chars: &[char], start: usize, nested: bool, out: &mut String) -> usize {
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i] == '/' && chars.get(i + 1) == Some(&'*') && (nested || depth == 0) {
+            depth += 1;
+            i += 2;
+            continue;
+        }
+        if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                break;
+            }
+            continue;
+        }
+        i += 1;
+    }
+    push_newlines(&chars[start..i.min(chars.len())], out);
+    i
+}
+
+fn skip_triple_quoted(chars: &[char], start: usize, quote: char, out: &mut String) -> usize {
+    let mut i = start + 3;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if chars[i] == quote && chars.get(i + 1) == Some(&quote) && chars.get(i + 2) == Some(&quote)
+        {
+            i += 3;
+            break;
+        }
+        i += 1;
+    }
+    let end = i.min(chars.len());
+    push_newlines(&chars[start..end], out);
+    end
+}
+
+fn copy_quoted(chars: &[char], start: usize, quote: char, out: &mut String) -> usize {
+    out.push(quote);
+    let mut i = start + 1;
+    while i < chars.len() {
+        let c = chars[i];
+        out.push(c);
+        i += 1;
+        if c == '\\' {
+            if let Some(escaped) = chars.get(i) {
+                out.push(*escaped);
+                i += 1;
+            }
+            continue;
+        }
+        if c == quote {
+            break;
+        }
+    }
+    i
+}
+
+/// `'x'` / `'\n'` are char literals; anything else after `'` is a lifetime or label.
+fn copy_rust_quote(chars: &[char], start: usize, out: &mut String) -> usize {
+    let is_char_literal = match chars.get(start + 1) {
+        Some('\\') => true,
+        Some(_) => chars.get(start + 2) == Some(&'\''),
+        None => false,
+    };
+    if is_char_literal {
+        return copy_quoted(chars, start, '\'', out);
+    }
+    out.push('\'');
+    start + 1
+}
+
+fn is_raw_string_start(chars: &[char], i: usize) -> bool {
+    if i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_') {
+        return false;
+    }
+    let mut j = i + 1;
+    while chars.get(j) == Some(&'#') {
+        j += 1;
+    }
+    chars.get(j) == Some(&'"')
+}
+
+fn copy_raw_string(chars: &[char], start: usize, out: &mut String) -> usize {
+    let mut i = start + 1;
+    let mut hashes = 0usize;
+    while chars.get(i) == Some(&'#') {
+        hashes += 1;
+        i += 1;
+    }
+    i += 1; // opening quote
+    while i < chars.len() {
+        if chars[i] == '"' && (1..=hashes).all(|k| chars.get(i + k) == Some(&'#')) {
+            i += 1 + hashes;
+            break;
+        }
+        i += 1;
+    }
+    let end = i.min(chars.len());
+    out.extend(&chars[start..end]);
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_lang_by_extension() {
+        assert_eq!(CommentLang::for_path("src/a.rs"), Some(CommentLang::Rust));
+        assert_eq!(CommentLang::for_path("web/a.tsx"), Some(CommentLang::CLike));
+        assert_eq!(CommentLang::for_path("x/a.py"), Some(CommentLang::Python));
+        assert_eq!(CommentLang::for_path("ci/run.sh"), Some(CommentLang::Hash));
+        assert_eq!(CommentLang::for_path("README.md"), None);
+    }
+
+    #[test]
+    fn rust_strips_line_and_nested_block_comments_but_keeps_strings() {
+        let src = "let a = \"// not a comment\"; // trailing\n/* outer /* inner */ still */let b = 'x';\n#[derive(Debug)]\nfn f<'a>(s: &'a str) -> &'a str { r#\"/* raw */\"# }\n";
+        assert_eq!(
+            strip_comments(CommentLang::Rust, src),
+            "let a = \"// not a comment\"; \nlet b = 'x';\n#[derive(Debug)]\nfn f<'a>(s: &'a str) -> &'a str { r#\"/* raw */\"# }\n"
+        );
+    }
+
+    #[test]
+    fn clike_strips_comments_and_keeps_template_literals() {
+        let src = "const u = `http://x`; // c\n/* multi\nline */const v = '/*';\n";
+        assert_eq!(
+            strip_comments(CommentLang::CLike, src),
+            "const u = `http://x`; \n\nconst v = '/*';\n"
+        );
+    }
+
+    #[test]
+    fn python_strips_hash_and_triple_quoted_blocks() {
+        let src = "def f():\n    \"\"\"Doc\n    string.\"\"\"\n    s = \"# kept\"  # dropped\n    return s\n";
+        assert_eq!(
+            strip_comments(CommentLang::Python, src),
+            "def f():\n    \n\n    s = \"# kept\"  \n    return s\n"
+        );
+    }
+
+    #[test]
+    fn hash_lang_strips_only_hash_comments() {
+        let src = "key = \"a#b\" # note\n// not a comment in toml\n";
+        assert_eq!(
+            strip_comments(CommentLang::Hash, src),
+            "key = \"a#b\" \n// not a comment in toml\n"
+        );
+    }
+}
@@ -1,14 +1,73 @@
-use crate::{
-    api::{
-        AgentDigest, CoverageSummary, Decision, FindingDetailsV2, FindingSeverity, FindingV2,
-        QualityPosture, RiskSummary, TrustScore, TrustWeights, Violation, ViolationTier,
-    },
-    repo::RepoConfig,
-};
-use std::{
-    collections::{BTreeMap, BTreeSet},
-    path::Path,
+use crate::api::{
+    AgentDigest, CheckSelection, CoverageSummary, Decision, FindingDetailsV2, FindingSeverity,
+    FindingV2, QualityPosture, RiskSummary, TrustScore, TrustWeights, Violation, ViolationTier,
 };
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+mod coverage;
+
+pub(crate) use coverage::build_coverage;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PartialRunSummary {
+    pub selection: CheckSelection,
+    /// Configured check kinds that were not run.
+    pub skipped_checks: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CheckError {
+    /// Check kind: the `<check>` of the `<check>.check_failed` violation.
+    pub check: String,
+    /// Configured check id; the violation path when the failure names no id (the snapshot path
+    /// for `quality_delta`).
+    pub id: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MetricDelta {
+    pub baseline: i64,
+    pub current: i64,
+    pub delta: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LocDelta {
+    pub path: String,
+    pub baseline: Option<usize>,
+    pub current: Option<usize>,
+}
+
+/// Snapshot-vs-snapshot diff for reviewing a baseline refresh. Without a stored snapshot,
+/// `baseline_present=false` and every baseline value is zero/empty.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BaselineDiff {
+    pub snapshot_path: String,
+    pub baseline_present: bool,
+    pub trust_score: MetricDelta,
+    pub coverage_covered: MetricDelta,
+    pub coverage_total: MetricDelta,
+    pub weighted_risk: MetricDelta,
+    pub findings_total: MetricDelta,
+    pub loc_changed: Vec<LocDelta>,
+    pub surface_added: Vec<String>,
+    pub surface_removed: Vec<String>,
+    pub duplicate_groups_added: usize,
+    pub duplicate_groups_removed: usize,
+    pub config_hash_changed: bool,
+    /// Ratchet regressions the write would absorb into the new baseline.
+    pub regressions: Vec<Violation>,
+}
+
+/// A file and how many findings point at it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct FileHotspot {
+    pub path: String,
+    pub count: usize,
+}
 
 fn boundary_rule_id(v: &Violation) -> Option<&str> {
     v.details
@@ -27,8 +86,11 @@ fn boundary_rule_category(rule_id: &str) -> Option<&'static str> {
     }
 }
 
-fn finding_category(v: &Violation) -> &'static str {
+pub(crate) fn finding_category(v: &Violation) -> &'static str {
     let code = v.code.as_str();
+    if code == "tools.timeout_defaulted" {
+        return "resilience_defaults";
+    }
     if code == "boundary.rule_violation"
         && let Some(rule_id) = boundary_rule_id(v)
         && let Some(category) = boundary_rule_category(rule_id)
@@ -38,7 +100,7 @@ fn finding_category(v: &Violation) -> &'static str {
 
     if code.starts_with("boundary.") || code.starts_with("exception.") {
         "policy_theater"
-    } else if code.starts_with("loc.") {
+    } else if code.starts_with("loc.") || code.starts_with("import_cycles.") {
         "god_module_cycles"
     } else if code.starts_with("surface.") {
         "public_surface_bloat"
@@ -52,13 +114,19 @@ fn finding_category(v: &Violation) -> &'static str {
         "unplugged_iron"
     } else if code.starts_with("orphan_api.") {
         "public_surface_bloat"
-    } else if code.starts_with("complexity_budget.") {
+    } else if code.starts_with("complexity_budget.") || code.starts_with("max_params.") {
         "god_module_cycles"
-    } else if code.starts_with("contract_break.") || code.starts_with("change_impact.") {
+    } else if code.starts_with("contract_break.")
+        || code.starts_with("change_impact.")
+        || code.starts_with("test_presence.")
+        || code.starts_with("escalation.")
+        || code.starts_with("governance.")
+    {
         "policy_theater"
-    } else if code.starts_with("supply_chain.") {
+    } else if code.starts_with("supply_chain.") || code.starts_with("binary_artifacts.") {
         "dependency_hygiene"
     } else if code.starts_with("tool_budget.")
+        || code == "tools.metadata_incomplete"
         || code.starts_with("quality_delta.")
         || code.starts_with("gate.")
         || code.starts_with("witness.")
@@ -71,7 +139,7 @@ fn finding_category(v: &Violation) -> &'static str {
     }
 }
 
-fn finding_severity(code: &str) -> FindingSeverity {
+pub(crate) fn finding_severity(code: &str) -> FindingSeverity {
     if code.contains("read_failed") || code.contains("check_failed") {
         FindingSeverity::High
     } else if code.starts_with("quality_delta.")
@@ -79,6 +147,7 @@ fn finding_severity(code: &str) -> FindingSeverity {
         || code.starts_with("config.threshold_weakened")
         || code.starts_with("config.mandatory_check_removed")
         || code.starts_with("contract_break.removed_symbol")
+        || code.starts_with("governance.trust_grade_below_minimum")
     {
         FindingSeverity::Critical
     } else if code.starts_with("boundary.")
@@ -86,6 +155,7 @@ fn finding_severity(code: &str) -> FindingSeverity {
         || code.starts_with("env_registry.")
         || code.starts_with("exception.allowlist_invalid")
         || code.starts_with("arch_layers.")
+        || code.starts_with("import_cycles.")
         || code.starts_with("change_impact.")
         || code.starts_with("reuse_first.")
     {
@@ -96,6 +166,12 @@ fn finding_severity(code: &str) -> FindingSeverity {
         || code.starts_with("dead_code.")
         || code.starts_with("orphan_api.")
         || code.starts_with("complexity_budget.")
+        || code.starts_with("max_params.")
+        || code.starts_with("test_presence.")
+        || code.starts_with("escalation.")
+        || code.starts_with("file_encoding.")
+        || code.starts_with("binary_artifacts.")
+        || code == "duplicates.found"
     {
         FindingSeverity::Medium
     } else {
@@ -103,6 +179,21 @@ fn finding_severity(code: &str) -> FindingSeverity {
     }
 }
 
+/// Severity of a violation: by code, except `duplicates.found`, whose check weighs group size
+/// and file size into `details.severity`.
+pub(crate) fn violation_severity(v: &Violation) -> FindingSeverity {
+    if v.code == "duplicates.found"
+        && let Some(severity) = v
+            .details
+            .as_ref()
+            .and_then(|d| d.get("severity"))
+            .and_then(|s| serde_json::from_value(s.clone()).ok())
+    {
+        return severity;
+    }
+    finding_severity(&v.code)
+}
+
 fn finding_fix_recipe(v: &Violation) -> Option<&'static str> {
     let code = v.code.as_str();
     if code == "boundary.rule_violation"
@@ -154,6 +245,10 @@ fn finding_fix_recipe(v: &Violation) -> Option<&'static str> {
         Some(
             "Restore allowed dependency direction between layers and remove forbidden cross-layer imports.",
         )
+    } else if code.starts_with("import_cycles.") {
+        Some(
+            "Break the cycle: move the shared items into a module both sides depend on, or invert one import so dependencies point one way.",
+        )
     } else if code.starts_with("dead_code.") {
         Some("Remove unused private code or wire it into the real runtime path with tests.")
     } else if code.starts_with("orphan_api.") {
@@ -162,6 +257,10 @@ fn finding_fix_recipe(v: &Violation) -> Option<&'static str> {
         Some(
             "Split complex function into focused units until function length and complexity budgets are green.",
         )
+    } else if code.starts_with("max_params.") {
+        Some(
+            "Group related parameters into a struct/options object or split the function so the parameter list fits max_params.",
+        )
     } else if code.starts_with("contract_break.") {
         Some(
             "Align API/schema changes with compatibility policy or update contract baseline through approved change process.",
@@ -170,6 +269,32 @@ fn finding_fix_recipe(v: &Violation) -> Option<&'static str> {
         Some(
             "Update impact mapping so changed paths require the correct gate tools and rerun gate.",
         )
+    } else if code.starts_with("governance.trust_grade_below_minimum") {
+        Some(
+            "Fix the highest-severity findings (or extend failure-mode coverage) until the trust grade meets governance.min_trust_grade; do not lower the floor to pass.",
+        )
+    } else if code.starts_with("binary_artifacts.") {
+        Some(
+            "Remove the artifact from version control (git rm --cached), ignore it in .gitignore, and publish it via releases or Git LFS instead.",
+        )
+    } else if code.starts_with("file_encoding.") {
+        Some(
+            "Re-save the file as UTF-8 without BOM and with LF line endings (add a .gitattributes `eol=lf` rule to keep it that way).",
+        )
+    } else if code.starts_with("naming_convention.") {
+        Some(
+            "Rename the file or type to the configured convention (details.expected) and update its references.",
+        )
+    } else if code.starts_with("magic_numbers.") {
+        Some(
+            "Name the literal with a constant that explains it (or add it to allowed_values if it is self-explanatory).",
+        )
+    } else if code.starts_with("test_presence.") {
+        Some("Add or update a test matching the configured test_globs for the changed source file.")
+    } else if code.starts_with("escalation.") {
+        Some(
+            "Fix enough occurrences of the escalated code to drop below the [[escalation]] threshold.",
+        )
     } else if code.starts_with("supply_chain.lockfile_missing") {
         Some(
             "Add and commit the ecosystem lockfile (Cargo.lock / package-lock.json / pnpm-lock.yaml / poetry.lock) before merge.",
@@ -192,6 +317,10 @@ fn finding_fix_recipe(v: &Violation) -> Option<&'static str> {
         Some("Remove exact duplicate tool definitions or consolidate to one canonical tool entry.")
     } else if code.starts_with("tools.duplicate_semantic") {
         Some("Review semantically similar tools and merge if they duplicate developer intent.")
+    } else if code == "tools.metadata_incomplete" {
+        Some(
+            "Describe what the tool checks and when to run it (at least [tools] min_description_len chars) and declare it under its owning plugin.",
+        )
     } else if code.starts_with("exception.") {
         Some(
             "Fix allowlist entry or expiry and rerun validate/gate to keep suppressions explicit and bounded.",
@@ -201,19 +330,92 @@ fn finding_fix_recipe(v: &Violation) -> Option<&'static str> {
     }
 }
 
+/// `details` keys that identify which rule/symbol a finding is about; positions (`line`,
+/// `offset`) and measurements are left out so the fingerprint survives unrelated edits.
+const FINGERPRINT_CONTEXT_KEYS: &[&str] = &[
+    "check_id",
+    "rule_id",
+    "matched",
+    "symbol",
+    "tool_id",
+    "plugin_id",
+    "var",
+    "dependency",
+    "ecosystem",
+    "from_layer",
+    "to_layer",
+    "gate_kind",
+    "exception_id",
+];
+
+pub(crate) fn finding_fingerprint(v: &Violation) -> String {
+    let path = v
+        .path
+        .as_deref()
+        .map(|p| p.replace('\\', "/"))
+        .map(|p| p.trim_start_matches("./").to_string())
+        .unwrap_or_default();
+    let mut input = format!("{}\n{path}", v.code);
+    if let Some(details) = v.details.as_ref().and_then(|d| d.as_object()) {
+        for key in FINGERPRINT_CONTEXT_KEYS {
+            if let Some(value) = details.get(*key) {
+                input.push_str(&format!("\n{key}={value}"));
+            }
+        }
+    }
+    crate::hash::sha256_hex(input.as_bytes())
+}
+
+/// Links carried by the violation itself; structured tool reports put theirs in
+/// `details.evidence_ref`.
+fn evidence_refs(v: &Violation) -> Vec<String> {
+    v.details
+        .as_ref()
+        .and_then(|d| d.get("evidence_ref"))
+        .and_then(|r| r.as_str())
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|r| vec![r.to_string()])
+        .unwrap_or_default()
+}
+
 fn to_finding_v2(v: &Violation) -> FindingV2 {
     FindingV2 {
         code: format!("finding.{}", v.code),
         message: v.message.clone(),
         path: v.path.clone(),
+        fingerprint: finding_fingerprint(v),
         details: FindingDetailsV2 {
-            severity: finding_severity(&v.code),
+            severity: violation_severity(v),
             category: finding_category(v).to_string(),
             confidence: "high".to_string(),
-            evidence_refs: vec![],
+            evidence_refs: evidence_refs(v),
             fix_recipe: finding_fix_recipe(v).map(ToString::to_string),
             legacy_details: v.details.clone(),
+            suppressed: false,
+            suppression_reason: None,
         },
+        first_seen: None,
+        first_seen_commit: None,
+    }
+}
+
+/// Copies the matched fix recipe into each violation's `details.fix_recipe`.
+/// Non-object `details` are left untouched.
+pub(crate) fn attach_fix_recipes(violations: &mut [Violation]) {
+    for v in violations {
+        let Some(recipe) = finding_fix_recipe(v) else {
+            continue;
+        };
+        if let serde_json::Value::Object(map) = v
+            .details
+            .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+        {
+            map.insert(
+                "fix_recipe".to_string(),
+                serde_json::Value::String(recipe.to_string()),
+            );
+        }
     }
 }
 
@@ -223,120 +425,47 @@ pub(crate) fn to_findings_v2(violations: &[Violation]) -> Vec<FindingV2> {
     findings_v2
 }
 
+/// Findings for allowlist-suppressed violations, tagged `suppressed` with the exception reason.
+pub(crate) fn to_suppressed_findings_v2(suppressed: &[Violation]) -> Vec<FindingV2> {
+    let mut findings = to_findings_v2(suppressed);
+    for f in &mut findings {
+        f.details.suppressed = true;
+        f.details.suppression_reason = f
+            .details
+            .legacy_details
+            .as_ref()
+            .and_then(|d| d.pointer("/suppressed_by/reason"))
+            .and_then(|r| r.as_str())
+            .map(ToString::to_string);
+    }
+    findings
+}
+
+fn code_prefix(finding_code: &str) -> &str {
+    let code = finding_code
+        .strip_prefix("finding.")
+        .unwrap_or(finding_code);
+    code.split('.').next().unwrap_or(code)
+}
+
 pub(crate) fn build_risk_summary(findings_v2: &[FindingV2]) -> RiskSummary {
     let mut by_category: BTreeMap<String, usize> = BTreeMap::new();
     let mut by_severity: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_code_prefix: BTreeMap<String, usize> = BTreeMap::new();
     for f in findings_v2 {
         *by_category.entry(f.details.category.clone()).or_insert(0) += 1;
         *by_severity
             .entry(format!("{:?}", f.details.severity).to_lowercase())
             .or_insert(0) += 1;
+        *by_code_prefix
+            .entry(code_prefix(&f.code).to_string())
+            .or_insert(0) += 1;
     }
     RiskSummary {
         findings_total: findings_v2.len(),
         by_category,
         by_severity,
-    }
-}
-
-pub(crate) fn build_coverage(
-    catalog: &[String],
-    repo_root: &Path,
-    cfg: &RepoConfig,
-) -> CoverageSummary {
-    if catalog.is_empty() {
-        return CoverageSummary {
-            catalog_total: 0,
-            catalog_covered: 0,
-            percent: 0.0,
-            covered_modes: vec![],
-            uncovered_modes: vec![],
-            effective_covered_modes: vec![],
-            declared_but_ineffective_modes: vec![],
-        };
-    }
-    let mut covered: BTreeSet<String> = BTreeSet::new();
-    let mut ineffective: BTreeSet<String> = BTreeSet::new();
-    let has_boundary_rule = |id: &str| {
-        cfg.checks
-            .boundary
-            .iter()
-            .flat_map(|b| b.rules.iter())
-            .any(|r| r.id == id)
-    };
-    let has_effective_boundary = cfg.checks.boundary.iter().any(|b| !b.rules.is_empty());
-    let has_effective_surface = cfg.checks.surface.iter().any(|s| !s.rules.is_empty());
-    let has_effective_loc = cfg.checks.loc.iter().any(|l| l.max_loc < 10_000);
-
-    if has_effective_boundary {
-        covered.insert("policy_theater".to_string());
-    }
-    if !cfg.checks.boundary.is_empty() && !has_effective_boundary {
-        ineffective.insert("policy_theater".to_string());
-    }
-    if !cfg.checks.tool_budget.is_empty() {
-        covered.insert("policy_theater".to_string());
-    }
-    if has_boundary_rule("no-runtime-stdout") {
-        covered.insert("fail_open".to_string());
-    }
-    if !cfg.checks.duplicates.is_empty() {
-        covered.insert("unplugged_iron".to_string());
-    }
-    if !cfg.checks.reuse_first.is_empty() || !cfg.checks.dead_code.is_empty() {
-        covered.insert("unplugged_iron".to_string());
-    }
-    if !cfg.checks.env_registry.is_empty() {
-        covered.insert("env_sprawl".to_string());
-    }
-    if has_effective_surface {
-        covered.insert("public_surface_bloat".to_string());
-    }
-    if !cfg.checks.orphan_api.is_empty() {
-        covered.insert("public_surface_bloat".to_string());
-    }
-    if has_effective_loc {
-        covered.insert("god_module_cycles".to_string());
-    }
-    if !cfg.checks.complexity_budget.is_empty() {
-        covered.insert("god_module_cycles".to_string());
-    }
-    if has_boundary_rule("no-runtime-unwrap-expect")
-        || has_boundary_rule("no-runtime-panic")
-        || has_effective_loc
-    {
-        covered.insert("resilience_defaults".to_string());
-    }
-    if !cfg.checks.arch_layers.is_empty() || !cfg.checks.contract_break.is_empty() {
-        covered.insert("policy_theater".to_string());
-    }
-    if repo_root.join(".agents/skills").is_dir() {
-        covered.insert("knowledge_continuity".to_string());
-    }
-    if !cfg.checks.supply_chain.is_empty() {
-        covered.insert("security_baseline".to_string());
-        covered.insert("dependency_hygiene".to_string());
-    }
-    if !cfg.gate.flagship.is_empty() && cfg.checks.supply_chain.is_empty() {
-        ineffective.insert("security_baseline".to_string());
-        ineffective.insert("dependency_hygiene".to_string());
-    }
-
-    let uncovered: Vec<String> = catalog
-        .iter()
-        .filter(|c| !covered.contains(c.as_str()))
-        .map(|c| c.to_string())
-        .collect();
-    let percent = ((covered.len() as f64 / catalog.len() as f64) * 100.0 * 100.0).round() / 100.0;
-
-    CoverageSummary {
-        catalog_total: catalog.len(),
-        catalog_covered: covered.len(),
-        percent,
-        covered_modes: covered.iter().cloned().collect(),
-        uncovered_modes: uncovered,
-        effective_covered_modes: covered.into_iter().collect(),
-        declared_but_ineffective_modes: ineffective.into_iter().collect(),
+        by_code_prefix,
     }
 }
 
@@ -396,6 +525,16 @@ pub(crate) fn build_trust_score(
     }
 }
 
+/// Sort key for severity-first ordering (critical = 0).
+pub(crate) fn severity_rank(severity: FindingSeverity) -> u8 {
+    match severity {
+        FindingSeverity::Critical => 0,
+        FindingSeverity::High => 1,
+        FindingSeverity::Medium => 2,
+        FindingSeverity::Low => 3,
+    }
+}
+
 pub(crate) fn compute_weighted_risk(risk: &RiskSummary) -> i32 {
     let mut total = 0i32;
     for (sev, count) in &risk.by_severity {
@@ -442,6 +581,25 @@ fn top_violation_codes(violations: &[Violation], limit: usize) -> Vec<String> {
         .collect()
 }
 
+fn hotspot_files(findings: &[FindingV2], limit: usize) -> Vec<FileHotspot> {
+    let mut by_path: BTreeMap<&str, usize> = BTreeMap::new();
+    for f in findings {
+        if let Some(path) = f.path.as_deref() {
+            *by_path.entry(path).or_insert(0) += 1;
+        }
+    }
+    let mut ranked = by_path.into_iter().collect::<Vec<_>>();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(path, count)| FileHotspot {
+            path: path.to_string(),
+            count,
+        })
+        .collect()
+}
+
 pub(crate) fn build_agent_digest(
     decision: &Decision,
     violations: &[Violation],
@@ -505,79 +663,9 @@ pub(crate) fn build_agent_digest_with_suppressed(
         confidence: confidence.to_string(),
         suppressed_count: suppressed.len(),
         suppressed_top_codes: top_violation_codes(suppressed, 3),
+        hotspot_files: hotspot_files(findings, 3),
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::api::{DecisionReason, DecisionStatus, ErrorClass};
-
-    fn test_decision() -> Decision {
-        Decision {
-            status: DecisionStatus::Blocked,
-            reasons: vec![DecisionReason {
-                code: "boundary.rule_violation".to_string(),
-                class: ErrorClass::ContractBreak,
-                tier: ViolationTier::Blocking,
-            }],
-            blocking_count: 1,
-            observation_count: 0,
-        }
-    }
-
-    fn test_finding(code: &str, category: &str, fix_recipe: Option<&str>) -> FindingV2 {
-        FindingV2 {
-            code: code.to_string(),
-            message: "msg".to_string(),
-            path: None,
-            details: FindingDetailsV2 {
-                severity: FindingSeverity::High,
-                category: category.to_string(),
-                confidence: "high".to_string(),
-                evidence_refs: vec![],
-                fix_recipe: fix_recipe.map(ToString::to_string),
-                legacy_details: None,
-            },
-        }
-    }
-
-    #[test]
-    fn agent_digest_wrapper_without_suppressed_keeps_defaults() {
-        let decision = test_decision();
-        let findings = vec![test_finding(
-            "finding.boundary.rule_violation",
-            "policy_theater",
-            Some("Fix boundary"),
-        )];
-        let digest = build_agent_digest(&decision, &[], &findings);
-        assert_eq!(digest.suppressed_count, 0);
-        assert!(digest.suppressed_top_codes.is_empty());
-    }
-
-    #[test]
-    fn agent_digest_with_suppressed_reports_top_codes() {
-        let decision = test_decision();
-        let findings = vec![test_finding(
-            "finding.boundary.rule_violation",
-            "policy_theater",
-            Some("Fix boundary"),
-        )];
-        let suppressed = vec![
-            Violation::observation("exception.expired", "x", None, None),
-            Violation::observation("exception.expired", "x", None, None),
-            Violation::observation("loc.max_exceeded", "x", None, None),
-            Violation::observation("boundary.rule_violation", "x", None, None),
-        ];
-        let digest = build_agent_digest_with_suppressed(&decision, &[], &findings, &suppressed);
-        assert_eq!(digest.suppressed_count, 4);
-        assert_eq!(
-            digest.suppressed_top_codes,
-            vec![
-                "exception.expired".to_string(),
-                "boundary.rule_violation".to_string(),
-                "loc.max_exceeded".to_string()
-            ]
-        );
-    }
-}
+mod tests;
@@ -0,0 +1,178 @@
+use crate::{api::CoverageSummary, failure_modes::is_builtin_failure_mode, repo::RepoConfig};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+fn check_ids<T>(checks: &[T], id: fn(&T) -> &String) -> Vec<String> {
+    checks.iter().map(|c| id(c).clone()).collect()
+}
+
+/// Names a `[coverage] mappings` entry may refer to: check kinds with at least one instance,
+/// every check `id`, and every registered tool id.
+fn configured_check_names(cfg: &RepoConfig) -> BTreeSet<String> {
+    let mut names: BTreeSet<String> = cfg.tools.keys().cloned().collect();
+    let c = &cfg.checks;
+    let kinds = [
+        ("loc", check_ids(&c.loc, |x| &x.id)),
+        ("env_registry", check_ids(&c.env_registry, |x| &x.id)),
+        ("boundary", check_ids(&c.boundary, |x| &x.id)),
+        ("surface", check_ids(&c.surface, |x| &x.id)),
+        ("duplicates", check_ids(&c.duplicates, |x| &x.id)),
+        ("supply_chain", check_ids(&c.supply_chain, |x| &x.id)),
+        ("tool_budget", check_ids(&c.tool_budget, |x| &x.id)),
+        ("reuse_first", check_ids(&c.reuse_first, |x| &x.id)),
+        ("arch_layers", check_ids(&c.arch_layers, |x| &x.id)),
+        ("dead_code", check_ids(&c.dead_code, |x| &x.id)),
+        ("orphan_api", check_ids(&c.orphan_api, |x| &x.id)),
+        (
+            "complexity_budget",
+            check_ids(&c.complexity_budget, |x| &x.id),
+        ),
+        ("contract_break", check_ids(&c.contract_break, |x| &x.id)),
+        ("test_presence", check_ids(&c.test_presence, |x| &x.id)),
+        ("file_encoding", check_ids(&c.file_encoding, |x| &x.id)),
+        ("max_params", check_ids(&c.max_params, |x| &x.id)),
+        (
+            "binary_artifacts",
+            check_ids(&c.binary_artifacts, |x| &x.id),
+        ),
+        ("import_cycles", check_ids(&c.import_cycles, |x| &x.id)),
+        (
+            "naming_convention",
+            check_ids(&c.naming_convention, |x| &x.id),
+        ),
+        ("magic_numbers", check_ids(&c.magic_numbers, |x| &x.id)),
+    ];
+    for (kind, ids) in kinds {
+        if !ids.is_empty() {
+            names.insert(kind.to_string());
+            names.extend(ids);
+        }
+    }
+    names
+}
+
+pub(crate) fn build_coverage(
+    catalog: &[String],
+    repo_root: &Path,
+    cfg: &RepoConfig,
+) -> CoverageSummary {
+    if catalog.is_empty() {
+        return CoverageSummary {
+            catalog_total: 0,
+            catalog_covered: 0,
+            percent: 0.0,
+            covered_modes: vec![],
+            uncovered_modes: vec![],
+            effective_covered_modes: vec![],
+            declared_but_ineffective_modes: vec![],
+            pack_modes: BTreeMap::new(),
+        };
+    }
+    let mut covered: BTreeSet<String> = BTreeSet::new();
+    let mut ineffective: BTreeSet<String> = BTreeSet::new();
+    let has_boundary_rule = |id: &str| {
+        cfg.checks
+            .boundary
+            .iter()
+            .flat_map(|b| b.rules.iter())
+            .any(|r| r.id == id)
+    };
+    let has_effective_boundary = cfg.checks.boundary.iter().any(|b| !b.rules.is_empty());
+    let has_effective_surface = cfg.checks.surface.iter().any(|s| !s.rules.is_empty());
+    let has_effective_loc = cfg.checks.loc.iter().any(|l| l.max_loc < 10_000);
+
+    if has_effective_boundary {
+        covered.insert("policy_theater".to_string());
+    }
+    if !cfg.checks.boundary.is_empty() && !has_effective_boundary {
+        ineffective.insert("policy_theater".to_string());
+    }
+    if !cfg.checks.tool_budget.is_empty() {
+        covered.insert("policy_theater".to_string());
+    }
+    if has_boundary_rule("no-runtime-stdout") {
+        covered.insert("fail_open".to_string());
+    }
+    if !cfg.checks.duplicates.is_empty() {
+        covered.insert("unplugged_iron".to_string());
+    }
+    if !cfg.checks.reuse_first.is_empty() || !cfg.checks.dead_code.is_empty() {
+        covered.insert("unplugged_iron".to_string());
+    }
+    if !cfg.checks.env_registry.is_empty() {
+        covered.insert("env_sprawl".to_string());
+    }
+    if has_effective_surface {
+        covered.insert("public_surface_bloat".to_string());
+    }
+    if !cfg.checks.orphan_api.is_empty() {
+        covered.insert("public_surface_bloat".to_string());
+    }
+    if has_effective_loc {
+        covered.insert("god_module_cycles".to_string());
+    }
+    if !cfg.checks.complexity_budget.is_empty()
+        || !cfg.checks.max_params.is_empty()
+        || !cfg.checks.import_cycles.is_empty()
+    {
+        covered.insert("god_module_cycles".to_string());
+    }
+    if has_boundary_rule("no-runtime-unwrap-expect")
+        || has_boundary_rule("no-runtime-panic")
+        || has_effective_loc
+    {
+        covered.insert("resilience_defaults".to_string());
+    }
+    if !cfg.checks.arch_layers.is_empty()
+        || !cfg.checks.contract_break.is_empty()
+        || !cfg.checks.test_presence.is_empty()
+    {
+        covered.insert("policy_theater".to_string());
+    }
+    if repo_root.join(".agents/skills").is_dir() {
+        covered.insert("knowledge_continuity".to_string());
+    }
+    if !cfg.checks.supply_chain.is_empty() {
+        covered.insert("security_baseline".to_string());
+        covered.insert("dependency_hygiene".to_string());
+    }
+    if !cfg.gate.flagship.is_empty() && cfg.checks.supply_chain.is_empty() {
+        ineffective.insert("security_baseline".to_string());
+        ineffective.insert("dependency_hygiene".to_string());
+    }
+    if let Some(contract) = cfg.quality_contract.as_ref()
+        && !contract.coverage.mappings.is_empty()
+    {
+        // Built-in and governance-mandatory modes are only covered by the built-in mapping above.
+        let configured = configured_check_names(cfg);
+        for (mode, checks) in &contract.coverage.mappings {
+            if catalog.contains(mode)
+                && !is_builtin_failure_mode(mode)
+                && !contract.governance.mandatory_failure_modes.contains(mode)
+                && checks.iter().any(|c| configured.contains(c.as_str()))
+            {
+                covered.insert(mode.clone());
+            }
+        }
+    }
+
+    let uncovered: Vec<String> = catalog
+        .iter()
+        .filter(|c| !covered.contains(c.as_str()))
+        .map(|c| c.to_string())
+        .collect();
+    let percent = ((covered.len() as f64 / catalog.len() as f64) * 100.0 * 100.0).round() / 100.0;
+
+    CoverageSummary {
+        catalog_total: catalog.len(),
+        catalog_covered: covered.len(),
+        percent,
+        covered_modes: covered.iter().cloned().collect(),
+        uncovered_modes: uncovered,
+        effective_covered_modes: covered.into_iter().collect(),
+        declared_but_ineffective_modes: ineffective.into_iter().collect(),
+        pack_modes: BTreeMap::new(),
+    }
+}
@@ -0,0 +1,176 @@
+use super::*;
+use crate::api::{DecisionReason, DecisionStatus, ErrorClass};
+
+fn test_decision() -> Decision {
+    Decision {
+        status: DecisionStatus::Blocked,
+        reasons: vec![DecisionReason {
+            code: "boundary.rule_violation".to_string(),
+            class: ErrorClass::ContractBreak,
+            tier: ViolationTier::Blocking,
+            source: None,
+            origin: None,
+        }],
+        blocking_count: 1,
+        observation_count: 0,
+    }
+}
+
+fn test_finding(code: &str, category: &str, fix_recipe: Option<&str>) -> FindingV2 {
+    FindingV2 {
+        code: code.to_string(),
+        message: "msg".to_string(),
+        path: None,
+        fingerprint: String::new(),
+        details: FindingDetailsV2 {
+            severity: FindingSeverity::High,
+            category: category.to_string(),
+            confidence: "high".to_string(),
+            evidence_refs: vec![],
+            fix_recipe: fix_recipe.map(ToString::to_string),
+            legacy_details: None,
+            suppressed: false,
+            suppression_reason: None,
+        },
+        first_seen: None,
+        first_seen_commit: None,
+    }
+}
+
+#[test]
+fn risk_summary_counts_code_prefixes() {
+    let findings = to_findings_v2(&[
+        Violation::blocking("boundary.rule_violation", "x", None, None),
+        Violation::blocking("boundary.rule_violation", "x", None, None),
+        Violation::blocking("boundary.check_failed", "x", None, None),
+        Violation::observation("loc.max_exceeded", "x", None, None),
+        Violation::observation("loc.max_exceeded", "x", None, None),
+        Violation::blocking("supply_chain.lockfile_missing", "x", None, None),
+    ]);
+    let risk = build_risk_summary(&findings);
+    assert_eq!(risk.by_code_prefix.get("boundary"), Some(&3));
+    assert_eq!(risk.by_code_prefix.get("loc"), Some(&2));
+    assert_eq!(risk.by_code_prefix.get("supply_chain"), Some(&1));
+    assert_eq!(
+        risk.by_code_prefix.values().sum::<usize>(),
+        risk.findings_total
+    );
+}
+
+#[test]
+fn agent_digest_wrapper_without_suppressed_keeps_defaults() {
+    let decision = test_decision();
+    let findings = vec![test_finding(
+        "finding.boundary.rule_violation",
+        "policy_theater",
+        Some("Fix boundary"),
+    )];
+    let digest = build_agent_digest(&decision, &[], &findings);
+    assert_eq!(digest.suppressed_count, 0);
+    assert!(digest.suppressed_top_codes.is_empty());
+}
+
+#[test]
+fn agent_digest_with_suppressed_reports_top_codes() {
+    let decision = test_decision();
+    let findings = vec![test_finding(
+        "finding.boundary.rule_violation",
+        "policy_theater",
+        Some("Fix boundary"),
+    )];
+    let suppressed = vec![
+        Violation::observation("exception.expired", "x", None, None),
+        Violation::observation("exception.expired", "x", None, None),
+        Violation::observation("loc.max_exceeded", "x", None, None),
+        Violation::observation("boundary.rule_violation", "x", None, None),
+    ];
+    let digest = build_agent_digest_with_suppressed(&decision, &[], &findings, &suppressed);
+    assert_eq!(digest.suppressed_count, 4);
+    assert_eq!(
+        digest.suppressed_top_codes,
+        vec![
+            "exception.expired".to_string(),
+            "boundary.rule_violation".to_string(),
+            "loc.max_exceeded".to_string()
+        ]
+    );
+}
+
+#[test]
+fn agent_digest_ranks_hotspot_files_by_finding_count() {
+    let at = |path: &str| {
+        Violation::blocking("boundary.rule_violation", "x", Some(path.to_string()), None)
+    };
+    let findings = to_findings_v2(&[
+        at("src/b.rs"),
+        at("src/hot.rs"),
+        at("src/a.rs"),
+        at("src/hot.rs"),
+        at("src/c.rs"),
+        at("src/hot.rs"),
+        at("src/c.rs"),
+        Violation::blocking("supply_chain.lockfile_missing", "x", None, None),
+    ]);
+    let digest = build_agent_digest(&test_decision(), &[], &findings);
+    assert_eq!(
+        digest.hotspot_files,
+        vec![
+            FileHotspot {
+                path: "src/hot.rs".to_string(),
+                count: 3
+            },
+            FileHotspot {
+                path: "src/c.rs".to_string(),
+                count: 2
+            },
+            FileHotspot {
+                path: "src/a.rs".to_string(),
+                count: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn heavy_duplicates_weigh_more_on_trust() {
+    let dup = |severity: &str| {
+        Violation::observation(
+            "duplicates.found",
+            "duplicate files found".to_string(),
+            None,
+            Some(serde_json::json!({ "severity": severity })),
+        )
+    };
+    let medium = to_findings_v2(&[dup("medium")]);
+    let high = to_findings_v2(&[dup("high")]);
+    assert_eq!(medium[0].details.severity, FindingSeverity::Medium);
+    assert_eq!(high[0].details.severity, FindingSeverity::High);
+    assert!(
+        build_trust_score(&high, true, 100.0).score < build_trust_score(&medium, true, 100.0).score
+    );
+}
+
+#[test]
+fn fingerprint_ignores_line_shifts_but_tracks_rule_context() {
+    let at_line = |path: &str, rule_id: &str, line: u64| {
+        Violation::blocking(
+            "boundary.rule_violation",
+            format!("avoid unwrap (line {line})"),
+            Some(path.to_string()),
+            Some(serde_json::json!({
+                "rule_id": rule_id,
+                "line": line,
+                "matched": "unwrap(",
+            })),
+        )
+    };
+    let before = to_findings_v2(&[at_line("src/lib.rs", "no-unwrap", 12)]);
+    let shifted = to_findings_v2(&[at_line("./src\\lib.rs", "no-unwrap", 40)]);
+    assert_eq!(before[0].fingerprint.len(), 64);
+    assert_eq!(before[0].fingerprint, shifted[0].fingerprint);
+
+    let other_rule = to_findings_v2(&[at_line("src/lib.rs", "no-expect", 12)]);
+    let other_path = to_findings_v2(&[at_line("src/main.rs", "no-unwrap", 12)]);
+    assert_ne!(before[0].fingerprint, other_rule[0].fingerprint);
+    assert_ne!(before[0].fingerprint, other_path[0].fingerprint);
+}
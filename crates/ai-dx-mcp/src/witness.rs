@@ -1,10 +1,21 @@
 use crate::api::{ApiError, GateKind, GateOutput, WitnessMeta};
 use crate::hash::sha256_hex;
 use serde::{Deserialize, Serialize};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
+mod diff;
+
+pub use diff::GateDiffOutput;
+pub(crate) use diff::diff_witness_files;
+
+const DEFAULT_WITNESS_DIR: &str = ".agents/mcp/compas/witness";
 const WITNESS_MAX_FILES: usize = 20;
 const WITNESS_MAX_TOTAL_BYTES: u64 = 2 * 1024 * 1024;
+/// Keys that differ between runs of an unchanged gate; nulled (at any depth) for `content_sha256`.
+const VOLATILE_WITNESS_KEYS: &[&str] = &["timings_ms", "duration_ms"];
+/// Fields of the top-level `job` object nulled for `content_sha256`; elsewhere (e.g. an
+/// allowlist entry's `expires_at` in violation details) these keys are content.
+const VOLATILE_JOB_KEYS: &[&str] = &["job_id", "created_at", "updated_at", "expires_at"];
 
 fn gate_kind_slug(kind: GateKind) -> &'static str {
     match kind {
@@ -92,6 +103,38 @@ fn rotate_witness_dir(dir: &Path, keep_path: &Path) -> Result<usize, std::io::Er
     rotate_witness_dir_with_limits(dir, keep_path, WITNESS_MAX_FILES, WITNESS_MAX_TOTAL_BYTES)
 }
 
+fn null_volatile_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                if VOLATILE_WITNESS_KEYS.contains(&key.as_str()) {
+                    *v = serde_json::Value::Null;
+                } else {
+                    null_volatile_keys(v);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(null_volatile_keys),
+        _ => {}
+    }
+}
+
+/// Timestamp-independent hash of the witness payload (see `VOLATILE_WITNESS_KEYS`).
+fn witness_content_sha256(out: &GateOutput) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(out)?;
+    null_volatile_keys(&mut value);
+    if let Some(serde_json::Value::Object(job)) = value.get_mut("job") {
+        for key in VOLATILE_JOB_KEYS {
+            if let Some(v) = job.get_mut(*key) {
+                *v = serde_json::Value::Null;
+            }
+        }
+    }
+    Ok(sha256_hex(
+        crate::canonical_json::to_canonical_string(&value).as_bytes(),
+    ))
+}
+
 fn compute_entry_hash(
     prev_hash: &str,
     witness_sha256: &str,
@@ -175,32 +218,85 @@ pub(crate) fn append_chain_entry(
     Ok(entry)
 }
 
+/// `--witness-dir` override first, then `[proof] witness_dir`, then the default location.
+fn resolve_witness_dir(repo_root: &Path, override_dir: Option<&str>) -> Result<String, String> {
+    let configured = match override_dir {
+        Some(dir) => Some(dir.to_string()),
+        None => crate::repo::load_repo_config(repo_root)
+            .ok()
+            .and_then(|cfg| cfg.quality_contract)
+            .and_then(|contract| contract.proof.witness_dir),
+    };
+    let Some(raw) = configured else {
+        return Ok(DEFAULT_WITNESS_DIR.to_string());
+    };
+    let rel = crate::repo::safe_relative_path(raw.trim(), "witness dir")?
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(v) => Some(v.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    if rel.is_empty() {
+        return Err(format!(
+            "witness dir must name a directory below the repo root: {raw:?}"
+        ));
+    }
+    Ok(rel)
+}
+
+/// Rejects the witness dir when symlinks resolve it outside the repo root, then creates it;
+/// checking first means nothing is created outside the root.
+fn ensure_witness_dir(repo_root: &Path, dir: &Path) -> Result<(), String> {
+    crate::repo::ensure_within_repo_root(repo_root, dir, &format!("witness dir {dir:?}"))?;
+    std::fs::create_dir_all(dir).map_err(|e| format!("failed to create witness dir {dir:?}: {e}"))
+}
+
 pub(crate) fn maybe_write_gate_witness(
     repo_root: &Path,
     kind: GateKind,
     write_witness: bool,
+    witness_dir: Option<&str>,
+    explain_verdict: bool,
     mut out: GateOutput,
 ) -> GateOutput {
     if !write_witness {
         return out;
     }
+    // The witness records what the caller is shown: reason provenance only on request.
+    if !explain_verdict {
+        out = crate::response::without_verdict_sources(out);
+    }
 
+    if out.config_hash.is_none() {
+        // An unloadable config leaves the hash unset; the gate output already carries that error.
+        out.config_hash = crate::app::effective_checks_hash(repo_root).ok();
+    }
     out.validate.evidence = crate::evidence::build_validate_envelope(&out.validate);
     out.evidence = crate::evidence::build_gate_envelope(&out);
 
-    let witness_rel = format!(
-        ".agents/mcp/compas/witness/gate_{}.json",
-        gate_kind_slug(kind)
-    );
+    let witness_dir = match resolve_witness_dir(repo_root, witness_dir) {
+        Ok(dir) => dir,
+        Err(message) => {
+            out.ok = false;
+            out.error = Some(ApiError {
+                code: "witness.dir_invalid".to_string(),
+                message,
+            });
+            out.witness_path = None;
+            out.witness = None;
+            return out;
+        }
+    };
+    let witness_rel = format!("{witness_dir}/gate_{}.json", gate_kind_slug(kind));
     let witness_path = repo_root.join(&witness_rel);
 
-    if let Some(parent) = witness_path.parent()
-        && let Err(e) = std::fs::create_dir_all(parent)
-    {
+    if let Err(message) = ensure_witness_dir(repo_root, &repo_root.join(&witness_dir)) {
         out.ok = false;
         out.error = Some(ApiError {
             code: "witness.write_failed".to_string(),
-            message: format!("failed to create witness dir {:?}: {e}", parent),
+            message,
         });
         out.witness_path = None;
         out.witness = None;
@@ -222,6 +318,20 @@ pub(crate) fn maybe_write_gate_witness(
         }
     };
 
+    let content_sha256 = match witness_content_sha256(&out) {
+        Ok(hash) => hash,
+        Err(e) => {
+            out.ok = false;
+            out.error = Some(ApiError {
+                code: "witness.write_failed".to_string(),
+                message: format!("failed to hash witness content {witness_rel:?}: {e}"),
+            });
+            out.witness_path = None;
+            out.witness = None;
+            return out;
+        }
+    };
+
     let bytes = json.as_bytes();
     if let Err(e) = std::fs::write(&witness_path, bytes) {
         out.ok = false;
@@ -235,7 +345,7 @@ pub(crate) fn maybe_write_gate_witness(
     }
 
     // Append to hash-chain (fail-closed).
-    let chain_path = repo_root.join(".agents/mcp/compas/witness/chain.json");
+    let chain_path = repo_root.join(&witness_dir).join("chain.json");
     if let Err(e) = append_chain_entry(
         &chain_path,
         gate_kind_slug(kind),
@@ -271,6 +381,7 @@ pub(crate) fn maybe_write_gate_witness(
         path: witness_rel,
         size_bytes: bytes.len(),
         sha256: sha256_hex(bytes),
+        content_sha256,
         rotated_files,
     });
     out
@@ -299,6 +410,20 @@ mod tests {
         assert!(keep.exists());
     }
 
+    #[test]
+    fn witness_dir_escaping_through_a_symlink_is_rejected_before_creation() {
+        let repo = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        std::os::unix::fs::symlink(outside.path(), repo.path().join("link")).unwrap();
+
+        let err = ensure_witness_dir(repo.path(), &repo.path().join("link/witness")).unwrap_err();
+        assert!(err.contains("outside the repo root"), "{err}");
+        assert!(!outside.path().join("witness").exists());
+
+        ensure_witness_dir(repo.path(), &repo.path().join("w/nested")).unwrap();
+        assert!(repo.path().join("w/nested").is_dir());
+    }
+
     #[test]
     fn witness_chain_append_and_verify() {
         let dir = tempfile::tempdir().unwrap();
@@ -333,10 +458,8 @@ mod tests {
         assert!(!verify_chain_integrity(&chain));
     }
 
-    #[test]
-    fn witness_meta_written() {
-        let dir = tempfile::tempdir().unwrap();
-        let out = GateOutput {
+    fn gate_output() -> GateOutput {
+        GateOutput {
             ok: true,
             error: None,
             repo_root: ".".to_string(),
@@ -373,6 +496,13 @@ mod tests {
                 summary_md: None,
                 evidence: crate::api::EvidenceEnvelope::default(),
                 payload_meta: None,
+                timings_ms: None,
+                baseline_diff: None,
+                disabled_packs: vec![],
+                partial_run: None,
+                check_errors: vec![],
+                swamped: false,
+                swamped_summary: None,
             },
             receipts: vec![],
             witness_path: None,
@@ -395,18 +525,92 @@ mod tests {
             job: None,
             job_state: None,
             job_error: None,
-        };
+            all_findings: vec![],
+            config_hash: None,
+        }
+    }
 
-        let out = maybe_write_gate_witness(dir.path(), GateKind::CiFast, true, out);
+    #[test]
+    fn witness_meta_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = gate_output();
+
+        let out = maybe_write_gate_witness(dir.path(), GateKind::CiFast, true, None, false, out);
         assert!(out.ok);
         assert!(out.witness_path.is_some());
         let meta = out.witness.expect("witness meta");
         assert!(meta.size_bytes > 0);
         assert_eq!(meta.sha256.len(), 64);
+        assert_eq!(meta.content_sha256.len(), 64);
         assert!(
             dir.path()
                 .join(".agents/mcp/compas/witness/chain.json")
                 .is_file()
         );
     }
+
+    fn receipt(duration_ms: u64) -> crate::api::Receipt {
+        crate::api::Receipt {
+            tool_id: "cargo-test".to_string(),
+            success: true,
+            exit_code: Some(0),
+            timed_out: false,
+            duration_ms,
+            command: "cargo".to_string(),
+            args: vec!["test".to_string()],
+            stdout_tail: String::new(),
+            stderr_tail: String::new(),
+            stdout_bytes: 0,
+            stderr_bytes: 0,
+            stdout_sha256: sha256_hex(b""),
+            stderr_sha256: sha256_hex(b""),
+            structured_report: None,
+        }
+    }
+
+    #[test]
+    fn content_sha256_ignores_timings_but_tracks_decisions() {
+        let write = |duration_ms: u64, loc_ms: u64, ok: bool| {
+            let dir = tempfile::tempdir().unwrap();
+            let mut out = gate_output();
+            out.ok = ok;
+            out.receipts = vec![receipt(duration_ms)];
+            out.validate.timings_ms = Some([("loc".to_string(), loc_ms)].into());
+            let out =
+                maybe_write_gate_witness(dir.path(), GateKind::CiFast, true, None, false, out);
+            out.witness.expect("witness meta")
+        };
+
+        let first = write(120, 3, true);
+        let second = write(4_500, 41, true);
+        assert_ne!(first.sha256, second.sha256);
+        assert_eq!(first.content_sha256, second.content_sha256);
+
+        let failed = write(120, 3, false);
+        assert_ne!(first.content_sha256, failed.content_sha256);
+    }
+
+    #[test]
+    fn content_sha256_ignores_job_timestamps_but_tracks_nested_expires_at() {
+        let hash = |stamp: &str, allowlist_expiry: &str| {
+            let mut out = gate_output();
+            out.job = Some(crate::api::JobInfo {
+                job_id: format!("gate-{stamp}"),
+                state: crate::api::GateJobState::Succeeded,
+                created_at: stamp.to_string(),
+                updated_at: stamp.to_string(),
+                expires_at: stamp.to_string(),
+            });
+            out.validate.violations = vec![crate::api::Violation::blocking(
+                "exception.expired",
+                "exception expired",
+                Some(".agents/mcp/compas/allowlist.toml".to_string()),
+                Some(serde_json::json!({ "id": "e1", "expires_at": allowlist_expiry })),
+            )];
+            witness_content_sha256(&out).expect("content hash")
+        };
+        let first = hash("2026-01-01", "2026-03-01");
+        assert_eq!(first, hash("2026-02-01", "2026-03-01"));
+        assert_ne!(first, hash("2026-01-01", "2026-04-01"));
+    }
 }
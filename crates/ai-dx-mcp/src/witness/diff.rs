@@ -0,0 +1,185 @@
+use crate::api::{ApiError, GateOutput, Receipt};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReceiptDigest {
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolReceiptDiff {
+    pub tool_id: String,
+    /// `None` when the tool did not run in that witness.
+    pub a: Option<ReceiptDigest>,
+    pub b: Option<ReceiptDigest>,
+    /// Presence, success, exit_code or timed_out differ (duration alone does not count).
+    pub changed: bool,
+    pub duration_delta_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
+pub struct ViolationRef {
+    pub code: String,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GateDiffOutput {
+    pub ok: bool,
+    pub error: Option<ApiError>,
+    pub a_path: String,
+    pub b_path: String,
+    pub a_ok: Option<bool>,
+    pub b_ok: Option<bool>,
+    pub a_config_hash: Option<String>,
+    pub b_config_hash: Option<String>,
+    /// Both witnesses record a config hash and they differ: the gates ran under different policy.
+    pub config_hash_mismatch: bool,
+    pub tools: Vec<ToolReceiptDiff>,
+    /// Present in `b` but not in `a`.
+    pub violations_appeared: Vec<ViolationRef>,
+    /// Present in `a` but not in `b`.
+    pub violations_disappeared: Vec<ViolationRef>,
+}
+
+fn load_gate_witness(path: &Path) -> Result<GateOutput, String> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read witness {:?}: {e}", path))?;
+    serde_json::from_str(&raw).map_err(|e| format!("failed to parse witness {:?}: {e}", path))
+}
+
+fn digest(r: &Receipt) -> ReceiptDigest {
+    ReceiptDigest {
+        success: r.success,
+        exit_code: r.exit_code,
+        timed_out: r.timed_out,
+        duration_ms: r.duration_ms,
+    }
+}
+
+fn receipts_by_tool(out: &GateOutput) -> BTreeMap<String, ReceiptDigest> {
+    out.receipts
+        .iter()
+        .map(|r| (r.tool_id.clone(), digest(r)))
+        .collect()
+}
+
+/// Validate violations keep their path; gate-only reasons (receipt/impact) are keyed by code.
+fn violation_refs(out: &GateOutput) -> BTreeSet<ViolationRef> {
+    let mut refs: BTreeSet<ViolationRef> = out
+        .validate
+        .violations
+        .iter()
+        .map(|v| ViolationRef {
+            code: v.code.clone(),
+            path: v.path.clone(),
+        })
+        .collect();
+    let validate_codes: BTreeSet<&str> = out
+        .validate
+        .violations
+        .iter()
+        .map(|v| v.code.as_str())
+        .collect();
+    if let Some(verdict) = &out.verdict {
+        for reason in &verdict.decision.reasons {
+            if !validate_codes.contains(reason.code.as_str()) {
+                refs.insert(ViolationRef {
+                    code: reason.code.clone(),
+                    path: None,
+                });
+            }
+        }
+    }
+    refs
+}
+
+pub(crate) fn diff_gate_outputs(
+    a: &GateOutput,
+    b: &GateOutput,
+) -> (Vec<ToolReceiptDiff>, Vec<ViolationRef>, Vec<ViolationRef>) {
+    let a_receipts = receipts_by_tool(a);
+    let b_receipts = receipts_by_tool(b);
+    let tool_ids: BTreeSet<&String> = a_receipts.keys().chain(b_receipts.keys()).collect();
+    let tools = tool_ids
+        .into_iter()
+        .map(|tool_id| {
+            let a = a_receipts.get(tool_id).cloned();
+            let b = b_receipts.get(tool_id).cloned();
+            let changed = match (&a, &b) {
+                (Some(x), Some(y)) => {
+                    x.success != y.success
+                        || x.exit_code != y.exit_code
+                        || x.timed_out != y.timed_out
+                }
+                _ => true,
+            };
+            let duration_delta_ms = match (&a, &b) {
+                (Some(x), Some(y)) => Some(y.duration_ms as i64 - x.duration_ms as i64),
+                _ => None,
+            };
+            ToolReceiptDiff {
+                tool_id: tool_id.clone(),
+                a,
+                b,
+                changed,
+                duration_delta_ms,
+            }
+        })
+        .collect();
+
+    let a_refs = violation_refs(a);
+    let b_refs = violation_refs(b);
+    let appeared = b_refs.difference(&a_refs).cloned().collect();
+    let disappeared = a_refs.difference(&b_refs).cloned().collect();
+    (tools, appeared, disappeared)
+}
+
+pub(crate) fn diff_witness_files(a_path: &str, b_path: &str) -> GateDiffOutput {
+    let mut out = GateDiffOutput {
+        ok: false,
+        error: None,
+        a_path: a_path.to_string(),
+        b_path: b_path.to_string(),
+        a_ok: None,
+        b_ok: None,
+        a_config_hash: None,
+        b_config_hash: None,
+        config_hash_mismatch: false,
+        tools: vec![],
+        violations_appeared: vec![],
+        violations_disappeared: vec![],
+    };
+    let loaded = load_gate_witness(Path::new(a_path))
+        .and_then(|a| load_gate_witness(Path::new(b_path)).map(|b| (a, b)));
+    let (a, b) = match loaded {
+        Ok(v) => v,
+        Err(message) => {
+            out.error = Some(ApiError {
+                code: "witness.diff_load_failed".to_string(),
+                message,
+            });
+            return out;
+        }
+    };
+    let (tools, appeared, disappeared) = diff_gate_outputs(&a, &b);
+    out.ok = true;
+    out.a_ok = Some(a.ok);
+    out.b_ok = Some(b.ok);
+    out.config_hash_mismatch = matches!(
+        (&a.config_hash, &b.config_hash),
+        (Some(x), Some(y)) if x != y
+    );
+    out.a_config_hash = a.config_hash;
+    out.b_config_hash = b.config_hash;
+    out.tools = tools;
+    out.violations_appeared = appeared;
+    out.violations_disappeared = disappeared;
+    out
+}
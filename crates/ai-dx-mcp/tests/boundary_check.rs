@@ -1,5 +1,5 @@
 use ai_dx_mcp::{
-    checks::boundary::run_boundary_check,
+    checks::boundary::{match_boundary_rule, run_boundary_check},
     config::{BoundaryCheckConfigV2, BoundaryRuleConfigV2},
 };
 use tempfile::tempdir;
@@ -10,6 +10,8 @@ fn cfg(regex: &str) -> BoundaryCheckConfigV2 {
         include_globs: vec!["crates/**/*.rs".to_string()],
         exclude_globs: vec![],
         strip_rust_cfg_test_blocks: false,
+        respect_gitignore: false,
+        max_depth: None,
         rules: vec![BoundaryRuleConfigV2 {
             id: "rule-1".to_string(),
             message: Some("no glob imports".to_string()),
@@ -108,3 +110,101 @@ mod tests {
     let result = run_boundary_check(repo, &cfg).unwrap();
     assert_eq!(result.violations.len(), 1, "{:?}", result.violations);
 }
+
+#[test]
+fn match_boundary_rule_reports_every_match_with_line_and_column() {
+    let cfg = cfg(r"\.unwrap\s*\(");
+    let source = "fn a() {\n    x.unwrap();\n}\nfn b() { y.unwrap() }\n".to_string();
+    let matches = match_boundary_rule(&cfg, &cfg.rules[0], "src/lib.rs", source).unwrap();
+    let spans: Vec<(usize, usize, &str)> = matches
+        .iter()
+        .map(|m| (m.line, m.column, m.matched.as_str()))
+        .collect();
+    assert_eq!(spans, vec![(2, 6, ".unwrap("), (4, 11, ".unwrap(")]);
+    assert_eq!(matches[0].end - matches[0].start, ".unwrap(".len());
+}
+
+#[test]
+fn validate_lists_broken_boundary_rule_under_check_errors() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default")).unwrap();
+    std::fs::create_dir_all(repo.join("src")).unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "check_errors test plugin"
+
+[[checks.boundary]]
+id = "broken"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "unbalanced"
+deny_regex = "("
+
+[[checks.boundary]]
+id = "no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+deny_regex = "\\.unwrap\\(\\)"
+
+[[checks.naming_convention]]
+id = "names"
+include_globs = ["src/**/*.rs"]
+
+[[checks.naming_convention.rules]]
+language = "rust"
+file_name_regex = "("
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join("src/lib.rs"),
+        "pub fn one() -> i32 { Some(1).unwrap() }\n",
+    )
+    .unwrap();
+
+    let out = ai_dx_mcp::app::validate(
+        &repo.to_string_lossy(),
+        ai_dx_mcp::api::ValidateMode::Warn,
+        false,
+        None,
+    );
+    assert_eq!(out.check_errors.len(), 2, "{:?}", out.check_errors);
+    let err = &out.check_errors[0];
+    assert_eq!(
+        (err.check.as_str(), err.id.as_str()),
+        ("boundary", "broken")
+    );
+    assert!(
+        err.message
+            .contains("failed to compile boundary rule regex"),
+        "{}",
+        err.message
+    );
+    assert!(
+        out.violations
+            .iter()
+            .any(|v| v.code == "boundary.check_failed")
+    );
+    let naming = &out.check_errors[1];
+    assert_eq!(
+        (naming.check.as_str(), naming.id.as_str()),
+        ("naming_convention", "names")
+    );
+    assert!(
+        out.violations
+            .iter()
+            .any(|v| v.code == "boundary.rule_violation"),
+        "the healthy check still reports policy violations"
+    );
+}
@@ -0,0 +1,86 @@
+use serde_json::Value;
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "boundary-test plugin"
+
+[[checks.boundary]]
+id = "rust-no-unwrap"
+include_globs = ["src/**/*.rs"]
+strip_rust_cfg_test_blocks = true
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+deny_regex = 'unwrap\('
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join("candidate.rs"),
+        "fn parse(s: &str) -> u32 {\n    s.parse().unwrap()\n}\n\n#[cfg(test)]\nmod tests {\n    fn t() { None::<u8>.unwrap(); }\n}\n",
+    )
+    .expect("write candidate");
+}
+
+fn run_cli(repo: &Path, args: &[&str]) -> (Option<i32>, Value) {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .arg("boundary-test")
+        .args(args)
+        .arg("--repo-root")
+        .arg(repo)
+        .output()
+        .expect("run compas cli");
+    let payload = serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout must be JSON ({e}): {}",
+            String::from_utf8_lossy(&out.stdout)
+        )
+    });
+    (out.status.code(), payload)
+}
+
+#[test]
+fn boundary_test_reports_matched_line_for_configured_rule() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path());
+    let input = dir.path().join("candidate.rs");
+
+    let (code, payload) = run_cli(
+        dir.path(),
+        &["--rule", "no-unwrap", "--input", &input.to_string_lossy()],
+    );
+    assert_eq!(code, Some(0), "{payload}");
+    assert_eq!(payload["check_id"], "rust-no-unwrap");
+    let matches = payload["matches"].as_array().expect("matches");
+    // The cfg(test) module is stripped exactly like validate does.
+    assert_eq!(matches.len(), 1, "{payload}");
+    assert_eq!(matches[0]["line"], 2);
+    assert_eq!(matches[0]["column"], 15);
+    assert_eq!(matches[0]["matched"], "unwrap(");
+}
+
+#[test]
+fn boundary_test_unknown_rule_fails_with_code() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path());
+    let input = dir.path().join("candidate.rs");
+
+    let (code, payload) = run_cli(
+        dir.path(),
+        &["--rule", "missing", "--input", &input.to_string_lossy()],
+    );
+    assert_eq!(code, Some(1));
+    assert_eq!(payload["ok"], false);
+    assert_eq!(payload["error"]["code"], "boundary_test.rule_not_found");
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+fn run_cli(args: &[&str]) -> std::process::Output {
+    std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(args)
+        .output()
+        .expect("run compas cli")
+}
+
+fn canonicalize(path: &Path) -> String {
+    let out = run_cli(&["canonicalize", "--in", &path.to_string_lossy()]);
+    assert!(out.status.success(), "{out:?}");
+    String::from_utf8(out.stdout).expect("utf8 stdout")
+}
+
+#[test]
+fn differently_ordered_equivalent_json_canonicalizes_identically() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let a = dir.path().join("a.json");
+    let b = dir.path().join("b.json");
+    std::fs::write(
+        &a,
+        r#"{"schema":"compas.registry.manifest.v1","archive":{"sha256":"ab","name":"r.tar.gz"},"plugins":[{"id":"x","tags":["b","a"]}]}"#,
+    )
+    .expect("write a");
+    std::fs::write(
+        &b,
+        "{\n  \"plugins\": [ { \"tags\": [\"b\", \"a\"], \"id\": \"x\" } ],\n  \"archive\": {\n    \"name\": \"r.tar.gz\",\n    \"sha256\": \"ab\"\n  },\n  \"schema\": \"compas.registry.manifest.v1\"\n}\n",
+    )
+    .expect("write b");
+
+    let canonical = canonicalize(&a);
+    assert_eq!(canonical, canonicalize(&b));
+    assert_eq!(
+        canonical,
+        r#"{"archive":{"name":"r.tar.gz","sha256":"ab"},"plugins":[{"id":"x","tags":["b","a"]}],"schema":"compas.registry.manifest.v1"}"#
+    );
+}
+
+#[test]
+fn canonicalize_rejects_invalid_json() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let bad = dir.path().join("bad.json");
+    std::fs::write(&bad, "{\"a\": ").expect("write bad");
+    let out = run_cli(&[
+        "--error-format",
+        "json",
+        "canonicalize",
+        "--in",
+        &bad.to_string_lossy(),
+    ]);
+    assert_eq!(out.status.code(), Some(2));
+    let payload: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json error");
+    assert_eq!(payload["error"]["code"], "canonicalize.invalid_json");
+}
+
+#[test]
+fn canonical_json_flag_matches_canonicalize_of_pretty_output() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo = dir.path().to_string_lossy().to_string();
+    let pretty = run_cli(&["init", "--repo-root", &repo]);
+    let pretty_path = dir.path().join("pretty.json");
+    std::fs::write(&pretty_path, &pretty.stdout).expect("write pretty");
+
+    let canonical = run_cli(&["--canonical-json", "init", "--repo-root", &repo]);
+    let canonical = String::from_utf8(canonical.stdout).expect("utf8 stdout");
+    assert_eq!(
+        canonical.strip_suffix('\n').expect("trailing newline"),
+        canonicalize(&pretty_path)
+    );
+}
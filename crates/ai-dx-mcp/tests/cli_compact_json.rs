@@ -0,0 +1,45 @@
+use serde_json::Value;
+use std::path::Path;
+
+fn run_cli(repo: &Path, args: &[&str]) -> std::process::Output {
+    std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(args)
+        .arg("--repo-root")
+        .arg(repo)
+        .output()
+        .expect("run compas cli")
+}
+
+fn assert_compact_matches_pretty(repo: &Path, args: &[&str]) {
+    let pretty = run_cli(repo, args);
+    let mut compact_args = vec!["--compact-json"];
+    compact_args.extend_from_slice(args);
+    let compact = run_cli(repo, &compact_args);
+    assert_eq!(compact.status.code(), pretty.status.code());
+
+    let compact_text = String::from_utf8(compact.stdout).expect("utf8 stdout");
+    let line = compact_text
+        .strip_suffix('\n')
+        .expect("output ends with newline");
+    assert!(!line.contains('\n'), "compact output spans lines: {line}");
+    assert!(
+        String::from_utf8_lossy(&pretty.stdout)
+            .trim_end()
+            .contains('\n'),
+        "pretty output stays the default"
+    );
+
+    let compact: Value = serde_json::from_str(line).expect("compact stdout must be JSON");
+    let pretty: Value = serde_json::from_slice(&pretty.stdout).expect("pretty stdout must be JSON");
+    assert_eq!(compact, pretty);
+}
+
+#[test]
+fn compact_json_prints_single_line_init_and_validate_payloads() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    assert_compact_matches_pretty(dir.path(), &["init"]);
+
+    let applied = run_cli(dir.path(), &["init", "--apply"]);
+    assert!(applied.status.success(), "{applied:?}");
+    assert_compact_matches_pretty(dir.path(), &["validate", "warn"]);
+}
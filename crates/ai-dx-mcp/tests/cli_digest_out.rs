@@ -0,0 +1,41 @@
+mod common;
+
+use common::boundary_repo;
+
+#[test]
+fn digest_out_writes_agent_digest_next_to_full_stdout() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "validate --digest-out test");
+    let digest_path = dir.path().join("digest.json");
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["validate", "strict", "--digest-out"])
+        .arg(&digest_path)
+        .arg("--repo-root")
+        .arg(dir.path())
+        .output()
+        .expect("run compas validate");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(1), "{stdout}");
+    let payload: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is JSON");
+    assert!(payload["findings_v2"].is_array(), "{stdout}");
+
+    let digest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&digest_path).expect("read digest file"))
+            .expect("digest file is JSON");
+    let blockers = digest["top_blockers"].as_array().expect("top_blockers");
+    assert!(
+        blockers
+            .iter()
+            .any(|b| b.as_str().unwrap_or("").contains("boundary.rule_violation")),
+        "{digest}"
+    );
+    assert!(
+        !digest["minimal_fix_steps"]
+            .as_array()
+            .expect("minimal_fix_steps")
+            .is_empty(),
+        "{digest}"
+    );
+    assert_eq!(digest, payload["agent_digest"]);
+}
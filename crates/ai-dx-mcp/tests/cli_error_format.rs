@@ -0,0 +1,65 @@
+use serde_json::Value;
+
+fn run_cli(args: &[&str]) -> std::process::Output {
+    std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(args)
+        .output()
+        .expect("run compas cli")
+}
+
+#[test]
+fn unknown_validate_mode_with_json_error_format_is_parseable() {
+    let out = run_cli(&["validate", "bogus", "--error-format", "json"]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(
+        out.stderr.is_empty(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    assert_eq!(payload["ok"], Value::Bool(false));
+    assert_eq!(payload["error"]["code"], "cli.invalid_args");
+    assert!(
+        payload["error"]["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("unknown validate mode: bogus")),
+        "{payload}"
+    );
+}
+
+#[test]
+fn human_error_format_stays_default() {
+    let out = run_cli(&["validate", "bogus"]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(out.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("compas: unknown validate mode: bogus"));
+}
+
+#[test]
+fn unknown_command_with_json_error_format_reports_code() {
+    let out = run_cli(&["--error-format", "json", "frobnicate"]);
+    assert_eq!(out.status.code(), Some(2));
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    assert_eq!(payload["error"]["code"], "cli.unknown_command");
+}
+
+#[test]
+fn runtime_error_with_json_error_format_exits_2() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let missing = dir.path().join("missing.json");
+    let out = run_cli(&[
+        "--error-format",
+        "json",
+        "plugins",
+        "list",
+        "--registry",
+        &missing.to_string_lossy(),
+        "--repo-root",
+        &dir.path().to_string_lossy(),
+    ]);
+    assert_eq!(out.status.code(), Some(2));
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    assert_eq!(payload["ok"], Value::Bool(false));
+    assert_eq!(payload["error"]["code"], "plugins.failed");
+}
@@ -0,0 +1,60 @@
+use serde_json::Value;
+use std::path::Path;
+
+fn run_cli(repo: &Path) -> (Option<i32>, Value) {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .arg("exceptions")
+        .arg("--repo-root")
+        .arg(repo)
+        .output()
+        .expect("run compas cli");
+    let payload = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    (out.status.code(), payload)
+}
+
+#[test]
+fn exceptions_report_lists_entry_with_remaining_days() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    std::fs::create_dir_all(dir.path().join(".agents/mcp/compas")).expect("mkdir");
+    let expires = chrono::Utc::now().date_naive() + chrono::Duration::days(12);
+    std::fs::write(
+        dir.path().join(".agents/mcp/compas/allowlist.toml"),
+        format!(
+            r#"
+[[exceptions]]
+id = "loc-big-file"
+rule = "loc.max_exceeded"
+path = "src/big.rs"
+owner = "team-core"
+reason = "split tracked in roadmap"
+expires_at = "{}"
+"#,
+            expires.format("%Y-%m-%d")
+        ),
+    )
+    .expect("write allowlist");
+
+    let (code, payload) = run_cli(dir.path());
+    assert_eq!(code, Some(0), "{payload}");
+    let entries = payload["exceptions"].as_array().expect("exceptions");
+    assert_eq!(entries.len(), 1, "{payload}");
+    assert_eq!(entries[0]["code"], "loc.max_exceeded");
+    assert_eq!(entries[0]["path"], "src/big.rs");
+    assert_eq!(entries[0]["owner"], "team-core");
+    assert_eq!(entries[0]["days_until_expiry"], 12);
+}
+
+#[test]
+fn exceptions_report_fails_closed_on_invalid_allowlist() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    std::fs::create_dir_all(dir.path().join(".agents/mcp/compas")).expect("mkdir");
+    std::fs::write(
+        dir.path().join(".agents/mcp/compas/allowlist.toml"),
+        "[[exceptions]]\nid = \"x\"\n",
+    )
+    .expect("write allowlist");
+
+    let (code, payload) = run_cli(dir.path());
+    assert_eq!(code, Some(1));
+    assert_eq!(payload["error"]["code"], "exception.allowlist_invalid");
+}
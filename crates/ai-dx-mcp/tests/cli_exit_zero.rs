@@ -0,0 +1,40 @@
+mod common;
+
+use common::boundary_repo;
+use std::path::Path;
+
+fn run_validate(repo_root: &Path, global_flags: &[&str]) -> (Option<i32>, serde_json::Value) {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(global_flags)
+        .args(["validate", "strict", "--repo-root"])
+        .arg(repo_root)
+        .output()
+        .expect("run compas validate");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let json = serde_json::from_str(&stdout).expect("validate prints JSON");
+    (out.status.code(), json)
+}
+
+#[test]
+fn exit_zero_keeps_blocked_verdict_but_exits_zero() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "--exit-zero test");
+
+    let (code, json) = run_validate(dir.path(), &[]);
+    assert_eq!(code, Some(1));
+    assert_eq!(json["ok"], false);
+
+    let (code, json) = run_validate(dir.path(), &["--exit-zero"]);
+    assert_eq!(code, Some(0), "{json}");
+    assert_eq!(json["ok"], false, "{json}");
+    assert_eq!(json["verdict"]["decision"]["status"], "blocked", "{json}");
+}
+
+#[test]
+fn exit_zero_does_not_hide_usage_errors() {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["--exit-zero", "validate", "--no-such-flag"])
+        .output()
+        .expect("run compas validate");
+    assert_eq!(out.status.code(), Some(2));
+}
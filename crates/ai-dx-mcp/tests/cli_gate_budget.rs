@@ -0,0 +1,106 @@
+use serde_json::Value;
+use std::time::{Duration, Instant};
+
+fn write_slow_repo(repo: &std::path::Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "Gate budget CLI test plugin"
+
+[[tools]]
+id = "slow-tool"
+description = "Sleeps longer than the gate budget"
+command = "python3"
+args = ["-c", "import time; time.sleep(5)"]
+
+[gate]
+ci_fast = ["slow-tool"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+"#,
+    )
+    .expect("write quality_contract.toml");
+}
+
+fn reason_codes(payload: &Value) -> Vec<String> {
+    payload["verdict"]["decision"]["reasons"]
+        .as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|r| r["code"].as_str().map(ToString::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn gate_budget_ms_caps_wall_time_with_transient_violation() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_slow_repo(dir.path());
+
+    let started = Instant::now();
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["gate", "ci_fast", "--budget-ms", "1", "--repo-root"])
+        .arg(dir.path())
+        .env_remove("AI_DX_GATE_BUDGET_MS")
+        .output()
+        .expect("run compas gate");
+    assert!(
+        started.elapsed() < Duration::from_secs(4),
+        "budget must stop the slow tool early"
+    );
+    assert_eq!(out.status.code(), Some(1));
+
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    assert_eq!(payload["ok"], Value::Bool(false));
+    assert!(
+        reason_codes(&payload)
+            .iter()
+            .any(|c| c == "gate.run_failed_transient"),
+        "{payload}"
+    );
+}
+
+#[test]
+fn gate_budget_env_is_used_and_invalid_values_fail() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_slow_repo(dir.path());
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["gate", "ci_fast", "--repo-root"])
+        .arg(dir.path())
+        .env("AI_DX_GATE_BUDGET_MS", "1")
+        .output()
+        .expect("run compas gate");
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    assert!(
+        reason_codes(&payload)
+            .iter()
+            .any(|c| c == "gate.run_failed_transient"),
+        "{payload}"
+    );
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["gate", "ci_fast", "--repo-root"])
+        .arg(dir.path())
+        .env("AI_DX_GATE_BUDGET_MS", "soon")
+        .output()
+        .expect("run compas gate");
+    assert_eq!(out.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("AI_DX_GATE_BUDGET_MS expects"));
+}
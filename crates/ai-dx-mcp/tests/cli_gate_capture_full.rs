@@ -0,0 +1,88 @@
+mod common;
+
+use common::{write_file, write_quality_contract};
+use serde_json::Value;
+use std::path::Path;
+
+/// One tool printing ~60 KB (numbered lines) with a 100-byte stdout/stderr cap.
+fn chatty_repo(repo_root: &Path) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "gate --capture-full test"
+
+[[tools]]
+id = "chatty"
+description = "Prints a long log on both streams"
+command = "sh"
+args = ["-c", "i=0; while [ $i -lt 6000 ]; do echo line-$i; echo err-$i >&2; i=$((i+1)); done"]
+max_stdout_bytes = 100
+max_stderr_bytes = 100
+
+[gate]
+ci_fast = ["chatty"]
+ci = []
+flagship = []
+"#,
+    );
+    write_quality_contract(
+        repo_root,
+        r#"
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+"#,
+    );
+}
+
+fn run_gate(repo_root: &Path, extra: &[&str]) -> Value {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["gate", "ci_fast", "--repo-root"])
+        .arg(repo_root)
+        .args(extra)
+        .output()
+        .expect("run compas gate");
+    serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout must be JSON ({e}): {}",
+            String::from_utf8_lossy(&out.stdout)
+        )
+    })
+}
+
+#[test]
+fn capture_full_keeps_longer_tails_with_the_same_stream_hashes() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    chatty_repo(dir.path());
+
+    let capped = run_gate(dir.path(), &[]);
+    let full = run_gate(dir.path(), &["--capture-full"]);
+    assert_eq!(capped["ok"], true, "{capped}");
+    assert_eq!(full["ok"], true, "{full}");
+
+    let capped = &capped["receipts"][0];
+    let full = &full["receipts"][0];
+    for stream in ["stdout", "stderr"] {
+        let tail = format!("{stream}_tail");
+        let bytes = format!("{stream}_bytes");
+        let sha = format!("{stream}_sha256");
+        let capped_tail = capped[&tail].as_str().expect("capped tail");
+        let full_tail = full[&tail].as_str().expect("full tail");
+        assert!(capped_tail.len() <= 100, "{stream}: {}", capped_tail.len());
+        assert_eq!(full[&bytes], capped[&bytes], "{stream}");
+        assert_eq!(
+            full_tail.len() as u64,
+            full[&bytes].as_u64().expect("stream bytes"),
+            "{stream}: the whole stream fits under the ceiling"
+        );
+        assert!(full_tail.ends_with(capped_tail), "{stream}");
+        assert_eq!(full[&sha], capped[&sha], "{stream}");
+    }
+    assert!(
+        full["stdout_tail"]
+            .as_str()
+            .is_some_and(|t| t.starts_with("line-0\n"))
+    );
+}
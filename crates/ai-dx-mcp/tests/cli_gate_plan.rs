@@ -0,0 +1,130 @@
+mod common;
+
+use common::{write_file, write_quality_contract};
+use serde_json::Value;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+fn git(repo_root: &Path, args: &[&str]) {
+    let out = Command::new("git")
+        .args(args)
+        .current_dir(repo_root)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .expect("run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+/// Three tools that would leave a `ran-<id>` marker if the plan ever executed them.
+fn plan_repo(repo_root: &Path, impact: &str) {
+    let tool = |id: &str| {
+        format!(
+            "[[tools]]\nid = \"{id}\"\ndescription = \"Marks that {id} ran\"\ncommand = \"sh\"\nargs = [\"-c\", \"touch ran-{id}\"]\n"
+        )
+    };
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        &format!(
+            "[plugin]\nid = \"default\"\ndescription = \"gate --print-plan test\"\n\n{}\n{}\n{}\n[gate]\nci_fast = [\"lint\", \"unit\"]\nci = [\"lint\", \"unit\", \"e2e\"]\nflagship = []\n",
+            tool("lint"),
+            tool("unit"),
+            tool("e2e")
+        ),
+    );
+    write_quality_contract(repo_root, impact);
+}
+
+fn print_plan(repo_root: &Path, kind: &str) -> (Option<i32>, Value) {
+    let out = Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["gate", kind, "--print-plan", "--repo-root"])
+        .arg(repo_root)
+        .output()
+        .expect("run compas gate --print-plan");
+    let payload = serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout must be JSON ({e}): {}",
+            String::from_utf8_lossy(&out.stdout)
+        )
+    });
+    (out.status.code(), payload)
+}
+
+fn step_ids(plan: &Value) -> Vec<&str> {
+    plan["steps"]
+        .as_array()
+        .expect("steps")
+        .iter()
+        .map(|s| s["tool_id"].as_str().expect("tool_id"))
+        .collect()
+}
+
+#[test]
+fn print_plan_lists_the_configured_sequence_without_running_it() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    plan_repo(dir.path(), "");
+
+    let (code, plan) = print_plan(dir.path(), "ci");
+    assert_eq!(code, Some(0), "{plan}");
+    assert_eq!(plan["ok"], true);
+    assert_eq!(plan["kind"], "ci");
+    assert_eq!(step_ids(&plan), vec!["lint", "unit", "e2e"]);
+    assert_eq!(plan["steps"][2]["position"], 3);
+    assert_eq!(plan["steps"][2]["reasons"][0], "[gate] ci #3");
+
+    let (_, plan) = print_plan(dir.path(), "ci_fast");
+    assert_eq!(step_ids(&plan), vec!["lint", "unit"]);
+
+    for id in ["lint", "unit", "e2e"] {
+        assert!(!dir.path().join(format!("ran-{id}")).exists(), "{id} ran");
+    }
+    assert!(!dir.path().join(".agents/mcp/compas/witness").exists());
+}
+
+#[test]
+fn print_plan_reports_change_impact_requirements() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    plan_repo(
+        dir.path(),
+        r#"
+[impact]
+diff_base = "HEAD~1"
+
+[[impact.rules]]
+id = "e2e-for-api"
+path_globs = ["src/**/*.rs"]
+required_tools = ["e2e"]
+"#,
+    );
+    write_file(&dir.path().join("src/lib.rs"), "pub fn stable() {}\n");
+    git(dir.path(), &["init"]);
+    git(dir.path(), &["config", "user.email", "ci@example.com"]);
+    git(dir.path(), &["config", "user.name", "CI"]);
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "-m", "initial"]);
+    write_file(&dir.path().join("src/lib.rs"), "pub fn changed() {}\n");
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "-m", "change api"]);
+
+    let (code, plan) = print_plan(dir.path(), "ci");
+    assert_eq!(code, Some(0), "{plan}");
+    assert_eq!(plan["changed_files"], serde_json::json!(["src/lib.rs"]));
+    assert_eq!(
+        plan["steps"][2]["reasons"],
+        serde_json::json!(["[gate] ci #3", "change_impact: required by src/lib.rs"])
+    );
+
+    let (code, plan) = print_plan(dir.path(), "ci_fast");
+    assert_eq!(code, Some(1), "{plan}");
+    assert_eq!(plan["ok"], false);
+    assert_eq!(plan["missing_required_tools"], serde_json::json!(["e2e"]));
+    assert_eq!(
+        plan["violations"][0]["code"],
+        "change_impact.required_tool_missing"
+    );
+}
@@ -0,0 +1,210 @@
+mod common;
+
+use common::{write_file, write_quality_contract};
+use serde_json::Value;
+use std::path::Path;
+
+fn report_repo(repo_root: &Path) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "gate --reports test"
+
+[[tools]]
+id = "lint"
+description = "Lint tool with a JSON report"
+command = "echo"
+args = ["lint done"]
+report = { kind = "json", path = "reports/lint.json" }
+
+[gate]
+ci_fast = ["lint"]
+ci = []
+flagship = []
+"#,
+    );
+    write_quality_contract(
+        repo_root,
+        r#"
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+"#,
+    );
+    write_file(
+        &repo_root.join("reports/lint.json"),
+        r#"{"findings": [
+  {"code": "lint.unused_import", "severity": "low", "message": "unused import", "path": "src/a.rs", "line": 3},
+  {"code": "lint.shadowed_name", "severity": "low", "message": "shadowed name", "path": "src/b.rs", "line": 7}
+]}"#,
+    );
+}
+
+fn run_gate(repo_root: &Path, extra: &[&str]) -> Value {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["gate", "ci_fast", "--repo-root"])
+        .arg(repo_root)
+        .args(extra)
+        .output()
+        .expect("run compas gate");
+    serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout must be JSON ({e}): {}",
+            String::from_utf8_lossy(&out.stdout)
+        )
+    })
+}
+
+#[test]
+fn reports_flag_lifts_tool_findings_to_top_level() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    report_repo(dir.path());
+
+    let plain = run_gate(dir.path(), &[]);
+    assert!(plain.get("tool_reports").is_none(), "{plain}");
+
+    let payload = run_gate(dir.path(), &["--reports"]);
+    let reports = payload["tool_reports"].as_array().expect("tool_reports");
+    assert_eq!(reports.len(), 1, "{payload}");
+    assert_eq!(reports[0]["tool_id"], "lint");
+    let findings = reports[0]["findings"].as_array().expect("findings");
+    let codes: Vec<&str> = findings.iter().filter_map(|f| f["code"].as_str()).collect();
+    assert_eq!(codes, vec!["lint.unused_import", "lint.shadowed_name"]);
+    assert_eq!(findings[0]["path"], "src/a.rs");
+    assert_eq!(findings[0]["line"], 3);
+
+    let validate_codes: Vec<&str> = payload["validate"]["findings_v2"]
+        .as_array()
+        .expect("validate findings")
+        .iter()
+        .filter_map(|f| f["code"].as_str())
+        .collect();
+    assert!(
+        !validate_codes.iter().any(|c| c.contains("lint.")),
+        "{validate_codes:?}"
+    );
+}
+
+#[test]
+fn gate_merges_validate_and_tool_findings_into_all_findings() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    report_repo(dir.path());
+    write_file(
+        &dir.path()
+            .join(".agents/mcp/compas/plugins/checks/plugin.toml"),
+        r#"
+[plugin]
+id = "checks"
+description = "loc check producing a validate finding"
+
+[[checks.loc]]
+id = "loc-small"
+max_loc = 1
+include_globs = ["src/**/*.rs"]
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+"#,
+    );
+    write_file(
+        &dir.path().join("src/big.rs"),
+        "fn a() {}\nfn b() {}\nfn c() {}\n",
+    );
+    // Same code/path/line as the first finding: merged once.
+    write_file(
+        &dir.path().join("reports/lint.json"),
+        r#"{"findings": [
+  {"code": "lint.unused_import", "severity": "low", "message": "unused import", "path": "src/a.rs", "line": 3},
+  {"code": "lint.unused_import", "severity": "low", "message": "unused import again", "path": "src/a.rs", "line": 3}
+]}"#,
+    );
+
+    let payload = run_gate(dir.path(), &[]);
+    let all = payload["all_findings"].as_array().expect("all_findings");
+    let entries: Vec<(&str, &str, &str)> = all
+        .iter()
+        .map(|f| {
+            (
+                f["source"].as_str().unwrap_or_default(),
+                f["code"].as_str().unwrap_or_default(),
+                f["path"].as_str().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("validate", "finding.loc.max_exceeded", "src/big.rs"),
+            ("tool:lint", "lint.unused_import", "src/a.rs"),
+        ],
+        "{payload}"
+    );
+    assert_eq!(all[1]["line"], 3);
+    assert_eq!(all[1]["severity"], "low");
+}
+
+#[test]
+fn explain_verdict_attributes_tool_failure_to_the_tool() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    report_repo(dir.path());
+    write_file(
+        &dir.path()
+            .join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "gate --explain-verdict test"
+
+[[tools]]
+id = "broken"
+description = "Tool that always fails"
+command = "sh"
+args = ["-c", "echo broken; exit 1"]
+
+[gate]
+ci_fast = ["broken"]
+ci = []
+flagship = []
+"#,
+    );
+
+    let witness_reasons = || {
+        let path = dir
+            .path()
+            .join(".agents/mcp/compas/witness/gate_ci_fast.json");
+        let raw = std::fs::read_to_string(path).expect("read witness");
+        let witness: Value = serde_json::from_str(&raw).expect("witness JSON");
+        witness["verdict"]["decision"]["reasons"]
+            .as_array()
+            .expect("witness reasons")
+            .clone()
+    };
+
+    let plain = run_gate(dir.path(), &["--write-witness"]);
+    let reasons = plain["verdict"]["decision"]["reasons"]
+        .as_array()
+        .expect("reasons");
+    assert!(reasons.iter().all(|r| r.get("source").is_none()), "{plain}");
+    let written = witness_reasons();
+    assert!(
+        written
+            .iter()
+            .all(|r| r.get("source").is_none() && r.get("origin").is_none()),
+        "{written:?}"
+    );
+
+    let payload = run_gate(dir.path(), &["--explain-verdict", "--write-witness"]);
+    let reasons = payload["verdict"]["decision"]["reasons"]
+        .as_array()
+        .expect("reasons");
+    let failed = reasons
+        .iter()
+        .find(|r| r["code"] == "gate.tool_failed.broken")
+        .unwrap_or_else(|| panic!("tool failure reason missing: {payload}"));
+    assert_eq!(failed["source"], "tool:broken");
+    assert!(reasons.iter().all(|r| r["source"].is_string()), "{payload}");
+    assert!(
+        witness_reasons().iter().all(|r| r["source"].is_string()),
+        "{payload}"
+    );
+}
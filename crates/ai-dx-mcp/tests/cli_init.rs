@@ -54,6 +54,51 @@ fn cli_init_dry_run_and_apply_smoke() {
     );
 }
 
+#[test]
+fn cli_init_summary_lists_every_planned_write() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    std::fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"x\"\nversion = \"0.1.0\"\n",
+    )
+    .expect("write Cargo.toml");
+    let bin = env!("CARGO_BIN_EXE_ai-dx-mcp");
+
+    let run = |extra: &[&str]| -> InitOutput {
+        let out = std::process::Command::new(bin)
+            .arg("init")
+            .args(extra)
+            .arg("--repo-root")
+            .arg(dir.path())
+            .output()
+            .expect("run init");
+        assert!(
+            out.status.success(),
+            "stderr={}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice(&out.stdout).expect("parse InitOutput")
+    };
+
+    let plain = run(&[]);
+    let plain_md = plain.summary_md.expect("status summary");
+    assert!(!plain_md.contains("## Writes"), "{plain_md}");
+
+    let parsed = run(&["--summary"]);
+    let md = parsed.summary_md.expect("summary_md");
+    let plan = parsed.plan.expect("plan");
+    assert!(!plan.writes.is_empty());
+    assert!(md.contains("**Status:** planned"), "{md}");
+    assert!(md.contains("- `builtin:rust`"), "{md}");
+    assert!(
+        md.contains(&format!("## Writes ({})", plan.writes.len())),
+        "{md}"
+    );
+    for write in &plan.writes {
+        assert!(md.contains(&format!("- `{}`", write.path)), "{md}");
+    }
+}
+
 #[test]
 fn cli_validate_rejects_unknown_flags() {
     let bin = env!("CARGO_BIN_EXE_ai-dx-mcp");
@@ -384,3 +429,57 @@ fn cli_validate_write_baseline_ratchet_requires_and_accepts_maintenance() {
         serde_json::from_slice(&out.stdout).expect("parse validate output");
     assert_eq!(out_json.get("ok").and_then(|v| v.as_bool()), Some(true));
 }
+
+#[test]
+fn cli_init_packs_from_reproduces_locked_pack_set() {
+    let bin = env!("CARGO_BIN_EXE_ai-dx-mcp");
+    let source = tempfile::tempdir().expect("source repo");
+    std::fs::write(
+        source.path().join("Cargo.toml"),
+        "[package]\nname = \"x\"\nversion = \"0.1.0\"\n",
+    )
+    .expect("write Cargo.toml");
+    let out = std::process::Command::new(bin)
+        .args(["init", "--apply", "--repo-root"])
+        .arg(source.path())
+        .output()
+        .expect("run init on source repo");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let source_lock = source.path().join(".agents/mcp/compas/packs.lock");
+    let locked = std::fs::read_to_string(&source_lock).expect("read source packs.lock");
+    assert!(locked.contains("builtin:rust"), "{locked}");
+
+    // The target looks like a Python repo; --packs-from must override detection.
+    let target = tempfile::tempdir().expect("target repo");
+    std::fs::write(
+        target.path().join("pyproject.toml"),
+        "[project]\nname = \"y\"\n",
+    )
+    .expect("write pyproject.toml");
+    let out = std::process::Command::new(bin)
+        .args(["init", "--apply", "--packs-from"])
+        .arg(&source_lock)
+        .arg("--repo-root")
+        .arg(target.path())
+        .output()
+        .expect("run init --packs-from");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let reproduced = std::fs::read_to_string(target.path().join(".agents/mcp/compas/packs.lock"))
+        .expect("read target packs.lock");
+    assert_eq!(reproduced, locked);
+    assert!(
+        !target
+            .path()
+            .join(".agents/mcp/compas/packs/python/pack.toml")
+            .exists(),
+        "detected python pack must not be added"
+    );
+}
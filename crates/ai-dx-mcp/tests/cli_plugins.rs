@@ -164,7 +164,7 @@ fn plugins_install_requires_admin_lane() {
 
     assert_eq!(
         out.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&out.stdout),
         String::from_utf8_lossy(&out.stderr)
@@ -221,6 +221,335 @@ fn plugins_manifest_discovery_commands_work() {
         payload.get("id"),
         Some(&Value::String("spec-adr-gate".into()))
     );
+
+    let compact = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["list", "--json", "--compact-json"],
+    );
+    assert!(compact.status.success());
+    let text = String::from_utf8_lossy(&compact.stdout);
+    assert_eq!(text.trim_end().lines().count(), 1, "{text}");
+    let compact_rows: Vec<Value> = serde_json::from_str(&text).expect("parse compact list json");
+    assert_eq!(compact_rows, rows);
+}
+
+#[test]
+fn plugins_info_all_returns_every_manifest_plugin() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let info = run_plugins_cmd(repo_root.path(), &fixture, &["info", "--all"]);
+    assert!(
+        info.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&info.stdout),
+        String::from_utf8_lossy(&info.stderr)
+    );
+    let rows: Vec<Value> = serde_json::from_slice(&info.stdout).expect("parse info json");
+    let manifest: Value = serde_json::from_str(
+        &std::fs::read_to_string(&fixture.manifest_path).expect("read manifest"),
+    )
+    .expect("parse manifest");
+    let manifest_plugins = manifest["plugins"].as_array().expect("manifest plugins");
+    assert_eq!(rows.len(), manifest_plugins.len());
+    for (row, plugin) in rows.iter().zip(manifest_plugins) {
+        assert_eq!(row["id"], plugin["id"]);
+        assert!(
+            row["manifest_sha256"]
+                .as_str()
+                .is_some_and(|s| s.len() == 64),
+            "{row}"
+        );
+    }
+}
+
+#[test]
+fn plugins_info_diff_installed_reports_outdated_lock_hashes() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let diff = |args: &[&str]| -> Value {
+        let out = run_plugins_cmd(repo_root.path(), &fixture, args);
+        assert!(
+            out.status.success(),
+            "stdout={}, stderr={}",
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice(&out.stdout).expect("parse info json")
+    };
+
+    let before = diff(&["info", "spec", "--diff-installed"]);
+    assert_eq!(before["installed"]["status"], "not_installed", "{before}");
+
+    let install = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["install", "--admin-lane", "--plugins", "spec-adr-gate"],
+    );
+    assert!(install.status.success(), "install failed");
+    let fresh = diff(&["info", "spec", "--diff-installed"]);
+    assert_eq!(fresh["installed"]["status"], "up_to_date", "{fresh}");
+
+    // Simulate an install from an older registry: locked hashes no longer match the manifest.
+    let lock_path = repo_root
+        .path()
+        .join(".agents/mcp/compas/plugins.lock.json");
+    let mut lock: Value =
+        serde_json::from_str(&std::fs::read_to_string(&lock_path).expect("read lockfile"))
+            .expect("parse lockfile");
+    lock["registry_version"] = Value::String("test-0".into());
+    for entry in lock["files"].as_array_mut().expect("lock files") {
+        entry["sha256"] = Value::String("0".repeat(64));
+    }
+    std::fs::write(&lock_path, serde_json::to_string_pretty(&lock).unwrap())
+        .expect("write lockfile");
+
+    let rows = diff(&["info", "--all", "--diff-installed"]);
+    let rows = rows.as_array().expect("info --all array");
+    let spec = rows
+        .iter()
+        .find(|r| r["id"] == "spec-adr-gate")
+        .expect("spec row");
+    assert_eq!(spec["installed"]["status"], "outdated", "{spec}");
+    assert_eq!(spec["installed"]["installed_registry_version"], "test-0");
+    assert_eq!(spec["installed"]["manifest_registry_version"], "test-1");
+    assert_eq!(
+        spec["installed"]["changed_files"],
+        serde_json::json!([".agents/mcp/compas/plugins/spec-adr-gate/plugin.toml"])
+    );
+    let experimental = rows
+        .iter()
+        .find(|r| r["id"] == "experimental-plugin")
+        .expect("experimental row");
+    assert_eq!(experimental["installed"]["status"], "not_installed");
+}
+
+#[test]
+fn plugins_list_installed_joins_manifest_with_lockfile_drift() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let list = || -> Vec<Value> {
+        let out = run_plugins_cmd(
+            repo_root.path(),
+            &fixture,
+            &["list", "--installed", "--json"],
+        );
+        assert!(
+            out.status.success(),
+            "stdout={}, stderr={}",
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice(&out.stdout).expect("parse list json")
+    };
+    let row = |rows: &[Value], id: &str| -> Value {
+        rows.iter()
+            .find(|r| r["id"] == id)
+            .cloned()
+            .unwrap_or_else(|| panic!("missing row {id}"))
+    };
+
+    let install = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["install", "--admin-lane", "--plugins", "spec-adr-gate"],
+    );
+    assert!(install.status.success(), "install failed");
+
+    let rows = list();
+    let spec = row(&rows, "spec-adr-gate");
+    assert_eq!(spec["installed"], true, "{spec}");
+    assert_eq!(spec["drift"], "ok", "{spec}");
+    let experimental = row(&rows, "experimental-plugin");
+    assert_eq!(experimental["installed"], false, "{experimental}");
+    assert!(experimental["drift"].is_null(), "{experimental}");
+
+    let plugin_toml = repo_root
+        .path()
+        .join(".agents/mcp/compas/plugins/spec-adr-gate/plugin.toml");
+    std::fs::write(&plugin_toml, "[plugin]\nid = \"spec-adr-gate\"\n").expect("edit plugin");
+    assert_eq!(row(&list(), "spec-adr-gate")["drift"], "modified");
+    std::fs::remove_file(&plugin_toml).expect("remove plugin");
+    assert_eq!(row(&list(), "spec-adr-gate")["drift"], "missing");
+}
+
+fn set_plugin_requires(fixture: &RegistryFixture, plugin_id: &str, requires: &[&str]) {
+    let raw = std::fs::read_to_string(&fixture.manifest_path).expect("read manifest");
+    let mut manifest: Value = serde_json::from_str(&raw).expect("parse manifest");
+    let plugin = manifest["plugins"]
+        .as_array_mut()
+        .expect("plugins array")
+        .iter_mut()
+        .find(|p| p["id"] == plugin_id)
+        .expect("plugin in manifest");
+    plugin["requires"] = serde_json::json!(requires);
+    std::fs::write(
+        &fixture.manifest_path,
+        format!(
+            "{}\n",
+            serde_json::to_string_pretty(&manifest).expect("serialize manifest")
+        ),
+    )
+    .expect("write manifest");
+}
+
+#[test]
+fn plugins_strict_manifest_rejects_misspelled_fields() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let clean = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["list", "--json", "--strict-manifest"],
+    );
+    assert!(
+        clean.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&clean.stdout),
+        String::from_utf8_lossy(&clean.stderr)
+    );
+
+    // `maintainer` instead of `maintainers`: the lenient parse keeps it as extra metadata.
+    let raw = std::fs::read_to_string(&fixture.manifest_path).expect("read manifest");
+    let mut manifest: Value = serde_json::from_str(&raw).expect("parse manifest");
+    manifest["plugins"][0]["maintainer"] = serde_json::json!(["test"]);
+    std::fs::write(
+        &fixture.manifest_path,
+        serde_json::to_string_pretty(&manifest).expect("serialize manifest"),
+    )
+    .expect("write manifest");
+
+    let lenient = run_plugins_cmd(repo_root.path(), &fixture, &["list", "--json"]);
+    assert!(
+        lenient.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&lenient.stdout),
+        String::from_utf8_lossy(&lenient.stderr)
+    );
+
+    let strict = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["list", "--json", "--strict-manifest"],
+    );
+    assert!(!strict.status.success());
+    let stderr = String::from_utf8_lossy(&strict.stderr);
+    assert!(
+        stderr.contains("strict manifest") && stderr.contains("unknown field `maintainer`"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn plugins_install_reports_dependency_first_install_order() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+    set_plugin_requires(&fixture, "experimental-plugin", &["spec-adr-gate"]);
+
+    let install = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &[
+            "install",
+            "--admin-lane",
+            "--allow-experimental",
+            "--plugins",
+            "experimental-plugin,spec-adr-gate",
+            "--dry-run",
+        ],
+    );
+    assert!(
+        install.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&install.stderr)
+    );
+    let payload: Value = serde_json::from_slice(&install.stdout).expect("install json");
+    assert_eq!(
+        payload["install_order"],
+        serde_json::json!(["spec-adr-gate", "experimental-plugin"])
+    );
+    assert_eq!(
+        payload["plugins"],
+        serde_json::json!(["experimental-plugin", "spec-adr-gate"])
+    );
+
+    set_plugin_requires(&fixture, "spec-adr-gate", &["experimental-plugin"]);
+    let cycle = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &[
+            "install",
+            "--admin-lane",
+            "--allow-experimental",
+            "--plugins",
+            "experimental-plugin,spec-adr-gate",
+            "--dry-run",
+        ],
+    );
+    assert_eq!(cycle.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&cycle.stderr);
+    assert!(stderr.contains("plugin requires cycle among"), "{stderr}");
+}
+
+#[test]
+fn plugins_install_aborts_on_registry_version_mismatch() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let mismatch = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &[
+            "install",
+            "--admin-lane",
+            "--plugins",
+            "spec-adr-gate",
+            "--expect-registry-version",
+            "test-2",
+        ],
+    );
+    assert_eq!(mismatch.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&mismatch.stderr);
+    assert!(
+        stderr.contains("registry version mismatch: expected test-2, manifest has test-1"),
+        "stderr={stderr}"
+    );
+    let compas_dir = repo_root.path().join(".agents/mcp/compas");
+    assert!(!compas_dir.join("plugins/spec-adr-gate").exists());
+    assert!(!compas_dir.join("plugins.lock.json").exists());
+
+    let matching = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &[
+            "install",
+            "--admin-lane",
+            "--plugins",
+            "spec-adr-gate",
+            "--expect-registry-version",
+            "test-1",
+        ],
+    );
+    assert!(
+        matching.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&matching.stderr)
+    );
+    assert!(
+        compas_dir
+            .join("plugins/spec-adr-gate/plugin.toml")
+            .is_file()
+    );
 }
 
 #[test]
@@ -298,6 +627,59 @@ fn plugins_install_update_uninstall_admin_lane_flow() {
     );
 }
 
+#[test]
+fn plugins_uninstall_purge_cache_removes_registry_cache_entry() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+    let manifest_cache = repo_root
+        .path()
+        .join(".tmp-xdg-cache/compas/plugins/registry/manifest-v1");
+    let cache_entries = || -> Vec<std::path::PathBuf> {
+        std::fs::read_dir(&manifest_cache)
+            .map(|rd| rd.filter_map(|e| e.ok().map(|e| e.path())).collect())
+            .unwrap_or_default()
+    };
+
+    let install = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["install", "--admin-lane", "--plugins", "spec-adr-gate"],
+    );
+    assert!(install.status.success(), "{install:?}");
+    let entries = cache_entries();
+    assert_eq!(
+        entries.len(),
+        1,
+        "install must populate the cache: {entries:?}"
+    );
+
+    let dry_run = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["uninstall", "--admin-lane", "--purge-cache", "--dry-run"],
+    );
+    assert!(dry_run.status.success(), "{dry_run:?}");
+    assert!(entries[0].is_dir(), "dry-run must not purge the cache");
+
+    let uninstall = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["uninstall", "--admin-lane", "--purge-cache"],
+    );
+    assert!(uninstall.status.success(), "{uninstall:?}");
+    let payload: Value = serde_json::from_slice(&uninstall.stdout).expect("uninstall json");
+    assert_eq!(payload["cache_purged"], Value::Bool(true), "{payload}");
+    assert_eq!(
+        payload["cache_path"].as_str().map(std::path::PathBuf::from),
+        Some(entries[0].clone())
+    );
+    assert!(
+        !entries[0].exists(),
+        "cache entry must be gone after --purge-cache"
+    );
+}
+
 #[test]
 fn plugins_doctor_reports_missing_managed_files() {
     let repo_root = tempfile::tempdir().expect("temp repo");
@@ -342,3 +724,146 @@ fn plugins_doctor_reports_missing_managed_files() {
         "doctor must report missing managed file: {missing:?}"
     );
 }
+
+#[cfg(unix)]
+#[test]
+fn plugins_doctor_rejects_managed_dir_symlinked_outside_repo() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let outside = tempfile::tempdir().expect("temp outside");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let install = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["install", "--admin-lane", "--plugins", "spec-adr-gate"],
+    );
+    assert!(
+        install.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&install.stdout),
+        String::from_utf8_lossy(&install.stderr)
+    );
+
+    // Same bytes, but reached through a symlink that leaves the repo.
+    let plugin_dir = repo_root
+        .path()
+        .join(".agents/mcp/compas/plugins/spec-adr-gate");
+    let moved = outside.path().join("spec-adr-gate");
+    std::fs::rename(&plugin_dir, &moved).expect("move plugin dir out");
+    std::os::unix::fs::symlink(&moved, &plugin_dir).expect("symlink plugin dir");
+
+    let doctor = run_plugins_cmd(repo_root.path(), &fixture, &["doctor"]);
+    let stdout = String::from_utf8_lossy(&doctor.stdout);
+    let stderr = String::from_utf8_lossy(&doctor.stderr);
+    assert!(!doctor.status.success(), "stdout={stdout}, stderr={stderr}");
+    assert!(
+        format!("{stdout}{stderr}").contains("resolves outside the repo root"),
+        "stdout={stdout}, stderr={stderr}"
+    );
+}
+
+#[test]
+fn plugins_freeze_blocks_lock_changes_until_unfreeze() {
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let fixture = write_registry_fixture(registry_root.path());
+
+    let install = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["install", "--admin-lane", "--plugins", "spec-adr-gate"],
+    );
+    assert!(
+        install.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&install.stdout),
+        String::from_utf8_lossy(&install.stderr)
+    );
+
+    let freeze = run_plugins_cmd(repo_root.path(), &fixture, &["freeze", "--admin-lane"]);
+    assert!(
+        freeze.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&freeze.stdout),
+        String::from_utf8_lossy(&freeze.stderr)
+    );
+    let freeze_payload: Value = serde_json::from_slice(&freeze.stdout).expect("freeze json");
+    assert_eq!(freeze_payload.get("frozen"), Some(&Value::Bool(true)));
+
+    let compas_dir = repo_root.path().join(".agents/mcp/compas");
+    let frozen_marker = compas_dir.join("plugins.lock.frozen");
+    assert!(frozen_marker.is_file(), "frozen marker missing");
+    let lock: Value = serde_json::from_str(
+        &std::fs::read_to_string(compas_dir.join("plugins.lock.json")).expect("read lockfile"),
+    )
+    .expect("lockfile json");
+    assert_eq!(lock.get("frozen"), Some(&Value::Bool(true)));
+
+    // Re-resolving the same locked set is not drift.
+    let update = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &["update", "--admin-lane", "--dry-run"],
+    );
+    assert!(
+        update.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&update.stdout),
+        String::from_utf8_lossy(&update.stderr)
+    );
+
+    let blocked = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &[
+            "install",
+            "--admin-lane",
+            "--plugins",
+            "experimental-plugin",
+            "--allow-experimental",
+        ],
+    );
+    assert_eq!(
+        blocked.status.code(),
+        Some(2),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&blocked.stdout),
+        String::from_utf8_lossy(&blocked.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&blocked.stderr);
+    assert!(stderr.contains("--unfreeze"), "stderr: {stderr}");
+    assert!(
+        !compas_dir.join("plugins/experimental-plugin").exists(),
+        "frozen install must not touch plugin dirs"
+    );
+
+    let unfrozen = run_plugins_cmd(
+        repo_root.path(),
+        &fixture,
+        &[
+            "install",
+            "--admin-lane",
+            "--plugins",
+            "experimental-plugin",
+            "--allow-experimental",
+            "--unfreeze",
+        ],
+    );
+    assert!(
+        unfrozen.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&unfrozen.stdout),
+        String::from_utf8_lossy(&unfrozen.stderr)
+    );
+    assert!(compas_dir.join("plugins/experimental-plugin").is_dir());
+    assert!(
+        !frozen_marker.exists(),
+        "unfreeze must drop the frozen marker"
+    );
+    let lock: Value = serde_json::from_str(
+        &std::fs::read_to_string(compas_dir.join("plugins.lock.json")).expect("read lockfile"),
+    )
+    .expect("lockfile json");
+    assert_eq!(lock.get("frozen"), None);
+}
@@ -150,7 +150,7 @@ fn build_registry_archive_with_traversal_entry(root: &Path) -> PathBuf {
         out.extend_from_slice(data);
         let pad = (512 - (data.len() % 512)) % 512;
         if pad != 0 {
-            out.extend(std::iter::repeat(0u8).take(pad));
+            out.extend(std::iter::repeat_n(0u8, pad));
         }
     }
 
@@ -183,7 +183,7 @@ fn build_registry_archive_with_traversal_entry(root: &Path) -> PathBuf {
     );
 
     // End-of-archive markers.
-    tar_bytes.extend(std::iter::repeat(0u8).take(1024));
+    tar_bytes.extend(std::iter::repeat_n(0u8, 1024));
 
     let archive_name = "compas_plugins-fixture.tar.gz";
     let archive_path = root.join(archive_name);
@@ -288,7 +288,7 @@ fn manifest_install_rejects_archive_sha_mismatch() {
     let out = run_manifest_install(&repo_root, &manifest_path);
     assert_eq!(
         out.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&out.stdout),
         String::from_utf8_lossy(&out.stderr)
@@ -300,6 +300,55 @@ fn manifest_install_rejects_archive_sha_mismatch() {
     );
 }
 
+#[test]
+fn manifest_install_rejects_tampered_archive_before_extraction() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let cache = tempfile::tempdir().expect("cache");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    let archive_path = build_registry_archive(workspace.path(), false, false);
+    let manifest_path = write_manifest(workspace.path(), &archive_path, None);
+    // Tamper after the manifest pinned the original digest.
+    let mut bytes = std::fs::read(&archive_path).expect("read archive");
+    bytes.push(0);
+    std::fs::write(&archive_path, bytes).expect("write tampered archive");
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .env("XDG_CACHE_HOME", cache.path())
+        .args([
+            "plugins",
+            "install",
+            "--admin-lane",
+            "--registry",
+            &manifest_path.to_string_lossy(),
+            "--repo-root",
+            &repo_root.to_string_lossy(),
+            "--",
+            "--plugins",
+            "spec-adr-gate",
+            "--allow-unsigned",
+            "--force",
+        ])
+        .output()
+        .expect("run compas");
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("archive sha256 mismatch"),
+        "unexpected stderr: {stderr}"
+    );
+
+    // The digest is checked while copying, so nothing is unpacked and the bad copy is dropped.
+    let entries: Vec<PathBuf> = walkdir::WalkDir::new(cache.path())
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect();
+    assert!(entries.is_empty(), "cache must hold no files: {entries:?}");
+}
+
 #[test]
 fn manifest_install_rejects_symlink_entries_in_archive() {
     let workspace = tempfile::tempdir().expect("workspace");
@@ -312,7 +361,7 @@ fn manifest_install_rejects_symlink_entries_in_archive() {
     let out = run_manifest_install(&repo_root, &manifest_path);
     assert_eq!(
         out.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&out.stdout),
         String::from_utf8_lossy(&out.stderr)
@@ -336,7 +385,7 @@ fn manifest_install_rejects_path_traversal_entries_in_archive() {
     let out = run_manifest_install(&repo_root, &manifest_path);
     assert_eq!(
         out.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&out.stdout),
         String::from_utf8_lossy(&out.stderr)
@@ -347,3 +396,200 @@ fn manifest_install_rejects_path_traversal_entries_in_archive() {
         "unexpected stderr: {stderr}"
     );
 }
+
+#[derive(Clone, Copy)]
+enum ArchiveFormat {
+    TarGz,
+    TarXz,
+    Zip,
+}
+
+/// The same registry payload as `build_registry_archive`, packed as `format`.
+fn build_registry_archive_as(root: &Path, format: ArchiveFormat) -> PathBuf {
+    let gz_path = build_registry_archive(root, false, false);
+    let payload_root = root.join("payload_root");
+    match format {
+        ArchiveFormat::TarGz => gz_path,
+        ArchiveFormat::TarXz => {
+            let archive_path = root.join("compas_plugins-fixture.tar.xz");
+            let file = std::fs::File::create(&archive_path).expect("create archive");
+            let mut tar = tar::Builder::new(xz2::write::XzEncoder::new(file, 6));
+            tar.append_dir_all("compas_plugins-fixture", &payload_root)
+                .expect("append payload");
+            tar.into_inner()
+                .expect("finalize tar")
+                .finish()
+                .expect("finalize xz");
+            archive_path
+        }
+        ArchiveFormat::Zip => {
+            let archive_path = root.join("compas_plugins-fixture.zip");
+            let file = std::fs::File::create(&archive_path).expect("create archive");
+            let mut zip = zip::ZipWriter::new(file);
+            let options = zip::write::SimpleFileOptions::default();
+            for entry in walkdir::WalkDir::new(&payload_root).sort_by_file_name() {
+                let entry = entry.expect("walk payload");
+                let rel = entry
+                    .path()
+                    .strip_prefix(&payload_root)
+                    .expect("relative payload path")
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                let name = if rel.is_empty() {
+                    "compas_plugins-fixture/".to_string()
+                } else {
+                    format!("compas_plugins-fixture/{rel}")
+                };
+                if entry.file_type().is_dir() {
+                    zip.add_directory(name, options).expect("add zip dir");
+                } else {
+                    zip.start_file(name, options).expect("start zip file");
+                    zip.write_all(&std::fs::read(entry.path()).expect("read payload"))
+                        .expect("write zip file");
+                }
+            }
+            zip.finish().expect("finalize zip");
+            archive_path
+        }
+    }
+}
+
+fn installed_tree(repo_root: &Path) -> Vec<(String, Vec<u8>)> {
+    let plugins = repo_root.join(".agents/mcp/compas/plugins");
+    let mut files: Vec<(String, Vec<u8>)> = walkdir::WalkDir::new(&plugins)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(|e| {
+            let rel = e
+                .path()
+                .strip_prefix(&plugins)
+                .expect("relative plugin path")
+                .to_string_lossy()
+                .to_string();
+            (rel, std::fs::read(e.path()).expect("read installed file"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+fn install_from_format(format: ArchiveFormat) -> Vec<(String, Vec<u8>)> {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    let archive_path = build_registry_archive_as(workspace.path(), format);
+    let manifest_path = write_manifest(workspace.path(), &archive_path, None);
+    let out = run_manifest_install(&repo_root, &manifest_path);
+    assert_eq!(
+        out.status.code(),
+        Some(0),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    installed_tree(&repo_root)
+}
+
+#[test]
+fn manifest_install_extracts_tar_xz_and_zip_to_the_same_tree_as_tar_gz() {
+    let gz = install_from_format(ArchiveFormat::TarGz);
+    assert!(
+        gz.iter().any(|(rel, _)| rel == "spec-adr-gate/README.md"),
+        "{:?}",
+        gz.iter().map(|(rel, _)| rel).collect::<Vec<_>>()
+    );
+    assert_eq!(install_from_format(ArchiveFormat::TarXz), gz);
+    assert_eq!(install_from_format(ArchiveFormat::Zip), gz);
+}
+
+#[test]
+fn manifest_install_rejects_zip_traversal_and_unknown_formats() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    let archive_path = workspace.path().join("compas_plugins-fixture.zip");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&archive_path).expect("create"));
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("compas_plugins-fixture/../evil.txt", options)
+        .expect("start zip file");
+    zip.write_all(b"evil\n").expect("write zip file");
+    zip.finish().expect("finalize zip");
+    let manifest_path = write_manifest(workspace.path(), &archive_path, None);
+    let out = run_manifest_install(&repo_root, &manifest_path);
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("unsafe zip path component"),
+        "unexpected stderr: {stderr}"
+    );
+
+    let archive_path = workspace.path().join("compas_plugins-fixture.tar.bz2");
+    std::fs::write(&archive_path, b"BZh91AY&SY").expect("write archive");
+    let manifest_path = write_manifest(workspace.path(), &archive_path, None);
+    let out = run_manifest_install(&repo_root, &manifest_path);
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("unsupported registry archive format"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+/// Overwrites the uncompressed size every local and central zip header declares.
+fn understate_zip_sizes(bytes: &mut [u8], declared: u32) {
+    let mut i = 0;
+    while i + 4 <= bytes.len() {
+        let offset = match &bytes[i..i + 4] {
+            b"PK\x03\x04" => Some(22),
+            b"PK\x01\x02" => Some(24),
+            _ => None,
+        };
+        if let Some(offset) = offset {
+            bytes[i + offset..i + offset + 4].copy_from_slice(&declared.to_le_bytes());
+        }
+        i += 1;
+    }
+}
+
+#[test]
+fn manifest_install_counts_bytes_written_not_zip_declared_sizes() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    // One 10 MiB entry (the per-file cap) compressed once, then raw-copied 21 times: 210 MiB
+    // of real data against MAX_TOTAL_BYTES=200 MiB, while every header claims 1 byte.
+    let seed_path = workspace.path().join("seed.zip");
+    let mut seed = zip::ZipWriter::new(std::fs::File::create(&seed_path).expect("create"));
+    seed.start_file("seed", zip::write::SimpleFileOptions::default())
+        .expect("start zip file");
+    seed.write_all(&vec![0u8; 10 * 1024 * 1024])
+        .expect("write zip file");
+    seed.finish().expect("finalize seed zip");
+    let mut seed = zip::ZipArchive::new(std::fs::File::open(&seed_path).expect("open seed"))
+        .expect("read seed zip");
+
+    let archive_path = workspace.path().join("compas_plugins-fixture.zip");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&archive_path).expect("create"));
+    for i in 0..21 {
+        let entry = seed.by_index_raw(0).expect("seed entry");
+        zip.raw_copy_file_rename(entry, format!("compas_plugins-fixture/blob{i:02}.bin"))
+            .expect("copy zip entry");
+    }
+    zip.finish().expect("finalize zip");
+    let mut bytes = std::fs::read(&archive_path).expect("read zip");
+    understate_zip_sizes(&mut bytes, 1);
+    std::fs::write(&archive_path, &bytes).expect("write zip");
+
+    let manifest_path = write_manifest(workspace.path(), &archive_path, None);
+    let out = run_manifest_install(&repo_root, &manifest_path);
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("archive exceeds MAX_TOTAL_BYTES"),
+        "unexpected stderr: {stderr}"
+    );
+}
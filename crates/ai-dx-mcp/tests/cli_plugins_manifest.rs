@@ -119,6 +119,7 @@ fn hold_plugins_op_lock(repo_root: &Path) -> std::fs::File {
     }
     let file = std::fs::OpenOptions::new()
         .create(true)
+        .truncate(true)
         .read(true)
         .write(true)
         .open(&lock_path)
@@ -430,7 +431,7 @@ fn manifest_uninstall_rolls_back_when_lockfile_commit_fails() {
     );
     assert_eq!(
         injected.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&injected.stdout),
         String::from_utf8_lossy(&injected.stderr)
@@ -555,6 +556,109 @@ fn manifest_doctor_detects_type_drift_and_unknown_symlink() {
     );
 }
 
+#[test]
+fn manifest_doctor_fix_restores_locked_files_and_keeps_unknown() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+    let manifest_path = build_manifest_registry_fixture(workspace.path());
+
+    let install = run_compas(&[
+        "plugins".to_string(),
+        "install".to_string(),
+        "--admin-lane".to_string(),
+        "--registry".to_string(),
+        manifest_path.to_string_lossy().to_string(),
+        "--repo-root".to_string(),
+        repo_root.to_string_lossy().to_string(),
+        "--plugins".to_string(),
+        "spec-adr-gate".to_string(),
+        "--allow-unsigned".to_string(),
+    ]);
+    assert!(
+        install.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&install.stdout),
+        String::from_utf8_lossy(&install.stderr)
+    );
+
+    let plugin_dir = repo_root.join(".agents/mcp/compas/plugins/spec-adr-gate");
+    std::fs::remove_file(plugin_dir.join("README.md")).expect("delete locked file");
+    write_file(&plugin_dir.join("plugin.toml"), "id='tampered'\n");
+    write_file(&plugin_dir.join("notes.txt"), "local notes\n");
+
+    let doctor_args = |extra: &[&str]| {
+        let mut args = vec![
+            "plugins".to_string(),
+            "doctor".to_string(),
+            "--registry".to_string(),
+            manifest_path.to_string_lossy().to_string(),
+            "--repo-root".to_string(),
+            repo_root.to_string_lossy().to_string(),
+            "--allow-unsigned".to_string(),
+        ];
+        args.extend(extra.iter().map(|a| a.to_string()));
+        args
+    };
+
+    let no_lane = run_compas(&doctor_args(&["--fix"]));
+    assert_ne!(no_lane.status.code(), Some(0));
+    assert!(
+        String::from_utf8_lossy(&no_lane.stderr).contains("--admin-lane"),
+        "stderr={}",
+        String::from_utf8_lossy(&no_lane.stderr)
+    );
+    assert!(!plugin_dir.join("README.md").exists());
+
+    let fixed = run_compas(&doctor_args(&["--admin-lane", "--fix"]));
+    let payload: Value = serde_json::from_slice(&fixed.stdout).expect("parse doctor payload");
+    assert_eq!(
+        fixed.status.code(),
+        Some(1),
+        "unknown file keeps doctor red: {payload}"
+    );
+    assert_eq!(
+        payload["fix"]["restored_files"],
+        serde_json::json!([
+            ".agents/mcp/compas/plugins/spec-adr-gate/README.md",
+            ".agents/mcp/compas/plugins/spec-adr-gate/plugin.toml"
+        ]),
+        "{payload}"
+    );
+    assert_eq!(payload["fix"]["unfixable_files"], serde_json::json!([]));
+    assert_eq!(payload["missing_files"], serde_json::json!([]));
+    assert_eq!(payload["modified_files"], serde_json::json!([]));
+    assert_eq!(
+        payload["unknown_files"],
+        serde_json::json!([".agents/mcp/compas/plugins/spec-adr-gate/notes.txt"])
+    );
+
+    let lockfile: Value = serde_json::from_slice(
+        &std::fs::read(repo_root.join(".agents/mcp/compas/plugins.lock.json"))
+            .expect("read lockfile"),
+    )
+    .expect("parse lockfile");
+    for entry in lockfile["files"].as_array().expect("lock files") {
+        let path = entry["path"].as_str().expect("lock path");
+        assert_eq!(
+            Some(sha256_file(&repo_root.join(path)).as_str()),
+            entry["sha256"].as_str(),
+            "{path}"
+        );
+    }
+    assert_eq!(
+        std::fs::read_to_string(plugin_dir.join("notes.txt")).expect("unknown file kept"),
+        "local notes\n"
+    );
+    assert!(
+        !repo_root
+            .join(".agents/mcp/compas/plugins/.staging")
+            .read_dir()
+            .is_ok_and(|mut d| d.next().is_some()),
+        "staging dir must be cleaned up"
+    );
+}
+
 #[test]
 fn manifest_update_fails_when_plugins_operation_lock_is_held() {
     let workspace = tempfile::tempdir().expect("workspace");
@@ -598,7 +702,7 @@ fn manifest_update_fails_when_plugins_operation_lock_is_held() {
 
     assert_eq!(
         blocked.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&blocked.stdout),
         String::from_utf8_lossy(&blocked.stderr)
@@ -609,3 +713,87 @@ fn manifest_update_fails_when_plugins_operation_lock_is_held() {
         "expected lock contention error, got: {stderr}"
     );
 }
+
+#[test]
+fn manifest_export_round_trips_locked_plugin_set() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_a = workspace.path().join("repo-a");
+    let repo_b = workspace.path().join("repo-b");
+    std::fs::create_dir_all(&repo_a).expect("mkdir repo a");
+    std::fs::create_dir_all(&repo_b).expect("mkdir repo b");
+    let manifest_path = build_manifest_registry_fixture(workspace.path());
+
+    let install = run_compas(&[
+        "plugins".to_string(),
+        "install".to_string(),
+        "--admin-lane".to_string(),
+        "--registry".to_string(),
+        manifest_path.to_string_lossy().to_string(),
+        "--repo-root".to_string(),
+        repo_a.to_string_lossy().to_string(),
+        "--packs".to_string(),
+        "core".to_string(),
+        "--allow-unsigned".to_string(),
+    ]);
+    assert!(
+        install.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&install.stderr)
+    );
+
+    let export = run_compas(&[
+        "plugins".to_string(),
+        "export".to_string(),
+        "--repo-root".to_string(),
+        repo_a.to_string_lossy().to_string(),
+        "--format".to_string(),
+        "requirements".to_string(),
+    ]);
+    assert!(
+        export.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&export.stderr)
+    );
+    let text = String::from_utf8_lossy(&export.stdout).to_string();
+    assert!(text.contains("# registry_version: fixture-1"), "{text}");
+    assert!(text.contains("# manifest_sha256: "), "{text}");
+    let command = text
+        .lines()
+        .find(|l| !l.starts_with('#'))
+        .expect("install command line");
+    let mut argv: Vec<String> = command.split_whitespace().map(str::to_string).collect();
+    assert_eq!(argv.remove(0), "compas");
+    assert!(argv.contains(&"--packs".to_string()), "{command}");
+    argv.push("--repo-root".to_string());
+    argv.push(repo_b.to_string_lossy().to_string());
+
+    let replay = run_compas(&argv);
+    assert!(
+        replay.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&replay.stderr)
+    );
+
+    let lock = |repo: &Path| -> Value {
+        let raw = std::fs::read_to_string(repo.join(".agents/mcp/compas/plugins.lock.json"))
+            .expect("read lockfile");
+        serde_json::from_str(&raw).expect("parse lockfile")
+    };
+    let (a, b) = (lock(&repo_a), lock(&repo_b));
+    assert_eq!(a["plugins"], b["plugins"]);
+    assert_eq!(a["packs"], b["packs"]);
+    assert_eq!(a["manifest_sha256"], b["manifest_sha256"]);
+
+    let json = run_compas(&[
+        "plugins".to_string(),
+        "export".to_string(),
+        "--repo-root".to_string(),
+        repo_b.to_string_lossy().to_string(),
+        "--format".to_string(),
+        "json".to_string(),
+    ]);
+    let payload: Value = serde_json::from_slice(&json.stdout).expect("parse export json");
+    assert_eq!(payload["plugins"], a["plugins"]);
+    assert_eq!(payload["registry_version"], "fixture-1");
+    assert_eq!(payload["command"].as_str(), Some(command));
+}
@@ -0,0 +1,244 @@
+mod common;
+
+use common::write_file;
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use std::path::Path;
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Writes `registry.manifest.v1.json` + `registry.v1.tar.gz` (one plugin) under `root`.
+fn write_registry(root: &Path) {
+    let payload_root = root.join("payload/registry");
+    write_file(
+        &payload_root.join("plugins/spec-adr-gate/plugin.toml"),
+        "[plugin]\nid = \"spec-adr-gate\"\ndescription = \"Spec ADR gate\"\n",
+    );
+
+    let archive_name = "registry.v1.tar.gz";
+    let archive_path = root.join(archive_name);
+    let tar_gz = std::fs::File::create(&archive_path).expect("create archive");
+    let enc = flate2::write::GzEncoder::new(tar_gz, flate2::Compression::default());
+    let mut tar = tar::Builder::new(enc);
+    tar.append_dir_all("registry", &payload_root)
+        .expect("append payload");
+    let enc = tar.into_inner().expect("finish tar builder");
+    enc.finish().expect("finish gzip");
+    let archive_sha = sha256_hex(&std::fs::read(&archive_path).expect("read archive"));
+
+    let manifest = serde_json::json!({
+        "schema": "compas.registry.manifest.v1",
+        "registry_version": "offline-1",
+        "archive": { "name": archive_name, "sha256": archive_sha },
+        "plugins": [{
+            "id": "spec-adr-gate",
+            "aliases": [],
+            "path": "plugins/spec-adr-gate",
+            "status": "community",
+            "owner": "test",
+            "description": "Spec gate plugin",
+            "tier": "community",
+            "capabilities": ["adr"],
+            "requires": [],
+            "runtime_kind": "tool-backed",
+            "cost_class": "medium",
+            "artifacts_produced": [],
+            "package": {
+                "version": "1.0.0",
+                "type": "tool-backed",
+                "maturity": "stable",
+                "runtime": "python3",
+                "portable": true,
+                "languages": ["python"],
+                "entrypoint": "scripts/spec.py",
+                "license": "MIT"
+            }
+        }],
+        "packs": [{
+            "id": "core",
+            "description": "Core set",
+            "plugins": ["spec-adr-gate"],
+            "capabilities": ["adr"],
+            "requires": [],
+            "runtime_kind": "tool-backed",
+            "cost_class": "medium"
+        }]
+    });
+    std::fs::write(
+        root.join("registry.manifest.v1.json"),
+        serde_json::to_vec_pretty(&manifest).expect("serialize manifest"),
+    )
+    .expect("write manifest");
+}
+
+/// Serves files from `root` for exactly `requests` connections, then stops listening.
+fn serve_files(root: &Path, requests: usize) -> (String, std::thread::JoinHandle<()>) {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+    let base = format!("http://{}", listener.local_addr().expect("addr"));
+    let root = root.to_path_buf();
+    let handle = std::thread::spawn(move || {
+        for _ in 0..requests {
+            let (mut stream, _) = listener.accept().expect("accept");
+            let mut buf = vec![];
+            let mut chunk = [0u8; 1024];
+            while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut chunk).expect("read request");
+                if n == 0 {
+                    break;
+                }
+                buf.extend_from_slice(&chunk[..n]);
+            }
+            let request = String::from_utf8_lossy(&buf);
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+            let response = match std::fs::read(root.join(path.trim_start_matches('/'))) {
+                Ok(body) => {
+                    let mut out = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                        body.len()
+                    )
+                    .into_bytes();
+                    out.extend_from_slice(&body);
+                    out
+                }
+                Err(_) => {
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                        .to_vec()
+                }
+            };
+            stream.write_all(&response).expect("write response");
+        }
+    });
+    (base, handle)
+}
+
+fn run_plugins(
+    cache_home: &Path,
+    repo_root: &Path,
+    registry: &str,
+    args: &[&str],
+) -> std::process::Output {
+    std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .env("XDG_CACHE_HOME", cache_home)
+        .arg("plugins")
+        .args(args)
+        .args(["--registry", registry, "--repo-root"])
+        .arg(repo_root)
+        .arg("--allow-unsigned")
+        .output()
+        .expect("run plugins command")
+}
+
+fn describe(out: &std::process::Output) -> String {
+    format!(
+        "status={:?}, stdout={}, stderr={}",
+        out.status.code(),
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    )
+}
+
+#[test]
+fn offline_install_uses_prewarmed_cache_after_registry_goes_away() {
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let cache_home = tempfile::tempdir().expect("temp cache");
+    write_registry(registry_root.path());
+
+    // Manifest + archive: the only two requests the server ever answers.
+    let (base, server) = serve_files(registry_root.path(), 2);
+    let registry = format!("{base}/registry.manifest.v1.json");
+    let warm = run_plugins(
+        cache_home.path(),
+        repo_root.path(),
+        &registry,
+        &["install", "--admin-lane", "--plugins", "spec-adr-gate"],
+    );
+    assert!(warm.status.success(), "{}", describe(&warm));
+    server.join().expect("server thread");
+
+    let plugin_toml = repo_root
+        .path()
+        .join(".agents/mcp/compas/plugins/spec-adr-gate/plugin.toml");
+    let uninstall = run_plugins(
+        cache_home.path(),
+        repo_root.path(),
+        &registry,
+        &[
+            "uninstall",
+            "--admin-lane",
+            "--offline",
+            "--plugins",
+            "spec-adr-gate",
+        ],
+    );
+    assert!(uninstall.status.success(), "{}", describe(&uninstall));
+    assert!(!plugin_toml.exists());
+
+    let offline = run_plugins(
+        cache_home.path(),
+        repo_root.path(),
+        &registry,
+        &[
+            "install",
+            "--admin-lane",
+            "--offline",
+            "--plugins",
+            "spec-adr-gate",
+        ],
+    );
+    assert!(offline.status.success(), "{}", describe(&offline));
+    assert!(plugin_toml.is_file());
+}
+
+#[test]
+fn offline_install_with_cold_cache_reports_not_cached() {
+    let registry_root = tempfile::tempdir().expect("temp registry");
+    let repo_root = tempfile::tempdir().expect("temp repo");
+    let cache_home = tempfile::tempdir().expect("temp cache");
+    write_registry(registry_root.path());
+
+    // Nothing listens here: an offline run must not even try.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .expect("bind")
+        .local_addr()
+        .expect("addr")
+        .port();
+    let url = format!("http://127.0.0.1:{port}/registry.manifest.v1.json");
+    let local = registry_root
+        .path()
+        .join("registry.manifest.v1.json")
+        .to_string_lossy()
+        .to_string();
+
+    for (registry, what) in [(url.as_str(), "manifest"), (local.as_str(), "archive")] {
+        let out = run_plugins(
+            cache_home.path(),
+            repo_root.path(),
+            registry,
+            &[
+                "install",
+                "--admin-lane",
+                "--offline",
+                "--plugins",
+                "spec-adr-gate",
+            ],
+        );
+        assert!(!out.status.success(), "{}", describe(&out));
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(
+            stderr.contains("--offline") && stderr.contains("not cached") && stderr.contains(what),
+            "{}",
+            describe(&out)
+        );
+    }
+    assert!(
+        !repo_root
+            .path()
+            .join(".agents/mcp/compas/plugins/spec-adr-gate")
+            .exists()
+    );
+}
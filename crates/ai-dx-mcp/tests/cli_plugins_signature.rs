@@ -147,6 +147,20 @@ fn sign_manifest_b64(manifest_bytes: &[u8]) -> (String, String) {
     (sig_b64, pubkey_pem)
 }
 
+fn sign_manifest_ed25519_b64(manifest_bytes: &[u8], seed: u8) -> (String, String) {
+    // Deterministic test-only seed; Ed25519 signatures are raw 64 bytes, not DER.
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+    let sig: ed25519_dalek::Signature = signing_key.sign(manifest_bytes);
+    let sig_b64 = general_purpose::STANDARD.encode(sig.to_bytes());
+
+    let pubkey_pem = signing_key
+        .verifying_key()
+        .to_public_key_pem(LineEnding::LF)
+        .expect("pubkey pem");
+
+    (sig_b64, pubkey_pem)
+}
+
 fn write_manifest_fixture(dir: &Path, manifest: &str, sig_b64: &str, pubkey_pem: &str) -> PathBuf {
     let manifest_path = dir.join("registry.manifest.v1.json");
     let sig_path = dir.join("registry.manifest.v1.json.sig");
@@ -223,7 +237,7 @@ fn plugins_list_rejects_tampered_manifest() {
     let out = run_compas(&args);
     assert_eq!(
         out.status.code(),
-        Some(1),
+        Some(2),
         "stdout={}, stderr={}",
         String::from_utf8_lossy(&out.stdout),
         String::from_utf8_lossy(&out.stderr)
@@ -264,3 +278,42 @@ fn plugins_list_accepts_prior_pack_shape_without_aggregate_metadata() {
         String::from_utf8_lossy(&out.stderr)
     );
 }
+
+#[test]
+fn plugins_list_verifies_ed25519_signature_and_rejects_wrong_key() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let dir = workspace.path();
+
+    let manifest = fixture_manifest_bytes();
+    let (sig_b64, pubkey_pem) = sign_manifest_ed25519_b64(manifest.as_bytes(), 7);
+    let pubkey_path = write_manifest_fixture(dir, &manifest, &sig_b64, &pubkey_pem);
+    let args = vec![
+        "plugins".to_string(),
+        "list".to_string(),
+        "--registry".to_string(),
+        dir.join("registry.manifest.v1.json")
+            .to_string_lossy()
+            .to_string(),
+        "--".to_string(),
+        "--json".to_string(),
+        "--pubkey".to_string(),
+        pubkey_path.to_string_lossy().to_string(),
+    ];
+    let out = run_compas(&args);
+    assert!(
+        out.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    let (_, other_pubkey_pem) = sign_manifest_ed25519_b64(manifest.as_bytes(), 8);
+    write_file(&pubkey_path, &other_pubkey_pem);
+    let out = run_compas(&args);
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("signature verification failed"),
+        "unexpected stderr: {stderr}"
+    );
+}
@@ -127,10 +127,32 @@ fn write_manifest(
     manifest_path
 }
 
+fn set_min_compas_version(manifest_path: &Path, min: &str) {
+    let raw = std::fs::read_to_string(manifest_path).expect("read manifest");
+    let mut manifest: Value = serde_json::from_str(&raw).expect("parse manifest");
+    manifest["plugins"][0]["compat"]["min_compas_version"] = Value::from(min);
+    write_file(
+        manifest_path,
+        &format!(
+            "{}\n",
+            serde_json::to_string_pretty(&manifest).expect("serialize manifest")
+        ),
+    );
+}
+
 fn run_install(
     repo_root: &Path,
     manifest_path: &Path,
     extra_flags: &[&str],
+) -> std::process::Output {
+    run_install_with_force(repo_root, manifest_path, true, extra_flags)
+}
+
+fn run_install_with_force(
+    repo_root: &Path,
+    manifest_path: &Path,
+    force: bool,
+    extra_flags: &[&str],
 ) -> std::process::Output {
     let mut args = vec![
         "plugins".to_string(),
@@ -144,8 +166,10 @@ fn run_install(
         "--plugins".to_string(),
         "spec-adr-gate".to_string(),
         "--allow-unsigned".to_string(),
-        "--force".to_string(),
     ];
+    if force {
+        args.push("--force".to_string());
+    }
     for flag in extra_flags {
         args.push(flag.to_string());
     }
@@ -276,3 +300,84 @@ fn install_allows_sunset_with_compat_alias_and_emits_hint() {
         "stderr must include compatibility hint: {stderr}"
     );
 }
+
+#[test]
+fn install_blocks_plugin_requiring_newer_compas_unless_forced() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    let archive_path = build_registry_archive(workspace.path());
+    let manifest_path = write_manifest(workspace.path(), &archive_path, "community", false);
+    set_min_compas_version(&manifest_path, "999.0.0");
+
+    let out = run_install_with_force(&repo_root, &manifest_path, false, &[]);
+    assert_eq!(
+        out.status.code(),
+        Some(1),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("json payload");
+    assert_eq!(
+        payload.get("code").and_then(|v| v.as_str()),
+        Some("plugins.incompatible_compas_version")
+    );
+    assert_eq!(
+        payload["compat"]["incompatible_plugins"][0]["min_compas_version"],
+        "999.0.0"
+    );
+    assert!(
+        !repo_root
+            .join(".agents/mcp/compas/plugins/spec-adr-gate")
+            .exists(),
+        "blocked install must not write plugin files"
+    );
+
+    let forced = run_install_with_force(&repo_root, &manifest_path, true, &[]);
+    assert!(
+        forced.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&forced.stdout),
+        String::from_utf8_lossy(&forced.stderr)
+    );
+}
+
+#[test]
+fn install_allows_plugin_with_satisfied_min_compas_version() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    let archive_path = build_registry_archive(workspace.path());
+    let manifest_path = write_manifest(workspace.path(), &archive_path, "community", false);
+    set_min_compas_version(&manifest_path, "0.1.0");
+
+    let out = run_install_with_force(&repo_root, &manifest_path, false, &[]);
+    assert!(
+        out.status.success(),
+        "stdout={}, stderr={}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn install_rejects_manifest_with_invalid_min_compas_version() {
+    let workspace = tempfile::tempdir().expect("workspace");
+    let repo_root = workspace.path().join("repo");
+    std::fs::create_dir_all(&repo_root).expect("mkdir repo");
+
+    let archive_path = build_registry_archive(workspace.path());
+    let manifest_path = write_manifest(workspace.path(), &archive_path, "community", false);
+    set_min_compas_version(&manifest_path, "not-a-version");
+
+    let out = run_install(&repo_root, &manifest_path, &[]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("compat.min_compas_version"),
+        "stderr={stderr}"
+    );
+}
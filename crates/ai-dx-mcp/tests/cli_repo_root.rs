@@ -0,0 +1,79 @@
+mod common;
+
+use common::{write_file, write_quality_contract};
+use std::path::Path;
+
+fn minimal_repo(repo_root: &Path) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "--repo-root canonicalization test"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    );
+    write_quality_contract(repo_root, "");
+}
+
+fn run(args: &[&str], repo_root: &Path) -> std::process::Output {
+    std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(args)
+        .arg("--repo-root")
+        .arg(repo_root)
+        .output()
+        .expect("run compas")
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_repo_root_resolves_to_the_real_tree() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let real = dir.path().join("real");
+    minimal_repo(&real);
+    let link = dir.path().join("link");
+    std::os::unix::fs::symlink(&real, &link).expect("symlink repo root");
+    let canonical = real.canonicalize().expect("canonical real root");
+
+    let out = run(&["validate", "warn"], &link);
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout).expect("validate json");
+    assert_eq!(
+        json["repo_root"],
+        canonical.to_string_lossy().as_ref(),
+        "{json}"
+    );
+
+    let out = run(&["gate", "ci_fast", "--write-witness"], &link);
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout).expect("gate json");
+    assert_eq!(
+        json["repo_root"],
+        canonical.to_string_lossy().as_ref(),
+        "{json}"
+    );
+    assert!(
+        real.join(".agents/mcp/compas/witness/gate_ci_fast.json")
+            .is_file(),
+        "{json}"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn repo_root_symlinked_to_filesystem_root_is_rejected() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let link = dir.path().join("rootlink");
+    std::os::unix::fs::symlink("/", &link).expect("symlink to /");
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["--error-format", "json", "validate", "--repo-root"])
+        .arg(&link)
+        .output()
+        .expect("run compas validate");
+    assert_eq!(out.status.code(), Some(2));
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout).expect("error json");
+    assert_eq!(json["error"]["code"], "cli.invalid_args", "{json}");
+}
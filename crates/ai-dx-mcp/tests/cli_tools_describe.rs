@@ -0,0 +1,120 @@
+use serde_json::Value;
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "tools describe test plugin"
+
+[[tools]]
+id = "lint"
+description = "Runs the linter with repo config"
+command = "echo"
+args = ["lint"]
+
+[tools.env]
+LINT_CONFIG = "config/lint.toml"
+LINT_EXTRA = "1"
+
+[[checks.env_registry]]
+id = "env"
+registry_path = ".agents/mcp/compas/env_registry.toml"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/env_registry.toml"),
+        r#"[[vars]]
+name = "LINT_CONFIG"
+description = "Path to the lint config"
+
+[[vars]]
+name = "COMPAS_DESCRIBE_TEST_TOKEN"
+description = "API token"
+default = "s3cret-default"
+sensitive = true
+"#,
+    )
+    .expect("write env_registry.toml");
+}
+
+fn describe(repo: &Path, extra: &[&str]) -> (Option<i32>, Value) {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["tools", "describe", "lint"])
+        .args(extra)
+        .arg("--repo-root")
+        .arg(repo)
+        .output()
+        .expect("run tools describe");
+    let json = serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout is not JSON ({e}): stderr={}",
+            String::from_utf8_lossy(&out.stderr)
+        )
+    });
+    (out.status.code(), json)
+}
+
+fn env_var<'a>(out: &'a Value, name: &str) -> &'a Value {
+    out["resolved_env"]
+        .as_array()
+        .expect("resolved_env array")
+        .iter()
+        .find(|v| v["name"] == name)
+        .unwrap_or_else(|| panic!("{name} missing from {out}"))
+}
+
+#[test]
+fn tools_describe_resolved_env_lists_registered_and_tool_vars() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path());
+
+    let (code, plain) = describe(dir.path(), &[]);
+    assert_eq!(code, Some(0), "{plain}");
+    assert_eq!(plain["tool"]["id"], "lint");
+    assert_eq!(plain["tool"]["plugin_id"], "default");
+    assert!(plain.get("resolved_env").is_none(), "{plain}");
+
+    let (code, out) = describe(dir.path(), &["--resolved-env"]);
+    assert_eq!(code, Some(0), "{out}");
+
+    let config = env_var(&out, "LINT_CONFIG");
+    assert_eq!(config["registered"], true);
+    assert_eq!(config["source"], "tool");
+    assert_eq!(config["value"], "config/lint.toml");
+
+    let token = env_var(&out, "COMPAS_DESCRIBE_TEST_TOKEN");
+    assert_eq!(token["registered"], true);
+    assert_eq!(token["sensitive"], true);
+    assert_eq!(token["source"], "default");
+    let masked = token["value"].as_str().expect("masked value");
+    assert!(masked.starts_with("<redacted len=14"), "{masked}");
+    assert!(!out.to_string().contains("s3cret-default"));
+
+    let extra = env_var(&out, "LINT_EXTRA");
+    assert_eq!(extra["registered"], false);
+    assert_eq!(extra["source"], "tool");
+}
+
+#[test]
+fn tools_describe_unknown_tool_fails() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path());
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["tools", "describe", "nope", "--repo-root"])
+        .arg(dir.path())
+        .output()
+        .expect("run tools describe");
+    assert_eq!(out.status.code(), Some(1));
+    let json: Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(json["error"]["code"], "tools.unknown_tool_id");
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::boundary_repo;
+use serde_json::Value;
+use std::path::Path;
+
+fn run_validate(repo_root: &Path, extra: &[&str]) -> Value {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["validate", "warn", "--repo-root"])
+        .arg(repo_root)
+        .args(extra)
+        .output()
+        .expect("run compas validate");
+    serde_json::from_slice(&out.stdout).expect("stdout must be JSON")
+}
+
+fn boundary_violation(payload: &Value) -> &Value {
+    payload["violations"]
+        .as_array()
+        .and_then(|a| a.iter().find(|v| v["code"] == "boundary.rule_violation"))
+        .unwrap_or_else(|| panic!("boundary violation missing: {payload}"))
+}
+
+#[test]
+fn explain_inlines_fix_recipe_into_violations() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "validate --explain test");
+
+    let explained = run_validate(dir.path(), &["--explain"]);
+    let recipe = boundary_violation(&explained)["details"]["fix_recipe"].as_str();
+    assert!(
+        recipe.is_some_and(|r| r.contains("module boundaries")),
+        "{explained}"
+    );
+
+    let plain = run_validate(dir.path(), &[]);
+    assert!(boundary_violation(&plain)["details"]["fix_recipe"].is_null());
+}
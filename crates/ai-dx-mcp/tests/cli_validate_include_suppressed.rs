@@ -0,0 +1,120 @@
+mod common;
+
+use common::{write_file, write_quality_contract};
+use serde_json::Value;
+use std::path::Path;
+
+fn suppressed_boundary_repo(repo_root: &Path) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "validate --include-suppressed test"
+
+[[checks.boundary]]
+id = "no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+message = "avoid unwrap"
+deny_regex = "\\.unwrap\\(\\)"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    );
+    write_quality_contract(
+        repo_root,
+        r#"
+[exceptions]
+max_exceptions = 10
+max_suppressed_ratio = 1.0
+max_exception_window_days = 500000
+"#,
+    );
+    write_file(
+        &repo_root.join(".agents/mcp/compas/allowlist.toml"),
+        r#"
+[[exceptions]]
+id = "vendored-unwrap"
+rule = "boundary.rule_violation"
+path = "src/vendored.rs"
+owner = "platform"
+reason = "vendored code, upstream fix pending"
+expires_at = "2999-01-01"
+"#,
+    );
+    write_file(
+        &repo_root.join("src/lib.rs"),
+        "pub fn one() -> i32 { Some(1).unwrap() }\n",
+    );
+    write_file(
+        &repo_root.join("src/vendored.rs"),
+        "pub fn two() -> i32 { Some(2).unwrap() }\n",
+    );
+}
+
+fn run_validate(repo_root: &Path, extra: &[&str]) -> (Option<i32>, Value) {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["validate", "strict", "--repo-root"])
+        .arg(repo_root)
+        .args(extra)
+        .output()
+        .expect("run compas validate");
+    let payload = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    (out.status.code(), payload)
+}
+
+fn boundary_findings(payload: &Value) -> Vec<&Value> {
+    payload["findings_v2"]
+        .as_array()
+        .expect("findings_v2")
+        .iter()
+        .filter(|f| f["code"] == "finding.boundary.rule_violation")
+        .collect()
+}
+
+#[test]
+fn include_suppressed_tags_findings_without_changing_verdict() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    suppressed_boundary_repo(dir.path());
+
+    let (plain_code, plain) = run_validate(dir.path(), &[]);
+    assert_eq!(plain["suppressed"].as_array().map(Vec::len), Some(1));
+    assert!(
+        plain["suppressed"][0]["details"]
+            .get("suppressed_by")
+            .is_none(),
+        "{plain}"
+    );
+    let plain_findings = boundary_findings(&plain);
+    assert_eq!(plain_findings.len(), 1, "{plain}");
+    assert!(plain_findings[0]["details"].get("suppressed").is_none());
+
+    let (audit_code, audit) = run_validate(dir.path(), &["--include-suppressed"]);
+    let audit_findings = boundary_findings(&audit);
+    assert_eq!(audit_findings.len(), 2, "{audit}");
+    let tagged: Vec<_> = audit_findings
+        .iter()
+        .filter(|f| f["details"]["suppressed"] == true)
+        .collect();
+    assert_eq!(tagged.len(), 1, "{audit}");
+    assert_eq!(tagged[0]["path"], "src/vendored.rs");
+    assert_eq!(
+        tagged[0]["details"]["suppression_reason"],
+        "vendored code, upstream fix pending"
+    );
+    assert_eq!(
+        tagged[0]["details"]["legacy_details"]["suppressed_by"]["exception_id"],
+        "vendored-unwrap"
+    );
+
+    assert_eq!(plain_code, audit_code);
+    assert_eq!(plain["ok"], audit["ok"]);
+    assert_eq!(plain["verdict"], audit["verdict"]);
+    assert_eq!(plain["risk_summary"], audit["risk_summary"]);
+}
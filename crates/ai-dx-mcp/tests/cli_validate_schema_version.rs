@@ -0,0 +1,49 @@
+mod common;
+
+use common::boundary_repo;
+use serde_json::Value;
+use std::path::Path;
+
+fn run_validate(repo_root: &Path, extra: &[&str]) -> (Option<i32>, Value) {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["--error-format", "json", "validate", "warn", "--repo-root"])
+        .arg(repo_root)
+        .args(extra)
+        .output()
+        .expect("run compas validate");
+    let payload = serde_json::from_slice(&out.stdout).expect("stdout must be JSON");
+    (out.status.code(), payload)
+}
+
+#[test]
+fn schema_version_2_omits_v3_only_fields() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "validate --schema-version test");
+
+    let (_, current) = run_validate(dir.path(), &[]);
+    assert_eq!(current["schema_version"], "4");
+    assert!(current.get("findings_v2").is_some(), "{current}");
+    assert!(current.get("agent_digest").is_some(), "{current}");
+
+    let (_, v2) = run_validate(dir.path(), &["--schema-version", "2"]);
+    assert_eq!(v2["schema_version"], "2");
+    assert!(v2.get("findings_v2").is_none(), "{v2}");
+    assert!(v2.get("agent_digest").is_none(), "{v2}");
+    assert_eq!(v2["violations"], current["violations"]);
+}
+
+#[test]
+fn unsupported_schema_version_fails_closed() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "validate --schema-version test");
+
+    let (code, payload) = run_validate(dir.path(), &["--schema-version", "1"]);
+    assert_eq!(code, Some(2));
+    assert_eq!(payload["ok"], false);
+    assert!(
+        payload["error"]["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("not supported")),
+        "{payload}"
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::boundary_repo;
+
+#[test]
+fn summary_only_prints_digest_instead_of_json() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "validate --summary-only test");
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"))
+        .args(["validate", "strict", "--summary-only", "--repo-root"])
+        .arg(dir.path())
+        .output()
+        .expect("run compas validate");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert_eq!(out.status.code(), Some(1), "{stdout}");
+    assert!(stdout.starts_with("status: blocked"), "{stdout}");
+    assert!(stdout.contains("top blockers:"), "{stdout}");
+    assert!(stdout.contains("boundary.rule_violation"), "{stdout}");
+    assert!(!stdout.contains("findings_v2"), "{stdout}");
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_err());
+}
+
+#[test]
+fn summary_only_has_no_escape_sequences_off_a_terminal() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    boundary_repo(dir.path(), "validate --summary-only test");
+
+    for (no_color_env, extra_flag) in [(None, None), (Some("1"), None), (None, Some("--no-color"))]
+    {
+        let mut cmd = std::process::Command::new(env!("CARGO_BIN_EXE_ai-dx-mcp"));
+        cmd.env_remove("NO_COLOR");
+        if let Some(v) = no_color_env {
+            cmd.env("NO_COLOR", v);
+        }
+        if let Some(flag) = extra_flag {
+            cmd.arg(flag);
+        }
+        let out = cmd
+            .args(["validate", "strict", "--summary-only", "--repo-root"])
+            .arg(dir.path())
+            .output()
+            .expect("run compas validate");
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.starts_with("status: blocked"), "{stdout}");
+        assert!(!stdout.contains('\x1b'), "{stdout:?}");
+    }
+}
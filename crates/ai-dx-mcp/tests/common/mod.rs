@@ -0,0 +1,61 @@
+//! Repo fixtures shared by the integration tests. Each test binary compiles its own copy and
+//! uses only part of it.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+pub fn write_file(path: &Path, content: &str) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("mkdir parent");
+    }
+    std::fs::write(path, content).expect("write file");
+}
+
+/// A default plugin with one `no-unwrap` boundary rule and a `src/lib.rs` that breaks it.
+pub fn boundary_repo(repo_root: &Path, description: &str) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        &format!(
+            r#"
+[plugin]
+id = "default"
+description = "{description}"
+
+[[checks.boundary]]
+id = "no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+message = "avoid unwrap"
+deny_regex = "\\.unwrap\\(\\)"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#
+        ),
+    );
+    write_file(
+        &repo_root.join("src/lib.rs"),
+        "pub fn one() -> i32 { Some(1).unwrap() }\n",
+    );
+}
+
+/// A quality contract whose trust/coverage/risk thresholds never block, followed by `extra`.
+pub fn write_quality_contract(repo_root: &Path, extra: &str) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/quality_contract.toml"),
+        &format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+{extra}"#
+        ),
+    );
+}
@@ -0,0 +1,110 @@
+use ai_dx_mcp::{
+    api::{CoverageSummary, ValidateMode},
+    app::validate,
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, catalog: &str, coverage: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "coverage mappings test plugin"
+
+[[checks.boundary]]
+id = "license-header"
+include_globs = ["src/**/*.rs"]
+exclude_globs = []
+
+[[checks.boundary.rules]]
+id = "no-gpl"
+deny_regex = 'GPL-3\.0'
+message = "GPL-3.0 code is not allowed here"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/failure_modes.toml"),
+        format!("catalog = [{catalog}]\n"),
+    )
+    .expect("write failure_modes.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+{coverage}"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+    std::fs::write(repo.join("src/lib.rs"), "pub fn ok() {}\n").expect("write source");
+}
+
+fn coverage(coverage: &str) -> CoverageSummary {
+    coverage_with_catalog("\"policy_theater\", \"license_drift\"", coverage)
+}
+
+fn coverage_with_catalog(catalog: &str, coverage: &str) -> CoverageSummary {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), catalog, coverage);
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    );
+    out.coverage.expect("coverage summary")
+}
+
+#[test]
+fn custom_mapping_moves_a_custom_mode_to_covered() {
+    let before = coverage("");
+    assert_eq!(before.uncovered_modes, vec!["license_drift".to_string()]);
+    assert_eq!(before.catalog_covered, 1);
+
+    let after = coverage("\n[coverage]\nmappings = { license_drift = [\"license-header\"] }\n");
+    assert!(after.uncovered_modes.is_empty(), "{after:?}");
+    assert_eq!(
+        after.covered_modes,
+        vec!["license_drift".to_string(), "policy_theater".to_string()]
+    );
+    assert_eq!(after.catalog_covered, 2);
+    assert_eq!(after.percent, 100.0);
+}
+
+#[test]
+fn mapping_to_unconfigured_check_or_unknown_mode_covers_nothing() {
+    let out = coverage(
+        "\n[coverage]\nmappings = { license_drift = [\"missing-check\"], not_in_catalog = [\"boundary\"] }\n",
+    );
+    assert_eq!(out.uncovered_modes, vec!["license_drift".to_string()]);
+    assert_eq!(out.catalog_covered, 1);
+}
+
+#[test]
+fn mapping_cannot_cover_builtin_or_mandatory_modes() {
+    let out = coverage_with_catalog(
+        "\"policy_theater\", \"license_drift\", \"env_sprawl\", \"audit_trail\"",
+        "\n[governance]\nmandatory_failure_modes = [\"audit_trail\"]\n\n[coverage]\nmappings = { env_sprawl = [\"license-header\"], audit_trail = [\"boundary\"], license_drift = [\"license-header\"] }\n",
+    );
+    assert_eq!(
+        out.covered_modes,
+        vec!["license_drift".to_string(), "policy_theater".to_string()]
+    );
+    assert_eq!(
+        out.uncovered_modes,
+        vec!["env_sprawl".to_string(), "audit_trail".to_string()]
+    );
+}
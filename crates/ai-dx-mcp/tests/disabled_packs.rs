@@ -0,0 +1,151 @@
+use ai_dx_mcp::{
+    api::{DisabledPackSummary, ValidateMode},
+    app::validate,
+};
+use std::path::Path;
+
+const BOUNDARY_CHECK: &str = r#"
+[[checks.boundary]]
+id = "rust-no-unwrap"
+include_globs = ["src/**/*.rs"]
+exclude_globs = []
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+deny_regex = 'unwrap\('
+message = "avoid unwrap in library code"
+"#;
+
+fn write_repo(repo: &Path, disabled_packs: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/packs/rust")).expect("mkdir pack dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        format!(
+            r#"[plugin]
+id = "default"
+description = "disabled packs test plugin"
+{BOUNDARY_CHECK}
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#
+        ),
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/packs/rust/pack.toml"),
+        format!(
+            r#"[pack]
+id = "rust"
+version = "0.1.0"
+description = "Rust pack contributing the unwrap boundary"
+languages = ["rust"]
+{}"#,
+            BOUNDARY_CHECK.replace("checks.boundary", "checks_v2.boundary")
+        ),
+    )
+    .expect("write pack.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"{disabled_packs}
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+    std::fs::write(
+        repo.join("src/lib.rs"),
+        "pub fn parse(s: &str) -> u32 {\n    s.parse().unwrap()\n}\n",
+    )
+    .expect("write source");
+}
+
+fn run(disabled_packs: &str) -> ai_dx_mcp::api::ValidateOutput {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), disabled_packs);
+    validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    )
+}
+
+fn has_code(out: &ai_dx_mcp::api::ValidateOutput, code: &str) -> bool {
+    out.violations.iter().any(|v| v.code == code)
+}
+
+#[test]
+fn enabled_pack_reports_its_boundary_violations() {
+    let out = run("");
+    assert!(has_code(&out, "boundary.rule_violation"), "{out:?}");
+    assert!(has_code(&out, "packs.lock_missing"), "{out:?}");
+    assert!(out.disabled_packs.is_empty());
+}
+
+#[test]
+fn disabled_pack_skips_its_checks_but_keeps_lock_validation() {
+    let out = run(r#"disabled_packs = ["rust"]"#);
+    assert!(!has_code(&out, "boundary.rule_violation"), "{out:?}");
+    assert!(has_code(&out, "packs.lock_missing"), "{out:?}");
+    assert_eq!(
+        out.disabled_packs,
+        vec![DisabledPackSummary {
+            id: "rust".to_string(),
+            skipped_check_ids: vec!["rust-no-unwrap".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn unknown_disabled_pack_is_observed() {
+    let out = run(r#"disabled_packs = ["python"]"#);
+    assert!(has_code(&out, "packs.disabled_unknown"), "{out:?}");
+    assert!(has_code(&out, "boundary.rule_violation"), "{out:?}");
+}
+
+#[test]
+fn enabled_pack_failure_modes_extend_the_coverage_catalog() {
+    let catalog_total = |disabled_packs: &str| {
+        let dir = tempfile::tempdir().expect("temp repo");
+        write_repo(dir.path(), disabled_packs);
+        let manifest = dir.path().join(".agents/mcp/compas/packs/rust/pack.toml");
+        let raw = std::fs::read_to_string(&manifest).expect("read pack.toml");
+        std::fs::write(
+            &manifest,
+            format!("failure_modes = [\"panic_in_library\", \"fail_open\"]\n{raw}"),
+        )
+        .expect("write pack.toml");
+        let out = validate(
+            &dir.path().to_string_lossy(),
+            ValidateMode::Warn,
+            false,
+            None,
+        );
+        out.coverage.expect("coverage summary")
+    };
+
+    let disabled = catalog_total(r#"disabled_packs = ["rust"]"#);
+    let enabled = catalog_total("");
+    assert_eq!(enabled.catalog_total, disabled.catalog_total + 1);
+    assert!(disabled.pack_modes.is_empty());
+    assert_eq!(
+        enabled.pack_modes.get("panic_in_library"),
+        Some(&vec!["rust".to_string()])
+    );
+    // Already in the built-in catalog: deduped, but the pack is still recorded as a source.
+    assert_eq!(
+        enabled.pack_modes.get("fail_open"),
+        Some(&vec!["rust".to_string()])
+    );
+}
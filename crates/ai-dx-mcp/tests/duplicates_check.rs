@@ -9,6 +9,11 @@ fn cfg() -> DuplicatesCheckConfigV2 {
         exclude_globs: vec![],
         max_file_bytes: 4096,
         allowlist_globs: vec![],
+        respect_gitignore: false,
+        max_depth: None,
+        min_group_size: 2,
+        ignore_comments: false,
+        severity: Default::default(),
         baseline_path: ".agents/mcp/compas/baselines/duplicates.json".to_string(),
     }
 }
@@ -55,3 +60,177 @@ fn duplicates_allowlist_is_group_scoped_all_paths_must_match() {
     let r = run_duplicates_check(dir.path(), &c).unwrap();
     assert!(r.violations.is_empty());
 }
+
+#[test]
+fn duplicates_respect_gitignore_skips_ignored_copies() {
+    let dir = tempdir().unwrap();
+    seed(
+        dir.path(),
+        &[
+            (".gitignore", "node_modules/\n"),
+            ("crates/a/one.txt", "same body\n"),
+            ("crates/node_modules/pkg/one.txt", "same body\n"),
+        ],
+    );
+
+    let r = run_duplicates_check(dir.path(), &cfg()).unwrap();
+    assert!(
+        r.violations.iter().any(|v| v.code == "duplicates.found"),
+        "{:?}",
+        r.violations
+    );
+
+    let mut gitignore_cfg = cfg();
+    gitignore_cfg.respect_gitignore = true;
+    let r = run_duplicates_check(dir.path(), &gitignore_cfg).unwrap();
+    assert_eq!(r.files_scanned, 1);
+    assert!(r.violations.is_empty(), "{:?}", r.violations);
+}
+
+#[test]
+fn duplicates_min_group_size_ignores_smaller_groups() {
+    let dir = tempdir().unwrap();
+    seed(
+        dir.path(),
+        &[
+            ("crates/x/a.txt", "pair"),
+            ("crates/x/b.txt", "pair"),
+            ("crates/y/a.txt", "triple"),
+            ("crates/y/b.txt", "triple"),
+            ("crates/y/c.txt", "triple"),
+        ],
+    );
+
+    let mut c = cfg();
+    c.min_group_size = 3;
+    let r = run_duplicates_check(dir.path(), &c).unwrap();
+    assert_eq!(r.groups_total, 2, "both groups stay informational");
+    let found: Vec<_> = r
+        .violations
+        .iter()
+        .filter(|v| v.code == "duplicates.found")
+        .collect();
+    assert_eq!(found.len(), 1, "{:?}", r.violations);
+    let details = found[0].details.as_ref().unwrap();
+    assert_eq!(details["groups"], 1);
+    assert_eq!(details["files"], 3);
+    assert_eq!(details["examples"][0]["paths"][0], "crates/y/a.txt");
+
+    seed(dir.path(), &[("crates/y/c.txt", "no longer a copy")]);
+    let r = run_duplicates_check(dir.path(), &c).unwrap();
+    assert_eq!(r.groups_total, 2);
+    assert!(
+        !r.violations.iter().any(|v| v.code == "duplicates.found"),
+        "{:?}",
+        r.violations
+    );
+}
+
+#[test]
+fn duplicates_ignore_comments_groups_copies_differing_only_in_comments() {
+    let dir = tempdir().unwrap();
+    seed(
+        dir.path(),
+        &[
+            ("crates/x/a.rs", "// copy A\nfn f() -> u8 { 1 }\n"),
+            ("crates/x/b.rs", "/* copy B */\nfn f() -> u8 { 1 }\n"),
+            ("crates/x/c.py", "# note\ndef f():\n    return \"#1\"\n"),
+            ("crates/x/d.py", "def f():\n    return \"#2\"\n"),
+        ],
+    );
+
+    let mut c = cfg();
+    c.include_globs = vec!["crates/**".to_string()];
+    let r = run_duplicates_check(dir.path(), &c).unwrap();
+    assert_eq!(r.groups_total, 0, "{:?}", r.groups);
+
+    c.ignore_comments = true;
+    let r = run_duplicates_check(dir.path(), &c).unwrap();
+    let groups: Vec<_> = r.groups.values().cloned().collect();
+    assert_eq!(
+        groups,
+        vec![vec![
+            "crates/x/a.rs".to_string(),
+            "crates/x/b.rs".to_string()
+        ]],
+        "string contents must not be treated as comments"
+    );
+}
+
+#[test]
+fn duplicates_severity_scales_with_group_size_and_file_size() {
+    let severity = |files: &[(&str, &str)]| {
+        let dir = tempdir().unwrap();
+        seed(dir.path(), files);
+        let r = run_duplicates_check(dir.path(), &cfg()).unwrap();
+        let found = r
+            .violations
+            .iter()
+            .find(|v| v.code == "duplicates.found")
+            .expect("duplicates.found");
+        found.details.as_ref().unwrap()["severity"].clone()
+    };
+
+    let large = "fn body() {}\n".repeat(200);
+    let copies: Vec<String> = (0..5).map(|i| format!("crates/x/copy{i}.txt")).collect();
+    let heavy: Vec<(&str, &str)> = copies
+        .iter()
+        .map(|p| (p.as_str(), large.as_str()))
+        .collect();
+    assert_eq!(severity(&heavy), "high");
+
+    let tiny = [("crates/x/a.txt", "same"), ("crates/x/b.txt", "same")];
+    assert_eq!(severity(&tiny), "medium");
+}
+
+#[test]
+fn duplicates_min_group_size_also_filters_the_quality_snapshot() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    seed(
+        repo,
+        &[
+            (
+                ".agents/mcp/compas/plugins/default/plugin.toml",
+                r#"[plugin]
+id = "default"
+description = "duplicates snapshot fixture"
+
+[[checks.duplicates]]
+id = "dup"
+include_globs = ["crates/**/*.txt"]
+max_file_bytes = 4096
+min_group_size = 3
+baseline_path = ".agents/mcp/compas/baselines/duplicates.json"
+"#,
+            ),
+            (
+                ".agents/mcp/compas/quality_contract.toml",
+                "[quality]\nmin_trust_score = 0\nmin_coverage_percent = 0.0\nallow_trust_drop = true\nallow_coverage_drop = true\nmax_weighted_risk_increase = 999\n",
+            ),
+            ("crates/x/a.txt", "same"),
+            ("crates/x/b.txt", "same"),
+        ],
+    );
+    let root = repo.to_string_lossy().to_string();
+    let baseline = ai_dx_mcp::app::validate(&root, ai_dx_mcp::api::ValidateMode::Warn, true, None);
+    assert!(baseline.ok, "{:?}", baseline.error);
+    let snapshot: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(repo.join(".agents/mcp/compas/baselines/quality_snapshot.json"))
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(snapshot["duplicate_groups"], serde_json::json!([]));
+
+    seed(
+        repo,
+        &[("crates/y/c.txt", "other"), ("crates/y/d.txt", "other")],
+    );
+    let after = ai_dx_mcp::app::validate(&root, ai_dx_mcp::api::ValidateMode::Ratchet, false, None);
+    let codes: Vec<&str> = after.violations.iter().map(|v| v.code.as_str()).collect();
+    assert!(
+        !codes.contains(&"quality_delta.duplicates_regression"),
+        "{codes:?}"
+    );
+    assert!(after.ok, "{:?} {codes:?}", after.error);
+}
@@ -26,6 +26,7 @@ fn tool_with_env(id: &str, env_name: &str, env_value: &str) -> ProjectTool {
         timeout_ms: None,
         max_stdout_bytes: None,
         max_stderr_bytes: None,
+        max_tail_bytes: None,
         report: None,
         receipt_contract: None,
         env,
@@ -142,5 +143,55 @@ default = "super-secret"
         .find(|e| e.name == "TOKEN_VAR")
         .unwrap();
     assert!(matches!(entry.source, EffectiveConfigSource::Default));
-    assert_eq!(entry.value.as_deref(), Some("<redacted>"));
+    assert_eq!(
+        entry.value.as_deref(),
+        Some("<redacted len=12 sha256=aec80848>")
+    );
+}
+
+#[test]
+fn sensitive_env_value_is_masked_in_entries() {
+    let dir = tempdir().unwrap();
+    let registry_path = dir.path().join(".agents/mcp/compas");
+    std::fs::create_dir_all(&registry_path).unwrap();
+    std::fs::write(
+        registry_path.join("env_registry.toml"),
+        r#"
+[[vars]]
+name = "AI_DX_TEST_SENSITIVE_TOKEN"
+sensitive = true
+
+[[vars]]
+name = "AI_DX_TEST_PLAIN_LEVEL"
+"#,
+    )
+    .unwrap();
+    // Unique names keep this safe alongside other tests in the same process.
+    unsafe {
+        std::env::set_var("AI_DX_TEST_SENSITIVE_TOKEN", "ghp_live_secret_value");
+        std::env::set_var("AI_DX_TEST_PLAIN_LEVEL", "debug");
+    }
+
+    let tools = BTreeMap::new();
+    let r = run_env_registry_check(dir.path(), &cfg(), &tools);
+
+    let secret = r
+        .summary
+        .entries
+        .iter()
+        .find(|e| e.name == "AI_DX_TEST_SENSITIVE_TOKEN")
+        .unwrap();
+    assert!(matches!(secret.source, EffectiveConfigSource::Env));
+    let masked = secret.value.as_deref().unwrap();
+    assert!(masked.starts_with("<redacted len=21 sha256="), "{masked}");
+    let raw_json = serde_json::to_string(&r.summary).unwrap();
+    assert!(!raw_json.contains("ghp_live_secret_value"));
+
+    let plain = r
+        .summary
+        .entries
+        .iter()
+        .find(|e| e.name == "AI_DX_TEST_PLAIN_LEVEL")
+        .unwrap();
+    assert_eq!(plain.value.as_deref(), Some("debug"));
 }
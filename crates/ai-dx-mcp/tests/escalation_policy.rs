@@ -0,0 +1,109 @@
+use ai_dx_mcp::{
+    api::{ValidateMode, ViolationTier},
+    app::validate,
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, oversized_files: usize) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "escalation policy test plugin"
+
+[[checks.loc]]
+id = "loc-main"
+max_loc = 1
+include_globs = ["src/**/*.rs"]
+exclude_globs = []
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[[escalation]]
+code = "loc.max_exceeded"
+threshold = 3
+"#,
+    )
+    .expect("write quality_contract.toml");
+    for i in 0..oversized_files {
+        std::fs::write(
+            repo.join(format!("src/m{i}.rs")),
+            "pub fn a() {}\npub fn b() {}\n",
+        )
+        .expect("write source");
+    }
+}
+
+fn run(oversized_files: usize) -> ai_dx_mcp::api::ValidateOutput {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), oversized_files);
+    validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    )
+}
+
+#[test]
+fn observations_below_threshold_stay_observations() {
+    let out = run(2);
+    let loc: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "loc.max_exceeded")
+        .collect();
+    assert_eq!(loc.len(), 2, "{:?}", out.violations);
+    assert!(loc.iter().all(|v| v.tier == ViolationTier::Observation));
+    assert!(
+        !out.violations
+            .iter()
+            .any(|v| v.code == "escalation.threshold_reached")
+    );
+    let verdict = out.verdict.expect("verdict");
+    assert_eq!(verdict.decision.blocking_count, 0);
+}
+
+#[test]
+fn observations_at_threshold_produce_one_blocking_escalation() {
+    let out = run(3);
+    let escalations: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "escalation.threshold_reached")
+        .collect();
+    assert_eq!(escalations.len(), 1, "{:?}", out.violations);
+    assert_eq!(escalations[0].tier, ViolationTier::Blocking);
+    let details = escalations[0].details.as_ref().expect("details");
+    assert_eq!(details["count"], 3);
+    assert_eq!(details["threshold"], 3);
+
+    let verdict = out.verdict.expect("verdict");
+    assert_eq!(verdict.decision.blocking_count, 1);
+    assert!(
+        verdict
+            .decision
+            .reasons
+            .iter()
+            .any(|r| r.code == "escalation.threshold_reached" && r.tier == ViolationTier::Blocking)
+    );
+}
@@ -0,0 +1,142 @@
+use ai_dx_mcp::{
+    api::{ValidateMode, ValidateOutput, ViolationTier},
+    app::validate,
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, block_only_new: bool) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "findings ledger test plugin"
+
+[[checks.boundary]]
+id = "no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+deny_regex = "\\.unwrap\\(\\)"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[governance]
+min_failure_modes = 0
+findings_ledger = true
+block_only_new = {block_only_new}
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+    write_source(repo, "old");
+}
+
+fn write_source(repo: &Path, name: &str) {
+    std::fs::write(
+        repo.join(format!("src/{name}.rs")),
+        "pub fn a() -> u8 {\n    Some(1).unwrap()\n}\n",
+    )
+    .expect("write source");
+}
+
+fn run(repo: &Path, write_baseline: bool) -> ValidateOutput {
+    validate(
+        &repo.to_string_lossy(),
+        ValidateMode::Strict,
+        write_baseline,
+        None,
+    )
+}
+
+fn boundary_tier(out: &ValidateOutput, path: &str) -> ViolationTier {
+    out.violations
+        .iter()
+        .find(|v| v.code == "boundary.rule_violation" && v.path.as_deref() == Some(path))
+        .unwrap_or_else(|| panic!("no boundary violation for {path}: {:?}", out.violations))
+        .tier
+}
+
+#[test]
+fn baselined_finding_is_observation_and_new_finding_blocks() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo = dir.path();
+    write_repo(repo, true);
+    let baseline = run(repo, true);
+    assert!(
+        repo.join(".agents/mcp/compas/findings_ledger.json")
+            .is_file()
+    );
+    assert_eq!(
+        boundary_tier(&baseline, "src/old.rs"),
+        ViolationTier::Observation
+    );
+
+    let clean = run(repo, false);
+    assert!(clean.ok, "{:?}", clean.violations);
+
+    write_source(repo, "new");
+    let out = run(repo, false);
+    assert!(!out.ok);
+    assert_eq!(
+        boundary_tier(&out, "src/old.rs"),
+        ViolationTier::Observation
+    );
+    assert_eq!(boundary_tier(&out, "src/new.rs"), ViolationTier::Blocking);
+    assert!(
+        out.findings_v2
+            .iter()
+            .filter(|f| f.code == "finding.boundary.rule_violation")
+            .all(|f| f.first_seen.is_some()),
+        "{:?}",
+        out.findings_v2
+    );
+
+    let again = run(repo, false);
+    assert_eq!(
+        boundary_tier(&again, "src/new.rs"),
+        ViolationTier::Blocking,
+        "findings first seen after the baseline keep blocking"
+    );
+}
+
+#[test]
+fn ledger_without_block_only_new_only_annotates() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo = dir.path();
+    write_repo(repo, false);
+    let first = run(repo, false);
+    let second = run(repo, false);
+    assert!(!second.ok);
+    assert_eq!(
+        boundary_tier(&second, "src/old.rs"),
+        ViolationTier::Blocking
+    );
+    let first_seen = |out: &ValidateOutput| {
+        out.findings_v2
+            .iter()
+            .find(|f| f.code == "finding.boundary.rule_violation")
+            .and_then(|f| f.first_seen.clone())
+    };
+    assert!(first_seen(&first).is_some());
+    assert_eq!(first_seen(&first), first_seen(&second));
+}
@@ -0,0 +1,141 @@
+use ai_dx_mcp::{
+    api::{DecisionStatus, GateKind, GateOutput},
+    app::gate_with_budget,
+};
+use std::path::Path;
+
+const STATE_REL_PATH: &str = ".agents/mcp/compas/gate_circuit.json";
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "circuit breaker test plugin"
+
+[[tools]]
+id = "flaky"
+description = "Fails while fail.flag exists"
+command = "sh"
+args = ["-c", "if [ -f fail.flag ]; then echo failing; exit 1; fi; echo ok"]
+
+[gate]
+ci_fast = ["flaky"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+
+[proof]
+require_witness = false
+
+[gate]
+circuit_breaker = { failures = 2, window_min = 60 }
+"#,
+    )
+    .expect("write quality_contract.toml");
+}
+
+fn set_failing(repo: &Path, failing: bool) {
+    let flag = repo.join("fail.flag");
+    if failing {
+        std::fs::write(flag, "1").expect("write fail.flag");
+    } else {
+        std::fs::remove_file(flag).expect("remove fail.flag");
+    }
+}
+
+async fn run(repo: &Path) -> GateOutput {
+    gate_with_budget(
+        &repo.to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        false,
+        None,
+        None,
+        None,
+    )
+    .await
+}
+
+fn error_code(out: &GateOutput) -> Option<&str> {
+    out.error.as_ref().map(|e| e.code.as_str())
+}
+
+#[tokio::test]
+async fn repeated_failures_open_the_circuit_until_the_window_passes() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo = dir.path();
+    write_repo(repo);
+    set_failing(repo, true);
+
+    for _ in 0..2 {
+        let out = run(repo).await;
+        assert_eq!(error_code(&out), Some("gate.blocked"), "{:?}", out.error);
+        assert_eq!(out.receipts.len(), 1);
+    }
+
+    let open = run(repo).await;
+    assert_eq!(
+        error_code(&open),
+        Some("gate.circuit_open"),
+        "{:?}",
+        open.error
+    );
+    assert!(!open.ok);
+    assert!(open.receipts.is_empty(), "open circuit must not run tools");
+    let verdict = open.verdict.expect("verdict");
+    assert_eq!(verdict.decision.status, DecisionStatus::Retryable);
+
+    // Fixing the tool does not close the circuit early.
+    set_failing(repo, false);
+    let still_open = run(repo).await;
+    assert_eq!(error_code(&still_open), Some("gate.circuit_open"));
+
+    // Once the recorded failures age out of the window the gate runs (and passes) again.
+    let state_path = repo.join(STATE_REL_PATH);
+    let mut state: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&state_path).expect("read state"))
+            .expect("parse state");
+    state["failures"]["ci_fast"] = serde_json::json!([1, 2]);
+    std::fs::write(&state_path, state.to_string()).expect("age state");
+    let out = run(repo).await;
+    assert!(out.ok, "{:?}", out.error);
+    let state: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&state_path).expect("read state"))
+            .expect("parse state");
+    assert!(state["failures"].get("ci_fast").is_none(), "{state}");
+}
+
+#[tokio::test]
+async fn a_pass_resets_the_failure_count() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo = dir.path();
+    write_repo(repo);
+
+    set_failing(repo, true);
+    assert_eq!(error_code(&run(repo).await), Some("gate.blocked"));
+    set_failing(repo, false);
+    assert!(run(repo).await.ok);
+
+    set_failing(repo, true);
+    assert_eq!(error_code(&run(repo).await), Some("gate.blocked"));
+    assert_eq!(error_code(&run(repo).await), Some("gate.blocked"));
+    assert_eq!(error_code(&run(repo).await), Some("gate.circuit_open"));
+}
@@ -0,0 +1,149 @@
+use ai_dx_mcp::{
+    api::GateKind,
+    app::{gate, gate_diff},
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "gate-diff test plugin"
+
+[[tools]]
+id = "echo-tool"
+description = "Always passes"
+command = "echo"
+args = ["ok"]
+
+[[tools]]
+id = "flag-tool"
+description = "Passes only while ok.flag exists"
+command = "python3"
+args = ["-c", "import os, sys; sys.exit(0 if os.path.exists('ok.flag') else 3)"]
+
+[gate]
+ci_fast = ["echo-tool", "flag-tool"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+"#,
+    )
+    .expect("write quality_contract.toml");
+}
+
+async fn run_and_keep_witness(repo: &Path, keep_as: &Path) -> bool {
+    let out = gate(&repo.to_string_lossy(), GateKind::CiFast, false, true).await;
+    std::fs::copy(
+        repo.join(".agents/mcp/compas/witness/gate_ci_fast.json"),
+        keep_as,
+    )
+    .expect("copy witness");
+    out.ok
+}
+
+#[tokio::test]
+async fn gate_diff_reports_changed_tool_and_new_violation() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let keep = tempfile::tempdir().expect("witness dir");
+    write_repo(dir.path());
+
+    std::fs::write(dir.path().join("ok.flag"), "").expect("write flag");
+    let a_path = keep.path().join("a.json");
+    assert!(run_and_keep_witness(dir.path(), &a_path).await);
+
+    std::fs::remove_file(dir.path().join("ok.flag")).expect("remove flag");
+    let b_path = keep.path().join("b.json");
+    assert!(!run_and_keep_witness(dir.path(), &b_path).await);
+
+    let diff = gate_diff(&a_path.to_string_lossy(), &b_path.to_string_lossy());
+    assert!(diff.ok, "{:?}", diff.error);
+    assert_eq!(diff.a_ok, Some(true));
+    assert_eq!(diff.b_ok, Some(false));
+
+    let changed: Vec<&str> = diff
+        .tools
+        .iter()
+        .filter(|t| t.changed)
+        .map(|t| t.tool_id.as_str())
+        .collect();
+    assert_eq!(changed, vec!["flag-tool"]);
+    let flag = diff
+        .tools
+        .iter()
+        .find(|t| t.tool_id == "flag-tool")
+        .expect("flag-tool diff");
+    assert_eq!(flag.b.as_ref().and_then(|r| r.exit_code), Some(3));
+    assert!(!diff.violations_appeared.is_empty());
+    assert!(diff.violations_disappeared.is_empty());
+    assert!(diff.a_config_hash.is_some());
+    assert_eq!(diff.a_config_hash, diff.b_config_hash);
+    assert!(!diff.config_hash_mismatch);
+}
+
+#[tokio::test]
+async fn gate_diff_flags_witnesses_recorded_under_different_checks_config() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let keep = tempfile::tempdir().expect("witness dir");
+    write_repo(dir.path());
+    std::fs::write(dir.path().join("ok.flag"), "").expect("write flag");
+
+    let a_path = keep.path().join("a.json");
+    assert!(run_and_keep_witness(dir.path(), &a_path).await);
+
+    let plugin_toml = dir
+        .path()
+        .join(".agents/mcp/compas/plugins/default/plugin.toml");
+    let mut plugin = std::fs::read_to_string(&plugin_toml).expect("read plugin.toml");
+    plugin.push_str(
+        r#"
+[[checks.loc]]
+id = "loc-main"
+max_loc = 400
+include_globs = ["src/**/*.rs"]
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+"#,
+    );
+    std::fs::write(&plugin_toml, plugin).expect("write plugin.toml");
+    let b_path = keep.path().join("b.json");
+    assert!(run_and_keep_witness(dir.path(), &b_path).await);
+
+    let diff = gate_diff(&a_path.to_string_lossy(), &b_path.to_string_lossy());
+    assert!(diff.ok, "{:?}", diff.error);
+    let (Some(a_hash), Some(b_hash)) = (&diff.a_config_hash, &diff.b_config_hash) else {
+        panic!("both witnesses record a config hash: {diff:?}");
+    };
+    assert!(a_hash.starts_with("sha256:"), "{a_hash}");
+    assert_ne!(a_hash, b_hash);
+    assert!(diff.config_hash_mismatch);
+}
+
+#[test]
+fn gate_diff_reports_unreadable_witness() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let missing = dir.path().join("missing.json");
+    let diff = gate_diff(&missing.to_string_lossy(), &missing.to_string_lossy());
+    assert!(!diff.ok);
+    assert_eq!(
+        diff.error.map(|e| e.code),
+        Some("witness.diff_load_failed".to_string())
+    );
+}
@@ -0,0 +1,129 @@
+use ai_dx_mcp::{
+    api::{DecisionStatus, GateKind},
+    app::gate_with_budget,
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, gate_section: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "fail-fast test plugin"
+
+[[tools]]
+id = "fail-a"
+description = "Always fails with exit 1"
+command = "sh"
+args = ["-c", "echo fail-a; exit 1"]
+
+[[tools]]
+id = "fail-b"
+description = "Always fails with exit 2"
+command = "sh"
+args = ["-c", "echo fail-b; exit 2"]
+
+[[tools]]
+id = "pass-c"
+description = "Always passes"
+command = "echo"
+args = ["ok"]
+
+[gate]
+ci_fast = ["fail-a", "fail-b", "pass-c"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+
+[proof]
+require_witness = false
+
+{gate_section}
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+}
+
+fn receipt_ids(out: &ai_dx_mcp::api::GateOutput) -> Vec<&str> {
+    out.receipts.iter().map(|r| r.tool_id.as_str()).collect()
+}
+
+async fn run(repo: &Path, fail_fast: Option<bool>) -> ai_dx_mcp::api::GateOutput {
+    gate_with_budget(
+        &repo.to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        false,
+        None,
+        None,
+        fail_fast,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn gate_stops_at_first_failure_by_default() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), "");
+    let out = run(dir.path(), None).await;
+    assert!(!out.ok, "{:?} {:?}", out.error, out.validate.error);
+    assert_eq!(
+        receipt_ids(&out),
+        vec!["fail-a"],
+        "{:?} {:?}",
+        out.error,
+        out.validate.error
+    );
+}
+
+#[tokio::test]
+async fn contract_fail_fast_false_collects_every_receipt_and_still_blocks() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), "[gate]\nfail_fast = false\n");
+    let out = run(dir.path(), None).await;
+    assert!(!out.ok, "{:?}", out.error);
+    assert_eq!(receipt_ids(&out), vec!["fail-a", "fail-b", "pass-c"]);
+    let codes: Vec<Option<i32>> = out.receipts.iter().map(|r| r.exit_code).collect();
+    assert_eq!(codes, vec![Some(1), Some(2), Some(0)]);
+    assert!(out.receipts[1].stdout_tail.contains("fail-b"));
+    let verdict = out.verdict.expect("verdict");
+    assert_eq!(verdict.decision.status, DecisionStatus::Blocked);
+
+    // An explicit override wins over the contract.
+    let out = run(dir.path(), Some(true)).await;
+    assert_eq!(
+        receipt_ids(&out),
+        vec!["fail-a"],
+        "{:?} {:?}",
+        out.error,
+        out.validate.error
+    );
+}
+
+#[tokio::test]
+async fn no_fail_fast_override_runs_all_tools() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), "");
+    let out = run(dir.path(), Some(false)).await;
+    assert!(!out.ok);
+    assert_eq!(receipt_ids(&out), vec!["fail-a", "fail-b", "pass-c"]);
+}
@@ -0,0 +1,97 @@
+use ai_dx_mcp::{api::GateKind, app::gate_with_budget};
+use std::path::Path;
+
+fn write_repo(repo: &Path, proof_section: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "witness kinds test plugin"
+
+[[tools]]
+id = "pass"
+description = "Always passes"
+command = "echo"
+args = ["ok"]
+
+[gate]
+ci_fast = ["pass"]
+ci = ["pass"]
+flagship = ["pass"]
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+
+[proof]
+{proof_section}
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+}
+
+async fn run(repo: &Path, kind: GateKind) -> ai_dx_mcp::api::GateOutput {
+    gate_with_budget(
+        &repo.to_string_lossy(),
+        kind,
+        false,
+        false,
+        None,
+        None,
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn require_witness_kinds_forces_witness_only_for_listed_kinds() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), r#"require_witness_kinds = ["flagship"]"#);
+
+    let out = run(dir.path(), GateKind::CiFast).await;
+    assert!(out.ok, "{:?} {:?}", out.error, out.validate.error);
+    assert!(out.witness_path.is_none(), "{:?}", out.witness_path);
+    assert!(
+        !dir.path()
+            .join(".agents/mcp/compas/witness/gate_ci_fast.json")
+            .exists()
+    );
+
+    let out = run(dir.path(), GateKind::Flagship).await;
+    assert!(out.ok, "{:?} {:?}", out.error, out.validate.error);
+    assert!(out.witness_path.is_some(), "{:?}", out.error);
+    assert!(
+        dir.path()
+            .join(".agents/mcp/compas/witness/gate_flagship.json")
+            .is_file()
+    );
+}
+
+#[tokio::test]
+async fn require_witness_true_covers_every_kind() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(
+        dir.path(),
+        "require_witness = true\nrequire_witness_kinds = [\"flagship\"]",
+    );
+
+    let out = run(dir.path(), GateKind::CiFast).await;
+    assert!(out.ok, "{:?} {:?}", out.error, out.validate.error);
+    assert!(out.witness_path.is_some(), "{:?}", out.error);
+}
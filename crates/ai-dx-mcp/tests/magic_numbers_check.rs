@@ -0,0 +1,73 @@
+use ai_dx_mcp::{
+    api::{ValidateMode, ViolationTier},
+    app::validate,
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default")).unwrap();
+    std::fs::create_dir_all(repo.join("src")).unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "magic numbers test plugin"
+
+[[checks.magic_numbers]]
+id = "magic"
+include_globs = ["src/**/*.rs"]
+allowed_values = [0, 1, -1, 60]
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join("src/session.rs"),
+        "pub const SESSION_TTL_SECS: u64 = 86400;\n\npub fn ttl_minutes() -> u64 {\n    SESSION_TTL_SECS / 60\n}\n\npub fn cache_ttl() -> u64 {\n    86400\n}\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn inline_literal_fires_but_const_definition_is_exempt() {
+    let dir = tempfile::tempdir().unwrap();
+    write_repo(dir.path());
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Ratchet,
+        false,
+        None,
+    );
+    let hits: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "magic_numbers.detected")
+        .collect();
+    assert_eq!(hits.len(), 1, "{:?}", out.violations);
+    assert_eq!(hits[0].tier, ViolationTier::Observation);
+    assert_eq!(hits[0].path.as_deref(), Some("src/session.rs"));
+    let details = hits[0].details.as_ref().unwrap();
+    assert_eq!(details["line"], 8);
+    assert_eq!(details["literal"], "86400");
+
+    assert!(out.ok, "{:?}", out.violations);
+    let verdict = out.verdict.expect("verdict");
+    assert_eq!(verdict.decision.blocking_count, 0);
+    assert!(verdict.decision.observation_count >= 1);
+}
@@ -0,0 +1,65 @@
+use ai_dx_mcp::{api::ValidateMode, app::validate};
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default")).unwrap();
+    std::fs::create_dir_all(repo.join("src")).unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "max params test plugin"
+
+[[checks.max_params]]
+id = "params"
+include_globs = ["src/**/*.rs"]
+max_params = 5
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join("src/lib.rs"),
+        "pub fn wide(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8) {}\n\npub fn ok(a: u8, b: u8) {}\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn validate_blocks_function_over_max_params() {
+    let dir = tempfile::tempdir().unwrap();
+    write_repo(dir.path());
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Ratchet,
+        false,
+        None,
+    );
+    let hits: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "max_params.exceeded")
+        .collect();
+    assert_eq!(hits.len(), 1, "{:?}", out.violations);
+    assert_eq!(hits[0].path.as_deref(), Some("src/lib.rs"));
+    assert!(hits[0].message.contains("wide"), "{}", hits[0].message);
+    assert!(!out.ok);
+    let verdict = out.verdict.expect("verdict");
+    assert!(verdict.decision.blocking_count >= 1);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use ai_dx_mcp::{
+    api::{ResponseMode, ValidateMode},
+    app::validate,
+    response::{finalize_validate, finalize_validate_with_limit},
+};
+use common::write_file;
+use std::path::Path;
+
+fn noisy_repo(repo_root: &Path) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "max_violations truncation test"
+
+[[checks.boundary]]
+id = "no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+message = "avoid unwrap"
+deny_regex = "\\.unwrap\\(\\)"
+
+[[checks.orphan_api]]
+id = "orphans"
+include_globs = ["src/**/*.rs"]
+blocking = true
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    );
+    for idx in 0..4 {
+        write_file(
+            &repo_root.join(format!("src/m{idx}.rs")),
+            &format!("pub fn orphan_{idx}() -> i32 {{ Some(1).unwrap() }}\n"),
+        );
+    }
+}
+
+#[test]
+fn max_violations_truncates_arrays_but_keeps_verdict() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    noisy_repo(dir.path());
+
+    let raw = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    );
+    let full = finalize_validate(raw.clone(), ResponseMode::Full);
+    let capped = finalize_validate_with_limit(raw, ResponseMode::Full, Some(3));
+
+    assert!(full.violations.len() > 3, "{:?}", full.violations);
+    assert_eq!(capped.violations.len(), 3);
+    assert!(capped.findings_v2.len() <= 3);
+    assert_ne!(full.violations.len(), capped.violations.len());
+    assert!(
+        capped
+            .violations
+            .iter()
+            .all(|v| v.code == "boundary.rule_violation"),
+        "high-severity boundary findings must rank first: {:?}",
+        capped.violations
+    );
+
+    let meta = capped.payload_meta.as_ref().expect("payload_meta");
+    assert!(meta.truncated);
+    assert_eq!(
+        meta.omitted.get("violations").copied(),
+        Some(full.violations.len() - 3)
+    );
+    assert_eq!(
+        meta.omitted.get("findings_v2").copied(),
+        Some(full.findings_v2.len() - 3)
+    );
+
+    assert_eq!(capped.ok, full.ok);
+    assert_eq!(
+        serde_json::to_value(&capped.verdict).unwrap(),
+        serde_json::to_value(&full.verdict).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&capped.risk_summary).unwrap(),
+        serde_json::to_value(&full.risk_summary).unwrap()
+    );
+}
+
+#[test]
+fn max_violations_adds_to_compact_omitted_counts() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    noisy_repo(dir.path());
+
+    let raw = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    );
+    let total = raw.violations.len();
+    let capped = finalize_validate_with_limit(raw, ResponseMode::Compact, Some(1));
+    let meta = capped.payload_meta.as_ref().expect("payload_meta");
+    assert_eq!(meta.mode, ResponseMode::Compact);
+    assert_eq!(meta.omitted.get("violations").copied(), Some(total - 1));
+}
@@ -540,3 +540,45 @@ async fn mcp_gate_async_job_mode_status_roundtrip() {
     client.close().await.ok();
     server.close().await.ok();
 }
+
+#[tokio::test]
+async fn mcp_rejects_repo_root_resolving_to_filesystem_root() {
+    let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+    let server_task = tokio::spawn(async move { AiDxServer::new().serve(server_io).await });
+    let mut client = ().serve(client_io).await.expect("serve client");
+    let mut server = server_task
+        .await
+        .expect("join server task")
+        .expect("serve server");
+
+    for (name, arguments) in [
+        (
+            "compas.validate",
+            serde_json::json!({ "repo_root": "/", "mode": "warn" }),
+        ),
+        ("compas.catalog", serde_json::json!({ "repo_root": "/" })),
+        (
+            "compas.gate",
+            serde_json::json!({ "repo_root": "/", "kind": "ci_fast", "dry_run": true }),
+        ),
+    ] {
+        let out = client
+            .call_tool(CallToolRequestParams {
+                meta: None,
+                name: name.into(),
+                arguments: arguments.as_object().cloned(),
+                task: None,
+            })
+            .await
+            .unwrap_or_else(|e| panic!("call {name}: {e}"));
+        let out: serde_json::Value = out.into_typed().expect("typed output");
+        assert_eq!(out["ok"], false, "{name}: {out}");
+        assert_eq!(
+            out["error"]["code"], "compas.invalid_repo_root",
+            "{name}: {out}"
+        );
+    }
+
+    client.close().await.ok();
+    server.close().await.ok();
+}
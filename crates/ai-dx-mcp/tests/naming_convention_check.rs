@@ -0,0 +1,83 @@
+use ai_dx_mcp::{api::ValidateMode, app::validate};
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default")).unwrap();
+    std::fs::create_dir_all(repo.join("src")).unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "naming convention test plugin"
+
+[[checks.naming_convention]]
+id = "naming"
+include_globs = ["src/**/*.rs"]
+
+[[checks.naming_convention.rules]]
+language = "rust"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+"#,
+    )
+    .unwrap();
+    std::fs::write(repo.join("src/lib.rs"), "pub struct Config;\n").unwrap();
+    std::fs::write(repo.join("src/HttpClient.rs"), "pub struct HttpClient;\n").unwrap();
+    std::fs::write(
+        repo.join("src/handlers.rs"),
+        "pub struct Handler;\n\npub enum request_kind { Get }\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn validate_blocks_miscased_file_and_type() {
+    let dir = tempfile::tempdir().unwrap();
+    write_repo(dir.path());
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Ratchet,
+        false,
+        None,
+    );
+    let hits: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "naming_convention.violation")
+        .collect();
+    assert_eq!(hits.len(), 2, "{:?}", out.violations);
+
+    let file_hit = hits
+        .iter()
+        .find(|v| v.path.as_deref() == Some("src/HttpClient.rs"))
+        .expect("mis-cased file name fires");
+    assert_eq!(file_hit.details.as_ref().unwrap()["kind"], "file_name");
+
+    let type_hit = hits
+        .iter()
+        .find(|v| v.path.as_deref() == Some("src/handlers.rs"))
+        .expect("out-of-convention type fires");
+    let details = type_hit.details.as_ref().unwrap();
+    assert_eq!(details["kind"], "type_name");
+    assert_eq!(details["name"], "request_kind");
+    assert_eq!(details["line"], 3);
+
+    assert!(!out.ok);
+    let verdict = out.verdict.expect("verdict");
+    assert!(verdict.decision.blocking_count >= 2);
+}
@@ -50,3 +50,48 @@ fn public_surface_max_is_observation() {
             .all(|v| { matches!(v.tier, ai_dx_mcp::api::ViolationTier::Observation) })
     );
 }
+
+fn write_two_surface_repo(repo: &std::path::Path, order: [&str; 2]) {
+    seed_repo(repo, "pub fn a() {}\npub fn b() {}\n");
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default")).unwrap();
+    let surfaces: String = order
+        .iter()
+        .map(|name| {
+            format!(
+                "[[checks.surface]]\nid = \"surface-{name}\"\nmax_items = 10\ninclude_globs = [\"crates/**/*.rs\"]\nbaseline_path = \".agents/mcp/compas/baselines/{name}.json\"\n[[checks.surface.rules]]\nregex = \"^pub\\\\s+fn\\\\s+([A-Za-z0-9_]+)\"\n\n"
+            )
+        })
+        .collect();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        format!(
+            "[plugin]\nid = \"default\"\ndescription = \"surface tie test\"\n\n{surfaces}[gate]\nci_fast = []\nci = []\nflagship = []\n"
+        ),
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        "[quality]\nmin_trust_score = 0\nmin_coverage_percent = 0.0\nallow_trust_drop = true\nallow_coverage_drop = true\nmax_weighted_risk_increase = 999\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn public_surface_summary_breaks_item_ties_by_baseline_path() {
+    for order in [["zeta", "alpha"], ["alpha", "zeta"]] {
+        let dir = tempdir().unwrap();
+        write_two_surface_repo(dir.path(), order);
+        let out = ai_dx_mcp::app::validate(
+            &dir.path().to_string_lossy(),
+            ai_dx_mcp::api::ValidateMode::Warn,
+            false,
+            None,
+        );
+        let summary = out.public_surface.expect("public_surface summary");
+        assert_eq!(summary.items_total, 2, "order={order:?}");
+        assert_eq!(
+            summary.baseline_path, ".agents/mcp/compas/baselines/alpha.json",
+            "order={order:?}"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use ai_dx_mcp::{
     api::{DecisionStatus, ValidateMode},
-    app::validate,
+    app::{validate, validate_baseline_dry_run, validate_baseline_migrate_only},
 };
 
 fn write_repo(repo: &std::path::Path, max_loc: usize) {
@@ -121,3 +121,178 @@ fn quality_delta_blocks_trust_regression_after_baseline() {
         "ratchet validate must be blocked on trust regression"
     );
 }
+
+#[test]
+fn baseline_dry_run_reports_trust_delta_without_writing() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo_root = dir.path();
+    let repo_root_str = repo_root.to_string_lossy().to_string();
+    let snapshot = repo_root.join(".agents/mcp/compas/baselines/quality_snapshot.json");
+
+    write_repo(repo_root, 100);
+    assert!(validate(&repo_root_str, ValidateMode::Warn, true, None).ok);
+    let before = std::fs::read_to_string(&snapshot).expect("read snapshot");
+
+    write_repo(repo_root, 1);
+    let out = validate_baseline_dry_run(&repo_root_str, ValidateMode::Ratchet, false);
+    let diff = out.baseline_diff.expect("baseline_diff");
+    assert!(diff.baseline_present);
+    assert!(diff.trust_score.delta < 0, "{:?}", diff.trust_score);
+    assert_eq!(
+        diff.trust_score.delta,
+        diff.trust_score.current - diff.trust_score.baseline
+    );
+    assert!(diff.config_hash_changed);
+    assert!(
+        diff.regressions
+            .iter()
+            .any(|v| v.code == "quality_delta.trust_regression"),
+        "{:?}",
+        diff.regressions
+    );
+
+    let after = std::fs::read_to_string(&snapshot).expect("read snapshot");
+    assert_eq!(before, after, "dry-run must not rewrite the snapshot");
+}
+
+#[test]
+fn baseline_migrate_only_turns_prior_baselines_into_snapshot() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo_root = dir.path();
+    let repo_root_str = repo_root.to_string_lossy().to_string();
+    let snapshot = repo_root.join(".agents/mcp/compas/baselines/quality_snapshot.json");
+
+    write_repo(repo_root, 100);
+    std::fs::create_dir_all(repo_root.join(".agents/mcp/compas/baselines"))
+        .expect("mkdir baselines");
+    std::fs::write(
+        repo_root.join(".agents/mcp/compas/baselines/loc.json"),
+        r#"{"files":{"src/lib.rs":3}}"#,
+    )
+    .expect("write prior loc baseline");
+
+    let out = validate_baseline_migrate_only(&repo_root_str);
+    assert!(out.ok, "{:?}", out.error);
+    assert_eq!(
+        out.snapshot_path,
+        ".agents/mcp/compas/baselines/quality_snapshot.json"
+    );
+    let reported = out.snapshot.expect("migrated snapshot");
+    assert_eq!(reported["loc_per_file"]["src/lib.rs"], 3);
+    let written: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&snapshot).expect("read snapshot"))
+            .expect("parse snapshot");
+    assert_eq!(written, reported);
+
+    let ratchet = validate(&repo_root_str, ValidateMode::Ratchet, false, None);
+    assert!(ratchet.ok, "{:?}", ratchet.violations);
+
+    let again = validate_baseline_migrate_only(&repo_root_str);
+    assert!(!again.ok);
+    assert_eq!(again.error.expect("error").code, "baseline.snapshot_exists");
+}
+
+#[test]
+fn config_hash_ignores_key_and_check_block_order() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo_root = dir.path();
+    let repo_root_str = repo_root.to_string_lossy().to_string();
+    write_repo(repo_root, 100);
+    let plugin_path = repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml");
+    let extra_check = r#"
+[[checks.loc]]
+id = "loc-tests"
+max_loc = 200
+include_globs = ["tests/**/*.rs"]
+exclude_globs = []
+baseline_path = ".agents/mcp/compas/baselines/loc_tests.json"
+"#;
+    let plugin = std::fs::read_to_string(&plugin_path).expect("read plugin.toml");
+    std::fs::write(&plugin_path, format!("{plugin}{extra_check}")).expect("write plugin.toml");
+
+    let baseline = validate(&repo_root_str, ValidateMode::Warn, true, None);
+    assert!(baseline.ok, "baseline validate: {:?}", baseline.error);
+
+    // Same checks: blocks swapped and keys reordered.
+    std::fs::write(
+        &plugin_path,
+        r#"[plugin]
+description = "quality_delta integration test plugin"
+id = "default"
+
+[[tools]]
+args = ["ok"]
+command = "echo"
+description = "No-op gate tool for integration regression checks"
+id = "noop"
+
+[gate]
+ci = []
+ci_fast = ["noop"]
+flagship = []
+
+[[checks.loc]]
+baseline_path = ".agents/mcp/compas/baselines/loc_tests.json"
+exclude_globs = []
+include_globs = ["tests/**/*.rs"]
+max_loc = 200
+id = "loc-tests"
+
+[[checks.loc]]
+include_globs = ["src/**/*.rs"]
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+max_loc = 100
+exclude_globs = []
+id = "loc-main"
+"#,
+    )
+    .expect("rewrite plugin.toml");
+
+    let after = validate(&repo_root_str, ValidateMode::Ratchet, false, None);
+    let codes: Vec<&str> = after.violations.iter().map(|v| v.code.as_str()).collect();
+    assert!(
+        !codes.contains(&"quality_delta.config_changed"),
+        "reordering must not change the config hash: {codes:?}"
+    );
+    assert!(after.ok, "{:?} {codes:?}", after.error);
+}
+
+#[test]
+fn config_hash_from_earlier_releases_is_accepted_until_rebaseline() {
+    // What releases before the canonical hash stored for `write_repo(_, 100)`'s checks.
+    const LEGACY_HASH: &str =
+        "sha256:4811310ad05b6e37230d9272ec3df1ab84789ab9faf1375a2d20dc2d2e038743";
+    let dir = tempfile::tempdir().expect("temp repo");
+    let repo_root = dir.path();
+    let repo_root_str = repo_root.to_string_lossy().to_string();
+    write_repo(repo_root, 100);
+    let baseline = validate(&repo_root_str, ValidateMode::Warn, true, None);
+    assert!(baseline.ok, "baseline validate: {:?}", baseline.error);
+
+    let snapshot_path = repo_root.join(".agents/mcp/compas/baselines/quality_snapshot.json");
+    let mut snapshot: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&snapshot_path).expect("read snapshot"))
+            .expect("parse snapshot");
+    let canonical_hash = snapshot["config_hash"].clone();
+    assert_ne!(canonical_hash, LEGACY_HASH);
+    snapshot["config_hash"] = LEGACY_HASH.into();
+    std::fs::write(&snapshot_path, snapshot.to_string()).expect("write snapshot");
+    let contract_path = repo_root.join(".agents/mcp/compas/quality_contract.toml");
+    let contract = std::fs::read_to_string(&contract_path).expect("read contract");
+    std::fs::write(
+        &contract_path,
+        format!("{contract}\n[governance]\nconfig_hash = \"{LEGACY_HASH}\"\n"),
+    )
+    .expect("write contract");
+
+    let after = validate(&repo_root_str, ValidateMode::Ratchet, false, None);
+    let codes: Vec<&str> = after.violations.iter().map(|v| v.code.as_str()).collect();
+    assert!(after.ok, "{:?} {codes:?}", after.error);
+
+    let rebaselined = validate(&repo_root_str, ValidateMode::Warn, true, None);
+    assert!(rebaselined.ok, "rebaseline: {:?}", rebaselined.error);
+    let snapshot: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&snapshot_path).expect("read snapshot"))
+            .expect("parse snapshot");
+    assert_eq!(snapshot["config_hash"], canonical_hash);
+}
@@ -0,0 +1,169 @@
+use ai_dx_mcp::{
+    api::{
+        DecisionStatus, GateKind, GateOutput, ResponseMode, ValidateMode, ValidateOutput,
+        ViolationTier,
+    },
+    app::{gate, validate},
+    response::finalize_validate,
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, oversized_files: usize, reporting: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "swamped reporting test plugin"
+
+[[checks.loc]]
+id = "loc-main"
+max_loc = 1
+include_globs = ["src/**/*.rs"]
+exclude_globs = []
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+
+[[tools]]
+id = "noop"
+description = "Succeeds without doing anything"
+command = "sh"
+args = ["-c", "true"]
+
+[gate]
+ci_fast = ["noop"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+
+[[escalation]]
+code = "loc.max_exceeded"
+threshold = 3
+{reporting}"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+    for i in 0..oversized_files {
+        std::fs::write(
+            repo.join(format!("src/m{i:02}.rs")),
+            "pub fn a() {}\npub fn b() {}\n",
+        )
+        .expect("write source");
+    }
+}
+
+/// Validate as the CLI/MCP print it: the collapse happens in the output layer.
+fn run(oversized_files: usize, reporting: &str) -> ValidateOutput {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), oversized_files, reporting);
+    finalize_validate(
+        validate(
+            &dir.path().to_string_lossy(),
+            ValidateMode::Ratchet,
+            false,
+            None,
+        ),
+        ResponseMode::Full,
+    )
+}
+
+async fn run_gate(oversized_files: usize, reporting: &str) -> GateOutput {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), oversized_files, reporting);
+    gate(
+        &dir.path().to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        false,
+    )
+    .await
+}
+
+#[test]
+fn below_threshold_payload_is_untouched() {
+    let out = run(12, "\n[reporting]\nswamped_threshold = 100\n");
+    assert!(!out.swamped);
+    assert!(out.swamped_summary.is_none());
+    assert!(out.violations.len() > 12, "{:?}", out.violations);
+}
+
+#[test]
+fn crossing_threshold_collapses_output_and_keeps_the_verdict() {
+    let full = run(12, "");
+    let out = run(12, "\n[reporting]\nswamped_threshold = 10\n");
+
+    let full_verdict = full.verdict.expect("full verdict");
+    let verdict = out.verdict.as_ref().expect("swamped verdict");
+    assert_eq!(full_verdict.decision.status, DecisionStatus::Blocked);
+    assert_eq!(verdict.decision.status, full_verdict.decision.status);
+    assert_eq!(
+        verdict.decision.blocking_count,
+        full_verdict.decision.blocking_count
+    );
+    assert_eq!(
+        verdict.decision.observation_count,
+        full_verdict.decision.observation_count
+    );
+    assert_eq!(out.ok, full.ok);
+
+    assert!(out.swamped);
+    let summary = out.swamped_summary.as_ref().expect("swamped summary");
+    assert_eq!(summary.threshold, 10);
+    assert_eq!(summary.violations_total, full.violations.len());
+    assert_eq!(summary.by_code["loc.max_exceeded"], 12);
+    assert_eq!(summary.by_code["escalation.threshold_reached"], 1);
+    assert_eq!(
+        summary.by_category.values().sum::<usize>(),
+        summary.violations_total
+    );
+    assert_eq!(summary.examples_kept, Some(out.violations.len()));
+    assert!(out.violations.len() < 10, "{:?}", out.violations);
+    assert!(out.findings_v2.len() <= out.violations.len());
+    assert!(verdict.decision.reasons.len() <= out.violations.len());
+    // Blocking examples come first so the reason for the verdict stays visible.
+    assert_eq!(out.violations[0].tier, ViolationTier::Blocking);
+    assert_eq!(out.violations[0].code, "escalation.threshold_reached");
+    assert_eq!(verdict.decision.reasons[0].tier, ViolationTier::Blocking);
+}
+
+#[tokio::test]
+async fn gate_verdict_ignores_the_swamped_threshold() {
+    let full = run_gate(12, "").await;
+    let swamped = run_gate(12, "\n[reporting]\nswamped_threshold = 10\n").await;
+
+    let full_decision = &full.verdict.as_ref().expect("full verdict").decision;
+    let decision = &swamped.verdict.as_ref().expect("swamped verdict").decision;
+    assert_eq!(full_decision.status, DecisionStatus::Blocked);
+    assert_eq!(decision.status, full_decision.status);
+    assert_eq!(decision.blocking_count, full_decision.blocking_count);
+    assert_eq!(decision.observation_count, full_decision.observation_count);
+    assert_eq!(decision.reasons.len(), full_decision.reasons.len());
+    assert_eq!(
+        swamped.validate.violations.len(),
+        full.validate.violations.len()
+    );
+    assert!(!swamped.validate.swamped);
+    let summary = swamped
+        .validate
+        .swamped_summary
+        .as_ref()
+        .expect("swamped summary");
+    assert_eq!(summary.examples_kept, None);
+}
@@ -0,0 +1,147 @@
+use ai_dx_mcp::{
+    api::{GateKind, ViolationTier},
+    app::gate,
+    repo::{RepoConfigError, load_repo_config},
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, aliases: &str, ci_fast: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        format!(
+            r#"[plugin]
+id = "default"
+description = "Tool alias test plugin"
+
+[[tools]]
+id = "unit-tests"
+description = "Renamed test tool"
+command = "echo"
+args = ["ok"]
+
+[canonical_tools.aliases]
+{aliases}
+
+[gate]
+ci_fast = {ci_fast}
+ci = []
+flagship = []
+"#
+        ),
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+"#,
+    )
+    .expect("write quality_contract.toml");
+}
+
+#[tokio::test]
+async fn gate_resolves_old_alias_to_renamed_tool() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), r#"test = "unit-tests""#, r#"["test"]"#);
+
+    let out = gate(
+        &dir.path().to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        false,
+    )
+    .await;
+    assert!(out.ok, "{:?}", out.error);
+    let tool_ids: Vec<&str> = out.receipts.iter().map(|r| r.tool_id.as_str()).collect();
+    assert_eq!(tool_ids, vec!["unit-tests"]);
+
+    let verdict = out.verdict.expect("verdict");
+    let reason = verdict
+        .decision
+        .reasons
+        .iter()
+        .find(|r| r.code == "gate.observation.tool_alias_used")
+        .expect("alias observation");
+    assert_eq!(reason.tier, ViolationTier::Observation);
+    assert_eq!(verdict.decision.blocking_count, 0);
+}
+
+#[tokio::test]
+async fn gate_rejects_alias_and_target_in_same_sequence() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(
+        dir.path(),
+        r#"test = "unit-tests""#,
+        r#"["test", "unit-tests"]"#,
+    );
+
+    let out = gate(
+        &dir.path().to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        false,
+    )
+    .await;
+    assert!(!out.ok);
+    assert_eq!(
+        out.error.map(|e| e.code),
+        Some("gate.duplicate_tool_id".to_string())
+    );
+}
+
+#[test]
+fn alias_to_unknown_or_shadowing_tool_fails_closed() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), r#"test = "missing""#, r#"["unit-tests"]"#);
+    match load_repo_config(dir.path()) {
+        Err(RepoConfigError::InvalidToolAlias { alias, .. }) => assert_eq!(alias, "test"),
+        other => panic!("expected InvalidToolAlias, got {other:?}"),
+    }
+
+    write_repo(
+        dir.path(),
+        r#"unit-tests = "unit-tests""#,
+        r#"["unit-tests"]"#,
+    );
+    match load_repo_config(dir.path()) {
+        Err(e @ RepoConfigError::InvalidToolAlias { .. }) => {
+            assert_eq!(e.code(), "config.invalid_tool_alias")
+        }
+        other => panic!("expected InvalidToolAlias, got {other:?}"),
+    }
+}
+
+#[test]
+fn plugin_canonical_tools_rejects_wiring_lists() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), r#"test = "unit-tests""#, r#"["unit-tests"]"#);
+    let plugin_toml = dir
+        .path()
+        .join(".agents/mcp/compas/plugins/default/plugin.toml");
+    let raw = std::fs::read_to_string(&plugin_toml).expect("read plugin.toml");
+    std::fs::write(
+        &plugin_toml,
+        raw.replace(
+            "[canonical_tools.aliases]",
+            "[canonical_tools]\nbuild = [\"unit-tests\"]\n\n[canonical_tools.aliases]",
+        ),
+    )
+    .expect("write plugin.toml");
+    match load_repo_config(dir.path()) {
+        Err(RepoConfigError::ParsePlugin { message, .. }) => {
+            assert!(message.contains("unknown field `build`"), "{message}")
+        }
+        other => panic!("expected ParsePlugin, got {other:?}"),
+    }
+}
@@ -1,13 +1,26 @@
 use ai_dx_mcp::{api::ValidateMode, app::validate};
 
+fn write_named_plugin(repo: &std::path::Path, plugin_id: &str, body: &str) {
+    let dir = repo.join(".agents/mcp/compas/plugins").join(plugin_id);
+    std::fs::create_dir_all(&dir).expect("mkdir plugin dir");
+    std::fs::write(dir.join("plugin.toml"), body).expect("write plugin.toml");
+}
+
 fn write_plugin(repo: &std::path::Path, body: &str) {
-    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
-        .expect("mkdir plugin dir");
-    std::fs::write(
-        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
-        body,
-    )
-    .expect("write plugin.toml");
+    write_named_plugin(repo, "default", body);
+}
+
+fn tool_budget_codes(repo: &std::path::Path) -> Vec<String> {
+    let out = validate(&repo.to_string_lossy(), ValidateMode::Strict, false, None);
+    assert!(out.error.is_none(), "{:?}", out.error);
+    let mut codes: Vec<String> = out
+        .violations
+        .iter()
+        .filter(|v| v.code.starts_with("tool_budget."))
+        .map(|v| v.code.clone())
+        .collect();
+    codes.sort();
+    codes
 }
 
 #[test]
@@ -60,7 +73,7 @@ max_checks_total = 10
     assert!(
         out.violations
             .iter()
-            .any(|v| v.code == "tool_budget.max_tools_total_exceeded"),
+            .any(|v| v.code == "tool_budget.total_exceeded"),
         "{:?}",
         out.violations
     );
@@ -112,3 +125,148 @@ max_checks_total = 1
         out.violations
     );
 }
+
+const THREE_TOOLS: &str = r#"
+[[tools]]
+id = "t1"
+description = "tool 1 for budget test"
+command = "echo"
+args = ["ok"]
+
+[[tools]]
+id = "t2"
+description = "tool 2 for budget test"
+command = "echo"
+args = ["ok"]
+
+[[tools]]
+id = "t3"
+description = "tool 3 for budget test"
+command = "echo"
+args = ["ok"]
+"#;
+
+#[test]
+fn tool_budget_total_dimension_fires_alone() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_plugin(
+        dir.path(),
+        &format!(
+            r#"[plugin]
+id = "default"
+description = "Total budget dimension test plugin"
+{THREE_TOOLS}
+[gate]
+ci_fast = ["t1"]
+ci = []
+flagship = []
+
+[[checks.tool_budget]]
+id = "tool-budget"
+max_tools_total = 2
+max_tools_per_plugin = 10
+max_gate_tools_per_kind = 10
+max_checks_total = 10
+max_plugins_with_tools = 1
+"#
+        ),
+    );
+    assert_eq!(
+        tool_budget_codes(dir.path()),
+        vec!["tool_budget.total_exceeded"]
+    );
+}
+
+#[test]
+fn tool_budget_gate_dimension_honors_per_kind_override() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_plugin(
+        dir.path(),
+        &format!(
+            r#"[plugin]
+id = "default"
+description = "Gate budget dimension test plugin"
+{THREE_TOOLS}
+[gate]
+ci_fast = ["t1", "t2", "t3"]
+ci = []
+flagship = ["t1", "t2", "t3"]
+
+[[checks.tool_budget]]
+id = "tool-budget"
+max_tools_total = 10
+max_tools_per_plugin = 10
+max_gate_tools_per_kind = 10
+max_checks_total = 10
+max_gate_tools = {{ flagship = 2 }}
+"#
+        ),
+    );
+    assert_eq!(
+        tool_budget_codes(dir.path()),
+        vec!["tool_budget.gate_exceeded"]
+    );
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Strict,
+        false,
+        None,
+    );
+    let gate = out
+        .violations
+        .iter()
+        .find(|v| v.code == "tool_budget.gate_exceeded")
+        .and_then(|v| v.details.clone())
+        .expect("gate_exceeded details");
+    assert_eq!(gate["gate_kind"], "flagship");
+    assert_eq!(gate["max"], 2);
+}
+
+#[test]
+fn tool_budget_plugin_fanout_dimension_fires_alone() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_plugin(
+        dir.path(),
+        r#"[plugin]
+id = "default"
+description = "Plugin fanout dimension test plugin"
+
+[[tools]]
+id = "t1"
+description = "tool 1 for budget test"
+command = "echo"
+args = ["ok"]
+
+[gate]
+ci_fast = ["t1"]
+ci = []
+flagship = []
+
+[[checks.tool_budget]]
+id = "tool-budget"
+max_tools_total = 10
+max_tools_per_plugin = 10
+max_gate_tools_per_kind = 10
+max_checks_total = 10
+max_plugins_with_tools = 1
+"#,
+    );
+    write_named_plugin(
+        dir.path(),
+        "extra",
+        r#"[plugin]
+id = "extra"
+description = "Second plugin contributing a tool"
+
+[[tools]]
+id = "t2"
+description = "tool 2 for budget test"
+command = "echo"
+args = ["ok"]
+"#,
+    );
+    assert_eq!(
+        tool_budget_codes(dir.path()),
+        vec!["tool_budget.plugin_fanout_exceeded"]
+    );
+}
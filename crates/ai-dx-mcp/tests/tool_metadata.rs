@@ -0,0 +1,124 @@
+use ai_dx_mcp::{api::ValidateMode, app::validate, repo::load_repo_config};
+
+const PLUGIN: &str = r#"
+[plugin]
+id = "default"
+description = "Tool metadata test plugin"
+tool_import_globs = []
+
+[[tools]]
+id = "lint"
+description = "Run the linter"
+command = "echo"
+args = ["lint"]
+
+[[tools]]
+id = "unit-tests"
+description = "Run the unit test suite with coverage collection enabled"
+command = "echo"
+args = ["test"]
+timeout_ms = 30000
+
+[gate]
+ci_fast = ["unit-tests"]
+ci = ["unit-tests"]
+flagship = ["unit-tests"]
+"#;
+
+fn write_repo(repo: &std::path::Path, tools_section: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default")).expect("mkdir plugin");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        PLUGIN,
+    )
+    .expect("write plugin");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+{tools_section}
+"#
+        ),
+    )
+    .expect("write quality_contract");
+}
+
+fn run(tools_section: &str) -> ai_dx_mcp::api::ValidateOutput {
+    let dir = tempfile::tempdir().expect("tmp");
+    write_repo(dir.path(), tools_section);
+    validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    )
+}
+
+#[test]
+fn under_described_tool_is_flagged() {
+    let out = run("[tools]\nmin_description_len = 24\n");
+    let flagged: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "tools.metadata_incomplete")
+        .collect();
+    assert_eq!(flagged.len(), 1, "{:?}", out.violations);
+    let details = flagged[0].details.as_ref().expect("details");
+    assert_eq!(details["tool_id"], "lint");
+    assert_eq!(details["plugin_id"], "default");
+    assert_eq!(details["missing"], serde_json::json!(["description"]));
+    assert_eq!(details["description_len"], 14);
+    assert_eq!(details["min_description_len"], 24);
+    assert_eq!(
+        flagged[0].path.as_deref(),
+        Some(".agents/mcp/compas/plugins/default/plugin.toml")
+    );
+}
+
+#[test]
+fn default_floor_matches_loader_and_stays_quiet() {
+    let out = run("");
+    assert!(
+        !out.violations
+            .iter()
+            .any(|v| v.code == "tools.metadata_incomplete"),
+        "{:?}",
+        out.violations
+    );
+}
+
+#[test]
+fn tool_without_timeout_inherits_contract_default_and_is_observed() {
+    let dir = tempfile::tempdir().expect("tmp");
+    write_repo(
+        dir.path(),
+        "[tools.defaults]\ntimeout_ms = 120000\nmax_stdout_bytes = 4096\n",
+    );
+
+    let cfg = load_repo_config(dir.path()).expect("load config");
+    assert_eq!(cfg.tools["lint"].timeout_ms, Some(120_000));
+    assert_eq!(cfg.tools["lint"].max_stdout_bytes, Some(4096));
+    assert_eq!(cfg.tools["unit-tests"].timeout_ms, Some(30_000));
+
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    );
+    let flagged: Vec<_> = out
+        .violations
+        .iter()
+        .filter(|v| v.code == "tools.timeout_defaulted")
+        .collect();
+    assert_eq!(flagged.len(), 1, "{:?}", out.violations);
+    let details = flagged[0].details.as_ref().expect("details");
+    assert_eq!(details["tool_id"], "lint");
+    assert_eq!(details["timeout_ms"], 120_000);
+}
@@ -0,0 +1,89 @@
+use ai_dx_mcp::{api::ValidateMode, app::validate};
+use std::path::Path;
+
+fn write_repo(repo: &Path, min_trust_grade: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "trust grade floor test plugin"
+
+[[checks.loc]]
+id = "loc-main"
+max_loc = 1
+include_globs = ["src/**/*.rs"]
+exclude_globs = []
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[governance]
+min_failure_modes = 0
+min_trust_grade = "{min_trust_grade}"
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+    for i in 0..4 {
+        std::fs::write(
+            repo.join(format!("src/m{i}.rs")),
+            "pub fn a() {}\npub fn b() {}\n",
+        )
+        .expect("write source");
+    }
+}
+
+fn run(min_trust_grade: &str) -> ai_dx_mcp::api::ValidateOutput {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), min_trust_grade);
+    validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Strict,
+        false,
+        None,
+    )
+}
+
+fn has_floor_violation(out: &ai_dx_mcp::api::ValidateOutput) -> bool {
+    out.violations
+        .iter()
+        .any(|v| v.code == "governance.trust_grade_below_minimum")
+}
+
+#[test]
+fn c_grade_repo_fails_b_floor() {
+    let out = run("B");
+    let trust = out.trust_score.as_ref().expect("trust score");
+    assert_eq!(trust.grade, "C", "score={}", trust.score);
+    assert!(has_floor_violation(&out), "{:?}", out.violations);
+    assert!(!out.ok);
+    let verdict = out.verdict.expect("verdict");
+    assert!(verdict.decision.blocking_count >= 1);
+}
+
+#[test]
+fn c_grade_repo_passes_f_floor() {
+    let out = run("F");
+    assert_eq!(out.trust_score.as_ref().expect("trust score").grade, "C");
+    assert!(!has_floor_violation(&out), "{:?}", out.violations);
+    assert!(out.ok, "{:?}", out.violations);
+}
@@ -0,0 +1,150 @@
+use ai_dx_mcp::{
+    api::{CheckSelection, ValidateMode, ValidateOutput},
+    app::{validate, validate_selected},
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "check selection test plugin"
+
+[[checks.loc]]
+id = "loc-main"
+max_loc = 1
+include_globs = ["src/**/*.rs"]
+baseline_path = ".agents/mcp/compas/baselines/loc.json"
+
+[[checks.boundary]]
+id = "rust-no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+deny_regex = 'unwrap\('
+message = "avoid unwrap in library code"
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        r#"[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[governance]
+mandatory_checks = ["boundary", "loc", "surface"]
+"#,
+    )
+    .expect("write quality_contract.toml");
+    std::fs::write(
+        repo.join("src/lib.rs"),
+        "pub fn parse(s: &str) -> u32 {\n    s.parse().unwrap()\n}\n",
+    )
+    .expect("write source");
+}
+
+fn run(selection: &CheckSelection) -> ValidateOutput {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path());
+    validate_selected(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        selection,
+    )
+}
+
+fn codes(out: &ValidateOutput) -> Vec<&str> {
+    out.violations.iter().map(|v| v.code.as_str()).collect()
+}
+
+fn only(kinds: &[&str]) -> CheckSelection {
+    CheckSelection {
+        only: kinds.iter().map(|k| k.to_string()).collect(),
+        ..CheckSelection::default()
+    }
+}
+
+#[test]
+fn full_run_reports_every_configured_check() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path());
+    let out = validate(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+    );
+    let codes = codes(&out);
+    assert!(codes.contains(&"loc.max_exceeded"), "{codes:?}");
+    assert!(codes.contains(&"boundary.rule_violation"), "{codes:?}");
+    assert!(out.partial_run.is_none());
+}
+
+#[test]
+fn only_loc_runs_loc_checks_but_keeps_contract_checks() {
+    let out = run(&only(&["loc"]));
+    let codes = codes(&out);
+    assert!(codes.contains(&"loc.max_exceeded"), "{codes:?}");
+    assert!(
+        !codes.iter().any(|c| c.starts_with("boundary.")),
+        "{codes:?}"
+    );
+    assert!(
+        codes.contains(&"config.mandatory_check_removed"),
+        "contract checks still run: {codes:?}"
+    );
+    let partial = out.partial_run.expect("partial run marker");
+    assert_eq!(partial.skipped_checks, vec!["boundary".to_string()]);
+    assert!(!partial.selection.skip_contract);
+}
+
+#[test]
+fn only_loc_with_skip_contract_reports_only_loc_violations() {
+    let out = run(&CheckSelection {
+        skip_contract: true,
+        ..only(&["loc"])
+    });
+    let codes = codes(&out);
+    assert!(!codes.is_empty());
+    assert!(codes.iter().all(|c| c.starts_with("loc.")), "{codes:?}");
+    assert!(
+        out.partial_run
+            .expect("partial run marker")
+            .selection
+            .skip_contract
+    );
+}
+
+#[test]
+fn skip_leaves_out_named_kinds() {
+    let out = run(&CheckSelection {
+        skip: vec!["loc".to_string()],
+        ..CheckSelection::default()
+    });
+    let codes = codes(&out);
+    assert!(codes.contains(&"boundary.rule_violation"), "{codes:?}");
+    assert!(!codes.iter().any(|c| c.starts_with("loc.")), "{codes:?}");
+}
+
+#[test]
+fn unknown_check_kind_is_rejected() {
+    let out = run(&only(&["lints"]));
+    let err = out.error.expect("selection error");
+    assert_eq!(err.code, "config.check_selection_invalid");
+    assert!(err.message.contains("lints"), "{}", err.message);
+}
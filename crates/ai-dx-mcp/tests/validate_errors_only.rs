@@ -0,0 +1,191 @@
+use ai_dx_mcp::{
+    api::{CheckSelection, GateKind, ValidateMode, ValidateOutput, ViolationTier},
+    app::{gate, validate, validate_errors_only},
+};
+use std::path::Path;
+
+fn write_repo(repo: &Path, source: &str, gate_section: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::create_dir_all(repo.join("src")).expect("mkdir src dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "errors-only test plugin"
+
+[[tools]]
+id = "echo-ok"
+description = "Always passes"
+command = "sh"
+args = ["-c", "echo ok"]
+
+[[checks.boundary]]
+id = "rust-no-unwrap"
+include_globs = ["src/**/*.rs"]
+exclude_globs = []
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+deny_regex = 'unwrap\('
+message = "avoid unwrap in library code"
+
+[[checks.naming_convention]]
+id = "naming"
+include_globs = ["src/**/*.rs"]
+
+[[checks.naming_convention.rules]]
+language = "rust"
+tier = "observation"
+
+[gate]
+ci_fast = ["echo-ok"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+
+[proof]
+require_witness = false
+
+{gate_section}
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+    // `BadName` trips the observation-tier naming rule in every scenario.
+    std::fs::write(repo.join("src/BadName.rs"), source).expect("write source");
+}
+
+fn run_both(source: &str) -> (ValidateOutput, ValidateOutput) {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), source, "");
+    let root = dir.path().to_string_lossy().to_string();
+    let full = validate(&root, ValidateMode::Ratchet, false, None);
+    let light = validate_errors_only(
+        &root,
+        ValidateMode::Ratchet,
+        false,
+        &CheckSelection::default(),
+    );
+    (full, light)
+}
+
+fn assert_insights_omitted(out: &ValidateOutput) {
+    assert!(out.findings_v2.is_empty());
+    assert!(out.risk_summary.is_none());
+    assert!(out.coverage.is_none());
+    assert!(out.trust_score.is_none());
+    assert!(out.quality_posture.is_none());
+    assert!(out.agent_digest.is_none());
+    assert!(
+        out.violations
+            .iter()
+            .all(|v| v.tier == ViolationTier::Blocking)
+    );
+}
+
+fn codes(out: &ValidateOutput, tier: ViolationTier) -> Vec<String> {
+    let mut codes: Vec<String> = out
+        .violations
+        .iter()
+        .filter(|v| v.tier == tier)
+        .map(|v| v.code.clone())
+        .collect();
+    codes.sort();
+    codes
+}
+
+#[test]
+fn errors_only_matches_full_decision_when_blocked() {
+    let (full, light) = run_both("pub fn parse(s: &str) -> u32 {\n    s.parse().unwrap()\n}\n");
+    assert!(!full.ok, "{:?}", full.violations);
+    assert_eq!(light.ok, full.ok);
+    assert_eq!(
+        light.verdict.as_ref().map(|v| v.decision.status),
+        full.verdict.as_ref().map(|v| v.decision.status)
+    );
+    assert!(!codes(&full, ViolationTier::Observation).is_empty());
+    assert!(full.coverage.is_some() && full.agent_digest.is_some());
+
+    assert_insights_omitted(&light);
+    assert_eq!(
+        codes(&light, ViolationTier::Blocking),
+        codes(&full, ViolationTier::Blocking)
+    );
+    assert!(
+        codes(&light, ViolationTier::Blocking).contains(&"boundary.rule_violation".to_string())
+    );
+}
+
+#[test]
+fn errors_only_matches_full_decision_when_passing() {
+    let (full, light) = run_both("pub fn ok() {}\n");
+    assert!(full.ok, "{:?}", full.violations);
+    assert!(light.ok, "{:?}", light.violations);
+    assert!(light.violations.is_empty());
+    assert_insights_omitted(&light);
+}
+
+#[tokio::test]
+async fn gate_preflight_can_opt_into_errors_only() {
+    for (source, passes) in [
+        ("pub fn ok() {}\n", true),
+        ("pub fn f() -> u8 { None.unwrap() }\n", false),
+    ] {
+        let mut results = vec![];
+        for gate_section in ["", "[gate]\npreflight_lean = true\n"] {
+            let dir = tempfile::tempdir().expect("temp repo");
+            write_repo(dir.path(), source, gate_section);
+            let out = gate(
+                &dir.path().to_string_lossy(),
+                GateKind::CiFast,
+                false,
+                false,
+            )
+            .await;
+            results.push(out);
+        }
+        let (full, light) = (&results[0], &results[1]);
+        assert_eq!(
+            full.ok, passes,
+            "{:?} {:?}",
+            full.error, full.validate.error
+        );
+        assert_eq!(light.ok, full.ok, "{:?} / {:?}", light.error, full.error);
+        let decision = |out: &ai_dx_mcp::api::GateOutput| {
+            serde_json::to_value(&out.verdict.as_ref().expect("verdict").decision)
+                .expect("serialize decision")
+        };
+        assert_eq!(decision(light), decision(full));
+        assert_eq!(light.all_findings.len(), full.all_findings.len());
+        assert!(!full.all_findings.is_empty());
+        assert_eq!(
+            codes(&light.validate, ViolationTier::Observation),
+            codes(&full.validate, ViolationTier::Observation)
+        );
+
+        assert!(full.validate.coverage.is_some());
+        let preflight = &light.validate;
+        assert_eq!(preflight.findings_v2.len(), full.validate.findings_v2.len());
+        assert!(preflight.risk_summary.is_none());
+        assert!(preflight.coverage.is_none());
+        assert!(preflight.trust_score.is_none());
+        assert!(preflight.quality_posture.is_none());
+        assert!(preflight.agent_digest.is_none());
+    }
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use ai_dx_mcp::{api::ValidateMode, app::validate_with_timings};
+use common::write_file;
+use std::path::Path;
+
+fn repo_with_checks(repo_root: &Path) {
+    write_file(
+        &repo_root.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"
+[plugin]
+id = "default"
+description = "validate timings test"
+
+[[checks.boundary]]
+id = "no-unwrap"
+include_globs = ["src/**/*.rs"]
+
+[[checks.boundary.rules]]
+id = "no-unwrap"
+message = "avoid unwrap"
+deny_regex = "\\.unwrap\\(\\)"
+
+[[checks.complexity_budget]]
+id = "cx"
+include_globs = ["src/**/*.rs"]
+max_function_lines = 80
+max_cyclomatic = 15
+max_cognitive = 20
+
+[gate]
+ci_fast = []
+ci = []
+flagship = []
+"#,
+    );
+    write_file(&repo_root.join("src/lib.rs"), "pub fn one() -> i32 { 1 }\n");
+}
+
+#[test]
+fn timings_are_populated_per_check_when_requested() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    repo_with_checks(dir.path());
+
+    let out = validate_with_timings(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+        true,
+    );
+    let timings = out.timings_ms.expect("timings_ms must be present");
+    for key in ["boundary", "complexity_budget", "total"] {
+        assert!(timings.contains_key(key), "missing {key}: {timings:?}");
+    }
+    assert!(!timings.contains_key("loc"), "{timings:?}");
+}
+
+#[test]
+fn timings_are_absent_by_default() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    repo_with_checks(dir.path());
+
+    let out = validate_with_timings(
+        &dir.path().to_string_lossy(),
+        ValidateMode::Warn,
+        false,
+        None,
+        false,
+    );
+    assert!(out.timings_ms.is_none());
+    let payload = serde_json::to_value(&out).expect("serialize");
+    assert!(payload.get("timings_ms").is_none());
+}
@@ -0,0 +1,122 @@
+use ai_dx_mcp::{api::GateKind, app::gate_with_budget};
+use std::path::Path;
+
+fn write_repo(repo: &Path, proof: &str) {
+    std::fs::create_dir_all(repo.join(".agents/mcp/compas/plugins/default"))
+        .expect("mkdir plugin dir");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/plugins/default/plugin.toml"),
+        r#"[plugin]
+id = "default"
+description = "witness dir test plugin"
+
+[[tools]]
+id = "echo-tool"
+description = "Always passes"
+command = "echo"
+args = ["ok"]
+
+[gate]
+ci_fast = ["echo-tool"]
+ci = []
+flagship = []
+"#,
+    )
+    .expect("write plugin.toml");
+    std::fs::write(
+        repo.join(".agents/mcp/compas/quality_contract.toml"),
+        format!(
+            r#"
+[quality]
+min_trust_score = 0
+min_coverage_percent = 0.0
+allow_trust_drop = true
+allow_coverage_drop = true
+max_weighted_risk_increase = 999
+
+[receipt_defaults]
+min_duration_ms = 0
+min_stdout_bytes = 0
+
+{proof}
+"#
+        ),
+    )
+    .expect("write quality_contract.toml");
+}
+
+#[tokio::test]
+async fn witness_dir_override_redirects_witness_and_chain() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), "");
+    let out = gate_with_budget(
+        &dir.path().to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        true,
+        None,
+        Some("artifacts/witness"),
+        None,
+    )
+    .await;
+    assert!(out.ok, "gate failed: {:?}", out.error);
+    assert_eq!(
+        out.witness_path.as_deref(),
+        Some("artifacts/witness/gate_ci_fast.json")
+    );
+    assert!(
+        dir.path()
+            .join("artifacts/witness/gate_ci_fast.json")
+            .is_file()
+    );
+    assert!(dir.path().join("artifacts/witness/chain.json").is_file());
+    assert!(
+        !dir.path()
+            .join(".agents/mcp/compas/witness/gate_ci_fast.json")
+            .exists()
+    );
+}
+
+#[tokio::test]
+async fn witness_dir_from_contract_is_used_without_override() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), "[proof]\nwitness_dir = \"out/proof\"\n");
+    let out = gate_with_budget(
+        &dir.path().to_string_lossy(),
+        GateKind::CiFast,
+        false,
+        true,
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert!(out.ok, "gate failed: {:?}", out.error);
+    assert_eq!(
+        out.witness_path.as_deref(),
+        Some("out/proof/gate_ci_fast.json")
+    );
+    assert!(dir.path().join("out/proof/gate_ci_fast.json").is_file());
+}
+
+#[tokio::test]
+async fn witness_dir_escaping_repo_root_fails_closed() {
+    let dir = tempfile::tempdir().expect("temp repo");
+    write_repo(dir.path(), "");
+    for bad in ["../outside", "/tmp/witness", "."] {
+        let out = gate_with_budget(
+            &dir.path().to_string_lossy(),
+            GateKind::CiFast,
+            false,
+            true,
+            None,
+            Some(bad),
+            None,
+        )
+        .await;
+        assert!(!out.ok, "{bad} must be rejected");
+        let err = out.error.expect("error");
+        assert_eq!(err.code, "witness.dir_invalid", "{bad}: {err:?}");
+        assert!(out.witness_path.is_none());
+    }
+}